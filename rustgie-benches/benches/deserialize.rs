@@ -0,0 +1,45 @@
+﻿use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rustgie_benches::fixtures;
+use rustgie_types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie_types::destiny::entities::items::{DestinyItemComponent, DestinyItemInstanceComponent};
+use rustgie_types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup;
+use rustgie_types::destiny::responses::DestinyProfileResponse;
+use rustgie_types::DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// Times parsing `json` as `T` under `group/name`, reporting throughput in bytes.
+fn bench_parse<T: DeserializeOwned>(c: &mut Criterion, group: &str, name: &str, json: &str) {
+    let mut group = c.benchmark_group(group);
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_with_input(BenchmarkId::from_parameter(name), json, |b, json| {
+        b.iter(|| serde_json::from_str::<T>(json).expect("benchmark fixtures parse"))
+    });
+    group.finish();
+}
+
+fn profile(c: &mut Criterion) {
+    bench_parse::<DestinyProfileResponse>(c, "profile", "full_response", &fixtures::profile_response());
+}
+
+fn item_definitions(c: &mut Criterion) {
+    bench_parse::<HashMap<u32, DestinyInventoryItemDefinition>>(c, "item_definitions", "table", &fixtures::item_definition_table());
+}
+
+fn dictionary_keys(c: &mut Criterion) {
+    bench_parse::<DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent>(
+        c,
+        "dictionary_keys",
+        "int64_keyed",
+        &fixtures::item_instances(),
+    );
+    bench_parse::<Vec<DestinyItemInstanceComponent>>(c, "dictionary_keys", "values_only", &fixtures::item_instance_values());
+}
+
+fn enum_heavy(c: &mut Criterion) {
+    bench_parse::<Vec<DestinyItemComponent>>(c, "enum_heavy", "item_components", &fixtures::item_components());
+    bench_parse::<Vec<DestinyHistoricalStatsPeriodGroup>>(c, "enum_heavy", "activities", &fixtures::activities());
+}
+
+criterion_group!(benches, profile, item_definitions, dictionary_keys, enum_heavy);
+criterion_main!(benches);
@@ -0,0 +1,112 @@
+﻿//! Comparing criterion's results with the committed baseline in `baseline.json`.
+//!
+//! The baseline holds each benchmark's mean time in nanoseconds, keyed by its criterion ID (`group/function`). Criterion
+//! writes the latest run of each benchmark to `target/criterion/<id>/new/estimates.json`, and [`read_results`] collects
+//! those means under the same IDs.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// The regression threshold `compare` uses when none is given, in percent.
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Each benchmark's mean time in nanoseconds from a run everything else is compared with.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq)]
+pub struct Baseline {
+    pub benchmarks: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+    /// Reads a baseline written by [`Baseline::save`].
+    pub fn load(path: &Path) -> Result<Baseline> {
+        let contents = std::fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("{} is not a benchmark baseline", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n").with_context(|| format!("Could not write {}", path.display()))
+    }
+}
+
+/// Where the committed baseline lives.
+pub fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("baseline.json")
+}
+
+/// Where `cargo bench` leaves criterion's results: `criterion` in the workspace's target directory, or in `CARGO_TARGET_DIR` if it's set.
+pub fn criterion_dir() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("target"));
+    target.join("criterion")
+}
+
+/// The mean time in nanoseconds of the latest run of every benchmark under `dir`, keyed by benchmark ID.
+pub fn read_results(dir: &Path) -> Result<BTreeMap<String, f64>> {
+    let mut results = BTreeMap::new();
+    collect_results(dir, "", &mut results)?;
+    Ok(results)
+}
+
+fn collect_results(dir: &Path, id: &str, results: &mut BTreeMap<String, f64>) -> Result<()> {
+    let estimates = dir.join("new").join("estimates.json");
+    if !id.is_empty() && estimates.is_file() {
+        let contents = std::fs::read_to_string(&estimates).with_context(|| format!("Could not read {}", estimates.display()))?;
+        let parsed: Value = serde_json::from_str(&contents).with_context(|| format!("{} is not JSON", estimates.display()))?;
+        let mean = parsed["mean"]["point_estimate"]
+            .as_f64()
+            .ok_or_else(|| anyhow!("{} has no mean", estimates.display()))?;
+        results.insert(id.to_string(), mean);
+    }
+
+    let entries = std::fs::read_dir(dir).with_context(|| format!("Could not read {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        // Criterion keeps each run's data in these, and its HTML reports in `report`; none of them hold benchmarks.
+        if !entry.file_type()?.is_dir() || ["new", "base", "change", "report"].contains(&name.as_str()) {
+            continue;
+        }
+        let child_id = if id.is_empty() { name } else { format!("{id}/{name}") };
+        collect_results(&entry.path(), &child_id, results)?;
+    }
+    Ok(())
+}
+
+/// One benchmark's mean in the baseline and in the latest results, either of which may be missing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub id: String,
+    pub baseline_ns: Option<f64>,
+    pub current_ns: Option<f64>,
+}
+
+impl Comparison {
+    /// How much slower the latest run was than the baseline, in percent; negative when it was faster.
+    pub fn change_percent(&self) -> Option<f64> {
+        match (self.baseline_ns, self.current_ns) {
+            (Some(baseline), Some(current)) if baseline > 0.0 => Some((current - baseline) / baseline * 100.0),
+            _ => None,
+        }
+    }
+
+    /// Whether the latest run was more than `threshold_percent` slower than the baseline.
+    pub fn regressed(&self, threshold_percent: f64) -> bool {
+        matches!(self.change_percent(), Some(change) if change > threshold_percent)
+    }
+}
+
+/// Every benchmark in either `baseline` or `results`, in ID order.
+pub fn compare(baseline: &Baseline, results: &BTreeMap<String, f64>) -> Vec<Comparison> {
+    let ids: BTreeSet<&String> = baseline.benchmarks.keys().chain(results.keys()).collect();
+    ids.into_iter()
+        .map(|id| Comparison {
+            id: id.clone(),
+            baseline_ns: baseline.benchmarks.get(id).copied(),
+            current_ns: results.get(id).copied(),
+        })
+        .collect()
+}
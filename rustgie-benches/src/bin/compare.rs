@@ -0,0 +1,74 @@
+﻿#![forbid(unsafe_code)]
+
+//! Compares the latest `cargo bench -p rustgie-benches` run with `baseline.json`, failing if anything regressed.
+//!
+//! ```text
+//! cargo run -p rustgie-benches --bin compare -- [--threshold <percent>] [--criterion-dir <dir>] [--save]
+//! ```
+//!
+//! `--threshold` is how much slower than the baseline a benchmark's mean may get, in percent, and defaults to 10.
+//! `--save` rewrites the baseline from the latest results instead of comparing with it.
+
+use anyhow::{anyhow, Context, Result};
+use rustgie_benches::baseline::{baseline_path, compare, criterion_dir, read_results, Baseline, DEFAULT_THRESHOLD_PERCENT};
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: compare [--threshold <percent>] [--criterion-dir <dir>] [--save]";
+
+fn main() -> Result<()> {
+    let mut threshold = DEFAULT_THRESHOLD_PERCENT;
+    let mut results_dir = criterion_dir();
+    let mut save = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threshold" => {
+                let value = args.next().ok_or_else(|| anyhow!(USAGE))?;
+                threshold = value.parse().with_context(|| format!("{value} is not a percentage"))?;
+            }
+            "--criterion-dir" => results_dir = PathBuf::from(args.next().ok_or_else(|| anyhow!(USAGE))?),
+            "--save" => save = true,
+            _ => return Err(anyhow!(USAGE)),
+        }
+    }
+
+    let results = read_results(&results_dir)?;
+    if results.is_empty() {
+        return Err(anyhow!("No benchmark results in {}; run `cargo bench -p rustgie-benches` first", results_dir.display()));
+    }
+    let path = baseline_path();
+    if save {
+        Baseline { benchmarks: results }.save(&path)?;
+        println!("Wrote the baseline to {}", path.display());
+        return Ok(());
+    }
+
+    let baseline = Baseline::load(&path)?;
+    let mut failures = 0;
+    for comparison in compare(&baseline, &results) {
+        let status = match (comparison.baseline_ns, comparison.current_ns) {
+            (None, _) => "new, not in the baseline".to_string(),
+            (_, None) => {
+                failures += 1;
+                "MISSING from the results".to_string()
+            }
+            _ => {
+                let change = comparison.change_percent().unwrap_or_default();
+                if comparison.regressed(threshold) {
+                    failures += 1;
+                    format!("{change:+.1}% REGRESSED")
+                } else {
+                    format!("{change:+.1}%")
+                }
+            }
+        };
+        println!("{:<40} {status}", comparison.id);
+    }
+
+    if failures > 0 {
+        return Err(anyhow!(
+            "{failures} benchmarks regressed by more than {threshold}% or are missing from the results"
+        ));
+    }
+    Ok(())
+}
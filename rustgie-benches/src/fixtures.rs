@@ -0,0 +1,143 @@
+﻿//! Benchmark inputs, built from the seed files in `fixtures/`.
+//!
+//! Each function returns JSON text, so the benchmarks time parsing and nothing else. The seeds are real responses and
+//! definitions trimmed to one of each kind of entry; scaling them up copies those entries with fresh IDs and hashes.
+
+use serde_json::{Map, Value};
+
+/// The number of entries in [`item_definition_table`], about a tenth of the live manifest's table.
+pub const ITEM_DEFINITION_COUNT: usize = 5000;
+/// The number of items in the vault of [`profile_response`].
+pub const VAULT_ITEM_COUNT: usize = 600;
+/// The number of items in each character's inventory in [`profile_response`].
+pub const CHARACTER_ITEM_COUNT: usize = 120;
+/// The number of entries in [`item_instances`], [`item_instance_values`] and [`item_components`].
+pub const ITEM_COUNT: usize = 2000;
+/// The number of activities in [`activities`].
+pub const ACTIVITY_COUNT: usize = 1000;
+
+/// The first instance ID given to copied items, far from the seed's own.
+const FIRST_COPIED_INSTANCE_ID: i64 = 6917529900001000000;
+
+fn seed(json: &str) -> Value {
+    serde_json::from_str(json).expect("benchmark seeds are valid JSON")
+}
+
+fn profile_seed() -> Value {
+    seed(include_str!("../fixtures/profile.json"))
+}
+
+/// A `DestinyProfileResponse` for a player with [`VAULT_ITEM_COUNT`] items in the vault, [`CHARACTER_ITEM_COUNT`] in each
+/// character's inventory, and the instances, stats, sockets and plug objectives of every instanced item.
+pub fn profile_response() -> String {
+    let mut profile = profile_seed();
+    let mut components = profile["itemComponents"].take();
+    let mut next_instance_id = FIRST_COPIED_INSTANCE_ID;
+
+    fill_items(&mut profile["profileInventory"]["data"]["items"], VAULT_ITEM_COUNT, &mut components, &mut next_instance_id);
+    if let Some(inventories) = profile["characterInventories"]["data"].as_object_mut() {
+        for inventory in inventories.values_mut() {
+            fill_items(&mut inventory["items"], CHARACTER_ITEM_COUNT, &mut components, &mut next_instance_id);
+        }
+    }
+    profile["itemComponents"] = components;
+    profile.to_string()
+}
+
+/// Grows `items` to `count` by copying its entries in turn. Copies of instanced items get new instance IDs, and copies of
+/// their entries in every item component.
+fn fill_items(items: &mut Value, count: usize, components: &mut Value, next_instance_id: &mut i64) {
+    let items = match items.as_array_mut() {
+        Some(items) => items,
+        None => return,
+    };
+    let templates = items.clone();
+    for template in templates.iter().cycle().take(count.saturating_sub(items.len())) {
+        let mut item = template.clone();
+        if let Some(instance_id) = template["itemInstanceId"].as_str() {
+            let copy_id = next_instance_id.to_string();
+            *next_instance_id += 1;
+            item["itemInstanceId"] = Value::from(copy_id.as_str());
+            if let Some(components) = components.as_object_mut() {
+                for component in components.values_mut() {
+                    if let Some(data) = component["data"].as_object_mut() {
+                        if let Some(entry) = data.get(instance_id).cloned() {
+                            data.insert(copy_id.clone(), entry);
+                        }
+                    }
+                }
+            }
+        }
+        items.push(item);
+    }
+}
+
+/// The manifest's `DestinyInventoryItemDefinition` table as one JSON object keyed by hash, with [`ITEM_DEFINITION_COUNT`]
+/// weapons, armor, plugs, emblems and consumables.
+pub fn item_definition_table() -> String {
+    let templates: Vec<Value> = match seed(include_str!("../fixtures/item_definitions.json")) {
+        Value::Object(definitions) => definitions.into_iter().map(|(_, definition)| definition).collect(),
+        _ => panic!("the item definition seed is an object keyed by hash"),
+    };
+    let mut table = Map::new();
+    for (index, template) in templates.iter().cycle().take(ITEM_DEFINITION_COUNT).enumerate() {
+        let hash = 100_000_000 + index as u32 * 7919;
+        let mut definition = template.clone();
+        definition["hash"] = Value::from(hash);
+        definition["index"] = Value::from(index);
+        table.insert(hash.to_string(), definition);
+    }
+    Value::Object(table).to_string()
+}
+
+/// The seed profile's item instances, repeated to `count` entries.
+fn instance_seeds(count: usize) -> impl Iterator<Item = Value> {
+    let profile = profile_seed();
+    let instances: Vec<Value> = profile["itemComponents"]["instances"]["data"]
+        .as_object()
+        .map(|instances| instances.values().cloned().collect())
+        .unwrap_or_default();
+    instances.into_iter().cycle().take(count)
+}
+
+/// A `DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent` with [`ITEM_COUNT`] entries, whose keys are
+/// instance IDs sent as strings.
+pub fn item_instances() -> String {
+    let data: Map<String, Value> = instance_seeds(ITEM_COUNT)
+        .zip(FIRST_COPIED_INSTANCE_ID..)
+        .map(|(instance, id)| (id.to_string(), instance))
+        .collect();
+    serde_json::json!({ "data": data, "privacy": 1 }).to_string()
+}
+
+/// The same instances as [`item_instances`] in a plain array, to tell the cost of the string keys from that of the values.
+pub fn item_instance_values() -> String {
+    Value::Array(instance_seeds(ITEM_COUNT).collect()).to_string()
+}
+
+/// [`ITEM_COUNT`] `DestinyItemComponent`s, whose fields are mostly enums and flags.
+pub fn item_components() -> String {
+    let profile = profile_seed();
+    let templates = profile["profileInventory"]["data"]["items"].as_array().cloned().unwrap_or_default();
+    Value::Array(templates.into_iter().cycle().take(ITEM_COUNT).collect()).to_string()
+}
+
+/// [`ACTIVITY_COUNT`] `DestinyHistoricalStatsPeriodGroup`s, as a page of activity history returns them, each with an
+/// activity mode, a list of modes and a membership type.
+pub fn activities() -> String {
+    let activities = match seed(include_str!("../fixtures/activities.json")) {
+        Value::Array(activities) => activities,
+        _ => panic!("the activity seed is an array"),
+    };
+    let activities: Vec<Value> = activities
+        .into_iter()
+        .cycle()
+        .take(ACTIVITY_COUNT)
+        .zip(13000000001i64..)
+        .map(|(mut activity, instance_id)| {
+            activity["activityDetails"]["instanceId"] = Value::from(instance_id.to_string());
+            activity
+        })
+        .collect();
+    Value::Array(activities).to_string()
+}
@@ -0,0 +1,24 @@
+﻿#![forbid(unsafe_code)]
+
+//! Deserialization benchmarks for `rustgie_types`, and a check that they haven't regressed against a committed baseline.
+//!
+//! The benchmarks in `benches/deserialize.rs` cover the hot paths of parsing Bungie's responses: a large
+//! `DestinyProfileResponse`, a 5,000-entry inventory item definition table, dictionary components keyed by `int64`
+//! strings, and structs made mostly of enums and flags. Their inputs come from [`fixtures`], which scales the small seed
+//! files in `fixtures/` up to realistic sizes so the repository doesn't carry megabytes of JSON.
+//!
+//! # Checking for regressions
+//!
+//! Before a release, or after regenerating the types or changing their serde attributes, run
+//!
+//! ```text
+//! cargo bench -p rustgie-benches
+//! cargo run -p rustgie-benches --bin compare -- --threshold 10
+//! ```
+//!
+//! `compare` reads criterion's results and exits with an error if any benchmark's mean is more than the threshold percent
+//! slower than in `baseline.json`. Timings only compare on the same machine, so record a baseline on the machine releases
+//! are checked on with `--save`, and commit it. See [`baseline`].
+
+pub mod baseline;
+pub mod fixtures;
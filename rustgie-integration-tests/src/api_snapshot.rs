@@ -0,0 +1,311 @@
+﻿//! A snapshot of `rustgie_types`' public API, used to catch breaking changes in regenerated types before they are published.
+//!
+//! [`extract`] parses the crate's sources with `syn`, starting at `lib.rs` and following `pub mod` declarations, and lists
+//! every public item as one line: modules, structs and their public fields, enums and their variants, functions, trait
+//! methods, trait impls, constants, type aliases, re-exports, and the arguments of item-generating macro invocations.
+//! The committed snapshot lives in `api/rustgie_types.api`, and the `api_snapshot` test fails whenever it differs from the
+//! sources, listing each difference as additive or breaking.
+//!
+//! # Semver policy
+//!
+//! While `rustgie_types` is 0.x, a release with any breaking entry bumps the minor version and a release with only additive
+//! entries bumps the patch version. Breaking means anything removed or changed, a new variant on an exhaustive enum, a new
+//! field on a struct that callers can build with a struct literal, or a new required trait method.
+//!
+//! # Updating the snapshot
+//!
+//! After regenerating or changing the types, run
+//!
+//! ```text
+//! UPDATE_API_SNAPSHOT=1 cargo test -p rustgie-integration-tests --test api_snapshot
+//! ```
+//!
+//! review the changes it prints, and commit the rewritten snapshot in the same commit as the change to the types.
+
+use anyhow::{anyhow, Context, Result};
+use quote::ToTokens;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use syn::{Attribute, Fields, ImplItem, Item, TraitItem, Visibility};
+
+/// Where the committed snapshot for `rustgie_types` lives.
+pub fn snapshot_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("api").join("rustgie_types.api")
+}
+
+/// The `src` directory of `rustgie_types` in this workspace.
+pub fn types_source_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("rustgie_types").join("src")
+}
+
+/// The public API of the crate rooted at `src_dir/lib.rs`, one item per line.
+pub fn extract(src_dir: &Path) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    walk_file(&src_dir.join("lib.rs"), src_dir, "crate", &mut lines)?;
+    Ok(lines)
+}
+
+/// Whether a difference from the snapshot needs a minor (breaking) or patch (additive) version bump.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Additive,
+    Breaking,
+}
+
+/// One line that differs between the snapshot and the sources.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiChange {
+    pub kind: ChangeKind,
+    /// `true` when the line is new in the sources, `false` when it was removed from them.
+    pub added: bool,
+    pub line: String,
+    pub reason: &'static str,
+}
+
+/// Compares the snapshot's lines with the current ones, breaking changes first.
+pub fn diff(snapshot: &[String], current: &[String]) -> Vec<ApiChange> {
+    let before: HashSet<&str> = snapshot.iter().map(String::as_str).collect();
+    let after: HashSet<&str> = current.iter().map(String::as_str).collect();
+    let old_items: HashMap<&str, &str> = snapshot.iter().filter_map(|line| Some((item_key(line)?, line.as_str()))).collect();
+
+    let mut changes: Vec<ApiChange> = snapshot
+        .iter()
+        .filter(|line| !after.contains(line.as_str()))
+        .map(|line| ApiChange {
+            kind: ChangeKind::Breaking,
+            added: false,
+            line: line.clone(),
+            reason: "removed or changed",
+        })
+        .collect();
+
+    for line in current.iter().filter(|line| !before.contains(line.as_str())) {
+        let owner = |separator: &str| {
+            let path = line.split_whitespace().nth(1)?;
+            let (owner, _) = path.rsplit_once(separator)?;
+            old_items.get(owner).copied()
+        };
+        let (kind, reason) = if line.starts_with("variant ") && owner("::").is_some_and(|e| !e.contains("non_exhaustive")) {
+            (ChangeKind::Breaking, "new variant on an existing exhaustive enum")
+        } else if line.starts_with("field ")
+            && owner(".").is_some_and(|s| !s.contains("non_exhaustive") && !s.contains("private fields"))
+        {
+            (ChangeKind::Breaking, "new field on an existing struct that can be built with a struct literal")
+        } else if line.starts_with("trait_fn ") && line.ends_with("(required)") && owner("::").is_some() {
+            (ChangeKind::Breaking, "new required method on an existing trait")
+        } else {
+            (ChangeKind::Additive, "added")
+        };
+        changes.push(ApiChange {
+            kind,
+            added: true,
+            line: line.clone(),
+            reason,
+        });
+    }
+
+    changes.sort_by_key(|change| change.kind != ChangeKind::Breaking);
+    changes
+}
+
+/// For struct, enum and trait lines, the path that their fields, variants and methods are listed under.
+fn item_key(line: &str) -> Option<&str> {
+    let mut words = line.split_whitespace();
+    match words.next()? {
+        "struct" | "enum" | "trait" => words.next(),
+        _ => None,
+    }
+}
+
+fn walk_file(file: &Path, child_dir: &Path, module: &str, lines: &mut Vec<String>) -> Result<()> {
+    let source = std::fs::read_to_string(file).with_context(|| format!("Could not read {}", file.display()))?;
+    let parsed = syn::parse_file(source.trim_start_matches('\u{feff}'))
+        .map_err(|e| anyhow!("Could not parse {}: {e}", file.display()))?;
+    walk_items(&parsed.items, child_dir, module, lines)
+}
+
+fn walk_items(items: &[Item], child_dir: &Path, module: &str, lines: &mut Vec<String>) -> Result<()> {
+    for item in items {
+        match item {
+            Item::Mod(m) if is_pub(&m.vis) => {
+                let path = format!("{module}::{}", m.ident);
+                lines.push(format!("mod {path}"));
+                let dir = child_dir.join(m.ident.to_string());
+                match &m.content {
+                    Some((_, items)) => walk_items(items, &dir, &path, lines)?,
+                    None if dir.join("mod.rs").exists() => walk_file(&dir.join("mod.rs"), &dir, &path, lines)?,
+                    None => walk_file(&child_dir.join(format!("{}.rs", m.ident)), &dir, &path, lines)?,
+                }
+            }
+            Item::Struct(s) if is_pub(&s.vis) => {
+                let path = format!("{module}::{}", s.ident);
+                let private = s.fields.iter().any(|f| !is_pub(&f.vis));
+                let mut flags = attribute_flags(&s.attrs);
+                if private {
+                    flags.push("private fields".to_string());
+                }
+                lines.push(format!("struct {path}{}{}", tokens(&s.generics), flag_suffix(&flags)));
+                for (index, field) in s.fields.iter().enumerate().filter(|(_, f)| is_pub(&f.vis)) {
+                    let name = field.ident.as_ref().map_or_else(|| index.to_string(), ToString::to_string);
+                    lines.push(format!("field {path}.{name}: {}", tokens(&field.ty)));
+                }
+            }
+            Item::Enum(e) if is_pub(&e.vis) => {
+                let path = format!("{module}::{}", e.ident);
+                lines.push(format!("enum {path}{}{}", tokens(&e.generics), flag_suffix(&attribute_flags(&e.attrs))));
+                for variant in &e.variants {
+                    let fields = match &variant.fields {
+                        Fields::Unit => String::new(),
+                        fields => tokens(fields),
+                    };
+                    let discriminant = variant.discriminant.as_ref().map_or_else(String::new, |(_, d)| format!(" = {}", tokens(d)));
+                    lines.push(format!("variant {path}::{}{fields}{discriminant}", variant.ident));
+                }
+            }
+            Item::Fn(f) if is_pub(&f.vis) => lines.push(format!("fn {module}::{}", signature(&f.sig))),
+            Item::Trait(t) if is_pub(&t.vis) => {
+                let path = format!("{module}::{}", t.ident);
+                let supertraits = if t.supertraits.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {}", tokens(&t.supertraits))
+                };
+                lines.push(format!("trait {path}{}{supertraits}", tokens(&t.generics)));
+                for trait_item in &t.items {
+                    if let TraitItem::Fn(f) = trait_item {
+                        let provided = if f.default.is_some() { "provided" } else { "required" };
+                        lines.push(format!("trait_fn {path}::{} ({provided})", signature(&f.sig)));
+                    }
+                }
+            }
+            Item::Impl(i) => {
+                let self_ty = tokens(&i.self_ty);
+                match &i.trait_ {
+                    Some((negative, trait_path, _)) => {
+                        let negative = if negative.is_some() { "!" } else { "" };
+                        lines.push(format!("impl {module}: {}{negative}{} for {self_ty}", tokens(&i.generics), tokens(trait_path)));
+                    }
+                    None => {
+                        for impl_item in &i.items {
+                            match impl_item {
+                                ImplItem::Fn(f) if is_pub(&f.vis) => {
+                                    lines.push(format!("fn {module}::{self_ty}::{}", signature(&f.sig)));
+                                }
+                                ImplItem::Const(c) if is_pub(&c.vis) => {
+                                    lines.push(format!("const {module}::{self_ty}::{}: {}", c.ident, tokens(&c.ty)));
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Item::Const(c) if is_pub(&c.vis) => lines.push(format!("const {module}::{}: {}", c.ident, tokens(&c.ty))),
+            Item::Static(s) if is_pub(&s.vis) => lines.push(format!("static {module}::{}: {}", s.ident, tokens(&s.ty))),
+            Item::Type(t) if is_pub(&t.vis) => {
+                lines.push(format!("type {module}::{}{} = {}", t.ident, tokens(&t.generics), tokens(&t.ty)));
+            }
+            Item::Use(u) if is_pub(&u.vis) => lines.push(format!("use {module}: {}", tokens(&u.tree))),
+            Item::Macro(m) => {
+                let name = tokens(&m.mac.path);
+                if name == "macro_rules" {
+                    if m.attrs.iter().any(|a| a.path().is_ident("macro_export")) {
+                        let ident = m.ident.as_ref().map_or_else(String::new, ToString::to_string);
+                        lines.push(format!("macro_rules crate::{ident}!"));
+                    }
+                } else {
+                    // Items generated by local macros are invisible to syn, so their arguments stand in for them.
+                    for argument in split_arguments(m.mac.tokens.clone()) {
+                        lines.push(format!("macro {module}::{name}! {argument}"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn is_pub(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+/// Attributes that change what callers can do with a type: its derives, repr and `non_exhaustive`.
+fn attribute_flags(attrs: &[Attribute]) -> Vec<String> {
+    let mut flags = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("non_exhaustive") {
+            flags.push("non_exhaustive".to_string());
+        } else if attr.path().is_ident("derive") || attr.path().is_ident("repr") {
+            flags.push(tokens(attr.meta.clone()).trim_start_matches("#").to_string());
+        }
+    }
+    flags
+}
+
+fn flag_suffix(flags: &[String]) -> String {
+    if flags.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", flags.join(", "))
+    }
+}
+
+fn signature(sig: &syn::Signature) -> String {
+    let mut sig = sig.clone();
+    for input in sig.inputs.iter_mut() {
+        if let syn::FnArg::Typed(arg) = input {
+            // Parameter names aren't part of the API.
+            *arg.pat = syn::parse_quote!(_);
+        }
+    }
+    let asyncness = if sig.asyncness.is_some() { "async " } else { "" };
+    let output = match &sig.output {
+        syn::ReturnType::Default => String::new(),
+        syn::ReturnType::Type(_, ty) => format!(" -> {}", tokens(ty)),
+    };
+    let where_clause = sig.generics.where_clause.as_ref().map_or_else(String::new, |w| format!(" {}", tokens(w)));
+    format!("{asyncness}{}{}({}){output}{where_clause}", sig.ident, tokens(&sig.generics), tokens(&sig.inputs))
+}
+
+/// Splits a macro's arguments at top-level commas and semicolons.
+fn split_arguments(stream: proc_macro2::TokenStream) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut current = proc_macro2::TokenStream::new();
+    for tree in stream {
+        match &tree {
+            proc_macro2::TokenTree::Punct(p) if p.as_char() == ',' || p.as_char() == ';' => {
+                if !current.is_empty() {
+                    arguments.push(tokens(std::mem::take(&mut current)));
+                }
+            }
+            _ => current.extend(std::iter::once(tree)),
+        }
+    }
+    if !current.is_empty() {
+        arguments.push(tokens(current));
+    }
+    arguments
+}
+
+/// The tokens as source text with the spacing `quote` adds around punctuation removed.
+fn tokens<T: ToTokens>(item: T) -> String {
+    let mut text = item.to_token_stream().to_string();
+    for (spaced, tight) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        ("< ", "<"),
+        (" <", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        ("& ", "&"),
+        (" ;", ";"),
+    ] {
+        text = text.replace(spaced, tight);
+    }
+    text.replace("->", " -> ").replace("  ", " ")
+}
@@ -0,0 +1,178 @@
+﻿#![forbid(unsafe_code)]
+
+//! Records the `player_profile_item` session against the live Bungie API.
+//!
+//! ```text
+//! BUNGIE_API_KEY=... cargo run -p rustgie-integration-tests --bin capture -- "Name#1234"
+//! ```
+//!
+//! The flow is player resolve -> profile -> item details -> manifest -> item definitions. Membership and character IDs
+//! are scrubbed into stable fake values before anything is written, and the manifest's item definition table is
+//! trimmed to the items the session actually touches so the fixture stays small.
+
+use anyhow::{anyhow, Context, Result};
+use rustgie_integration_tests::{envelope_response, IdKind, Interaction, Scrubber, Session};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+const BUNGIE: &str = "https://www.bungie.net";
+
+struct Recorder {
+    http: reqwest::Client,
+    session: Session,
+}
+
+impl Recorder {
+    async fn record(
+        &mut self,
+        method: &str,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<Value> {
+        let url = reqwest::Url::parse_with_params(&format!("{BUNGIE}{path}"), query)?;
+        let request = match (method, body) {
+            ("GET", _) => self.http.get(url),
+            ("POST", Some(body)) => self.http.post(url).json(&body),
+            ("POST", None) => self.http.post(url),
+            _ => return Err(anyhow!("Unsupported method {method}")),
+        };
+
+        let response = request.send().await.with_context(|| format!("Request to {path} failed"))?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("application/json")
+            .to_string();
+        let body: Value = response.json().await.with_context(|| format!("{path} did not return JSON"))?;
+
+        self.session.interactions.push(Interaction {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<BTreeMap<_, _>>(),
+            status,
+            content_type,
+            body: body.clone(),
+        });
+
+        Ok(body)
+    }
+}
+
+fn id_from(value: &Value, field: &str) -> Result<i64> {
+    let raw = value.get(field).ok_or_else(|| anyhow!("Missing {field}"))?;
+    match raw {
+        Value::String(s) => Ok(s.parse()?),
+        Value::Number(n) => n.as_i64().ok_or_else(|| anyhow!("{field} is not an integer")),
+        _ => Err(anyhow!("{field} has an unexpected type")),
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let api_key = std::env::var("BUNGIE_API_KEY").with_context(|| "Set BUNGIE_API_KEY to capture a session")?;
+    let bungie_name = std::env::args().nth(1).ok_or_else(|| anyhow!("Usage: capture <Name#1234>"))?;
+    let (display_name, code) = bungie_name
+        .rsplit_once('#')
+        .ok_or_else(|| anyhow!("Bungie names look like Name#1234"))?;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("X-API-Key", reqwest::header::HeaderValue::try_from(api_key)?);
+
+    let mut recorder = Recorder {
+        http: reqwest::Client::builder().default_headers(headers).build()?,
+        session: Session::new("player_profile_item"),
+    };
+    let mut scrubber = Scrubber::new();
+
+    let search = recorder
+        .record(
+            "POST",
+            "/Platform/Destiny2/SearchDestinyPlayerByBungieName/-1/",
+            &[],
+            Some(json!({ "displayName": display_name, "displayNameCode": code.parse::<i16>()? })),
+        )
+        .await?;
+    let player = envelope_response(&search)?
+        .get(0)
+        .ok_or_else(|| anyhow!("No player named {bungie_name}"))?
+        .clone();
+    let membership_id = id_from(&player, "membershipId")?;
+    let membership_type = player
+        .get("membershipType")
+        .and_then(Value::as_i64)
+        .ok_or_else(|| anyhow!("Missing membershipType"))?;
+    scrubber.register(membership_id, IdKind::DestinyMembership);
+
+    let profile = recorder
+        .record(
+            "GET",
+            &format!("/Platform/Destiny2/{membership_type}/Profile/{membership_id}/"),
+            &[("components", "100,200,205")],
+            None,
+        )
+        .await?;
+    let profile = envelope_response(&profile)?;
+
+    let character_ids = profile["profile"]["data"]["characterIds"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Profile has no characterIds"))?;
+    for character_id in character_ids {
+        let character_id: i64 = character_id.as_str().unwrap_or_default().parse()?;
+        scrubber.register(character_id, IdKind::Character);
+    }
+
+    let first_character = character_ids
+        .first()
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Account has no characters"))?;
+    let equipped = profile["characterEquipment"]["data"][first_character]["items"]
+        .as_array()
+        .ok_or_else(|| anyhow!("No equipment on {first_character}"))?;
+    let weapon = equipped.first().ok_or_else(|| anyhow!("Nothing equipped"))?;
+    let instance_id = id_from(weapon, "itemInstanceId")?;
+
+    let mut item_hashes: Vec<u64> = equipped.iter().filter_map(|i| i["itemHash"].as_u64()).collect();
+    item_hashes.sort_unstable();
+    item_hashes.dedup();
+
+    recorder
+        .record(
+            "GET",
+            &format!("/Platform/Destiny2/{membership_type}/Profile/{membership_id}/Item/{instance_id}/"),
+            &[("components", "300,304,305")],
+            None,
+        )
+        .await?;
+
+    let manifest = recorder.record("GET", "/Platform/Destiny2/Manifest/", &[], None).await?;
+    let item_table_path = envelope_response(&manifest)?["jsonWorldComponentContentPaths"]["en"]
+        ["DestinyInventoryItemDefinition"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Manifest has no English item definition table"))?
+        .to_string();
+
+    let mut table = recorder.record("GET", &item_table_path, &[], None).await?;
+    if let Value::Object(definitions) = &mut table {
+        definitions.retain(|hash, _| hash.parse::<u64>().is_ok_and(|h| item_hashes.contains(&h)));
+    }
+    if let Some(last) = recorder.session.interactions.last_mut() {
+        last.body = table;
+    }
+
+    if let Some(bungie_net_id) = search["Response"][0]["bungieNetMembershipId"].as_str() {
+        scrubber.register(bungie_net_id.parse()?, IdKind::BungieNetMembership);
+    }
+
+    let mut session = recorder.session;
+    for interaction in &mut session.interactions {
+        scrubber.scrub_interaction(interaction);
+    }
+
+    let written = session.save()?;
+    println!("Wrote {}", written.display());
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+﻿#![forbid(unsafe_code)]
+
+//! Rewrites `rustgie_types/types-changes.json` from two generations of Bungie's OpenAPI spec.
+//!
+//! ```text
+//! cargo run -p rustgie-integration-tests --bin types_changes -- previous-openapi.json openapi.json
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use rustgie_integration_tests::type_changes::{changes_path, diff_specs};
+use serde_json::Value;
+
+fn read_spec(path: &str) -> Result<Value> {
+    let contents = std::fs::read_to_string(path).with_context(|| format!("Could not read {path}"))?;
+    serde_json::from_str(&contents).with_context(|| format!("{path} is not JSON"))
+}
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let (previous, current) = match (args.next(), args.next()) {
+        (Some(previous), Some(current)) => (previous, current),
+        _ => return Err(anyhow!("Usage: types_changes <previous-openapi.json> <openapi.json>")),
+    };
+
+    let changes = diff_specs(&read_spec(&previous)?, &read_spec(&current)?)?;
+    let path = changes_path();
+    std::fs::write(&path, serde_json::to_string_pretty(&changes)? + "\n")
+        .with_context(|| format!("Could not write {}", path.display()))?;
+    println!("Wrote {} changes to {}", changes.len(), path.display());
+    Ok(())
+}
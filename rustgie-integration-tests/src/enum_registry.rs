@@ -0,0 +1,41 @@
+﻿//! Lists of generated enums, for tests that should cover every one of them.
+//!
+//! `tests/property_round_trips.rs` compares these lists with the `rustgie_types` sources, so an enum added by the
+//! generator fails that test until it's listed here.
+
+/// Invokes `$callback!` with the path of every `#[bitflags]` enum in `rustgie_types`, comma separated.
+#[macro_export]
+macro_rules! bitflags_enums {
+    ($callback:ident) => {
+        $callback! {
+            rustgie::types::applications::ApplicationScopes,
+            rustgie::types::destiny::DestinyCollectibleState,
+            rustgie::types::destiny::DestinyGameVersions,
+            rustgie::types::destiny::DestinyJoinClosedReasons,
+            rustgie::types::destiny::DestinyPartyMemberStates,
+            rustgie::types::destiny::DestinyPresentationNodeState,
+            rustgie::types::destiny::DestinyProgressionRewardItemState,
+            rustgie::types::destiny::DestinyRecordState,
+            rustgie::types::destiny::DestinyVendorItemState,
+            rustgie::types::destiny::EquipFailureReason,
+            rustgie::types::destiny::EquippingItemBlockAttributes,
+            rustgie::types::destiny::ItemState,
+            rustgie::types::destiny::PlugUiStyles,
+            rustgie::types::destiny::SocketPlugSources,
+            rustgie::types::destiny::TransferStatuses,
+            rustgie::types::destiny::VendorItemStatus,
+            rustgie::types::destiny::definitions::DestinyTalentNodeStepDamageTypes,
+            rustgie::types::destiny::definitions::DestinyTalentNodeStepGuardianAttributes,
+            rustgie::types::destiny::definitions::DestinyTalentNodeStepImpactEffects,
+            rustgie::types::destiny::definitions::DestinyTalentNodeStepLightAbilities,
+            rustgie::types::destiny::definitions::DestinyTalentNodeStepWeaponPerformances,
+            rustgie::types::forum::ForumTopicsCategoryFiltersEnum,
+            rustgie::types::forums::ForumFlagsEnum,
+            rustgie::types::forums::ForumPostCategoryEnums,
+            rustgie::types::groups_v2::Capabilities,
+            rustgie::types::ignores::IgnoreStatus,
+            rustgie::types::social::friends::PresenceOnlineStateFlags,
+            rustgie::types::user::OptInFlags
+        }
+    };
+}
@@ -0,0 +1,237 @@
+﻿#![forbid(unsafe_code)]
+
+//! Recorded Bungie API sessions replayed through wiremock.
+//!
+//! A session is a JSON file in `sessions/` holding the exact request/response pairs of a real run, captured with
+//! `cargo run -p rustgie-integration-tests --bin capture` and scrubbed of membership and character IDs so it can be committed.
+//! Tests mount a session on a [`MockServer`] and point a [`rustgie::RustgieClient`] at it.
+//!
+//! [`api_snapshot`] holds the public API snapshot tooling for `rustgie_types`, [`enum_registry`] lists its generated enums,
+//! and [`type_changes`] computes its changelog from two generations of Bungie's spec.
+
+pub mod api_snapshot;
+pub mod enum_registry;
+pub mod type_changes;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// The API key sent by every replayed client. Sessions never contain the real key used during capture.
+pub const TEST_API_KEY: &str = "rustgie-integration-tests";
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Session {
+    pub name: String,
+    pub interactions: Vec<Interaction>,
+}
+
+/// One recorded request and the raw response Bungie gave for it.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Interaction {
+    pub method: String,
+    pub path: String,
+    #[serde(default)]
+    pub query: BTreeMap<String, String>,
+    pub status: u16,
+    #[serde(default = "default_content_type")]
+    pub content_type: String,
+    pub body: Value,
+}
+
+fn default_content_type() -> String {
+    "application/json; charset=utf-8".to_string()
+}
+
+impl Session {
+    pub fn new(name: &str) -> Session {
+        Session {
+            name: name.to_string(),
+            interactions: Vec::new(),
+        }
+    }
+
+    /// Loads `sessions/{name}.json` from this crate.
+    pub fn load(name: &str) -> Result<Session> {
+        let path = session_path(name);
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not read session file {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Could not parse session file {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        let path = session_path(&self.name);
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Could not write session file {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Registers every interaction on the server. Each one answers at most once, so a test that repeats a call it
+    /// didn't record fails loudly instead of silently replaying stale data.
+    pub async fn mount(&self, server: &MockServer) {
+        for interaction in &self.interactions {
+            let body = match &interaction.body {
+                Value::String(raw) => raw.clone().into_bytes(),
+                json => serde_json::to_vec(json).expect("serde_json::Value always serializes"),
+            };
+
+            let mut mock = Mock::given(method(interaction.method.as_str())).and(path(interaction.path.as_str()));
+            for (key, value) in &interaction.query {
+                mock = mock.and(query_param(key.as_str(), value.as_str()));
+            }
+
+            mock.respond_with(
+                ResponseTemplate::new(interaction.status)
+                    .insert_header("Content-Type", interaction.content_type.as_str())
+                    .set_body_bytes(body),
+            )
+            .up_to_n_times(1)
+            .named(format!("{} {} {}", self.name, interaction.method, interaction.path))
+            .mount(server)
+            .await;
+        }
+    }
+}
+
+fn session_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("sessions")
+        .join(format!("{name}.json"))
+}
+
+/// Starts a mock server replaying the named session and a client pointed at it.
+pub async fn replay(name: &str) -> Result<(MockServer, rustgie::RustgieClient)> {
+    let session = Session::load(name)?;
+    let server = MockServer::start().await;
+    session.mount(&server).await;
+
+    let client = client_for(&server)?;
+
+    Ok((server, client))
+}
+
+/// A client that sends every request to `server`, for tests that mount their own mocks.
+pub fn client_for(server: &MockServer) -> Result<rustgie::RustgieClient> {
+    rustgie::RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .build()
+}
+
+/// A `200 OK` Bungie API envelope reporting success, with `response` as its `Response`, for tests that mount their own mocks.
+pub fn success_envelope(response: Value) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "Response": response,
+        "ErrorCode": 1,
+        "ThrottleSeconds": 0,
+        "ErrorStatus": "Success",
+        "Message": "Ok",
+        "MessageData": {}
+    }))
+}
+
+/// The kinds of identifiers that tie a recording to a real person.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdKind {
+    DestinyMembership,
+    BungieNetMembership,
+    Character,
+}
+
+impl IdKind {
+    fn fake_base(self) -> i64 {
+        match self {
+            IdKind::DestinyMembership => 4611686018400000000,
+            IdKind::BungieNetMembership => 10000000,
+            IdKind::Character => 2305843009200000000,
+        }
+    }
+}
+
+/// Replaces real identifiers with stable fake ones.
+///
+/// Fakes are handed out per kind in registration order, so capturing the same account twice produces the same fixture.
+/// Replacement covers JSON numbers, JSON strings (Bungie sends int64 IDs as strings), object keys (character-keyed
+/// component dictionaries), and request paths.
+#[derive(Default)]
+pub struct Scrubber {
+    replacements: HashMap<i64, i64>,
+    issued: HashMap<IdKind, i64>,
+}
+
+impl Scrubber {
+    pub fn new() -> Scrubber {
+        Scrubber::default()
+    }
+
+    /// Registers a real identifier and returns the fake it will be replaced with.
+    pub fn register(&mut self, real: i64, kind: IdKind) -> i64 {
+        if let Some(fake) = self.replacements.get(&real) {
+            return *fake;
+        }
+
+        let count = self.issued.entry(kind).or_insert(0);
+        *count += 1;
+        let fake = kind.fake_base() + *count;
+        self.replacements.insert(real, fake);
+        fake
+    }
+
+    pub fn scrub_value(&self, value: &mut Value) {
+        match value {
+            Value::Number(number) => {
+                if let Some(fake) = number.as_i64().and_then(|n| self.replacements.get(&n)) {
+                    *value = Value::from(*fake);
+                }
+            }
+            Value::String(string) => {
+                if let Some(fake) = string.parse::<i64>().ok().and_then(|n| self.replacements.get(&n)) {
+                    *string = fake.to_string();
+                }
+            }
+            Value::Array(array) => array.iter_mut().for_each(|v| self.scrub_value(v)),
+            Value::Object(object) => {
+                let entries = std::mem::take(object);
+                for (key, mut v) in entries {
+                    self.scrub_value(&mut v);
+                    object.insert(self.scrub_text(&key), v);
+                }
+            }
+            Value::Null | Value::Bool(_) => {}
+        }
+    }
+
+    /// Replaces identifiers appearing as whole path segments or whole strings.
+    pub fn scrub_text(&self, text: &str) -> String {
+        text.split('/')
+            .map(|segment| {
+                segment
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|n| self.replacements.get(&n))
+                    .map_or_else(|| segment.to_string(), |fake| fake.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    pub fn scrub_interaction(&self, interaction: &mut Interaction) {
+        interaction.path = self.scrub_text(&interaction.path);
+        for value in interaction.query.values_mut() {
+            *value = self.scrub_text(value);
+        }
+        self.scrub_value(&mut interaction.body);
+    }
+}
+
+/// Pulls the `Response` out of a recorded envelope, for capture code that needs IDs from one call to make the next.
+pub fn envelope_response(body: &Value) -> Result<&Value> {
+    body.get("Response")
+        .ok_or_else(|| anyhow!("Recorded body has no Response: {body}"))
+}
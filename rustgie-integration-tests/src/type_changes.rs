@@ -0,0 +1,169 @@
+﻿//! Computes `rustgie_types`' `types-changes.json` by diffing two generations of Bungie's OpenAPI spec.
+//!
+//! The types are generated from the spec's `components.schemas`, so that's the model compared here: schemas are types,
+//! an object schema's `properties` are its fields, and an enum schema's `x-enum-values` are its variants. Comparing the
+//! schemas rather than the generated Rust keeps renames in the generator's output from showing up as API changes.
+//!
+//! # Updating the changes
+//!
+//! After regenerating the types from a new spec, run
+//!
+//! ```text
+//! cargo run -p rustgie-integration-tests --bin types_changes -- previous-openapi.json openapi.json
+//! ```
+//!
+//! and commit the rewritten `rustgie_types/types-changes.json` with the regenerated types.
+
+use anyhow::{anyhow, Result};
+use rustgie_types::{ChangeKind, ChangeTarget, TypeChange};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Where the committed changes for `rustgie_types` live.
+pub fn changes_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join("rustgie_types").join("types-changes.json")
+}
+
+/// One schema, reduced to what the generated types depend on.
+enum Shape {
+    Object(BTreeMap<String, String>),
+    Enum(BTreeMap<String, String>),
+}
+
+impl Shape {
+    fn kind(&self) -> &'static str {
+        match self {
+            Shape::Object(_) => "object",
+            Shape::Enum(_) => "enum",
+        }
+    }
+}
+
+/// Every added, removed, and changed type, field, and enum variant between two OpenAPI specs, ordered by type name.
+pub fn diff_specs(previous: &Value, current: &Value) -> Result<Vec<TypeChange>> {
+    let previous = shapes(previous)?;
+    let current = shapes(current)?;
+    let names: BTreeSet<&String> = previous.keys().chain(current.keys()).collect();
+
+    let mut changes = Vec::new();
+    for name in names {
+        match (previous.get(name), current.get(name)) {
+            (None, Some(_)) => changes.push(change(ChangeKind::Added, ChangeTarget::Type, name, None, None, None)),
+            (Some(_), None) => changes.push(change(ChangeKind::Removed, ChangeTarget::Type, name, None, None, None)),
+            (Some(Shape::Object(before)), Some(Shape::Object(after))) => {
+                diff_members(&mut changes, ChangeTarget::Field, name, before, after)
+            }
+            (Some(Shape::Enum(before)), Some(Shape::Enum(after))) => {
+                diff_members(&mut changes, ChangeTarget::EnumVariant, name, before, after)
+            }
+            (Some(before), Some(after)) => changes.push(change(
+                ChangeKind::Changed,
+                ChangeTarget::Type,
+                name,
+                None,
+                Some(before.kind().to_string()),
+                Some(after.kind().to_string()),
+            )),
+            (None, None) => {}
+        }
+    }
+    Ok(changes)
+}
+
+fn diff_members(
+    changes: &mut Vec<TypeChange>,
+    target: ChangeTarget,
+    type_name: &str,
+    before: &BTreeMap<String, String>,
+    after: &BTreeMap<String, String>,
+) {
+    let members: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    for member in members {
+        let kind = match (before.get(member), after.get(member)) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            (Some(old), Some(new)) if old != new => ChangeKind::Changed,
+            _ => continue,
+        };
+        changes.push(change(kind, target, type_name, Some(member), before.get(member).cloned(), after.get(member).cloned()));
+    }
+}
+
+fn change(
+    kind: ChangeKind,
+    target: ChangeTarget,
+    type_name: &str,
+    member: Option<&String>,
+    before: Option<String>,
+    after: Option<String>,
+) -> TypeChange {
+    TypeChange {
+        kind,
+        target,
+        type_name: type_name.to_string(),
+        member: member.cloned(),
+        before,
+        after,
+    }
+}
+
+fn shapes(spec: &Value) -> Result<BTreeMap<String, Shape>> {
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or_else(|| anyhow!("The spec has no components.schemas"))?;
+    Ok(schemas.iter().map(|(name, schema)| (name.clone(), shape(schema))).collect())
+}
+
+fn shape(schema: &Value) -> Shape {
+    if let Some(values) = schema.get("x-enum-values").and_then(Value::as_array) {
+        return Shape::Enum(
+            values
+                .iter()
+                .filter_map(|value| {
+                    let identifier = value.get("identifier")?.as_str()?;
+                    Some((identifier.to_string(), value.get("numericValue").map(scalar).unwrap_or_default()))
+                })
+                .collect(),
+        );
+    }
+    let properties = schema.get("properties").and_then(Value::as_object).map(Map::iter);
+    Shape::Object(properties.into_iter().flatten().map(|(name, property)| (name.clone(), describe(property))).collect())
+}
+
+/// A field's type as one short string: a schema name, `integer:int32`, `[T]` for arrays, `{K: V}` for dictionaries,
+/// with a trailing `?` when it's nullable.
+fn describe(schema: &Value) -> String {
+    let described = if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        reference.trim_start_matches(SCHEMA_REF_PREFIX).to_string()
+    } else if let Some(inner) = schema.get("allOf").and_then(Value::as_array).and_then(|all| all.first()) {
+        describe(inner)
+    } else if let Some(items) = schema.get("items") {
+        format!("[{}]", describe(items))
+    } else if let Some(values) = schema.get("additionalProperties") {
+        let key = schema.get("x-dictionary-key").map_or_else(|| "string".to_string(), describe);
+        format!("{{{key}: {}}}", describe(values))
+    } else {
+        match (schema.get("type").and_then(Value::as_str), schema.get("format").and_then(Value::as_str)) {
+            (Some(kind), Some(format)) => format!("{kind}:{format}"),
+            (Some(kind), None) => kind.to_string(),
+            (None, _) => "unknown".to_string(),
+        }
+    };
+    if schema.get("nullable").and_then(Value::as_bool) == Some(true) {
+        format!("{described}?")
+    } else {
+        described
+    }
+}
+
+/// Bungie writes enum values as strings; this keeps numbers and strings alike as their text.
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
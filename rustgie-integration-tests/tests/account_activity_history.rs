@@ -0,0 +1,172 @@
+﻿use rustgie::types::destiny::entities::characters::CharacterId;
+use rustgie::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+use rustgie::types::BungieMembershipType;
+use rustgie::{CancellationToken, Middleware, MiddlewareFuture, Next, Outcome, RustgieClientBuilder};
+use rustgie_integration_tests::{client_for, success_envelope, TEST_API_KEY};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use time::macros::datetime;
+use time::{Duration, OffsetDateTime};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer};
+
+const MEMBERSHIP_ID: i64 = 4611686018400000001;
+const HUNTER: i64 = 2305843009200000001;
+const TITAN: i64 = 2305843009200000002;
+const WARLOCK: i64 = 2305843009200000003;
+const LATEST: OffsetDateTime = datetime!(2026-10-01 20:00 UTC);
+
+/// Activities played `minutes_ago` minutes before [`LATEST`], newest first as Bungie lists them.
+fn activities(minutes_ago: impl IntoIterator<Item = i64>) -> Value {
+    let activities: Vec<Value> = minutes_ago
+        .into_iter()
+        .map(|minutes| {
+            let period = LATEST - Duration::minutes(minutes);
+            json!({ "period": period.format(&time::format_description::well_known::Rfc3339).expect("formattable"), "values": {} })
+        })
+        .collect();
+    json!({ "activities": activities })
+}
+
+async fn mount_page(server: &MockServer, character_id: i64, page: i32, count: usize, response: Value, times: u64) {
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/Destiny2/3/Account/{MEMBERSHIP_ID}/Character/{character_id}/Stats/Activities/")))
+        .and(query_param("page", page.to_string()))
+        .and(query_param("count", count.to_string()))
+        .respond_with(success_envelope(response))
+        .expect(times)
+        .named(format!("page {page} of {character_id}"))
+        .mount(server)
+        .await;
+}
+
+fn minutes_ago(history: &[(CharacterId, rustgie::types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup)]) -> Vec<(i64, i64)> {
+    history.iter().map(|(character_id, group)| (character_id.get(), (LATEST - group.period).whole_minutes())).collect()
+}
+
+#[tokio::test]
+async fn histories_are_merged_newest_first() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_page(&server, HUNTER, 0, 5, activities([0, 30, 60, 90, 120]), 1).await;
+    mount_page(&server, TITAN, 0, 5, activities([10, 40, 70]), 1).await;
+    mount_page(&server, WARLOCK, 0, 5, activities([20, 50, 80, 110, 140]), 1).await;
+    let client = client_for(&server)?;
+
+    let history = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &[HUNTER, TITAN, WARLOCK].map(CharacterId::from), None, 5, &CancellationToken::new())
+        .await?
+        .into_inner();
+    assert_eq!(minutes_ago(&history), vec![(HUNTER, 0), (TITAN, 10), (WARLOCK, 20), (HUNTER, 30), (TITAN, 40)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn short_histories_run_out_before_count() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_page(&server, HUNTER, 0, 50, activities([5, 25]), 1).await;
+    mount_page(&server, TITAN, 0, 50, activities([]), 1).await;
+    mount_page(&server, WARLOCK, 0, 50, json!({}), 1).await;
+    let client = client_for(&server)?;
+
+    let history = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &[HUNTER, TITAN, WARLOCK].map(CharacterId::from), None, 50, &CancellationToken::new())
+        .await?
+        .into_inner();
+    assert_eq!(minutes_ago(&history), vec![(HUNTER, 5), (HUNTER, 25)], "short pages mean there's nothing more to fetch");
+    Ok(())
+}
+
+#[tokio::test]
+async fn later_pages_are_fetched_only_when_the_merge_reaches_them() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    // The hunter plays all the time; the titan and warlock interleave with the hunter's second page.
+    mount_page(&server, HUNTER, 0, 250, activities((0..250).map(|i| i * 2)), 1).await;
+    mount_page(&server, HUNTER, 1, 250, activities((250..500).map(|i| i * 2)), 1).await;
+    mount_page(&server, HUNTER, 2, 250, activities((500..750).map(|i| i * 2)), 0).await;
+    mount_page(&server, TITAN, 0, 250, activities([501, 503, 505]), 1).await;
+    mount_page(&server, WARLOCK, 0, 250, activities([499, 1500]), 1).await;
+    let client = client_for(&server)?;
+
+    let history = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &[HUNTER, TITAN, WARLOCK].map(CharacterId::from), None, 260, &CancellationToken::new())
+        .await?
+        .into_inner();
+    assert_eq!(history.len(), 260);
+    let merged = minutes_ago(&history);
+    assert!(merged.windows(2).all(|pair| pair[0].1 <= pair[1].1), "newest first");
+    assert_eq!(
+        merged[249..],
+        [(HUNTER, 498), (WARLOCK, 499), (HUNTER, 500), (TITAN, 501), (HUNTER, 502), (TITAN, 503), (HUNTER, 504), (TITAN, 505), (HUNTER, 506), (HUNTER, 508), (HUNTER, 510)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn mode_is_passed_to_every_character() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    for character_id in [HUNTER, TITAN] {
+        Mock::given(method("GET"))
+            .and(path(format!("/Platform/Destiny2/3/Account/{MEMBERSHIP_ID}/Character/{character_id}/Stats/Activities/")))
+            .and(query_param("mode", "4"))
+            .respond_with(success_envelope(activities([if character_id == HUNTER { 1 } else { 2 }])))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    let client = client_for(&server)?;
+
+    let history = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &[HUNTER, TITAN].map(CharacterId::from), Some(DestinyActivityModeType::Raid), 10, &CancellationToken::new())
+        .await?
+        .into_inner();
+    assert_eq!(minutes_ago(&history), vec![(HUNTER, 1), (TITAN, 2)]);
+    Ok(())
+}
+
+/// Cancels the token as the request for `path` goes out, standing in for a caller cancelling while it's in flight.
+struct CancelOn {
+    path: String,
+    cancellation: CancellationToken,
+}
+
+impl Middleware for CancelOn {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        if request.url().path() == self.path {
+            self.cancellation.cancel();
+        }
+        next.run(request)
+    }
+}
+
+#[tokio::test]
+async fn cancelling_stops_before_the_next_page() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_page(&server, HUNTER, 0, 250, activities(0..250), 1).await;
+    mount_page(&server, HUNTER, 1, 250, activities(250..500), 0).await;
+    mount_page(&server, TITAN, 0, 250, activities([]), 1).await;
+    let cancellation = CancellationToken::new();
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_middleware(Arc::new(CancelOn {
+            path: format!("/Platform/Destiny2/3/Account/{MEMBERSHIP_ID}/Character/{TITAN}/Stats/Activities/"),
+            cancellation: cancellation.clone(),
+        }))
+        .build()?;
+    let characters = [HUNTER, TITAN].map(CharacterId::from);
+
+    let outcome = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &characters, None, 300, &cancellation)
+        .await?;
+    assert!(outcome.is_cancelled());
+    let history = outcome.into_inner();
+    assert_eq!(history.len(), 250, "the first pages in flight are merged, the hunter's second page is never asked for");
+    assert_eq!(minutes_ago(&history)[249], (HUNTER, 249));
+
+    let outcome = client
+        .account_activity_history(BungieMembershipType::TigerSteam, MEMBERSHIP_ID, &characters, None, 300, &cancellation)
+        .await?;
+    assert_eq!(outcome, Outcome::Cancelled(Vec::new()), "nothing is requested once cancelled");
+    Ok(())
+}
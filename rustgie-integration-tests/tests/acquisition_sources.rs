@@ -0,0 +1,66 @@
+﻿use rustgie::types::destiny::definitions::collectibles::{acquisition_source, DestinyCollectibleDefinition, SourceInfo};
+use rustgie::types::destiny::definitions::{DestinyInventoryItemDefinition, DestinyRewardSourceDefinition};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const SUNSHOT: u32 = 2907129557;
+const GJALLARHORN: u32 = 1363886209;
+const SWORDFLIGHT: u32 = 2262062209;
+
+struct Definitions {
+    items: HashMap<u32, DestinyInventoryItemDefinition>,
+    collectibles: HashMap<u32, DestinyCollectibleDefinition>,
+    reward_sources: HashMap<u32, DestinyRewardSourceDefinition>,
+}
+
+impl Definitions {
+    fn source(&self, item_hash: u32) -> SourceInfo {
+        acquisition_source(self.collectibles.get(&item_hash), &self.items[&item_hash], &self.reward_sources)
+    }
+}
+
+fn fixture() -> anyhow::Result<Definitions> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/acquisition_sources.json"))?;
+    Ok(Definitions {
+        items: serde_json::from_value(fixture["items"].clone())?,
+        collectibles: serde_json::from_value(fixture["collectibles"].clone())?,
+        reward_sources: serde_json::from_value(fixture["rewardSources"].clone())?,
+    })
+}
+
+#[test]
+fn source_string_is_used_when_present() -> anyhow::Result<()> {
+    let defs = fixture()?;
+
+    assert_eq!(
+        defs.source(SUNSHOT),
+        SourceInfo {
+            text: Some("Source: Complete Exotic quests or open Exotic engrams.".to_string()),
+            categories: vec![],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn source_hashes_are_resolved_when_the_strings_are_empty() -> anyhow::Result<()> {
+    let defs = fixture()?;
+
+    // The unknown hash and the redacted dungeon chest are left out, and the repeated Grasp of Avarice hash counts once.
+    assert_eq!(
+        defs.source(GJALLARHORN),
+        SourceInfo {
+            text: Some("Exotic Quest, Grasp of Avarice".to_string()),
+            categories: vec!["Exotic Quest".to_string(), "Grasp of Avarice".to_string()],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn nothing_is_made_up_without_sources() -> anyhow::Result<()> {
+    let defs = fixture()?;
+
+    assert_eq!(defs.source(SWORDFLIGHT), SourceInfo::default());
+    Ok(())
+}
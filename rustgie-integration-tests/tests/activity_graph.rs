@@ -0,0 +1,52 @@
+﻿use rustgie::types::destiny::definitions::director::{resolve_graph, DestinyActivityGraphDefinition, GraphNodeAvailability};
+use rustgie::types::destiny::{ActivityGraphNodeHighlightType, DestinyActivity};
+use serde_json::json;
+
+const QUARRY: u32 = 2829206727;
+const HEROIC_ADVENTURE: u32 = 1438305118;
+const CORRUPTED: u32 = 224295651;
+
+fn graph() -> DestinyActivityGraphDefinition {
+    serde_json::from_str(include_str!("../fixtures/activity_graph_edz.json")).expect("valid graph definition")
+}
+
+fn available(activity_hash: u32, completed: bool) -> DestinyActivity {
+    serde_json::from_value(json!({
+        "activityHash": activity_hash, "isNew": false, "canLead": true, "canJoin": true,
+        "isCompleted": completed, "isVisible": true, "difficultyTier": 2
+    }))
+    .expect("valid activity")
+}
+
+#[test]
+fn destination_graph_with_a_locked_node() {
+    let graph = graph();
+    let live = vec![available(QUARRY, true), available(HEROIC_ADVENTURE, false)];
+
+    let nodes = resolve_graph(&graph, Some(&live));
+
+    let summary: Vec<(u32, Option<&str>, GraphNodeAvailability, Option<u32>)> =
+        nodes.iter().map(|n| (n.node_id, n.name, n.availability, n.active.map(|a| a.activity_hash))).collect();
+    assert_eq!(
+        summary,
+        [
+            (1166131024, Some("Lost Sector: The Quarry"), GraphNodeAvailability::Completed, Some(QUARRY)),
+            (3013414853, None, GraphNodeAvailability::Available, Some(HEROIC_ADVENTURE)),
+            (4046934917, Some("The Corrupted"), GraphNodeAvailability::Locked, None),
+        ]
+    );
+
+    let adventure = &nodes[1];
+    assert_eq!(adventure.activity_hashes, [1438305117, HEROIC_ADVENTURE]);
+    assert_eq!(adventure.featuring_states, [ActivityGraphNodeHighlightType::Hyper, ActivityGraphNodeHighlightType::Normal]);
+    assert_eq!(adventure.position.map(|p| (p.x, p.y)), Some((120, -40)));
+    assert_eq!(nodes[2].activity_hashes, [CORRUPTED]);
+}
+
+#[test]
+fn without_live_activities() {
+    let graph = graph();
+    let nodes = resolve_graph(&graph, None);
+    assert_eq!(nodes.len(), 3);
+    assert!(nodes.iter().all(|n| n.availability == GraphNodeAvailability::Unknown && n.active.is_none()));
+}
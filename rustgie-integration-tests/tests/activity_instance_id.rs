@@ -0,0 +1,50 @@
+﻿use rustgie::types::destiny::historical_stats::{ActivityInstanceId, DestinyHistoricalStatsActivity};
+use rustgie_integration_tests::replay;
+use serde_json::{json, Value};
+
+fn activity(instance_id: Value) -> Value {
+    json!({
+        "referenceId": 2693136600_u32, "directorActivityHash": 2693136600_u32, "instanceId": instance_id,
+        "mode": 4, "modes": [7, 4], "isPrivate": false, "membershipType": 3
+    })
+}
+
+#[test]
+fn parses_string_and_number_forms() {
+    for form in [json!("13000000001"), json!(13000000001_i64)] {
+        let parsed: DestinyHistoricalStatsActivity = serde_json::from_value(activity(form)).expect("valid activity");
+        assert_eq!(parsed.instance_id, ActivityInstanceId::from(13000000001));
+    }
+
+    let id: ActivityInstanceId = "13000000001".parse().expect("positive ID");
+    assert_eq!(id.get(), 13000000001);
+    assert_eq!(serde_json::to_value(id).expect("serializable"), json!("13000000001"), "serialized the way Bungie sends it");
+}
+
+#[test]
+fn rejects_zero_and_negative_ids() {
+    for form in [json!("0"), json!(0), json!("-5"), json!(-5), json!("not a number")] {
+        assert!(serde_json::from_value::<DestinyHistoricalStatsActivity>(activity(form.clone())).is_err(), "{form} was accepted");
+    }
+    assert!("0".parse::<ActivityInstanceId>().is_err());
+    assert!("-13000000001".parse::<ActivityInstanceId>().is_err());
+    assert_eq!(ActivityInstanceId::new(0), None);
+    assert_eq!(ActivityInstanceId::new(-1), None);
+}
+
+#[test]
+fn pgcr_url() {
+    let id = ActivityInstanceId::new(13000000001).expect("positive ID");
+    assert_eq!(id.pgcr_url().as_str(), "https://www.bungie.net/en/PGCR/13000000001");
+}
+
+#[tokio::test]
+async fn endpoint_accepts_the_id_from_history() -> anyhow::Result<()> {
+    let (_server, client) = replay("clan_members_identity").await?;
+    let id: ActivityInstanceId = "13000000001".parse()?;
+
+    let report = client.destiny2_get_post_game_carnage_report(id, None).await?;
+
+    assert_eq!(report.activity_details.map(|a| a.instance_id), Some(id));
+    Ok(())
+}
@@ -0,0 +1,100 @@
+﻿use rustgie_integration_tests::api_snapshot::{diff, extract, snapshot_path, types_source_dir, ApiChange, ChangeKind};
+use std::fmt::Write;
+
+const HEADER: &str = "# The public API of rustgie_types. Regenerate with UPDATE_API_SNAPSHOT=1; see rustgie-integration-tests/src/api_snapshot.rs.";
+
+fn report(changes: &[ApiChange]) -> String {
+    let mut report = String::new();
+    for change in changes {
+        let kind = match change.kind {
+            ChangeKind::Breaking => "breaking",
+            ChangeKind::Additive => "additive",
+        };
+        let sign = if change.added { '+' } else { '-' };
+        writeln!(report, "[{kind}] {sign} {} ({})", change.line, change.reason).expect("writing to a String");
+    }
+    report
+}
+
+#[test]
+fn rustgie_types_public_api_matches_snapshot() -> anyhow::Result<()> {
+    let current = extract(&types_source_dir())?;
+    let path = snapshot_path();
+    let snapshot: Vec<String> = std::fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(str::to_string)
+        .collect();
+    let changes = diff(&snapshot, &current);
+
+    if std::env::var_os("UPDATE_API_SNAPSHOT").is_some() {
+        std::fs::create_dir_all(path.parent().expect("the snapshot is in a directory"))?;
+        std::fs::write(&path, format!("{HEADER}\n{}\n", current.join("\n")))?;
+        print!("{}", report(&changes));
+        return Ok(());
+    }
+
+    let breaking = changes.iter().filter(|c| c.kind == ChangeKind::Breaking).count();
+    assert!(
+        changes.is_empty(),
+        "the public API of rustgie_types differs from {} ({breaking} breaking, {} additive); \
+         rerun with UPDATE_API_SNAPSHOT=1 and commit the snapshot with the change:\n{}",
+        path.display(),
+        changes.len() - breaking,
+        report(&changes)
+    );
+    Ok(())
+}
+
+fn lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn classifies_changes() {
+    let before = lines(&[
+        "enum crate::destiny::DestinyClass (derive(Clone, Debug))",
+        "variant crate::destiny::DestinyClass::Titan = 0",
+        "struct crate::destiny::Loadout (derive(Clone))",
+        "field crate::destiny::Loadout.name: Option<String>",
+        "struct crate::destiny::Opaque (private fields)",
+        "field crate::destiny::Opaque.id: i64",
+        "trait crate::Lookup",
+        "fn crate::destiny::Loadout::label(&self) -> String",
+    ]);
+    let after = lines(&[
+        "enum crate::destiny::DestinyClass (derive(Clone, Debug))",
+        "variant crate::destiny::DestinyClass::Titan = 0",
+        "variant crate::destiny::DestinyClass::Hunter = 1",
+        "struct crate::destiny::Loadout (derive(Clone))",
+        "field crate::destiny::Loadout.name: Option<String>",
+        "field crate::destiny::Loadout.color: u32",
+        "struct crate::destiny::Opaque (private fields)",
+        "field crate::destiny::Opaque.id: i64",
+        "field crate::destiny::Opaque.hash: u32",
+        "trait crate::Lookup",
+        "trait_fn crate::Lookup::get(&self) (required)",
+        "trait_fn crate::Lookup::all(&self) (provided)",
+        "fn crate::destiny::Loadout::label(&self) -> &str",
+        "struct crate::destiny::New (derive(Clone))",
+        "field crate::destiny::New.id: i64",
+    ]);
+
+    let changes: Vec<(ChangeKind, bool, &str)> = diff(&before, &after).iter().map(|c| (c.kind, c.added, c.reason)).collect();
+    assert_eq!(
+        changes,
+        [
+            (ChangeKind::Breaking, false, "removed or changed"),
+            (ChangeKind::Breaking, true, "new variant on an existing exhaustive enum"),
+            (ChangeKind::Breaking, true, "new field on an existing struct that can be built with a struct literal"),
+            (ChangeKind::Breaking, true, "new required method on an existing trait"),
+            (ChangeKind::Additive, true, "added"),
+            (ChangeKind::Additive, true, "added"),
+            (ChangeKind::Additive, true, "added"),
+            (ChangeKind::Additive, true, "added"),
+            (ChangeKind::Additive, true, "added"),
+        ]
+    );
+    assert!(diff(&after, &after).is_empty());
+}
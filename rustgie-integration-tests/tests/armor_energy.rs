@@ -0,0 +1,49 @@
+﻿use rustgie::types::destiny::entities::items::{energy_summary, DestinyItemInstanceComponent, EnergySummary};
+use rustgie::types::destiny::DestinyEnergyType;
+use serde_json::Value;
+
+fn instance(name: &str) -> anyhow::Result<DestinyItemInstanceComponent> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/armor_energy.json"))?;
+    Ok(serde_json::from_value(fixture[name].clone())?)
+}
+
+#[test]
+fn current_armor() -> anyhow::Result<()> {
+    let armor = instance("current")?;
+
+    let summary = energy_summary(&armor).expect("armor has energy");
+
+    assert_eq!(summary, EnergySummary { capacity: 10, used: 7, unused: 3 });
+    assert!(summary.can_fit_mod(3));
+    assert!(summary.can_fit_mod(0));
+    assert!(!summary.can_fit_mod(4));
+    assert_eq!(armor.energy.map(|e| e.energy_type), Some(DestinyEnergyType::Any));
+    Ok(())
+}
+
+#[test]
+fn energy_type_may_be_absent() -> anyhow::Result<()> {
+    let armor = instance("withoutType")?;
+
+    let energy = armor.energy.as_ref().expect("armor has energy");
+    assert_eq!((energy.energy_type_hash, energy.energy_type), (0, DestinyEnergyType::Any));
+    assert!(energy_summary(&armor).expect("armor has energy").can_fit_mod(11));
+    Ok(())
+}
+
+#[test]
+fn legacy_elemental_armor() -> anyhow::Result<()> {
+    let armor = instance("legacy")?;
+
+    assert_eq!(armor.energy.as_ref().map(|e| e.energy_type), Some(DestinyEnergyType::Arc));
+    let summary = energy_summary(&armor).expect("armor has energy");
+    assert_eq!(summary, EnergySummary { capacity: 10, used: 9, unused: 1 });
+    assert!(summary.can_fit_mod(1) && !summary.can_fit_mod(2), "the element no longer matters, only the cost");
+    Ok(())
+}
+
+#[test]
+fn weapons_have_no_energy() -> anyhow::Result<()> {
+    assert_eq!(energy_summary(&instance("weapon")?), None);
+    Ok(())
+}
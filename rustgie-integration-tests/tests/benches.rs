@@ -0,0 +1,106 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::items::{DestinyItemComponent, DestinyItemInstanceComponent};
+use rustgie::types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup;
+use rustgie::types::destiny::responses::DestinyProfileResponse;
+use rustgie::types::DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent;
+use rustgie_benches::baseline::{compare, read_results, Baseline};
+use rustgie_benches::fixtures;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+#[test]
+fn benchmark_fixtures_parse_at_full_size() -> anyhow::Result<()> {
+    let profile: DestinyProfileResponse = serde_json::from_str(&fixtures::profile_response())?;
+    let vault = profile.profile_inventory.and_then(|inventory| inventory.data).and_then(|inventory| inventory.items).expect("vault");
+    assert_eq!(vault.len(), fixtures::VAULT_ITEM_COUNT);
+    let inventories = profile.character_inventories.and_then(|inventories| inventories.data).expect("character inventories");
+    assert_eq!(inventories.len(), 3);
+    assert!(inventories.values().all(|inventory| inventory.items.as_ref().map(Vec::len) == Some(fixtures::CHARACTER_ITEM_COUNT)));
+    // Every copied item kept its components.
+    let instances = profile.item_components.and_then(|components| components.instances).and_then(|instances| instances.data).expect("instances");
+    assert!(vault.iter().filter_map(|item| item.item_instance_id).all(|id| instances.contains_key(&id)));
+
+    let definitions: HashMap<u32, DestinyInventoryItemDefinition> = serde_json::from_str(&fixtures::item_definition_table())?;
+    assert_eq!(definitions.len(), fixtures::ITEM_DEFINITION_COUNT);
+    assert!(definitions.iter().all(|(hash, definition)| definition.hash == *hash));
+
+    let keyed: DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent = serde_json::from_str(&fixtures::item_instances())?;
+    assert_eq!(keyed.data.map(|data| data.len()), Some(fixtures::ITEM_COUNT));
+    let values: Vec<DestinyItemInstanceComponent> = serde_json::from_str(&fixtures::item_instance_values())?;
+    assert_eq!(values.len(), fixtures::ITEM_COUNT);
+    let items: Vec<DestinyItemComponent> = serde_json::from_str(&fixtures::item_components())?;
+    assert_eq!(items.len(), fixtures::ITEM_COUNT);
+    let activities: Vec<DestinyHistoricalStatsPeriodGroup> = serde_json::from_str(&fixtures::activities())?;
+    assert_eq!(activities.len(), fixtures::ACTIVITY_COUNT);
+    Ok(())
+}
+
+#[test]
+fn only_slowdowns_past_the_threshold_are_regressions() {
+    let baseline = Baseline {
+        benchmarks: BTreeMap::from([
+            ("profile/full_response".to_string(), 1000.0),
+            ("item_definitions/table".to_string(), 1000.0),
+            ("enum_heavy/activities".to_string(), 1000.0),
+        ]),
+    };
+    let results = BTreeMap::from([
+        ("profile/full_response".to_string(), 1150.0),
+        ("item_definitions/table".to_string(), 1080.0),
+        ("dictionary_keys/int64_keyed".to_string(), 500.0),
+    ]);
+
+    let comparisons = compare(&baseline, &results);
+    let ids: Vec<&str> = comparisons.iter().map(|comparison| comparison.id.as_str()).collect();
+    assert_eq!(ids, ["dictionary_keys/int64_keyed", "enum_heavy/activities", "item_definitions/table", "profile/full_response"]);
+
+    let regressed: Vec<&str> = comparisons.iter().filter(|comparison| comparison.regressed(10.0)).map(|comparison| comparison.id.as_str()).collect();
+    assert_eq!(regressed, ["profile/full_response"]);
+    assert_eq!(comparisons[3].change_percent().map(f64::round), Some(15.0));
+    // New and missing benchmarks have nothing to compare.
+    assert_eq!(comparisons[0].change_percent(), None);
+    assert_eq!(comparisons[1].current_ns, None);
+}
+
+#[test]
+fn results_are_read_from_criterion_output() -> anyhow::Result<()> {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("criterion");
+    let _ = std::fs::remove_dir_all(&dir);
+    for (id, mean) in [("profile/full_response", 3738191.4), ("enum_heavy/item_components", 1315969.8)] {
+        let run = dir.join(id).join("new");
+        std::fs::create_dir_all(&run)?;
+        std::fs::write(run.join("estimates.json"), format!(r#"{{"mean":{{"point_estimate":{mean},"standard_error":12.5}}}}"#))?;
+        // The previous run and the reports aren't benchmarks of their own.
+        std::fs::create_dir_all(dir.join(id).join("base"))?;
+        std::fs::create_dir_all(dir.join(id).join("report"))?;
+    }
+    std::fs::create_dir_all(dir.join("report"))?;
+
+    let results = read_results(&dir)?;
+    assert_eq!(
+        results,
+        BTreeMap::from([
+            ("enum_heavy/item_components".to_string(), 1315969.8),
+            ("profile/full_response".to_string(), 3738191.4),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn committed_baseline_covers_every_benchmark() -> anyhow::Result<()> {
+    let baseline = Baseline::load(&rustgie_benches::baseline::baseline_path())?;
+    let ids: Vec<&str> = baseline.benchmarks.keys().map(String::as_str).collect();
+    assert_eq!(
+        ids,
+        [
+            "dictionary_keys/int64_keyed",
+            "dictionary_keys/values_only",
+            "enum_heavy/activities",
+            "enum_heavy/item_components",
+            "item_definitions/table",
+            "profile/full_response",
+        ]
+    );
+    Ok(())
+}
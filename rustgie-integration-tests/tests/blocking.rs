@@ -0,0 +1,47 @@
+﻿use rustgie::RustgieClientBuilder;
+use rustgie_integration_tests::{Session, TEST_API_KEY};
+use wiremock::MockServer;
+
+/// Starts a mock server replaying `name` from a plain thread, the way a blocking caller would set one up.
+fn replay_without_runtime(name: &str) -> anyhow::Result<(tokio::runtime::Runtime, MockServer)> {
+    let setup = tokio::runtime::Runtime::new()?;
+    let session = Session::load(name)?;
+    let server = setup.block_on(async {
+        let server = MockServer::start().await;
+        session.mount(&server).await;
+        server
+    });
+    Ok((setup, server))
+}
+
+#[test]
+fn blocking_get() -> anyhow::Result<()> {
+    let (_setup, server) = replay_without_runtime("common_settings_email")?;
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .build_blocking()?;
+
+    let settings = client.get_common_settings(None)?;
+
+    assert_eq!(settings.environment.as_deref(), Some("live"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn blocking_client_refuses_to_run_inside_a_runtime() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .build_blocking()?;
+
+    let error = client.get_common_settings(None).expect_err("called from an async context");
+    assert!(error.to_string().contains("async runtime"), "{error}");
+    assert!(server.received_requests().await.expect("recording enabled").is_empty());
+
+    drop(client);
+    Ok(())
+}
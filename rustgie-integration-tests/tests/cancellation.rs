@@ -0,0 +1,185 @@
+﻿use rustgie::pages::collect_pages;
+use rustgie::types::destiny::requests::DestinyItemTransferRequest;
+use rustgie::types::BungieMembershipType;
+use rustgie::{BulkTransfer, CancellationToken, ClanAdmin, Middleware, MiddlewareFuture, Next, Outcome, RustgieClientBuilder};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const GROUP_ID: i64 = 3074427;
+
+/// Cancels the token as request number `on_request` (from 1) goes out, standing in for a caller cancelling while it's in flight.
+struct CancelOnRequest {
+    on_request: usize,
+    seen: AtomicUsize,
+    cancellation: CancellationToken,
+}
+
+impl Middleware for CancelOnRequest {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        if self.seen.fetch_add(1, Ordering::SeqCst) + 1 == self.on_request {
+            self.cancellation.cancel();
+        }
+        next.run(request)
+    }
+}
+
+fn client(server: &MockServer, cancellation: &CancellationToken, on_request: usize) -> anyhow::Result<rustgie::RustgieClient> {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_middleware(Arc::new(CancelOnRequest {
+            on_request,
+            seen: AtomicUsize::new(0),
+            cancellation: cancellation.clone(),
+        }))
+        .build()
+}
+
+fn invitation_page(membership_id: i64, has_more: bool) -> ResponseTemplate {
+    success_envelope(json!({
+        "results": [{
+            "groupId": GROUP_ID.to_string(),
+            "creationDate": "2023-06-10T12:00:00Z",
+            "resolveState": 0,
+            "destinyUserInfo": {
+                "LastSeenDisplayName": "Guardian", "LastSeenDisplayNameType": 3,
+                "crossSaveOverride": 0, "applicableMembershipTypes": [3], "isPublic": true, "membershipType": 3,
+                "membershipId": membership_id.to_string(), "displayName": "Guardian"
+            }
+        }],
+        "totalResults": 0,
+        "hasMore": has_more,
+        "useTotalResults": false
+    }))
+}
+
+#[tokio::test]
+async fn paginator_cancelled_between_pages() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let invited = format!("/Platform/GroupV2/{GROUP_ID}/Members/InvitedIndividuals/");
+    Mock::given(path(invited.as_str()))
+        .and(query_param("currentpage", "1"))
+        .respond_with(invitation_page(4611686018400000002, true))
+        .mount(&server)
+        .await;
+    Mock::given(path(invited.as_str()))
+        .and(query_param("currentpage", "2"))
+        .respond_with(invitation_page(4611686018400000003, false))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let cancellation = CancellationToken::new();
+    let client = client(&server, &cancellation, 1)?;
+
+    let overview = ClanAdmin::new(&client, "admin-token")
+        .with_cancellation(cancellation)
+        .pending_overview(GROUP_ID)
+        .await?;
+
+    let Outcome::Cancelled(partial) = overview else {
+        panic!("expected a cancelled overview");
+    };
+    assert_eq!(partial.iter().map(|m| m.membership.1).collect::<Vec<_>>(), [4611686018400000002], "the page in flight is kept");
+    assert_eq!(server.received_requests().await.expect("recording enabled").len(), 1, "nothing after the cancelled page");
+    Ok(())
+}
+
+/// Pages 0 to 3, one item each, cancelling the token while page `cancel_during` loads.
+async fn pages(cancel_during: Option<i32>, cancellation: CancellationToken) -> anyhow::Result<Outcome<Vec<i32>>> {
+    collect_pages(0, &cancellation, |page| {
+        if cancel_during == Some(page) {
+            cancellation.cancel();
+        }
+        async move { Ok((vec![page], page < 3)) }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn collect_pages_runs_to_completion_or_stops() -> anyhow::Result<()> {
+    assert_eq!(pages(None, CancellationToken::new()).await?, Outcome::Completed(vec![0, 1, 2, 3]));
+    assert_eq!(pages(Some(1), CancellationToken::new()).await?, Outcome::Cancelled(vec![0, 1]));
+    assert_eq!(pages(Some(3), CancellationToken::new()).await?, Outcome::Completed(vec![0, 1, 2, 3]), "cancelled during the last page");
+
+    let already = CancellationToken::new();
+    already.cancel();
+    assert_eq!(pages(None, already).await?, Outcome::Cancelled(Vec::new()));
+    Ok(())
+}
+
+fn transfer(item_id: i64) -> DestinyItemTransferRequest {
+    DestinyItemTransferRequest {
+        item_reference_hash: 2907129557,
+        stack_size: 1,
+        transfer_to_vault: true,
+        item_id,
+        character_id: 2305843009300000001,
+        membership_type: BungieMembershipType::TigerSteam,
+    }
+}
+
+#[tokio::test]
+async fn bulk_transfer_cancelled_mid_batch() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/TransferItem/"))
+        .respond_with(success_envelope(json!(0)))
+        .mount(&server)
+        .await;
+    let cancellation = CancellationToken::new();
+    let client = client(&server, &cancellation, 2)?;
+
+    let outcome = BulkTransfer::new(&client, "access-token")
+        .with_cancellation(cancellation)
+        .run(vec![transfer(1), transfer(2), transfer(3), transfer(4)])
+        .await;
+
+    assert!(outcome.is_cancelled());
+    let report = outcome.into_inner();
+    let ids = |requests: &[DestinyItemTransferRequest]| requests.iter().map(|r| r.item_id).collect::<Vec<_>>();
+    assert_eq!(ids(&report.transferred), [1, 2], "the transfer in flight when cancelled still finishes");
+    assert!(report.failed.is_empty());
+    assert_eq!(ids(&report.remaining), [3, 4]);
+    assert_eq!(server.received_requests().await.expect("recording enabled").len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_transfer_records_failures_and_carries_on() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/TransferItem/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ErrorCode": 1623,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "DestinyItemNotFound",
+            "Message": "The item requested was not found.",
+            "MessageData": {}
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/TransferItem/"))
+        .respond_with(success_envelope(json!(0)))
+        .mount(&server)
+        .await;
+    let client = client(&server, &CancellationToken::new(), 0)?;
+
+    let outcome = BulkTransfer::new(&client, "access-token").run(vec![transfer(1), transfer(2)]).await;
+
+    let Outcome::Completed(report) = outcome else {
+        panic!("nothing cancelled this batch");
+    };
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0.item_id, 1);
+    assert_eq!(report.transferred.len(), 1);
+    assert!(report.remaining.is_empty());
+    Ok(())
+}
@@ -0,0 +1,171 @@
+﻿use rustgie::types::groups_v2::{GroupMember, RuntimeGroupMemberType};
+use rustgie::types::BungieMembershipType;
+use rustgie::{CancellationToken, ClanRoster, PromotionError};
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer};
+
+const GROUP_ID: i64 = 3074427;
+const FOUNDER: i64 = 4611686018400000010;
+const ADMIN: i64 = 4611686018400000011;
+const MEMBER: i64 = 4611686018400000012;
+const BEGINNER: i64 = 4611686018400000013;
+
+fn member(membership_id: i64, member_type: i32) -> Value {
+    json!({
+        "memberType": member_type,
+        "isOnline": false,
+        "lastOnlineStatusChange": "1686700800",
+        "groupId": GROUP_ID.to_string(),
+        "destinyUserInfo": {
+            "LastSeenDisplayName": "Guardian", "LastSeenDisplayNameType": 3,
+            "crossSaveOverride": 0, "applicableMembershipTypes": [3], "isPublic": true,
+            "membershipType": 3, "membershipId": membership_id.to_string(), "displayName": "Guardian"
+        },
+        "joinDate": "2021-02-23T04:00:00Z"
+    })
+}
+
+async fn roster_server() -> MockServer {
+    let server = MockServer::start().await;
+    let members = format!("/Platform/GroupV2/{GROUP_ID}/Members/");
+    let page = |results: Vec<Value>, page: i32, has_more: bool| {
+        success_envelope(json!({
+            "results": results,
+            "totalResults": 4,
+            "hasMore": has_more,
+            "query": { "itemsPerPage": 2, "currentPage": page },
+            "useTotalResults": true
+        }))
+    };
+    Mock::given(method("GET"))
+        .and(path(members.as_str()))
+        .and(query_param("currentpage", "1"))
+        .respond_with(page(vec![member(FOUNDER, 5), member(ADMIN, 3)], 1, true))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(members.as_str()))
+        .and(query_param("currentpage", "2"))
+        .respond_with(page(vec![member(MEMBER, 2), member(BEGINNER, 1)], 2, false))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn by_id<'r>(roster: &'r ClanRoster<'_>, membership_id: i64) -> &'r GroupMember {
+    roster.member((BungieMembershipType::TigerSteam, membership_id)).expect("on the roster")
+}
+
+#[tokio::test]
+async fn legal_promotion_is_sent() -> anyhow::Result<()> {
+    let server = roster_server().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/3/{BEGINNER}/SetMembershipType/2/")))
+        .and(header("Authorization", "Bearer admin-token"))
+        .respond_with(success_envelope(json!(0)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let roster = ClanRoster::fetch(&client, "admin-token", GROUP_ID, &CancellationToken::new()).await?.into_inner();
+    assert_eq!(roster.members().len(), 4);
+    let admin = by_id(&roster, ADMIN);
+
+    roster.edit_member_type(admin, by_id(&roster, BEGINNER), RuntimeGroupMemberType::Member).await?;
+    assert_eq!(
+        ClanRoster::can_promote(by_id(&roster, FOUNDER), by_id(&roster, MEMBER), RuntimeGroupMemberType::Admin),
+        Ok(()),
+        "founders can make admins"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn founder_promotion_is_refused_locally() -> anyhow::Result<()> {
+    let server = roster_server().await;
+    let client = client_for(&server)?;
+    let roster = ClanRoster::fetch(&client, "founder-token", GROUP_ID, &CancellationToken::new()).await?.into_inner();
+    let founder = by_id(&roster, FOUNDER);
+    let admin = by_id(&roster, ADMIN);
+
+    let error = roster
+        .edit_member_type(founder, admin, RuntimeGroupMemberType::Founder)
+        .await
+        .expect_err("founders are made by abdicating");
+    assert_eq!(error.downcast_ref::<PromotionError>(), Some(&PromotionError::FounderViaEdit));
+
+    let member = by_id(&roster, MEMBER);
+    assert_eq!(
+        ClanRoster::can_promote(admin, member, RuntimeGroupMemberType::Admin),
+        Err(PromotionError::AboveActorRank)
+    );
+    assert_eq!(
+        ClanRoster::can_promote(admin, founder, RuntimeGroupMemberType::Member),
+        Err(PromotionError::TargetNotBelowActor)
+    );
+    assert_eq!(
+        ClanRoster::can_promote(member, by_id(&roster, BEGINNER), RuntimeGroupMemberType::Member),
+        Err(PromotionError::ActorNotAdmin)
+    );
+    assert_eq!(ClanRoster::can_promote(admin, admin, RuntimeGroupMemberType::Member), Err(PromotionError::SelfEdit));
+
+    let posts = server
+        .received_requests()
+        .await
+        .expect("request recording is enabled")
+        .iter()
+        .filter(|request| request.method.as_str() == "POST")
+        .count();
+    assert_eq!(posts, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn abdication_only_to_admins() -> anyhow::Result<()> {
+    let server = roster_server().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Admin/AbdicateFoundership/3/{ADMIN}/")))
+        .and(header("Authorization", "Bearer founder-token"))
+        .respond_with(success_envelope(json!(true)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+    let roster = ClanRoster::fetch(&client, "founder-token", GROUP_ID, &CancellationToken::new()).await?.into_inner();
+
+    let error = roster
+        .abdicate((BungieMembershipType::TigerSteam, MEMBER))
+        .await
+        .expect_err("members have to be made admins first");
+    assert_eq!(error.downcast_ref::<PromotionError>(), Some(&PromotionError::TargetNotAdmin));
+    let error = roster.abdicate((BungieMembershipType::TigerSteam, 4611686018400000099)).await.expect_err("not a member");
+    assert_eq!(error.downcast_ref::<PromotionError>(), Some(&PromotionError::NotOnRoster));
+
+    assert!(roster.abdicate((BungieMembershipType::TigerSteam, ADMIN)).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn members_without_user_info_are_not_each_other() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let client = client_for(&server)?;
+    let anonymous = |member_type: i32| -> GroupMember {
+        let mut member = member(0, member_type);
+        member["destinyUserInfo"] = Value::Null;
+        serde_json::from_value(member).expect("valid group member")
+    };
+    let (admin, beginner) = (anonymous(3), anonymous(1));
+    let roster = ClanRoster::new(&client, "admin-token", GROUP_ID, vec![admin.clone(), beginner.clone()]);
+
+    assert_eq!(ClanRoster::can_promote(&admin, &beginner, RuntimeGroupMemberType::Member), Ok(()));
+    assert!(roster.member((BungieMembershipType::None, 0)).is_none());
+    let error = roster
+        .edit_member_type(&admin, &beginner, RuntimeGroupMemberType::Member)
+        .await
+        .expect_err("there's no membership to send");
+    assert_eq!(error.downcast_ref::<PromotionError>(), Some(&PromotionError::NoMembership));
+    Ok(())
+}
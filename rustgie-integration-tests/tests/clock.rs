@@ -0,0 +1,175 @@
+﻿use rustgie::types::api_response_::BungieTokenResponse;
+use rustgie::{FixedClock, OAuthSession, RustgieClient, RustgieClientBuilder, RustgieError};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use time::macros::datetime;
+use time::OffsetDateTime;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const RECEIVED_AT: OffsetDateTime = datetime!(2023-06-14 02:00:00 UTC);
+
+fn client(server: &MockServer, clock: &Arc<FixedClock>, throttle_retries: u32) -> anyhow::Result<RustgieClient> {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_oauth_client_id(12345)
+        .with_oauth_client_secret("s3cret")
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_clock(clock.clone())
+        .with_sleeper(clock.clone())
+        .with_throttle_retries(throttle_retries)
+        .build()
+}
+
+fn token(access_token: &str) -> Value {
+    json!({
+        "access_token": access_token,
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "refresh_token": "scrubbed-refresh",
+        "refresh_expires_in": 7776000,
+        "membership_id": "10000001"
+    })
+}
+
+async fn requests_to(server: &MockServer, endpoint: &str) -> usize {
+    let requests = server.received_requests().await.expect("request recording is enabled");
+    requests.iter().filter(|r| r.url.path() == endpoint).count()
+}
+
+#[tokio::test]
+async fn session_refreshes_only_near_expiry() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/App/OAuth/Token/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(token("refreshed")))
+        .mount(&server)
+        .await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    let client = client(&server, &clock, 0)?;
+    let initial: BungieTokenResponse = serde_json::from_value(token("initial"))?;
+    let session = OAuthSession::new(initial, RECEIVED_AT);
+
+    clock.advance(Duration::from_secs(3539));
+    assert_eq!(session.access_token(&client).await?, "initial");
+    assert_eq!(requests_to(&server, "/Platform/App/OAuth/Token/").await, 0);
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(session.access_token(&client).await?, "refreshed", "refreshes within the 60 second leeway");
+    assert_eq!(requests_to(&server, "/Platform/App/OAuth/Token/").await, 1);
+
+    clock.advance(Duration::from_secs(3000));
+    assert_eq!(session.access_token(&client).await?, "refreshed", "the refreshed token's lifetime starts at refresh time");
+    assert_eq!(requests_to(&server, "/Platform/App/OAuth/Token/").await, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_with_expired_refresh_token_needs_reauthorization() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    let client = client(&server, &clock, 0)?;
+    let session = OAuthSession::new(serde_json::from_value(token("initial"))?, RECEIVED_AT);
+
+    clock.set(datetime!(2023-09-12 02:00:00 UTC));
+    assert!(session.access_token(&client).await.is_err());
+    assert!(server.received_requests().await.expect("recording enabled").is_empty());
+    Ok(())
+}
+
+fn throttled(throttle_seconds: i32) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "ErrorCode": 36,
+        "ThrottleSeconds": throttle_seconds,
+        "ErrorStatus": "ThrottleLimitExceededMomentarily",
+        "Message": "Please wait a moment before trying again.",
+        "MessageData": {}
+    }))
+}
+
+async fn locales_server(throttled_responses: u64, throttle_seconds: i32) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(throttled(throttle_seconds))
+        .up_to_n_times(throttled_responses)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "en": "en" })))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn throttled_requests_back_off_exponentially() -> anyhow::Result<()> {
+    let server = locales_server(3, 0).await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    let client = client(&server, &clock, 3)?;
+
+    let locales = client.get_available_locales(None).await?;
+
+    assert_eq!(locales["en"], "en");
+    assert_eq!(clock.sleeps(), [Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(4)]);
+    assert_eq!(client.now(), RECEIVED_AT + Duration::from_secs(7));
+    Ok(())
+}
+
+#[tokio::test]
+async fn throttle_seconds_from_bungie_win() -> anyhow::Result<()> {
+    let server = locales_server(1, 5).await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    let client = client(&server, &clock, 3)?;
+
+    client.get_available_locales(None).await?;
+
+    assert_eq!(clock.sleeps(), [Duration::from_secs(5)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_run_out() -> anyhow::Result<()> {
+    let server = locales_server(3, 0).await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    let client = client(&server, &clock, 2)?;
+
+    let error = client.get_available_locales(None).await.expect_err("still throttled");
+    assert!(error.downcast_ref::<RustgieError>().is_some_and(RustgieError::is_throttled));
+    assert_eq!(clock.sleeps().len(), 2);
+    assert_eq!(requests_to(&server, "/Platform/GetAvailableLocales/").await, 3);
+
+    let no_retries = locales_server(1, 0).await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+    assert!(client_for_default(&no_retries, &clock)?.get_available_locales(None).await.is_err());
+    assert!(clock.sleeps().is_empty(), "throttling is returned immediately by default");
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_json_responses_are_checked_for_throttling() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let envelope = json!({ "ErrorCode": 36, "ThrottleSeconds": 0, "ErrorStatus": "ThrottleLimitExceededMomentarily", "Message": "" });
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(envelope.to_string(), "text/plain"))
+        .mount(&server)
+        .await;
+    let clock = Arc::new(FixedClock::new(RECEIVED_AT));
+
+    assert!(client(&server, &clock, 3)?.get_available_locales(None).await.is_err());
+    assert!(clock.sleeps().is_empty());
+    assert_eq!(requests_to(&server, "/Platform/GetAvailableLocales/").await, 1);
+    Ok(())
+}
+
+fn client_for_default(server: &MockServer, clock: &Arc<FixedClock>) -> anyhow::Result<RustgieClient> {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_sleeper(clock.clone())
+        .build()
+}
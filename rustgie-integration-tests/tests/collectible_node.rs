@@ -0,0 +1,58 @@
+﻿use rustgie::types::destiny::{DestinyCollectibleState, DestinyComponentType};
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+use std::collections::HashMap;
+
+/// The Monument to Lost Lights kiosk node.
+const MONUMENT_NODE_HASH: u32 = 1881970629;
+const THORN: (u32, u32) = (4009683574, 4190156464);
+const ANARCHY: (u32, u32) = (3373297113, 2376481550);
+const WISH_ENDER: (u32, u32) = (1660030044, 814876684);
+
+#[tokio::test]
+async fn monument_kiosk_previews() -> anyhow::Result<()> {
+    let (_server, client) = replay("collectible_node_monument").await?;
+    let response = client
+        .destiny2_get_collectible_node_details(
+            2305843009200000001,
+            MONUMENT_NODE_HASH,
+            4611686018400000001,
+            BungieMembershipType::TigerSteam,
+            Some(vec![
+                DestinyComponentType::Collectibles,
+                DestinyComponentType::ItemStats,
+                DestinyComponentType::ItemSockets,
+                DestinyComponentType::ItemReusablePlugs,
+            ]),
+            None,
+        )
+        .await?;
+
+    // Stands in for DestinyCollectibleDefinition.itemHash lookups; Wish-Ender is deliberately unmapped.
+    let item_hashes: HashMap<u32, u32> = [THORN, ANARCHY].into_iter().collect();
+    let previews = response.collectible_previews(|hash| item_hashes.get(&hash).copied());
+
+    let order: Vec<u32> = previews.iter().map(|p| p.collectible_hash).collect();
+    assert_eq!(order, [WISH_ENDER.0, ANARCHY.0, THORN.0]);
+
+    let wish_ender = &previews[0];
+    assert_eq!(wish_ender.item_hash, None);
+    assert!(wish_ender.stats.is_none() && wish_ender.sockets.is_none());
+    assert!(wish_ender.collectible.state.contains(DestinyCollectibleState::NotAcquired));
+
+    let anarchy = &previews[1];
+    assert_eq!(anarchy.item_hash, Some(ANARCHY.1));
+    assert!(anarchy.collectible.state.contains(DestinyCollectibleState::CannotAffordMaterialRequirements));
+    assert_eq!(anarchy.stats.and_then(|s| s.stats.as_ref()).map(HashMap::len), Some(1));
+    assert!(anarchy.sockets.is_none(), "components Bungie didn't return for an item stay empty");
+
+    let thorn = &previews[2];
+    assert!(thorn.collectible.state.is_empty(), "acquired and purchasable");
+    assert_eq!(thorn.sockets.and_then(|s| s.sockets.as_ref()).map(Vec::len), Some(2));
+    assert_eq!(
+        thorn.reusable_plugs.and_then(|p| p.plugs.as_ref()).and_then(|p| p.get(&0)).map(Vec::len),
+        Some(2)
+    );
+    assert!(thorn.instance.is_none(), "previews are never instanced");
+    Ok(())
+}
@@ -0,0 +1,66 @@
+﻿use rustgie::types::compat::{
+    parse_collectible_definition_lenient, parse_item_definition_lenient, parse_record_definition_lenient, ManifestFormatVersion,
+};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::{DamageType, DestinyBreakerType, DestinyPresentationNodeType};
+use serde_json::Value;
+
+fn legacy(name: &str) -> Value {
+    let definitions: Value = serde_json::from_str(include_str!("../fixtures/legacy_definitions.json")).expect("valid JSON");
+    definitions[name].clone()
+}
+
+#[test]
+fn pre_breaker_type_items_are_upgraded() -> anyhow::Result<()> {
+    let sunshot = legacy("preBreakerTypesItem");
+    assert_eq!(ManifestFormatVersion::probe(&sunshot), ManifestFormatVersion::PreBreakerTypes);
+    assert!(serde_json::from_value::<DestinyInventoryItemDefinition>(sunshot.clone()).is_err());
+
+    let sunshot = parse_item_definition_lenient(&sunshot)?;
+    assert_eq!(sunshot.hash, 2907129557);
+    assert_eq!(sunshot.breaker_type, DestinyBreakerType::None);
+    assert!(!sunshot.is_wrapper);
+    assert_eq!(sunshot.damage_types, Some(vec![DamageType::Thermal]));
+    assert_eq!(sunshot.damage_type_hashes, Some(vec![1847026933]));
+    assert_eq!(sunshot.default_damage_type_hash, Some(1847026933));
+    let quality = sunshot.quality.expect("quality block");
+    assert_eq!(quality.current_version, 0);
+    assert_eq!(quality.versions, None);
+    assert_eq!(quality.infusion_category_hash, 3372674214);
+    Ok(())
+}
+
+#[test]
+fn pre_power_cap_items_keep_what_they_had() -> anyhow::Result<()> {
+    let gjallarhorn = legacy("prePowerCapsItem");
+    assert_eq!(ManifestFormatVersion::probe(&gjallarhorn), ManifestFormatVersion::PrePowerCaps);
+    assert!(ManifestFormatVersion::PrePowerCaps.needs_upgrade());
+
+    let gjallarhorn = parse_item_definition_lenient(&gjallarhorn)?;
+    assert_eq!(gjallarhorn.damage_type_hashes, Some(vec![3454344768]));
+    assert_eq!(gjallarhorn.quality.expect("quality block").current_version, 0);
+    Ok(())
+}
+
+#[test]
+fn current_items_parse_unchanged() -> anyhow::Result<()> {
+    let definitions: Value = serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json"))?;
+    let sunshot = &definitions["2907129557"];
+    assert_eq!(ManifestFormatVersion::probe_blob(sunshot.to_string().as_bytes())?, ManifestFormatVersion::Current);
+    assert!(!ManifestFormatVersion::Current.needs_upgrade());
+    assert_eq!(parse_item_definition_lenient(sunshot)?, serde_json::from_value(sunshot.clone())?);
+    Ok(())
+}
+
+#[test]
+fn older_records_and_collectibles_are_upgraded() -> anyhow::Result<()> {
+    let record = parse_record_definition_lenient(&legacy("preGildingRecord"))?;
+    assert_eq!(record.hash, 2648109757);
+    assert!(!record.for_title_gilding && !record.should_show_large_icons);
+    assert_eq!(record.presentation_node_type, DestinyPresentationNodeType::Default);
+
+    let collectible = parse_collectible_definition_lenient(&legacy("untypedCollectible"))?;
+    assert_eq!(collectible.item_hash, 2907129557);
+    assert_eq!(collectible.presentation_node_type, DestinyPresentationNodeType::Default);
+    Ok(())
+}
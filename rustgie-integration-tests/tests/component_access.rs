@@ -0,0 +1,139 @@
+﻿use rustgie::types::components::{classify, ComponentAccess, ComponentPrivacySetting, ComponentResponseState};
+use rustgie::types::destiny::responses::DestinyProfileResponse;
+use rustgie::types::DictionaryComponentResponseOfint64AndDestinyCharacterComponent as Characters;
+use serde_json::json;
+
+use ComponentAccess::{Available, Disabled, NotRequested, Private};
+use ComponentPrivacySetting as Privacy;
+
+#[test]
+fn truth_table() {
+    // (has data, privacy, disabled) => access, for every combination.
+    let table = [
+        (false, Privacy::None, None, NotRequested),
+        (false, Privacy::None, Some(false), NotRequested),
+        (false, Privacy::None, Some(true), Disabled),
+        (false, Privacy::Public, None, NotRequested),
+        (false, Privacy::Public, Some(false), NotRequested),
+        (false, Privacy::Public, Some(true), Disabled),
+        (false, Privacy::Private, None, Private),
+        (false, Privacy::Private, Some(false), Private),
+        (false, Privacy::Private, Some(true), Disabled),
+        (true, Privacy::None, None, Available),
+        (true, Privacy::None, Some(false), Available),
+        (true, Privacy::None, Some(true), Disabled),
+        (true, Privacy::Public, None, Available),
+        (true, Privacy::Public, Some(false), Available),
+        (true, Privacy::Public, Some(true), Disabled),
+        (true, Privacy::Private, None, Available),
+        (true, Privacy::Private, Some(false), Available),
+        (true, Privacy::Private, Some(true), Disabled),
+    ];
+
+    for (has_data, privacy, disabled, expected) in table {
+        let data = if has_data { Some("data") } else { None };
+        assert_eq!(
+            classify(&data, privacy, disabled),
+            expected,
+            "has data: {has_data}, privacy: {privacy:?}, disabled: {disabled:?}"
+        );
+    }
+}
+
+#[test]
+fn component_wrappers_classify_themselves() -> anyhow::Result<()> {
+    let profile: DestinyProfileResponse = serde_json::from_value(json!({
+        "responseMintedTimestamp": "2024-01-02T03:04:05Z",
+        "secondaryComponentsMintedTimestamp": "2024-01-02T03:04:05Z",
+        "characters": { "privacy": 1, "data": {} },
+        "characterInventories": { "privacy": 2 },
+        "profileInventory": { "privacy": 1, "disabled": true },
+        "profileCurrencies": { "privacy": 1 },
+    }))?;
+
+    assert_eq!(profile.characters.as_ref().map(|c| c.access()), Some(Available));
+    assert_eq!(profile.character_inventories.as_ref().map(|c| c.access()), Some(Private));
+    assert_eq!(profile.profile_inventory.as_ref().map(|c| c.access()), Some(Disabled));
+    assert_eq!(profile.profile_currencies.as_ref().map(|c| c.access()), Some(NotRequested));
+    Ok(())
+}
+
+fn characters(component: serde_json::Value) -> Characters {
+    serde_json::from_value(component).expect("valid characters component")
+}
+
+fn character(character_id: &str) -> serde_json::Value {
+    json!({
+        "membershipId": "4611686018400000001",
+        "membershipType": 3,
+        "characterId": character_id,
+        "dateLastPlayed": "2024-01-02T03:04:05Z",
+        "minutesPlayedThisSession": "0",
+        "minutesPlayedTotal": "1200",
+        "light": 1810,
+        "stats": {},
+        "raceHash": 898834093,
+        "genderHash": 3111576190u32,
+        "classHash": 671679327,
+        "raceType": 1,
+        "classType": 1,
+        "genderType": 1,
+        "emblemPath": "",
+        "emblemBackgroundPath": "",
+        "emblemHash": 0,
+        "emblemColor": { "red": 0, "green": 0, "blue": 0, "alpha": 255 },
+        "levelProgression": {
+            "progressionHash": 1716568313, "dailyProgress": 0, "dailyLimit": 0, "weeklyProgress": 0, "weeklyLimit": 0,
+            "currentProgress": 0, "level": 50, "levelCap": 50, "stepIndex": 50, "progressToNextLevel": 0, "nextLevelAt": 0
+        },
+        "baseCharacterLevel": 50,
+        "percentToNextLevel": 0.0,
+        "titleRecordHash": null
+    })
+}
+
+#[test]
+fn entries_iterate_whatever_came_back() {
+    let populated = characters(json!({
+        "privacy": 1,
+        "data": { "2305843009200000001": character("2305843009200000001"), "2305843009200000002": character("2305843009200000002") }
+    }));
+    let mut entries = populated.entries();
+    assert!(entries.was_populated());
+    assert_eq!(entries.len(), 2);
+    entries.next();
+    assert_eq!(entries.len(), 1, "len counts what's left");
+    let mut ids: Vec<i64> = populated.entries().map(|(id, _)| *id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [2305843009200000001, 2305843009200000002]);
+}
+
+#[test]
+fn missing_and_empty_data_both_iterate_nothing_but_differ_in_was_populated() {
+    let not_requested = characters(json!({ "privacy": 1 }));
+    let private = characters(json!({ "privacy": 2 }));
+    let empty = characters(json!({ "privacy": 1, "data": {} }));
+
+    for (component, populated) in [(&not_requested, false), (&private, false), (&empty, true)] {
+        let entries = component.entries();
+        assert_eq!(entries.len(), 0);
+        assert_eq!(entries.was_populated(), populated, "{component:?}");
+        assert_eq!(component.entries().count(), 0);
+    }
+    assert_eq!(empty.access(), Available, "an empty map is still data");
+}
+
+#[test]
+fn disabled_components_have_no_entries_even_with_data() {
+    let disabled = characters(json!({
+        "privacy": 1,
+        "disabled": true,
+        "data": { "2305843009200000001": character("2305843009200000001") }
+    }));
+    assert_eq!(disabled.access(), Disabled);
+    assert!(!disabled.entries().was_populated());
+    assert_eq!(disabled.entries().count(), 0);
+
+    let enabled = characters(json!({ "privacy": 1, "disabled": false, "data": {} }));
+    assert!(enabled.entries().was_populated(), "disabled: false is the same as no flag");
+}
@@ -0,0 +1,66 @@
+﻿use rustgie::types::destiny::components::items::{crafted_info, CraftedInfo, DestinyItemPlugObjectivesComponent};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::items::DestinyItemSocketsComponent;
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct Fixture {
+    definitions: HashMap<u32, DestinyInventoryItemDefinition>,
+    weapons: Value,
+}
+
+fn fixture() -> anyhow::Result<Fixture> {
+    let weapons: Value = serde_json::from_str(include_str!("../fixtures/crafted_weapon.json"))?;
+    let definitions = serde_json::from_value(weapons["definitions"].clone())?;
+    Ok(Fixture { definitions, weapons })
+}
+
+fn weapon(fixture: &Fixture, name: &str) -> anyhow::Result<(DestinyItemPlugObjectivesComponent, DestinyItemSocketsComponent)> {
+    let weapon = &fixture.weapons[name];
+    Ok((serde_json::from_value(weapon["plugObjectives"].clone())?, serde_json::from_value(weapon["sockets"].clone())?))
+}
+
+#[test]
+fn level_twenty_crafted_weapon() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let (objectives, sockets) = weapon(&fixture, "crafted")?;
+
+    let info = crafted_info(&objectives, &sockets, &fixture.definitions);
+
+    assert_eq!(
+        info,
+        Some(CraftedInfo {
+            level: 20,
+            level_progress: 0.25,
+            date_crafted: Some(time::macros::datetime!(2023-06-14 00:00:00 UTC)),
+            enhanced_perk_count: 2,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn non_crafted_weapon() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let (objectives, sockets) = weapon(&fixture, "notCrafted")?;
+
+    assert_eq!(crafted_info(&objectives, &sockets, &fixture.definitions), None);
+
+    let (crafted_objectives, _) = weapon(&fixture, "crafted")?;
+    assert_eq!(
+        crafted_info(&crafted_objectives, &sockets, &fixture.definitions),
+        None,
+        "objectives alone don't make a weapon crafted without the frame plugged"
+    );
+    Ok(())
+}
+
+#[test]
+fn frame_without_definition_is_not_recognized() -> anyhow::Result<()> {
+    let mut fixture = fixture()?;
+    let (objectives, sockets) = weapon(&fixture, "crafted")?;
+    fixture.definitions.remove(&659359923);
+
+    assert_eq!(crafted_info(&objectives, &sockets, &fixture.definitions), None);
+    Ok(())
+}
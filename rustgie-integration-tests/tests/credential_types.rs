@@ -0,0 +1,35 @@
+﻿use rustgie::types::user::models::{has_linked, public_display_names};
+use rustgie::types::BungieCredentialType;
+use rustgie_integration_tests::replay;
+
+const MEMBERSHIP_ID: i64 = 20000002;
+
+#[tokio::test]
+async fn twitch_and_steam_are_linked() -> anyhow::Result<()> {
+    let (_server, client) = replay("credential_types_twitch_steam").await?;
+
+    let credentials = client.user_get_credential_types_for_target_account(MEMBERSHIP_ID, Some("owner-token")).await?;
+
+    assert!(has_linked(&credentials, BungieCredentialType::TwitchId));
+    assert!(has_linked(&credentials, BungieCredentialType::SteamId));
+    assert!(has_linked(&credentials, BungieCredentialType::Psnid), "the owner also sees private credentials");
+    assert!(!has_linked(&credentials, BungieCredentialType::Xuid));
+    assert_eq!(
+        public_display_names(&credentials),
+        [(BungieCredentialType::SteamId, "scrubbed_steam"), (BungieCredentialType::TwitchId, "scrubbed_twitch")]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn partner_display_names_on_the_profile() -> anyhow::Result<()> {
+    let (_server, client) = replay("credential_types_twitch_steam").await?;
+
+    let user = client.user_get_bungie_net_user_by_id(MEMBERSHIP_ID, None).await?;
+
+    assert_eq!(user.partner_display_name(BungieCredentialType::TwitchId), Some("scrubbed_twitch"));
+    assert_eq!(user.partner_display_name(BungieCredentialType::SteamId), Some("scrubbed_steam"));
+    assert_eq!(user.partner_display_name(BungieCredentialType::Psnid), None, "empty names count as unset");
+    assert_eq!(user.partner_display_name(BungieCredentialType::Fake), None);
+    Ok(())
+}
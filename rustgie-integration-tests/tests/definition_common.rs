@@ -0,0 +1,69 @@
+﻿use rustgie::types::destiny::definitions::common::HasDefinitionCommon;
+use rustgie::types::destiny::definitions::records::DestinyRecordDefinition;
+use rustgie::types::destiny::definitions::seasons::DestinySeasonDefinition;
+use rustgie::types::destiny::definitions::{DestinyDefinition, DestinyInventoryItemDefinition};
+use rustgie_integration_tests::api_snapshot::{extract, types_source_dir};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+
+/// Checks a whole fixture table through the trait alone: every definition is filed under its own hash, isn't redacted,
+/// and parses back to itself after serializing.
+fn check_table<T>(fixture: &str) -> anyhow::Result<Vec<DestinyDefinition>>
+where
+    T: HasDefinitionCommon + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let table: HashMap<u32, T> = serde_json::from_str(fixture)?;
+    let mut commons = Vec::new();
+    for (hash, definition) in &table {
+        assert_eq!(definition.hash(), *hash);
+        assert!(!definition.redacted());
+        let reparsed: T = serde_json::from_str(&serde_json::to_string(definition)?)?;
+        assert_eq!(&reparsed, definition);
+        commons.push(definition.common());
+    }
+    commons.sort_by_key(|common| common.hash);
+    Ok(commons)
+}
+
+#[test]
+fn fixture_tables_work_through_the_trait() -> anyhow::Result<()> {
+    let items = check_table::<DestinyInventoryItemDefinition>(include_str!("../fixtures/inventory_item_definitions.json"))?;
+    let records = check_table::<DestinyRecordDefinition>(include_str!("../fixtures/record_definitions.json"))?;
+    let seasons = check_table::<DestinySeasonDefinition>(include_str!("../fixtures/season_definitions.json"))?;
+
+    assert!(!items.is_empty() && !records.is_empty() && !seasons.is_empty());
+    let record: DestinyRecordDefinition =
+        serde_json::from_value(serde_json::from_str::<serde_json::Value>(include_str!("../fixtures/record_definitions.json"))?["1060780635"].clone())?;
+    assert_eq!(
+        record.common(),
+        DestinyDefinition { hash: record.hash, index: record.index, redacted: record.redacted }
+    );
+    Ok(())
+}
+
+#[test]
+fn every_definition_implements_the_trait() -> anyhow::Result<()> {
+    let api = extract(&types_source_dir())?;
+    let mut fields: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for line in &api {
+        if let Some((owner, field)) = line.strip_prefix("field ").and_then(|f| f.split_once(':')).and_then(|(path, _)| path.rsplit_once('.')) {
+            fields.entry(owner).or_default().insert(field);
+        }
+    }
+    let definitions: BTreeSet<&str> = fields
+        .iter()
+        .filter(|(_, fields)| ["hash", "index", "redacted"].iter().all(|f| fields.contains(f)))
+        .map(|(owner, _)| *owner)
+        .collect();
+    let implemented: BTreeSet<&str> = api
+        .iter()
+        .filter_map(|line| line.strip_prefix("macro crate::destiny::definitions::common::impl_definition_common! "))
+        .collect();
+
+    let missing: Vec<_> = definitions.difference(&implemented).collect();
+    assert!(missing.is_empty(), "add these to impl_definition_common!: {missing:?}");
+    assert_eq!(implemented.len(), 64);
+    Ok(())
+}
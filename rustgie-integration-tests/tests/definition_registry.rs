@@ -0,0 +1,189 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::{CancellationToken, DefinitionProvider, DefinitionRegistry, DefinitionSnapshot, Outcome};
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ITEM_HASH: u32 = 3487922223;
+const TABLES: [&str; 5] = [
+    "DestinyInventoryItemDefinition",
+    "DestinyActivityDefinition",
+    "DestinyVendorDefinition",
+    "DestinyRecordDefinition",
+    "DestinyPresentationNodeDefinition",
+];
+
+fn content_path(table: &str, version: &str) -> String {
+    format!("/common/destiny2_content/json/en/{table}-{version}.json")
+}
+
+/// Version `version` of the manifest. Only the item table's path changes between versions.
+fn manifest(version: &str) -> ResponseTemplate {
+    let paths: serde_json::Map<String, Value> = TABLES
+        .iter()
+        .map(|table| {
+            let table_version = if *table == "DestinyInventoryItemDefinition" { version } else { "shared" };
+            (table.to_string(), json!(content_path(table, table_version)))
+        })
+        .collect();
+    success_envelope(json!({ "version": version, "jsonWorldComponentContentPaths": { "en": paths } }))
+}
+
+fn items(name: &str) -> Value {
+    let fixture: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    let mut item = fixture[ITEM_HASH.to_string()].clone();
+    item["displayProperties"]["name"] = json!(name);
+    json!({ ITEM_HASH.to_string(): item })
+}
+
+/// Serves manifest v1 once and v2 after that. The v2 item table takes `v2_delay` to download.
+async fn server(v2_delay: Duration) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Destiny2/Manifest/"))
+        .respond_with(manifest("v1"))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(manifest("v2")).mount(&server).await;
+    for (version, name, delay) in [("v1", "Generation One", Duration::ZERO), ("v2", "Generation Two", v2_delay)] {
+        Mock::given(method("GET"))
+            .and(path(content_path("DestinyInventoryItemDefinition", version)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(items(name)).set_delay(delay))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    for table in &TABLES[1..] {
+        Mock::given(method("GET"))
+            .and(path(content_path(table, "shared")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(1)
+            .named(format!("{table} is only downloaded once"))
+            .mount(&server)
+            .await;
+    }
+    server
+}
+
+fn item_name(snapshot: &DefinitionSnapshot) -> Option<&str> {
+    snapshot.inventory_items().get(&ITEM_HASH)?.display_properties.as_ref()?.name.as_deref()
+}
+
+#[tokio::test]
+async fn refresh_swaps_only_changed_tables() -> anyhow::Result<()> {
+    let server = server(Duration::ZERO).await;
+    let client = client_for(&server)?;
+    let registry = DefinitionRegistry::new();
+    assert_eq!(registry.snapshot().generation(), 0);
+    assert!(registry.snapshot().inventory_items().is_empty());
+
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Completed(true));
+    let first = registry.snapshot();
+    assert_eq!((first.generation(), first.version(), first.locale()), (1, Some("v1"), Some("en")));
+    assert_eq!(item_name(&first), Some("Generation One"));
+
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Completed(true));
+    let second = registry.snapshot();
+    assert_eq!((second.generation(), second.version()), (2, Some("v2")));
+    assert_eq!(item_name(&second), Some("Generation Two"));
+    assert_eq!(item_name(&first), Some("Generation One"), "snapshots taken earlier keep their generation");
+
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Completed(false), "nothing changed");
+    assert_eq!(registry.snapshot().generation(), 2);
+
+    let provider: &dyn DefinitionProvider = &second;
+    let item = provider.definition::<DestinyInventoryItemDefinition>(ITEM_HASH)?.expect("item is in the snapshot");
+    assert_eq!(item.display_properties.and_then(|d| d.name).as_deref(), Some("Generation Two"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn snapshots_are_consistent_during_a_refresh() -> anyhow::Result<()> {
+    let server = server(Duration::from_millis(300)).await;
+    let client = client_for(&server)?;
+    let registry = DefinitionRegistry::new();
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Completed(true));
+
+    let done = AtomicBool::new(false);
+    let refresh = async {
+        let swapped = registry.refresh(&client, "en").await;
+        done.store(true, Ordering::SeqCst);
+        swapped
+    };
+    let observe = async {
+        let mut seen = Vec::new();
+        while !done.load(Ordering::SeqCst) {
+            let snapshot = registry.snapshot();
+            seen.push((snapshot.generation(), snapshot.version().map(str::to_string), item_name(&snapshot).map(str::to_string)));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        seen
+    };
+    let (swapped, seen) = tokio::join!(refresh, observe);
+
+    assert_eq!(swapped?, Outcome::Completed(true));
+    assert!(seen.len() > 1, "snapshots can be taken while the refresh is downloading");
+    for (generation, version, name) in seen {
+        assert_eq!(generation, 1, "the new generation only appears once every table is ready");
+        assert_eq!(version.as_deref(), Some("v1"));
+        assert_eq!(name.as_deref(), Some("Generation One"));
+    }
+    let latest = registry.snapshot();
+    assert_eq!((latest.generation(), latest.version(), item_name(&latest)), (2, Some("v2"), Some("Generation Two")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_locale_leaves_the_registry_alone() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(manifest("v1")).mount(&server).await;
+    let client = client_for(&server)?;
+    let registry = DefinitionRegistry::new();
+
+    assert!(registry.refresh(&client, "fr").await.is_err());
+    assert_eq!(registry.snapshot().generation(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn cancelled_refreshes_swap_nothing_in() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(manifest("v1")).mount(&server).await;
+    Mock::given(method("GET"))
+        .and(path(content_path("DestinyInventoryItemDefinition", "v1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(items("Generation One")).set_delay(Duration::from_millis(300)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    for table in &TABLES[1..] {
+        Mock::given(method("GET"))
+            .and(path(content_path(table, "shared")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .named(format!("{table} is never downloaded"))
+            .mount(&server)
+            .await;
+    }
+    let client = client_for(&server)?;
+    let cancellation = CancellationToken::new();
+    let registry = DefinitionRegistry::new().with_cancellation(cancellation.clone());
+
+    let cancel = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancellation.cancel();
+    };
+    let (refreshed, ()) = tokio::join!(registry.refresh(&client, "en"), cancel);
+
+    assert_eq!(refreshed?, Outcome::Cancelled(false));
+    let snapshot = registry.snapshot();
+    assert_eq!((snapshot.generation(), snapshot.version()), (0, None), "the item table that finished isn't swapped in alone");
+    assert!(snapshot.inventory_items().is_empty());
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Cancelled(false));
+    Ok(())
+}
@@ -0,0 +1,72 @@
+﻿use rustgie::types::destiny::definitions::{DestinyClassDefinition, DestinyInventoryItemDefinition};
+use rustgie::types::destiny::{DestinyGender, DestinyItemSubType, DestinyItemType};
+use rustgie::types::diagnostics::{with_diagnostics, DeserializationEvent};
+use serde_json::{json, Value};
+
+/// Sunshot from the definitions fixture, as a future manifest might send it with a subtype this crate doesn't know yet.
+fn sunshot_with_new_subtype() -> Value {
+    let mut definitions: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    let mut sunshot = definitions["2907129557"].take();
+    sunshot["itemSubType"] = json!(34);
+    sunshot
+}
+
+#[test]
+fn unknown_enum_value_is_tolerated_and_reported() {
+    let (parsed, events) = with_diagnostics(|| serde_json::from_value::<DestinyInventoryItemDefinition>(sunshot_with_new_subtype()));
+
+    let sunshot = parsed.expect("an unknown subtype doesn't fail the definition");
+    assert_eq!(sunshot.item_sub_type, DestinyItemSubType::None);
+    assert_eq!(sunshot.item_type, DestinyItemType::Weapon);
+    assert_eq!(
+        events,
+        [DeserializationEvent::UnknownEnumValue {
+            type_name: std::any::type_name::<DestinyItemSubType>(),
+            value: 34,
+        }]
+    );
+
+    let reserialized = serde_json::to_value(&sunshot).expect("serializable");
+    assert_eq!(reserialized["itemSubType"], 0);
+}
+
+#[test]
+fn unparseable_map_key_is_skipped_and_reported() {
+    let (parsed, events) = with_diagnostics(|| {
+        serde_json::from_value::<DestinyClassDefinition>(json!({
+            "classType": 1,
+            "displayProperties": { "name": "Hunter", "hasIcon": false },
+            "genderedClassNames": { "Male": "Hunter", "1": "Hunter", "Nonbinary": "Hunter" },
+            "hash": 671679327,
+            "index": 1,
+            "redacted": false
+        }))
+    });
+
+    let hunter = parsed.expect("valid class definition");
+    let names = hunter.gendered_class_names.expect("names");
+    assert_eq!(names.len(), 2);
+    assert!(names.contains_key(&DestinyGender::Male) && names.contains_key(&DestinyGender::Female));
+    assert_eq!(
+        events,
+        [DeserializationEvent::SkippedMapKey {
+            type_name: std::any::type_name::<DestinyGender>(),
+            key: "Nonbinary".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn nothing_is_collected_outside_a_scope() {
+    let ((outer, inner_events), outer_events) = with_diagnostics(|| {
+        let outer = serde_json::from_value::<DestinyInventoryItemDefinition>(sunshot_with_new_subtype());
+        (outer, with_diagnostics(|| ()).1)
+    });
+    assert!(outer.is_ok());
+    assert!(inner_events.is_empty(), "a nested scope only sees its own events");
+    assert_eq!(outer_events.len(), 1);
+
+    assert!(serde_json::from_value::<DestinyInventoryItemDefinition>(sunshot_with_new_subtype()).is_ok());
+    assert!(with_diagnostics(|| ()).1.is_empty(), "events raised with no collector installed are dropped");
+}
@@ -0,0 +1,75 @@
+﻿use rustgie::types::destiny::definitions::common::{DestinyDisplayPropertiesDefinition, DisplayIcons, IconSize};
+use rustgie::types::destiny::definitions::{DestinyProgressionDisplayPropertiesDefinition, DestinyVendorDisplayPropertiesDefinition};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+fn fixture<T: DeserializeOwned>(name: &str) -> T {
+    let fixtures: Value = serde_json::from_str(include_str!("../fixtures/display_properties.json")).expect("valid JSON");
+    serde_json::from_value(fixtures[name].clone()).expect("valid display properties")
+}
+
+fn both_sizes(display: &impl DisplayIcons) -> (Option<&str>, Option<&str>) {
+    (display.best_icon(IconSize::Standard), display.best_icon(IconSize::Largest))
+}
+
+#[test]
+fn base_icon_only() {
+    let glimmer: DestinyDisplayPropertiesDefinition = fixture("baseIconOnly");
+    let icon = "/common/destiny2_content/icons/a3a01ad5e2d5d09bb03ac5ae2a4dcb52.png";
+    assert_eq!(both_sizes(&glimmer), (Some(icon), Some(icon)));
+    assert_eq!(
+        glimmer.best_icon_url(IconSize::Largest).map(String::from).as_deref(),
+        Some("https://www.bungie.net/common/destiny2_content/icons/a3a01ad5e2d5d09bb03ac5ae2a4dcb52.png")
+    );
+}
+
+#[test]
+fn sequences_fill_in_for_a_missing_base_icon() {
+    let seal: DestinyDisplayPropertiesDefinition = fixture("withSequences");
+    let icon = "/common/destiny2_content/icons/f1df3c6de7b0ca9d7f8ea3bdb1d8cb12.png";
+    assert_eq!(both_sizes(&seal), (Some(icon), Some(icon)), "the base icon wins over sequence frames");
+
+    let without_base: DestinyDisplayPropertiesDefinition = fixture("sequencesWithoutBaseIcon");
+    let frame = "/common/destiny2_content/icons/9a1c1d9b4e0cde2a0b3b1c4c3c1f0a21.png";
+    assert_eq!(both_sizes(&without_base), (Some(frame), Some(frame)), "empty paths and sequences are skipped");
+}
+
+#[test]
+fn high_res_icon_is_the_largest() {
+    let ikora: DestinyDisplayPropertiesDefinition = fixture("withHighResIcon");
+    assert_eq!(
+        both_sizes(&ikora),
+        (
+            Some("/common/destiny2_content/icons/5fb7fa1d0f0b2c8b3e2a5a4a1e0fb5e4.png"),
+            Some("/common/destiny2_content/icons/5fb7fa1d0f0b2c8b3e2a5a4a1e0fb5e4_highres.png")
+        )
+    );
+
+    let mut high_res_only = ikora;
+    high_res_only.icon = None;
+    assert_eq!(high_res_only.best_icon(IconSize::Standard), high_res_only.high_res_icon.as_deref());
+}
+
+#[test]
+fn nothing_to_show() {
+    let classified: DestinyDisplayPropertiesDefinition = fixture("noIcon");
+    assert_eq!(both_sizes(&classified), (None, None));
+    assert!(classified.best_icon_url(IconSize::Standard).is_none());
+}
+
+#[test]
+fn vendor_and_progression_display_properties() {
+    let mut vendor_json = json!({ "largeIcon": "/img/large.png", "mapIcon": "/img/map.png" });
+    vendor_json.as_object_mut().expect("object").extend(fixture::<Value>("withHighResIcon").as_object().expect("object").clone());
+    let vendor: DestinyVendorDisplayPropertiesDefinition = serde_json::from_value(vendor_json).expect("valid vendor display");
+    assert_eq!(vendor.best_icon(IconSize::Largest), vendor.high_res_icon.as_deref());
+
+    let mut progression_json = fixture::<Value>("sequencesWithoutBaseIcon");
+    progression_json["displayUnitsName"] = json!("Rank");
+    let progression: DestinyProgressionDisplayPropertiesDefinition =
+        serde_json::from_value(progression_json).expect("valid progression display");
+    assert_eq!(
+        progression.best_icon(IconSize::Standard),
+        Some("/common/destiny2_content/icons/9a1c1d9b4e0cde2a0b3b1c4c3c1f0a21.png")
+    );
+}
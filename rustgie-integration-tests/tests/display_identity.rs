@@ -0,0 +1,81 @@
+﻿use rustgie::types::fireteam::FireteamMember;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+use serde_json::json;
+
+const GROUP_ID: i64 = 3074427;
+
+#[tokio::test]
+async fn clan_members() -> anyhow::Result<()> {
+    let (_server, client) = replay("clan_members_identity").await?;
+    let members = client.group_v2_get_members_of_group(1, GROUP_ID, None, None, None).await?;
+    let members = members.results.expect("members page");
+
+    let cross_save = members[0].display_identity().expect("identity");
+    assert_eq!(cross_save.full_name(), "Rustgie#0007");
+    assert_eq!(cross_save.code, Some(7));
+    assert_eq!(cross_save.membership, (BungieMembershipType::TigerSteam, 4611686018400000001));
+    assert_eq!(
+        cross_save.avatar_url.as_ref().map(|u| u.as_str()),
+        Some("https://www.bungie.net/img/profile/avatars/cc13.jpg"),
+        "the bungie.net avatar wins over the platform icon"
+    );
+
+    let unlinked = members[1].display_identity().expect("identity");
+    assert_eq!(unlinked.name, "xX_Newlight_Xx", "no global name falls back to the last seen platform name");
+    assert_eq!(unlinked.code, None);
+    assert_eq!(unlinked.to_string(), "xX_Newlight_Xx");
+    assert_eq!(unlinked.membership, (BungieMembershipType::TigerPsn, 4611686018400000003));
+    assert_eq!(
+        unlinked.avatar_url.as_ref().map(|u| u.as_str()),
+        Some("https://www.bungie.net/img/theme/bungienet/icons/psnLogo.png")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn carnage_report_entries() -> anyhow::Result<()> {
+    let (_server, client) = replay("clan_members_identity").await?;
+    let report = client.destiny2_get_post_game_carnage_report(13000000001, None).await?;
+    let identities: Vec<_> = report
+        .entries
+        .expect("entries")
+        .iter()
+        .map(|entry| entry.display_identity().expect("identity").full_name())
+        .collect();
+
+    assert_eq!(identities, ["Rustgie#0007", "Newlight#0042"]);
+    Ok(())
+}
+
+#[test]
+fn fireteam_member_without_bungie_net_account() {
+    let member: FireteamMember = serde_json::from_value(json!({
+        "destinyUserInfo": {
+            "FireteamDisplayName": "Newlight",
+            "FireteamMembershipType": 2,
+            "iconPath": "https://www.bungie.net/img/theme/bungienet/icons/psnLogo.png",
+            "crossSaveOverride": 0,
+            "applicableMembershipTypes": [2],
+            "isPublic": true,
+            "membershipType": 2,
+            "membershipId": "4611686018400000003",
+            "displayName": "xX_Newlight_Xx"
+        },
+        "characterId": "2305843009200000011",
+        "dateJoined": "2023-06-14T01:00:00Z",
+        "hasMicrophone": false,
+        "lastPlatformInviteAttemptDate": "0001-01-01T00:00:00Z",
+        "lastPlatformInviteAttemptResult": 0
+    }))
+    .expect("valid fireteam member");
+
+    let identity = member.display_identity().expect("identity");
+    assert_eq!(identity.full_name(), "Newlight");
+    assert_eq!(identity.membership, (BungieMembershipType::TigerPsn, 4611686018400000003));
+    assert_eq!(
+        identity.avatar_url.as_ref().map(|u| u.as_str()),
+        Some("https://www.bungie.net/img/theme/bungienet/icons/psnLogo.png"),
+        "absolute icon paths are kept as-is"
+    );
+}
@@ -0,0 +1,191 @@
+﻿use rustgie::RustgieClientBuilder;
+use rustgie_integration_tests::TEST_API_KEY;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use wiremock::matchers::{header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CONTENT_PATH: &str = "/common/destiny2_content/sqlite/en/world_sql_content_test.content";
+
+fn content() -> Vec<u8> {
+    (0..64 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+/// A fresh destination under the target directory, with no partial download left from an earlier run.
+fn destination(name: &str) -> PathBuf {
+    let directory = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("downloads");
+    std::fs::create_dir_all(&directory).expect("creating the download directory");
+    let destination = directory.join(name);
+    for suffix in ["", ".part", ".part.etag"] {
+        let _ = std::fs::remove_file(format!("{}{suffix}", destination.display()));
+    }
+    destination
+}
+
+/// Reads a request's head, returning its lines.
+fn read_request(reader: &mut impl BufRead) -> Vec<String> {
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).expect("reading the request");
+        let line = line.trim_end().to_string();
+        if line.is_empty() {
+            return lines;
+        }
+        lines.push(line);
+    }
+}
+
+/// Serves `content` over two connections: the first announces all of it but hangs up halfway, and the second answers the
+/// range request for the rest. Returns the base URL and the second request's head.
+fn disconnecting_server(content: Vec<u8>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("binding a port");
+    let base_url = format!("http://{}", listener.local_addr().expect("bound address"));
+    let server = std::thread::spawn(move || {
+        let half = content.len() / 2;
+
+        let (mut stream, _) = listener.accept().expect("first connection");
+        read_request(&mut BufReader::new(stream.try_clone().expect("cloning the stream")));
+        write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"v1\"\r\n\r\n", content.len()).expect("writing headers");
+        stream.write_all(&content[..half]).expect("writing the first half");
+        stream.flush().expect("flushing");
+        drop(stream);
+
+        let (mut stream, _) = listener.accept().expect("second connection");
+        let request = read_request(&mut BufReader::new(stream.try_clone().expect("cloning the stream")));
+        write!(
+            stream,
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {half}-{}/{}\r\nETag: \"v1\"\r\n\r\n",
+            content.len() - half,
+            content.len() - 1,
+            content.len()
+        )
+        .expect("writing headers");
+        stream.write_all(&content[half..]).expect("writing the second half");
+        request
+    });
+    (base_url, server)
+}
+
+#[tokio::test]
+async fn interrupted_download_resumes_from_the_partial_file() -> anyhow::Result<()> {
+    let content = content();
+    let (base_url, server) = disconnecting_server(content.clone());
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&base_url)
+        .dangerously_allow_http(true)
+        .build()?;
+    let destination = destination("resumed.content");
+
+    let interrupted = client.download_content(CONTENT_PATH, &destination).run().await;
+    assert!(interrupted.is_err());
+    assert!(!destination.exists());
+    let part = std::fs::read(format!("{}.part", destination.display()))?;
+    assert!(!part.is_empty() && content.starts_with(&part));
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+    let report = client
+        .download_content(CONTENT_PATH, &destination)
+        .with_progress(move |done, total| recorded.lock().expect("progress poisoned").push((done, total)))
+        .run()
+        .await?;
+
+    let request = server.join().expect("server thread").join("\n").to_ascii_lowercase();
+    assert!(request.contains(&format!("range: bytes={}-", part.len())), "{request}");
+    assert!(request.contains("if-range: \"v1\""), "{request}");
+    assert_eq!(report.resumed_from, part.len() as u64);
+    assert_eq!(report.bytes, content.len() as u64);
+    assert_eq!(std::fs::read(&destination)?, content);
+    assert!(!PathBuf::from(format!("{}.part", destination.display())).exists());
+
+    let progress = progress.lock().expect("progress poisoned");
+    let total = Some(content.len() as u64);
+    assert_eq!(progress.first(), Some(&(part.len() as u64, total)));
+    assert_eq!(progress.last(), Some(&(content.len() as u64, total)));
+    assert!(progress.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn changed_files_are_downloaded_again_from_the_start() -> anyhow::Result<()> {
+    let content = content();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CONTENT_PATH))
+        .and(header_exists("range"))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("ETag", "\"v2\"")
+                .insert_header("Content-Range", format!("bytes 3-{}/{}", content.len() - 1, content.len()))
+                .set_body_bytes(content[3..].to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(CONTENT_PATH))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"v2\"").set_body_bytes(content.clone()))
+        .mount(&server)
+        .await;
+    let client = rustgie_integration_tests::client_for(&server)?;
+    let destination = destination("restarted.content");
+    std::fs::write(format!("{}.part", destination.display()), b"old")?;
+    std::fs::write(format!("{}.part.etag", destination.display()), "\"v1\"")?;
+
+    let report = client.download_content(CONTENT_PATH, &destination).run().await?;
+    assert_eq!(report.resumed_from, 0);
+    assert_eq!(std::fs::read(&destination)?, content);
+    Ok(())
+}
+
+#[tokio::test]
+async fn throttle_retries_leave_downloads_streaming() -> anyhow::Result<()> {
+    // JSON content is what the throttle middleware would otherwise read whole, looking for an envelope.
+    let content = br#"{"DestinyInventoryItemDefinition":{}}"#.repeat(2048);
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let base_url = format!("http://{}", listener.local_addr()?);
+    let (first_report, reported) = std::sync::mpsc::channel();
+    let server = std::thread::spawn({
+        let content = content.clone();
+        move || {
+            let (mut stream, _) = listener.accept().expect("connection");
+            read_request(&mut BufReader::new(stream.try_clone().expect("cloning the stream")));
+            let half = content.len() / 2;
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n", content.len())
+                .expect("writing headers");
+            stream.write_all(&content[..half]).expect("writing the first half");
+            stream.flush().expect("flushing");
+            // Hold back the rest until the client reports progress, or give up waiting.
+            let streamed = reported.recv_timeout(std::time::Duration::from_secs(5)).is_ok();
+            stream.write_all(&content[half..]).expect("writing the second half");
+            streamed
+        }
+    });
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&base_url)
+        .dangerously_allow_http(true)
+        .with_throttle_retries(3)
+        .build()?;
+    let destination = destination("streamed.json");
+
+    let report = client
+        .download_content(CONTENT_PATH, &destination)
+        .with_progress(move |done, _| {
+            if done > 0 {
+                let _ = first_report.send(());
+            }
+        })
+        .run()
+        .await?;
+
+    assert!(server.join().expect("server thread"), "no progress was reported before the body completed");
+    assert_eq!(report.bytes, content.len() as u64);
+    assert_eq!(std::fs::read(&destination)?, content);
+    Ok(())
+}
@@ -0,0 +1,71 @@
+﻿use enumflags2::BitFlags;
+use rustgie::types::diagnostics::{with_diagnostics, DeserializationEvent};
+use rustgie::types::user::{EmailSettings, OptInFlags};
+use rustgie_integration_tests::{replay, Session};
+
+async fn email_settings() -> anyhow::Result<EmailSettings> {
+    let (_server, client) = replay("common_settings_email").await?;
+    Ok(client.get_common_settings(None).await?.email_settings.expect("email settings"))
+}
+
+#[tokio::test]
+async fn dictionaries_are_keyed_by_name() -> anyhow::Result<()> {
+    let settings = email_settings().await?;
+
+    let opt_ins = settings.opt_in_definitions.as_ref().expect("opt-in definitions");
+    assert_eq!(opt_ins["System"].value, OptInFlags::System);
+    assert!(opt_ins["System"].set_by_default);
+    assert_eq!(opt_ins["PlayTests"].value, OptInFlags::PlayTests | OptInFlags::PlayTestsLocal, "combined flags");
+
+    let subscriptions = settings.subscription_definitions.as_ref().expect("subscription definitions");
+    assert_eq!(subscriptions["GuardianGames2023"].value, 8);
+
+    let views = settings.views.as_ref().expect("views");
+    let news = &views["Default"].view_settings.as_ref().expect("settings")[0];
+    assert_eq!(news.opt_in_aggregate_value, OptInFlags::Newsletter | OptInFlags::Marketing);
+    Ok(())
+}
+
+#[test]
+fn unknown_flag_bits_are_cleared_and_reported() -> anyhow::Result<()> {
+    let body = &Session::load("common_settings_email")?.interactions[0].body;
+    let (parsed, mut events) = with_diagnostics(|| serde_json::from_value::<EmailSettings>(body["Response"]["emailSettings"].clone()));
+    let settings = parsed?;
+
+    let opt_ins = settings.opt_in_definitions.as_ref().expect("opt-in definitions");
+    assert!(opt_ins["CreatorPrograms"].value.is_empty(), "a category made only of unknown bits");
+    let research = &settings.views.as_ref().expect("views")["Default"].view_settings.as_ref().expect("settings")[1];
+    assert_eq!(
+        research.opt_in_aggregate_value,
+        OptInFlags::UserResearch | OptInFlags::PlayTests | OptInFlags::PlayTestsLocal
+    );
+
+    events.sort_by_key(|event| match event {
+        DeserializationEvent::UnknownFlagBits { bits, .. } => *bits,
+        _ => 0,
+    });
+    let type_name = std::any::type_name::<OptInFlags>();
+    assert_eq!(
+        events,
+        [
+            DeserializationEvent::UnknownFlagBits { type_name, bits: 1024 },
+            DeserializationEvent::UnknownFlagBits { type_name, bits: 1536 },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn opted_in_and_subscribed_by_name() -> anyhow::Result<()> {
+    let settings = email_settings().await?;
+
+    let flags = OptInFlags::System | OptInFlags::Marketing | OptInFlags::PlayTests;
+    assert_eq!(settings.opted_in(flags), ["Marketing", "System"], "PlayTests also needs PlayTestsLocal");
+    assert_eq!(settings.opted_in(flags | OptInFlags::PlayTestsLocal), ["Marketing", "PlayTests", "System"]);
+    assert!(settings.opted_in(BitFlags::empty()).is_empty());
+    assert_eq!(settings.opted_in(BitFlags::all()).len(), 5, "CreatorPrograms has no known flags to match");
+
+    assert_eq!(settings.subscribed(1 | 8), ["GuardianGames2023", "LightfallLaunch"]);
+    assert!(settings.subscribed(4).is_empty());
+    Ok(())
+}
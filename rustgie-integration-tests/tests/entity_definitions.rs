@@ -0,0 +1,96 @@
+﻿use rustgie::types::destiny::definitions::{DestinyActivityDefinition, DestinyStatDefinition};
+use rustgie::CachedEntityDefinitions;
+use rustgie_integration_tests::client_for;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const HANDLING: u32 = 943549884;
+const STAT_PATH: &str = "/Platform/Destiny2/Manifest/DestinyStatDefinition/943549884/";
+
+fn handling(hash: u32) -> Value {
+    json!({
+        "Response": {
+            "displayProperties": { "name": "Handling", "description": "The speed with which the weapon can be readied and aimed.", "hasIcon": false },
+            "aggregationType": 2,
+            "hasComputedBlock": false,
+            "statCategory": 1,
+            "hash": hash,
+            "index": 33,
+            "redacted": false
+        },
+        "ErrorCode": 1,
+        "ThrottleSeconds": 0,
+        "ErrorStatus": "Success",
+        "Message": "Ok",
+        "MessageData": {}
+    })
+}
+
+async fn mount_handling(server: &MockServer, returned_hash: u32) {
+    Mock::given(method("GET"))
+        .and(path(STAT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(handling(returned_hash)))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn repeated_lookups_hit_the_cache() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_handling(&server, HANDLING).await;
+    let client = client_for(&server)?;
+    let cache = CachedEntityDefinitions::new(&client);
+
+    let first = cache.stat(HANDLING).await?;
+    let second = cache.definition::<DestinyStatDefinition>(HANDLING).await?;
+
+    assert_eq!(first, second);
+    assert_eq!(first.display_properties.and_then(|d| d.name).as_deref(), Some("Handling"));
+    assert_eq!(cache.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_misses_share_one_request() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_handling(&server, HANDLING).await;
+    let client = client_for(&server)?;
+    let cache = CachedEntityDefinitions::new(&client);
+
+    let (a, b, c) = tokio::join!(cache.stat(HANDLING), cache.stat(HANDLING), cache.stat(HANDLING));
+
+    assert_eq!(a?.hash, HANDLING);
+    assert_eq!(b?.hash, HANDLING);
+    assert_eq!(c?.hash, HANDLING);
+    Ok(())
+}
+
+#[tokio::test]
+async fn mismatched_hash_is_an_error() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_handling(&server, HANDLING + 1).await;
+    let client = client_for(&server)?;
+    let cache = CachedEntityDefinitions::new(&client);
+
+    let error = cache.stat(HANDLING).await.expect_err("hash mismatch");
+    assert!(error.to_string().contains("returned definition 943549885"), "{error}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn wrong_definition_type_is_an_error() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Destiny2/Manifest/DestinyActivityDefinition/943549884/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(handling(HANDLING)))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+    let cache = CachedEntityDefinitions::new(&client);
+
+    let error = cache.definition::<DestinyActivityDefinition>(HANDLING).await.expect_err("not an activity");
+    assert!(error.to_string().contains("is not a valid DestinyActivityDefinition"), "{error}");
+    Ok(())
+}
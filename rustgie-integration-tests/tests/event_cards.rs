@@ -0,0 +1,91 @@
+﻿use rustgie::types::destiny::definitions::presentation::DestinyPresentationNodeDefinition;
+use rustgie::types::destiny::definitions::seasons::DestinyEventCardDefinition;
+use rustgie::types::destiny::responses::{event_card_progress, DestinyProfileResponse, EventCardProgress};
+use serde_json::Value;
+use std::collections::HashMap;
+use time::macros::datetime;
+
+const SOLSTICE: u32 = 1226703617;
+
+struct Definitions {
+    event_cards: HashMap<u32, DestinyEventCardDefinition>,
+    presentation_nodes: HashMap<u32, DestinyPresentationNodeDefinition>,
+}
+
+fn fixture() -> anyhow::Result<(Definitions, Value)> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/event_card.json"))?;
+    Ok((
+        Definitions {
+            event_cards: serde_json::from_value(fixture["eventCardDefinitions"].clone())?,
+            presentation_nodes: serde_json::from_value(fixture["presentationNodeDefinitions"].clone())?,
+        },
+        fixture,
+    ))
+}
+
+fn profile(fixture: &Value, name: &str) -> anyhow::Result<DestinyProfileResponse> {
+    Ok(serde_json::from_value(fixture[name].clone())?)
+}
+
+#[test]
+fn progress_during_event() -> anyhow::Result<()> {
+    let (defs, fixture) = fixture()?;
+    let profile = profile(&fixture, "duringEvent")?;
+
+    let progress = event_card_progress(&profile, &defs.event_cards, &defs.presentation_nodes);
+    assert_eq!(
+        progress,
+        Some(EventCardProgress {
+            event_card_hash: SOLSTICE,
+            name: Some("Solstice"),
+            ends: Some(datetime!(2024-08-01 17:00 UTC)),
+            upgraded: true,
+            tickets: 9,
+            // 1003 is listed under both weeks but counted once; 1004 is only complete on the character.
+            challenges_completed: 3,
+            challenges_total: 5,
+            // The seal node lists itself as a child, which must not loop.
+            seal_completed: 1,
+            seal_total: 2,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn progress_without_records_or_currencies() -> anyhow::Result<()> {
+    let (defs, fixture) = fixture()?;
+    let mut profile = profile(&fixture, "duringEvent")?;
+    profile.profile_currencies = None;
+    profile.profile_inventory = None;
+    profile.profile_records = None;
+    profile.character_records = None;
+
+    let progress = event_card_progress(&profile, &defs.event_cards, &defs.presentation_nodes).unwrap();
+    assert_eq!(progress.tickets, 0);
+    assert_eq!((progress.challenges_completed, progress.challenges_total), (0, 5));
+    assert_eq!((progress.seal_completed, progress.seal_total), (0, 2));
+    Ok(())
+}
+
+#[test]
+fn no_progress_outside_events() -> anyhow::Result<()> {
+    let (defs, fixture) = fixture()?;
+    for name in ["betweenEvents", "beforeEventCards"] {
+        let profile = profile(&fixture, name)?;
+        assert_eq!(event_card_progress(&profile, &defs.event_cards, &defs.presentation_nodes), None, "{name}");
+    }
+
+    let profile = profile(&fixture, "duringEvent")?;
+    assert_eq!(event_card_progress(&profile, &HashMap::new(), &defs.presentation_nodes), None);
+    Ok(())
+}
+
+#[test]
+fn no_progress_without_profile_component() -> anyhow::Result<()> {
+    let (defs, fixture) = fixture()?;
+    let mut profile = profile(&fixture, "duringEvent")?;
+    profile.profile = None;
+    assert_eq!(event_card_progress(&profile, &defs.event_cards, &defs.presentation_nodes), None);
+    Ok(())
+}
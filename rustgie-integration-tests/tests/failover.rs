@@ -0,0 +1,191 @@
+﻿use reqwest::Url;
+use rustgie::{ConnectFailure, FixedClock, Middleware, MiddlewareFuture, Next, RustgieClientBuilder, RustgieError};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::macros::datetime;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Nothing routes to TEST-NET-1, so connecting either times out or fails outright.
+const UNROUTABLE: &str = "http://192.0.2.1";
+
+/// Records the origin of every attempt the client makes, including ones that never connect.
+#[derive(Default)]
+struct Attempts {
+    origins: Mutex<Vec<String>>,
+}
+
+impl Attempts {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.origins.lock().expect("attempts poisoned"))
+    }
+}
+
+impl Middleware for Attempts {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        self.origins.lock().expect("attempts poisoned").push(request.url().origin().ascii_serialization());
+        next.run(request)
+    }
+}
+
+async fn locales_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "en": "en" })))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn builder(base_url: &str) -> RustgieClientBuilder {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(base_url)
+        .with_connect_timeout(Duration::from_millis(250))
+        .dangerously_allow_http(true)
+}
+
+async fn connect_failure(base_url: &str) -> anyhow::Result<Option<ConnectFailure>> {
+    let error = builder(base_url).build()?.get_available_locales(None).await.unwrap_err();
+    Ok(error.downcast_ref::<RustgieError>().and_then(RustgieError::connect_failure))
+}
+
+/// A local port nothing is listening on.
+fn closed_port() -> anyhow::Result<u16> {
+    Ok(std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?.port())
+}
+
+#[tokio::test]
+async fn classifies_dns_failures() -> anyhow::Result<()> {
+    assert_eq!(connect_failure("http://rustgie-failover-test.invalid").await?, Some(ConnectFailure::Dns));
+    Ok(())
+}
+
+#[tokio::test]
+async fn classifies_refused_connections() -> anyhow::Result<()> {
+    let base_url = format!("http://127.0.0.1:{}", closed_port()?);
+    assert_eq!(connect_failure(&base_url).await?, Some(ConnectFailure::Tcp));
+    Ok(())
+}
+
+#[tokio::test]
+async fn classifies_tls_failures() -> anyhow::Result<()> {
+    // A plain-HTTP server can't complete a TLS handshake.
+    let server = locales_server().await;
+    let base_url = format!("https://{}", server.address());
+    assert_eq!(connect_failure(&base_url).await?, Some(ConnectFailure::Tls));
+    Ok(())
+}
+
+#[tokio::test]
+async fn classifies_unroutable_hosts() -> anyhow::Result<()> {
+    // Depending on the network this times out or is rejected as unreachable straight away.
+    let failure = connect_failure(UNROUTABLE).await?;
+    assert!(matches!(failure, Some(ConnectFailure::Timeout | ConnectFailure::Tcp)), "{failure:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn http_errors_are_not_connect_failures() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).respond_with(ResponseTemplate::new(503)).mount(&server).await;
+
+    let error = builder(&server.uri()).build()?.get_available_locales(None).await.unwrap_err();
+    let error = error.downcast_ref::<RustgieError>().unwrap();
+    assert!(matches!(error, RustgieError::HttpStatus { .. }));
+    assert_eq!(error.connect_failure(), None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn fails_over_when_the_primary_is_unreachable() -> anyhow::Result<()> {
+    let dead = format!("http://127.0.0.1:{}", closed_port()?);
+    let fallback = locales_server().await;
+    let attempts = Arc::new(Attempts::default());
+    let client = builder(UNROUTABLE)
+        .with_fallback_base_urls(vec![Url::parse(&dead)?, Url::parse(&fallback.uri())?])
+        .with_middleware(attempts.clone())
+        .build()?;
+
+    assert_eq!(client.get_available_locales(None).await?["en"], "en");
+    assert_eq!(attempts.take(), [UNROUTABLE.to_string(), dead, fallback.uri()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn never_fails_over_on_http_errors() -> anyhow::Result<()> {
+    let primary = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("<html>Server Error</html>"))
+        .expect(1)
+        .mount(&primary)
+        .await;
+    let fallback = MockServer::start().await;
+    Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&fallback).await;
+
+    let client = builder(&primary.uri()).with_fallback_base_urls(vec![Url::parse(&fallback.uri())?]).build()?;
+    let error = client.get_available_locales(None).await.unwrap_err();
+    assert!(matches!(error.downcast_ref::<RustgieError>(), Some(RustgieError::HttpStatus { .. })));
+    Ok(())
+}
+
+#[tokio::test]
+async fn returns_the_last_connect_failure_when_every_host_is_down() -> anyhow::Result<()> {
+    let dead = format!("http://127.0.0.1:{}", closed_port()?);
+    let client = builder(UNROUTABLE)
+        .with_fallback_base_urls(vec![Url::parse("http://rustgie-failover-test.invalid")?, Url::parse(&dead)?])
+        .build()?;
+
+    let error = client.get_available_locales(None).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<RustgieError>().and_then(RustgieError::connect_failure),
+        Some(ConnectFailure::Tcp)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_the_primary_after_the_cooldown() -> anyhow::Result<()> {
+    // Reserve a port for the primary, which is down until a server starts listening on it.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let primary_uri = format!("http://{}", listener.local_addr()?);
+    drop(listener);
+    let fallback = locales_server().await;
+
+    let clock = Arc::new(FixedClock::new(datetime!(2024-07-10 18:00 UTC)));
+    let attempts = Arc::new(Attempts::default());
+    let client = builder(&primary_uri)
+        .with_fallback_base_urls(vec![Url::parse(&fallback.uri())?])
+        .with_failover_cooldown(Duration::from_secs(60))
+        .with_clock(clock.clone())
+        .with_middleware(attempts.clone())
+        .build()?;
+
+    client.get_available_locales(None).await?;
+    assert_eq!(attempts.take(), [primary_uri.clone(), fallback.uri()]);
+
+    // Within the cooldown requests go straight to the fallback, and using it doesn't extend the cooldown.
+    clock.advance(Duration::from_secs(30));
+    client.get_available_locales(None).await?;
+    clock.advance(Duration::from_secs(29));
+    client.get_available_locales(None).await?;
+    assert_eq!(attempts.take(), [fallback.uri(), fallback.uri()]);
+
+    // Once it has passed the primary is tried first again, and is used as soon as it answers.
+    clock.advance(Duration::from_secs(1));
+    let primary = MockServer::builder().listener(std::net::TcpListener::bind(primary_uri.trim_start_matches("http://"))?).start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "de": "de" })))
+        .expect(2)
+        .mount(&primary)
+        .await;
+
+    assert_eq!(client.get_available_locales(None).await?["de"], "de");
+    assert_eq!(client.get_available_locales(None).await?["de"], "de");
+    assert_eq!(attempts.take(), [primary_uri.clone(), primary_uri]);
+    Ok(())
+}
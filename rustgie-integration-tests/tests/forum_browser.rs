@@ -0,0 +1,129 @@
+﻿use enumflags2::BitFlags;
+use rustgie::types::forum::{ForumTopicsCategoryFiltersEnum as Category, ForumTopicsQuickDateEnum, ForumTopicsSortEnum};
+use rustgie::ForumBrowser;
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, path_regex, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const GROUP_ID: i64 = 3074427;
+
+fn post(post_id: i64, subject: &str) -> Value {
+    json!({
+        "postId": post_id.to_string(), "parentPostId": "0", "topicId": post_id.to_string(), "threadDepth": 0,
+        "authorMembershipId": "10000001", "editorMembershipId": "0", "subject": subject, "body": "",
+        "urlLinkOrImage": "", "creationDate": "2023-06-12T18:00:04.113Z", "lastModified": "2023-06-12T18:00:04.113Z",
+        "tags": ["#destiny2"], "replyCount": 0, "viewCount": 0, "rating": 0, "ratingCount": 0,
+        "lastReplyTimestamp": "2023-06-12T18:00:04.113Z", "IsPinned": false, "urlMediaType": 0, "thumbnail": "",
+        "popularity": 1, "isActive": true, "isAnnouncement": false, "userRating": 0, "userHasRated": false,
+        "userHasMutedPost": false, "latestReplyPostId": "0", "latestReplyAuthorId": "0", "locale": "en"
+    })
+}
+
+fn page(results: Vec<Value>, has_more: bool, continuation_token: &str) -> ResponseTemplate {
+    success_envelope(json!({
+        "results": results,
+        "totalResults": 0,
+        "hasMore": has_more,
+        "query": { "itemsPerPage": 1, "currentPage": 0 },
+        "replacementContinuationToken": continuation_token,
+        "useTotalResults": false
+    }))
+}
+
+async fn requested_paths(server: &MockServer) -> Vec<String> {
+    let requests = server.received_requests().await.expect("request recording is enabled");
+    requests.iter().map(|r| r.url.path().to_string()).collect()
+}
+
+#[tokio::test]
+async fn category_flags_combine_in_the_path() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/Platform/Forum/"))
+        .respond_with(page(vec![], false, ""))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+    let (sort, date) = (ForumTopicsSortEnum::MostReplied, ForumTopicsQuickDateEnum::LastMonth);
+
+    client.forum_get_topics_paged(Category::Links | Category::Media, 0, 0, 10, date, sort, None, None, None).await?;
+    client.forum_get_topics_paged(Category::Polls, 0, 0, 10, date, sort, None, None, None).await?;
+    client.forum_get_core_topics_paged(BitFlags::<Category>::all(), 2, date, sort, None, None).await?;
+    client.forum_get_core_topics_paged(BitFlags::<Category>::empty(), 2, date, sort, None, None).await?;
+
+    assert_eq!(
+        requested_paths(&server).await,
+        [
+            "/Platform/Forum/GetTopicsPaged/0/10/0/2/2/9/",
+            "/Platform/Forum/GetTopicsPaged/0/10/0/2/2/128/",
+            "/Platform/Forum/GetCoreTopicsPaged/2/2/2/255/",
+            "/Platform/Forum/GetCoreTopicsPaged/2/2/2/0/",
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn latest_in_group_pages_until_no_more() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/Forum/GetTopicsPaged/0/2/{GROUP_ID}/1/0/0/")))
+        .respond_with(page(vec![post(1, "LFG raid"), post(2, "Clan rules")], true, "page-1-token"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/Forum/GetTopicsPaged/1/2/{GROUP_ID}/1/0/0/")))
+        .respond_with(page(vec![post(3, "Welcome")], false, "page-2-token"))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+    let browser = ForumBrowser::new(&client).with_page_size(2);
+
+    let topics = browser.latest_in_group(GROUP_ID).collect(10).await?;
+    assert!(!topics.is_cancelled());
+    let subjects: Vec<_> = topics.into_inner().into_iter().filter_map(|p| p.subject).collect();
+    assert_eq!(subjects, ["LFG raid", "Clan rules", "Welcome"]);
+
+    let first_page = browser.latest_in_group(GROUP_ID).page(0).await?;
+    assert_eq!(first_page.replacement_continuation_token.as_deref(), Some("page-1-token"));
+
+    let limited = browser.latest_in_group(GROUP_ID).collect(1).await?.into_inner();
+    assert_eq!(limited.len(), 2, "stops after max_pages even though more are available");
+    assert_eq!(requested_paths(&server).await.len(), 4);
+    Ok(())
+}
+
+#[tokio::test]
+async fn trending_and_tag_presets() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Forum/GetCoreTopicsPaged/0/3/3/0/"))
+        .and(query_param("locales", "en,fr"))
+        .respond_with(page(vec![post(4, "Patch notes")], false, ""))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Forum/GetTopicsPaged/0/25/0/1/1/24/"))
+        .and(query_param("tagstring", "#raids"))
+        .respond_with(page(vec![post(5, "Crota tips")], false, ""))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+    let browser = ForumBrowser::new(&client).with_locales("en,fr");
+
+    let trending = browser.trending().page(0).await?;
+    assert_eq!(trending.results.unwrap_or_default().len(), 1);
+
+    let tagged = ForumBrowser::new(&client)
+        .by_tag("raids")
+        .with_quick_date(ForumTopicsQuickDateEnum::LastYear)
+        .with_categories(Category::Media | Category::TextOnly)
+        .collect(3)
+        .await?
+        .into_inner();
+    assert_eq!(tagged[0].subject.as_deref(), Some("Crota tips"));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+﻿use rustgie::types::forum::{build_thread, ForumPostSortEnum, ThreadNode};
+use rustgie_integration_tests::replay;
+
+const TOPIC_ID: i64 = 263000001;
+
+fn summary<'a>(node: &ThreadNode<'a>) -> (Option<i64>, Option<&'a str>, Vec<Option<i64>>) {
+    (
+        node.post.post_id,
+        node.author.and_then(|a| a.display_name.as_deref()),
+        node.replies.iter().map(|r| r.post.post_id).collect(),
+    )
+}
+
+#[tokio::test]
+async fn thread_with_poll() -> anyhow::Result<()> {
+    let (_server, client) = replay("forum_thread_with_poll").await?;
+    let response = client
+        .forum_get_posts_threaded_paged(true, 0, 25, TOPIC_ID, 25, false, ForumPostSortEnum::Default, None, None)
+        .await?;
+
+    let tree = build_thread(&response);
+
+    assert_eq!(tree.roots.len(), 1, "the topic appears in results and relatedPosts but is one root");
+    let topic = &tree.roots[0];
+    assert_eq!(summary(topic), (Some(TOPIC_ID), Some("Rustgie"), vec![Some(263000002), Some(263000003)]));
+
+    let poll = topic.poll.expect("topic has a poll");
+    assert_eq!(poll.total_votes, 412);
+    assert_eq!(
+        poll.results.as_ref().and_then(|r| r.first()).and_then(|r| r.answer_text.as_deref()),
+        Some("Wrath of the Machine")
+    );
+
+    let crota = &topic.replies[1];
+    assert_eq!(summary(crota), (Some(263000003), Some("Cryptarch"), vec![Some(263000005)]));
+    assert!(crota.poll.is_none());
+    assert_eq!(summary(&crota.replies[0]), (Some(263000005), Some("Rustgie"), vec![]));
+
+    assert_eq!(tree.orphans.len(), 1, "the reply to a post outside the page is kept");
+    assert_eq!(summary(&tree.orphans[0]), (Some(263000007), Some("Ghaul"), vec![]));
+
+    let visited: Vec<_> = tree.iter().filter_map(|n| n.post.post_id).collect();
+    assert_eq!(visited, [TOPIC_ID, 263000002, 263000003, 263000005, 263000007]);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+﻿use rustgie::types::destiny::definitions::{DestinyClassDefinition, DestinyRaceDefinition};
+use rustgie::types::destiny::{DestinyClass, DestinyGender, DestinyRace};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const HUNTER: u32 = 671679327;
+const TITAN: u32 = 3655393761;
+const AWOKEN: u32 = 2803282938;
+const EXO: u32 = 898834093;
+
+/// French definitions, where class and race names differ by gender. The Titan has no gendered names and the Exo only a male one.
+fn table<T: DeserializeOwned>(key: &str) -> HashMap<u32, T> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/gendered_names.json")).expect("valid JSON");
+    serde_json::from_value(fixture[key].clone()).expect("valid definitions")
+}
+
+#[test]
+fn class_names_follow_the_gender() {
+    let classes = table::<DestinyClassDefinition>("classes");
+    let hunter = &classes[&HUNTER];
+    assert_eq!(hunter.class_name_for(DestinyGender::Male), Some("Chasseur"));
+    assert_eq!(hunter.class_name_for(DestinyGender::Female), Some("Chasseuse"));
+    assert_eq!(hunter.class_name_for(DestinyGender::Unknown), Some("Chasseur"), "falls back to the display name");
+
+    let titan = &classes[&TITAN];
+    for gender in [DestinyGender::Male, DestinyGender::Female, DestinyGender::Unknown] {
+        assert_eq!(titan.class_name_for(gender), Some("Titan"), "{gender:?}");
+    }
+}
+
+#[test]
+fn race_names_follow_the_gender() {
+    let races = table::<DestinyRaceDefinition>("races");
+    let awoken = &races[&AWOKEN];
+    assert_eq!(awoken.race_name_for(DestinyGender::Male), Some("Éveillé"));
+    assert_eq!(awoken.race_name_for(DestinyGender::Female), Some("Éveillée"));
+    assert_eq!(awoken.race_name_for(DestinyGender::Unknown), Some("Éveillé"), "falls back to the display name");
+
+    let exo = &races[&EXO];
+    assert_eq!(exo.race_name_for(DestinyGender::Female), Some("Exo"), "a missing gender falls back to the display name");
+    assert_eq!(exo.race_name_for(DestinyGender::Male), Some("Exo"));
+}
+
+#[test]
+fn gendered_names_survive_a_round_trip_through_numeric_keys() {
+    let classes = table::<DestinyClassDefinition>("classes");
+    let json = serde_json::to_value(&classes[&HUNTER]).expect("serializable");
+    assert_eq!(json["genderedClassNames"], serde_json::json!({ "0": "Chasseur", "1": "Chasseuse" }));
+
+    let hunter: DestinyClassDefinition = serde_json::from_value(json).expect("numeric gender keys parse");
+    assert_eq!(hunter, classes[&HUNTER]);
+    assert_eq!(hunter.class_name_for(DestinyGender::Female), Some("Chasseuse"));
+}
+
+#[test]
+fn enums_parse_from_names_and_numbers() {
+    for (name, number, gender) in [("Male", "0", DestinyGender::Male), ("Female", "1", DestinyGender::Female), ("Unknown", "2", DestinyGender::Unknown)] {
+        assert_eq!(name.parse::<DestinyGender>().ok(), Some(gender));
+        assert_eq!(number.parse::<DestinyGender>().ok(), Some(gender));
+        assert_eq!(gender.to_string(), number);
+    }
+    for (name, number, class) in [
+        ("Titan", "0", DestinyClass::Titan),
+        ("Hunter", "1", DestinyClass::Hunter),
+        ("Warlock", "2", DestinyClass::Warlock),
+        ("Unknown", "3", DestinyClass::Unknown),
+    ] {
+        assert_eq!(name.parse::<DestinyClass>().ok(), Some(class));
+        assert_eq!(number.parse::<DestinyClass>().ok(), Some(class));
+    }
+    for (name, number, race) in [
+        ("Human", "0", DestinyRace::Human),
+        ("Awoken", "1", DestinyRace::Awoken),
+        ("Exo", "2", DestinyRace::Exo),
+        ("Unknown", "3", DestinyRace::Unknown),
+    ] {
+        assert_eq!(name.parse::<DestinyRace>().ok(), Some(race));
+        assert_eq!(number.parse::<DestinyRace>().ok(), Some(race));
+    }
+
+    assert!("3".parse::<DestinyGender>().is_err());
+    assert!("4".parse::<DestinyClass>().is_err());
+    assert!("male".parse::<DestinyGender>().is_err());
+}
@@ -0,0 +1,226 @@
+﻿use rustgie::types::groups_v2::{GroupApplicationRequest, GroupApplicationResolveState};
+use rustgie::types::BungieMembershipType;
+use rustgie::{Applicant, ClanAdmin, PendingKind};
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const GROUP_ID: i64 = 3074427;
+const BOTH: i64 = 4611686018400000002;
+const INVITED_ONLY: i64 = 4611686018400000003;
+const APPLIED_ONLY: i64 = 4611686018400000004;
+
+fn application(membership_id: i64, created: &str, message: &str) -> Value {
+    json!({
+        "groupId": GROUP_ID.to_string(),
+        "creationDate": created,
+        "resolveState": 0,
+        "requestMessage": message,
+        "destinyUserInfo": {
+            "LastSeenDisplayName": "Guardian", "LastSeenDisplayNameType": 3,
+            "crossSaveOverride": 0, "applicableMembershipTypes": [3], "isPublic": true,
+            "membershipType": 3, "membershipId": membership_id.to_string(), "displayName": "Guardian"
+        }
+    })
+}
+
+fn page(results: Vec<Value>, page: i32, has_more: bool) -> ResponseTemplate {
+    success_envelope(json!({
+        "results": results,
+        "totalResults": 0,
+        "hasMore": has_more,
+        "query": { "itemsPerPage": 1, "currentPage": page },
+        "useTotalResults": false
+    }))
+}
+
+#[tokio::test]
+async fn admin_overview_merges_invited_and_applied() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let invited = format!("/Platform/GroupV2/{GROUP_ID}/Members/InvitedIndividuals/");
+    Mock::given(method("GET"))
+        .and(path(invited.as_str()))
+        .and(query_param("currentpage", "1"))
+        .and(header("Authorization", "Bearer admin-token"))
+        .respond_with(page(vec![application(BOTH, "2023-06-10T12:00:00Z", "")], 1, true))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(invited.as_str()))
+        .and(query_param("currentpage", "2"))
+        .respond_with(page(vec![application(INVITED_ONLY, "2023-06-12T12:00:00Z", "")], 2, false))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/Pending/")))
+        .and(query_param("currentpage", "1"))
+        .respond_with(page(
+            vec![
+                application(APPLIED_ONLY, "2023-06-13T12:00:00Z", "LF raid group"),
+                application(BOTH, "2023-06-09T12:00:00Z", "Friend of the clan"),
+            ],
+            1,
+            false,
+        ))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let overview = ClanAdmin::new(&client, "admin-token").pending_overview(GROUP_ID).await?;
+    assert!(!overview.is_cancelled());
+    let overview = overview.into_inner();
+
+    let summary: Vec<(i64, PendingKind)> = overview.iter().map(|m| (m.membership.1, m.kind)).collect();
+    assert_eq!(
+        summary,
+        [(BOTH, PendingKind::Both), (INVITED_ONLY, PendingKind::Invited), (APPLIED_ONLY, PendingKind::Applied)]
+    );
+
+    let both = &overview[0];
+    assert_eq!(both.membership.0, BungieMembershipType::TigerSteam);
+    assert!(both.invitation.is_some());
+    assert_eq!(
+        both.application.as_ref().and_then(|a| a.request_message.as_deref()),
+        Some("Friend of the clan")
+    );
+    assert_eq!(both.since(), Some(time::macros::datetime!(2023-06-09 12:00:00 UTC)), "the application came first");
+    Ok(())
+}
+
+/// An application from the bungie.net account `bungie_net_id`, optionally with a Destiny card.
+fn bungie_net_application(bungie_net_id: Option<i64>, destiny_id: Option<i64>, created: &str) -> Value {
+    let mut application = application(destiny_id.unwrap_or_default(), created, "");
+    if destiny_id.is_none() {
+        application["destinyUserInfo"] = Value::Null;
+    }
+    if let Some(bungie_net_id) = bungie_net_id {
+        application["bungieNetUserInfo"] = json!({
+            "crossSaveOverride": 0, "isPublic": false,
+            "membershipType": 254, "membershipId": bungie_net_id.to_string(), "displayName": "Guardian"
+        });
+    }
+    application
+}
+
+async fn overview_of(invited: Vec<Value>, pending: Vec<Value>) -> anyhow::Result<Vec<(i64, PendingKind)>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/InvitedIndividuals/")))
+        .respond_with(page(invited, 1, false))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/Pending/")))
+        .respond_with(page(pending, 1, false))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let overview = ClanAdmin::new(&client, "admin-token").pending_overview(GROUP_ID).await?.into_inner();
+    Ok(overview.iter().map(|m| (m.membership.1, m.kind)).collect())
+}
+
+#[tokio::test]
+async fn overview_never_merges_people_without_user_info() -> anyhow::Result<()> {
+    let summary = overview_of(
+        vec![bungie_net_application(None, None, "2023-06-10T12:00:00Z")],
+        vec![
+            bungie_net_application(None, None, "2023-06-11T12:00:00Z"),
+            bungie_net_application(None, None, "2023-06-12T12:00:00Z"),
+        ],
+    )
+    .await?;
+
+    assert_eq!(summary, [(0, PendingKind::Invited), (0, PendingKind::Applied), (0, PendingKind::Applied)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn overview_merges_on_the_bungie_net_membership() -> anyhow::Result<()> {
+    const BUNGIE_NET: i64 = 20254812;
+    const OTHER_BUNGIE_NET: i64 = 20254813;
+    let summary = overview_of(
+        vec![
+            bungie_net_application(Some(BUNGIE_NET), Some(BOTH), "2023-06-10T12:00:00Z"),
+            bungie_net_application(Some(OTHER_BUNGIE_NET), Some(INVITED_ONLY), "2023-06-11T12:00:00Z"),
+        ],
+        vec![
+            bungie_net_application(Some(BUNGIE_NET), None, "2023-06-12T12:00:00Z"),
+            bungie_net_application(Some(BUNGIE_NET + 100), Some(INVITED_ONLY), "2023-06-13T12:00:00Z"),
+        ],
+    )
+    .await?;
+
+    assert_eq!(
+        summary,
+        [(BOTH, PendingKind::Both), (INVITED_ONLY, PendingKind::Invited), (INVITED_ONLY, PendingKind::Applied)],
+        "a matching bungie.net membership merges without a Destiny card, and a different one doesn't merge despite one"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn applicant_sees_only_pending_applications() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let member = |group_id: i64, status: i32| {
+        json!({
+            "member": { "potentialStatus": status, "groupId": group_id.to_string(), "joinDate": "0001-01-01T00:00:00Z" },
+            "group": null
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/GroupV2/User/Potential/3/{BOTH}/1/1/")))
+        .and(header("Authorization", "Bearer user-token"))
+        .respond_with(success_envelope(json!({
+            "results": [member(GROUP_ID, 1), member(881267, 2)],
+            "totalResults": 2,
+            "hasMore": false,
+            "query": { "itemsPerPage": 100, "currentPage": 1 },
+            "useTotalResults": true
+        })))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let pending = Applicant::new(&client, BungieMembershipType::TigerSteam, BOTH).my_pending_clans("user-token").await?;
+
+    let groups: Vec<i64> = pending.iter().filter_map(|p| p.member.as_ref()).map(|m| m.group_id).collect();
+    assert_eq!(groups, [GROUP_ID]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn apply_and_rescind() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/Apply/3/")))
+        .and(body_json(json!({ "message": "LF raid group" })))
+        .respond_with(success_envelope(json!({ "resolution": 0 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/Platform/GroupV2/{GROUP_ID}/Members/RescindApplication/3/")))
+        .respond_with(success_envelope(json!({ "group": null, "groupDeleted": false })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let applied = client
+        .group_v2_request_group_membership(
+            GROUP_ID,
+            BungieMembershipType::TigerSteam,
+            GroupApplicationRequest { message: Some("LF raid group".to_string()) },
+            Some("user-token"),
+        )
+        .await?;
+    assert_eq!(applied.resolution, GroupApplicationResolveState::Unresolved);
+
+    let rescinded = client
+        .group_v2_rescind_group_membership(GROUP_ID, BungieMembershipType::TigerSteam, Some("user-token"))
+        .await?;
+    assert!(!rescinded.group_deleted);
+    Ok(())
+}
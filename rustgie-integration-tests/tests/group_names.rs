@@ -0,0 +1,104 @@
+﻿use rustgie::types::groups_v2::{GroupId, GroupType};
+use rustgie::{normalize_group_name, NameAvailability};
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn group(group_id: i64, name: &str) -> Value {
+    json!({
+        "groupId": group_id.to_string(), "name": name, "groupType": 1, "membershipIdCreated": "10000001",
+        "creationDate": "2017-09-06T17:00:00Z", "modificationDate": "2023-06-14T02:00:00Z", "about": "", "tags": [],
+        "memberCount": 87, "isPublic": true, "isPublicTopicAdminOnly": false, "motto": "", "allowChat": true,
+        "isDefaultPostPublic": false, "chatSecurity": 0, "locale": "en", "avatarImageIndex": 0, "homepage": 0,
+        "membershipOption": 0, "defaultPublicity": 2, "theme": "Group_Community1", "bannerPath": "", "avatarPath": "",
+        "conversationId": "0", "enableInvitationMessagingForAdmins": false
+    })
+}
+
+fn group_response(detail: Value) -> Value {
+    json!({
+        "detail": detail, "allianceStatus": 0, "groupJoinInviteCount": 0, "currentUserMembershipsInactiveForDestiny": false
+    })
+}
+
+#[test]
+fn names_are_normalized() {
+    assert_eq!(normalize_group_name("  Tower   Sweepers \t"), "Tower Sweepers");
+}
+
+#[tokio::test]
+async fn taken_names_report_the_group() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/GroupV2/NameV2/"))
+        .and(body_json(json!({ "groupName": "Tower Sweepers", "groupType": 1 })))
+        .respond_with(success_envelope(group_response(group(3074427, "Tower Sweepers"))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let availability = client.clan_name_available(" Tower  Sweepers ", GroupType::Clan).await?;
+    assert_eq!(availability, NameAvailability::Taken { group_id: Some(GroupId::from(3074427)) });
+
+    let by_path = client.group_v2_get_group_by_name("Tower Sweepers", GroupType::Clan, None).await;
+    assert!(by_path.is_err(), "the helper only uses the V2 endpoint, so V1 isn't mocked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn taken_names_without_details_have_no_group() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/GroupV2/NameV2/"))
+        .respond_with(success_envelope(json!({ "allianceStatus": 0, "groupJoinInviteCount": 0, "currentUserMembershipsInactiveForDestiny": false })))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let availability = client.clan_name_available("Tower Sweepers", GroupType::Clan).await?;
+    assert_eq!(availability, NameAvailability::Taken { group_id: None });
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_groups_are_available() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/GroupV2/NameV2/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ErrorCode": 622,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "GroupNotFound",
+            "Message": "The group was not found.",
+            "MessageData": {}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/GroupV2/Name/Tower%20Sweepers/1/"))
+        .respond_with(success_envelope(group_response(group(3074427, "Tower Sweepers"))))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    assert_eq!(client.clan_name_available("Vault Hoarders", GroupType::Clan).await?, NameAvailability::Available);
+
+    let found = client.group_v2_get_group_by_name("Tower Sweepers", GroupType::Clan, None).await?;
+    assert_eq!(found.detail.map(|g| g.group_id), Some(3074427));
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_names_never_reach_bungie() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let client = client_for(&server)?;
+
+    for name in ["X", "   X   ", "A name that is far too long for a clan", "Tower\u{0007}Sweepers"] {
+        let availability = client.clan_name_available(name, GroupType::Clan).await?;
+        assert!(matches!(availability, NameAvailability::InvalidFormat { .. }), "{name:?} gave {availability:?}");
+    }
+    assert!(server.received_requests().await.expect("request recording is enabled").is_empty());
+    Ok(())
+}
@@ -0,0 +1,72 @@
+﻿use rustgie::types::destiny::definitions::guardian_ranks::{
+    guardian_rank_display, DestinyGuardianRankConstantsDefinition, DestinyGuardianRankDefinition, GuardianRankDisplay,
+};
+use rustgie::types::destiny::responses::DestinyProfileResponse;
+use serde_json::Value;
+use std::collections::HashMap;
+
+struct Fixture {
+    rank_defs: HashMap<u32, DestinyGuardianRankDefinition>,
+    constants: DestinyGuardianRankConstantsDefinition,
+    fixture: Value,
+}
+
+impl Fixture {
+    fn profile(&self, name: &str) -> anyhow::Result<DestinyProfileResponse> {
+        Ok(serde_json::from_value(self.fixture[name].clone())?)
+    }
+}
+
+fn fixture() -> anyhow::Result<Fixture> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/guardian_ranks.json"))?;
+    Ok(Fixture {
+        rank_defs: serde_json::from_value(fixture["rankDefinitions"].clone())?,
+        constants: serde_json::from_value(fixture["constants"].clone())?,
+        fixture,
+    })
+}
+
+#[test]
+fn seasonal_rank_resets_below_the_lifetime_highest() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let profile = fixture.profile("seasonalProfile")?;
+
+    let display = guardian_rank_display(&profile, &fixture.rank_defs, &fixture.constants).expect("profile component");
+
+    assert_eq!(
+        display,
+        GuardianRankDisplay {
+            current_name: Some("Justiciar"),
+            current_number: 8,
+            lifetime_number: 10,
+            icon_url: Some("https://www.bungie.net/common/destiny2_content/icons/guardian_rank_08.png".parse()?),
+            seasonal: true,
+            resets_to: 6,
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn ranks_below_seven_are_kept() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let profile = fixture.profile("permanentProfile")?;
+
+    let display = guardian_rank_display(&profile, &fixture.rank_defs, &fixture.constants).expect("profile component");
+
+    assert_eq!(display.current_name, Some("Adventurer"));
+    assert_eq!((display.current_number, display.lifetime_number), (5, 9));
+    assert!(!display.seasonal);
+    assert_eq!(display.resets_to, 5);
+    Ok(())
+}
+
+#[test]
+fn missing_profile_component_has_no_ranks() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let mut profile = fixture.profile("seasonalProfile")?;
+    profile.profile = None;
+
+    assert_eq!(guardian_rank_display(&profile, &fixture.rank_defs, &fixture.constants), None);
+    Ok(())
+}
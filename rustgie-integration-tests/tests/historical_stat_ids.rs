@@ -0,0 +1,61 @@
+﻿use rustgie::types::destiny::historical_stats::definitions::HistoricalStatId;
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::json;
+use std::collections::HashSet;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer};
+
+#[test]
+fn every_known_id_round_trips() {
+    let mut seen = HashSet::new();
+    for id in HistoricalStatId::KNOWN {
+        assert!(seen.insert(id.as_str()), "{} listed twice", id.as_str());
+        assert!(!matches!(id, HistoricalStatId::Other(_)));
+        assert_eq!(&HistoricalStatId::from(id.as_str()), id);
+        assert_eq!(&id.to_string().parse::<HistoricalStatId>().expect("infallible"), id);
+        assert_eq!(&HistoricalStatId::from(id.as_str().to_string()), id);
+    }
+    assert_eq!(HistoricalStatId::from("lbSingleGameKills"), HistoricalStatId::LbSingleGameKills);
+}
+
+#[test]
+fn unknown_ids_pass_through_other() {
+    let typo = HistoricalStatId::from("Kills");
+    assert_eq!(typo, HistoricalStatId::Other("Kills".to_string()), "IDs are case sensitive");
+    assert_eq!(typo.as_str(), "Kills");
+
+    let future: HistoricalStatId = "lbSeasonalTriumphScore".parse().expect("infallible");
+    assert_eq!(future.to_string(), "lbSeasonalTriumphScore");
+}
+
+#[tokio::test]
+async fn leaderboards_send_the_id_string() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Destiny2/Stats/Leaderboards/Clans/3074427/"))
+        .respond_with(success_envelope(json!({})))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    client
+        .destiny2_get_clan_leaderboards(3074427, Some(10), Some("AllPvP"), HistoricalStatId::LbSingleGameKills, None)
+        .await?;
+    client
+        .destiny2_get_clan_leaderboards(3074427, None, None, HistoricalStatId::from("lbSeasonalTriumphScore"), None)
+        .await?;
+    client.destiny2_get_clan_leaderboards(3074427, None, None, None, None).await?;
+
+    let queries: Vec<String> = server
+        .received_requests()
+        .await
+        .expect("request recording is enabled")
+        .iter()
+        .map(|request| request.url.query().unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(
+        queries,
+        ["maxtop=10&modes=AllPvP&statid=lbSingleGameKills", "statid=lbSeasonalTriumphScore", ""]
+    );
+    Ok(())
+}
@@ -0,0 +1,99 @@
+﻿use rustgie::types::ignores::{IgnoreItemRequest, IgnoreStatus};
+use rustgie::RustgieError;
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const USER: i64 = 10000002;
+
+fn user_request_body() -> Value {
+    json!({
+        "ignoredItemId": "10000002",
+        "ignoredItemType": 3,
+        "comment": null,
+        "reason": null,
+        "itemContextId": null,
+        "itemContextType": 0,
+        "moderatorRequest": false,
+        "banLength": 0
+    })
+}
+
+#[tokio::test]
+async fn ignore_and_unignore_a_user() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Ignore/Ignore/"))
+        .and(header("Authorization", "Bearer user-token"))
+        .and(body_json(user_request_body()))
+        .respond_with(success_envelope(json!({ "isIgnored": true, "ignoreFlags": 1 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Ignore/Unignore/"))
+        .and(header("Authorization", "Bearer user-token"))
+        .and(body_json(user_request_body()))
+        .respond_with(success_envelope(json!({ "isIgnored": false, "ignoreFlags": 0 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let request = IgnoreItemRequest::user(USER);
+    assert_eq!(request.resulting_status(), Some(IgnoreStatus::IgnoredUser));
+
+    let ignored = client.ignore_ignore_item(request.clone(), "user-token").await?;
+    assert!(ignored.is_ignored);
+    assert!(ignored.ignore_flags.contains(IgnoreStatus::IgnoredUser));
+
+    let unignored = client.ignore_unignore_item(request, "user-token").await?;
+    assert!(!unignored.is_ignored);
+    assert!(unignored.ignore_flags.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn ignoring_twice_is_a_distinct_error() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Ignore/Ignore/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ErrorCode": 1009,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "IgnoreUserIgnored",
+            "Message": "You are already ignoring this user.",
+            "MessageData": {}
+        })))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let error = client
+        .ignore_ignore_item(IgnoreItemRequest::user(USER), "user-token")
+        .await
+        .expect_err("already ignored");
+    let error = error.downcast_ref::<RustgieError>().expect("a RustgieError");
+    assert!(error.is_already_ignored());
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_combinations_are_rejected_before_sending() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let client = client_for(&server)?;
+
+    let group_scoped_user = IgnoreItemRequest::user(USER).in_group(3074427);
+    assert!(client.ignore_ignore_item(group_scoped_user, "user-token").await.is_err());
+
+    let mut personal_ban = IgnoreItemRequest::user(USER);
+    personal_ban.ban_length = rustgie::types::ignores::IgnoreLength::Week;
+    assert!(client.ignore_ignore_item(personal_ban, "user-token").await.is_err());
+
+    assert!(IgnoreItemRequest::post(263000003).in_group(3074427).validate().is_ok());
+    assert!(IgnoreItemRequest::tag("#destiny2").validate().is_ok());
+
+    assert!(server.received_requests().await.expect("recording enabled").is_empty());
+    Ok(())
+}
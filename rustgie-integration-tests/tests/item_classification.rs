@@ -0,0 +1,70 @@
+﻿use rustgie::types::destiny::definitions::{ArmorFilter, DestinyInventoryItemDefinition, WeaponFilter};
+use rustgie::types::destiny::{DestinyAmmunitionType, DestinyClass, TierType};
+use std::collections::HashMap;
+
+const SUNSHOT: u32 = 2907129557;
+const GJALLARHORN: u32 = 1363886209;
+const SWORDFLIGHT_MASK: u32 = 2262062209;
+const DATURA_PATTERN_CORE: u32 = 3487922223;
+const ASCENDANT_SEA: u32 = 1907674138;
+
+const ENERGY_WEAPONS_SLOT_HASH: u32 = 2465295065;
+const HELMET_SLOT_HASH: u32 = 3448274439;
+
+fn definitions() -> HashMap<u32, DestinyInventoryItemDefinition> {
+    serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid definitions")
+}
+
+#[test]
+fn accessors() {
+    let definitions = definitions();
+
+    let sunshot = &definitions[&SUNSHOT];
+    assert_eq!(sunshot.tier(), TierType::Exotic);
+    assert_eq!(sunshot.ammo_type(), DestinyAmmunitionType::Primary);
+    assert_eq!(sunshot.slot_hash(), Some(ENERGY_WEAPONS_SLOT_HASH));
+    assert!(sunshot.is_weapon() && !sunshot.is_armor());
+
+    let gjallarhorn = &definitions[&GJALLARHORN];
+    assert_eq!(gjallarhorn.ammo_type(), DestinyAmmunitionType::Heavy, "classified by item subtype without an equipping block");
+    assert_eq!(gjallarhorn.slot_hash(), None);
+
+    let mask = &definitions[&SWORDFLIGHT_MASK];
+    assert_eq!(mask.tier(), TierType::Superior);
+    assert_eq!(mask.ammo_type(), DestinyAmmunitionType::None);
+    assert_eq!(mask.slot_hash(), Some(HELMET_SLOT_HASH));
+    assert!(mask.is_armor() && !mask.is_weapon());
+
+    let core = &definitions[&DATURA_PATTERN_CORE];
+    assert_eq!(core.tier(), TierType::Basic);
+    assert_eq!(core.ammo_type(), DestinyAmmunitionType::None);
+    assert_eq!(core.slot_hash(), None);
+    assert!(!core.is_weapon() && !core.is_armor(), "no categories falls back to itemType");
+
+    let emblem = &definitions[&ASCENDANT_SEA];
+    assert!(emblem.slot_hash().is_some());
+    assert!(!emblem.is_weapon() && !emblem.is_armor());
+}
+
+#[test]
+fn filters() {
+    let definitions = definitions();
+    let mut items: Vec<&DestinyInventoryItemDefinition> = definitions.values().collect();
+    items.sort_by_key(|item| item.hash);
+    let hashes = |matched: Vec<&DestinyInventoryItemDefinition>| matched.iter().map(|item| item.hash).collect::<Vec<_>>();
+
+    let mut exotic_weapons = hashes(WeaponFilter::new().tier(TierType::Exotic).apply(&items));
+    exotic_weapons.sort_unstable();
+    assert_eq!(exotic_weapons, [GJALLARHORN, SUNSHOT]);
+
+    let exotic_primaries = WeaponFilter::new().tier(TierType::Exotic).ammo_type(DestinyAmmunitionType::Primary);
+    assert_eq!(hashes(exotic_primaries.apply(&items)), [SUNSHOT]);
+    assert!(WeaponFilter::new().slot_hash(HELMET_SLOT_HASH).apply(&items).is_empty());
+
+    assert_eq!(hashes(ArmorFilter::new().class_type(DestinyClass::Hunter).apply(&items)), [SWORDFLIGHT_MASK]);
+    assert!(ArmorFilter::new().class_type(DestinyClass::Titan).apply(&items).is_empty());
+    assert_eq!(
+        hashes(ArmorFilter::new().tier(TierType::Superior).slot_hash(HELMET_SLOT_HASH).apply(&items)),
+        [SWORDFLIGHT_MASK]
+    );
+}
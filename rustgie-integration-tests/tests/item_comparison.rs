@@ -0,0 +1,125 @@
+﻿use rustgie::types::destiny::definitions::{DestinyItemStatBlockDefinition, DestinyStatDefinition, DestinyStatGroupDefinition};
+use rustgie::types::destiny::entities::items::{compare_items, compare_items_with, DestinyItemStatsComponent, StatDelta};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const MOBILITY: u32 = 2996146975;
+const RESILIENCE: u32 = 392767087;
+const RECOVERY: u32 = 1943323491;
+const DISCIPLINE: u32 = 1735777505;
+const INTELLECT: u32 = 144602215;
+const STRENGTH: u32 = 4244567218;
+const DEFENSE: u32 = 3897883278;
+const UNNAMED: u32 = 1480404414;
+
+struct Fixture {
+    stat_defs: HashMap<u32, DestinyStatDefinition>,
+    stat_group: DestinyStatGroupDefinition,
+    drop: DestinyItemStatsComponent,
+    equipped: DestinyItemStatsComponent,
+    definition_stats: DestinyItemStatBlockDefinition,
+}
+
+fn fixture() -> anyhow::Result<Fixture> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/item_comparison.json"))?;
+    Ok(Fixture {
+        stat_defs: serde_json::from_value(fixture["statDefinitions"].clone())?,
+        stat_group: serde_json::from_value(fixture["armorStatGroup"].clone())?,
+        drop: serde_json::from_value(fixture["drop"].clone())?,
+        equipped: serde_json::from_value(fixture["equipped"].clone())?,
+        definition_stats: serde_json::from_value(fixture["definitionStats"].clone())?,
+    })
+}
+
+fn summary(deltas: &[StatDelta]) -> Vec<(u32, i32, i32, i32)> {
+    deltas.iter().map(|d| (d.stat_hash, d.a_value, d.b_value, d.delta)).collect()
+}
+
+#[test]
+fn compares_by_definition_index_without_a_stat_group() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let deltas = compare_items(&fixture.drop, &fixture.equipped, &fixture.stat_defs);
+
+    assert_eq!(
+        summary(&deltas),
+        vec![
+            (DEFENSE, 0, 0, 0),
+            (MOBILITY, 22, 10, 12),
+            (RESILIENCE, 8, 16, -8),
+            (RECOVERY, 14, 0, 14),
+            (DISCIPLINE, 2, 20, -18),
+            (INTELLECT, 10, 6, 4),
+            (STRENGTH, 9, 12, -3),
+        ]
+    );
+    assert_eq!(deltas[1].name, Some("Mobility"));
+    assert!(deltas.iter().all(|d| d.display_maximum.is_none()));
+    Ok(())
+}
+
+#[test]
+fn stat_group_orders_and_scales_the_stats() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let deltas = compare_items_with(&fixture.equipped, &fixture.drop, &fixture.stat_defs, Some(&fixture.stat_group), false);
+
+    assert_eq!(
+        summary(&deltas),
+        vec![
+            (STRENGTH, 12, 9, 3),
+            (INTELLECT, 6, 10, -4),
+            (DISCIPLINE, 20, 2, 18),
+            (RECOVERY, 0, 14, -14),
+            (RESILIENCE, 16, 8, 8),
+            (MOBILITY, 10, 22, -12),
+        ]
+    );
+    assert!(deltas.iter().all(|d| d.display_maximum == Some(42)));
+    Ok(())
+}
+
+#[test]
+fn hidden_stats_follow_the_displayed_ones_when_asked_for() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let deltas = compare_items_with(&fixture.drop, &fixture.equipped, &fixture.stat_defs, Some(&fixture.stat_group), true);
+
+    let hashes: Vec<u32> = deltas.iter().map(|d| d.stat_hash).collect();
+    assert_eq!(hashes, vec![STRENGTH, INTELLECT, DISCIPLINE, RECOVERY, RESILIENCE, MOBILITY, DEFENSE, UNNAMED]);
+    let unnamed = deltas.last().unwrap();
+    assert_eq!((unnamed.name, unnamed.a_value, unnamed.b_value, unnamed.delta), (None, 5, 0, 5));
+    assert_eq!(unnamed.display_maximum, None);
+    Ok(())
+}
+
+#[test]
+fn definition_stats_stand_in_for_missing_instance_stats() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let from_definition = DestinyItemStatsComponent::from_definition(&fixture.definition_stats);
+
+    let deltas = compare_items(&from_definition, &fixture.equipped, &fixture.stat_defs);
+
+    assert_eq!(
+        summary(&deltas),
+        vec![
+            (DEFENSE, 0, 0, 0),
+            (MOBILITY, 2, 10, -8),
+            (RESILIENCE, 2, 16, -14),
+            (RECOVERY, 2, 0, 2),
+            (DISCIPLINE, 0, 20, -20),
+            (INTELLECT, 0, 6, -6),
+            (STRENGTH, 0, 12, -12),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn items_without_stats_compare_empty() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+    let empty = DestinyItemStatsComponent { stats: None };
+
+    assert!(compare_items(&empty, &empty, &fixture.stat_defs).is_empty());
+    Ok(())
+}
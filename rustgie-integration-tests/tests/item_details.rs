@@ -0,0 +1,72 @@
+﻿use rustgie::{DefinitionProvider, DefinitionTable};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::characters::CharacterId;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::client_for;
+use serde_json::Value;
+use std::collections::HashMap;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SUNSHOT: u32 = 2907129557;
+const SHAPED_WEAPON: u32 = 659359923;
+
+/// The fixture's inventory item definitions, standing in for a downloaded manifest.
+struct FixtureDefinitions(HashMap<u32, Value>);
+
+impl DefinitionProvider for FixtureDefinitions {
+    fn raw_definition(&self, table: &DefinitionTable, hash: u32) -> anyhow::Result<Option<Value>> {
+        assert_eq!(table, &DefinitionTable::new("DestinyInventoryItemDefinition"));
+        Ok(self.0.get(&hash).cloned())
+    }
+}
+
+async fn item_server(fixture: &Value) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Destiny2/3/Profile/4611686018400000001/Item/6917529900000000001/"))
+        .and(query_param("components", "307,300,304,305,310,309"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&fixture["getItem"]))
+        .expect(1)
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn item_details_resolve_sockets_and_degrade_hidden_components() -> anyhow::Result<()> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/item_details.json"))?;
+    let server = item_server(&fixture).await;
+    let defs = FixtureDefinitions(serde_json::from_value(fixture["definitions"].clone())?);
+
+    let details = client_for(&server)?
+        .item_details(BungieMembershipType::TigerSteam, 4611686018400000001, 6917529900000000001, &defs, None)
+        .await?;
+
+    assert_eq!(details.character_id, CharacterId::new(2305843009300000001));
+    assert_eq!(details.item.as_ref().map(|item| item.item_hash), Some(SUNSHOT));
+    assert_eq!(details.definition.as_ref().map(|definition| definition.hash), Some(SUNSHOT));
+    // The instance came back private and the stats disabled; the rest of the item is still there.
+    assert_eq!(details.instance, None);
+    assert_eq!(details.stats, None);
+
+    let sockets = details.sockets.as_ref().expect("sockets");
+    assert_eq!(sockets.len(), 6);
+    let names = |plugs: &[DestinyInventoryItemDefinition]| -> Vec<String> {
+        plugs.iter().filter_map(|plug| plug.display_properties.as_ref()?.name.clone()).collect()
+    };
+    assert_eq!(names(sockets[0].plug.as_slice()), ["Adaptive Frame"]);
+    // The third reusable plug isn't in the definitions and is left out.
+    assert_eq!(sockets[3].socket.reusable_plug_hashes.len(), 3);
+    assert_eq!(names(&sockets[3].reusable_plugs), ["Outlaw Enhanced", "Kill Clip"]);
+    assert_eq!(sockets[4].plug, None);
+    assert_eq!(sockets[5].plug.as_ref().map(|plug| plug.hash), Some(SHAPED_WEAPON));
+
+    let objectives = details.plug_objectives.as_ref().and_then(|plugs| plugs.objectives_per_plug.as_ref()).expect("plug objectives");
+    assert_eq!(objectives[&SHAPED_WEAPON].len(), 4);
+
+    // Without the ItemStats component, comparisons fall back to the definition's stats.
+    let stats = details.comparable_stats().and_then(|stats| stats.stats).expect("definition stats");
+    assert_eq!(stats[&4284893193].value, 140);
+    Ok(())
+}
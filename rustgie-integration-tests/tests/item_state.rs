@@ -0,0 +1,117 @@
+﻿use enumflags2::BitFlags;
+use rustgie::types::destiny::entities::items::{apply_lock_state, apply_tracked_state, DestinyItemComponent};
+use rustgie::types::destiny::ItemState;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::client_for;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CHARACTER_ID: i64 = 2305843009300000001;
+
+fn item(state: BitFlags<ItemState>, instance_id: Option<&str>) -> DestinyItemComponent {
+    serde_json::from_value(json!({
+        "itemHash": 1363886209, "itemInstanceId": instance_id, "quantity": 1, "bindStatus": 0, "location": 1,
+        "bucketHash": 1498876634, "transferStatus": 0, "lockable": true, "state": state.bits(), "isWrapper": false
+    }))
+    .expect("valid item")
+}
+
+fn envelope(error_code: i32, error_status: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "Response": 0,
+        "ErrorCode": error_code,
+        "ThrottleSeconds": 0,
+        "ErrorStatus": error_status,
+        "Message": error_status,
+        "MessageData": {}
+    }))
+}
+
+fn state_request(state: bool) -> Value {
+    json!({ "state": state, "itemId": "6917529862012347520", "characterId": CHARACTER_ID.to_string(), "membershipType": 3 })
+}
+
+#[test]
+fn lock_state_sets_and_clears_only_the_locked_flag() {
+    let others = ItemState::Masterwork | ItemState::Crafted | ItemState::Tracked;
+    let mut item = item(others, None);
+
+    apply_lock_state(&mut item, true);
+    assert_eq!(item.state, others | ItemState::Locked);
+    apply_lock_state(&mut item, true);
+    assert_eq!(item.state, others | ItemState::Locked);
+    apply_lock_state(&mut item, false);
+    assert_eq!(item.state, others);
+    apply_lock_state(&mut item, false);
+    assert_eq!(item.state, others);
+}
+
+#[test]
+fn tracked_state_sets_and_clears_only_the_tracked_flag() {
+    let others = ItemState::Locked | ItemState::HighlightedObjective;
+    let mut item = item(others, None);
+
+    apply_tracked_state(&mut item, true);
+    assert_eq!(item.state, others | ItemState::Tracked);
+    apply_tracked_state(&mut item, false);
+    assert_eq!(item.state, others);
+
+    let mut item = self::item(BitFlags::empty(), None);
+    apply_tracked_state(&mut item, true);
+    assert_eq!(item.state, ItemState::Tracked);
+}
+
+#[tokio::test]
+async fn locking_updates_the_cached_item() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/SetLockState/"))
+        .and(body_json(state_request(true)))
+        .respond_with(envelope(1, "Success"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut item = item(ItemState::Masterwork.into(), Some("6917529862012347520"));
+    client_for(&server)?
+        .set_item_locked(&mut item, CHARACTER_ID, BungieMembershipType::TigerSteam, true, "access-token")
+        .await?;
+    assert_eq!(item.state, ItemState::Masterwork | ItemState::Locked);
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_requests_roll_the_cached_item_back() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/SetTrackedState/"))
+        .and(body_json(state_request(false)))
+        .respond_with(envelope(1623, "DestinyItemNotFound"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let before = ItemState::Tracked | ItemState::HighlightedObjective;
+    let mut item = item(before, Some("6917529862012347520"));
+    let result = client_for(&server)?
+        .set_quest_tracked(&mut item, CHARACTER_ID, BungieMembershipType::TigerSteam, false, "access-token")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(item.state, before);
+    Ok(())
+}
+
+#[tokio::test]
+async fn uninstanced_items_are_refused_before_sending() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST")).respond_with(envelope(1, "Success")).expect(0).mount(&server).await;
+
+    let mut item = item(BitFlags::empty(), None);
+    let result = client_for(&server)?
+        .set_item_locked(&mut item, CHARACTER_ID, BungieMembershipType::TigerSteam, true, "access-token")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(item.state, BitFlags::empty());
+    Ok(())
+}
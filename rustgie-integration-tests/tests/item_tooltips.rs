@@ -0,0 +1,58 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::items::{active_tooltips, visible_values, DestinyItemComponent};
+use serde_json::{json, Value};
+
+/// A bounty definition with two tooltips and three rewards, built from the consumable in the definitions fixture.
+fn bounty() -> DestinyInventoryItemDefinition {
+    let fixture: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    let mut definition = fixture["3487922223"].clone();
+    definition["tooltipNotifications"] = json!([
+        { "displayString": "Expires at weekly reset", "displayStyle": "ui_display_style_info" },
+        { "displayString": "Rewards increase with Guardian Rank", "displayStyle": "ui_display_style_info" }
+    ]);
+    definition["value"] = json!({
+        "itemValue": [
+            { "itemHash": 3159615086_u32, "quantity": 5000, "hasConditionalVisibility": false },
+            { "itemHash": 1022552290, "quantity": 1, "hasConditionalVisibility": true },
+            { "itemHash": 3853748946_u32, "quantity": 2, "hasConditionalVisibility": false }
+        ],
+        "valueDescription": ""
+    });
+    serde_json::from_value(definition).expect("valid definition")
+}
+
+fn item(tooltip_indexes: Value, value_visibility: Value) -> DestinyItemComponent {
+    serde_json::from_value(json!({
+        "itemHash": 3487922223_u32, "quantity": 1, "bindStatus": 0, "location": 1, "bucketHash": 1345459588,
+        "transferStatus": 2, "lockable": false, "state": 0, "isWrapper": false,
+        "tooltipNotificationIndexes": tooltip_indexes, "itemValueVisibility": value_visibility, "versionNumber": 0
+    }))
+    .expect("valid item")
+}
+
+#[test]
+fn tooltips_skip_out_of_range_indexes() {
+    let definition = bounty();
+    let item = item(json!([1, 7, -1, 0]), Value::Null);
+
+    let tooltips: Vec<Option<&str>> =
+        active_tooltips(&item, &definition).iter().map(|t| t.display_string.as_deref()).collect();
+
+    assert_eq!(tooltips, [Some("Rewards increase with Guardian Rank"), Some("Expires at weekly reset")]);
+    assert!(active_tooltips(&self::item(Value::Null, Value::Null), &definition).is_empty());
+}
+
+#[test]
+fn hidden_values_are_dropped() {
+    let definition = bounty();
+
+    let hashes = |visibility: Value| -> Vec<u32> {
+        visible_values(&item(json!([]), visibility), &definition).iter().map(|v| v.item_hash).collect()
+    };
+
+    assert_eq!(hashes(json!([true, false, true])), [3159615086, 3853748946]);
+    assert_eq!(hashes(Value::Null), [3159615086, 1022552290, 3853748946], "no visibility list shows everything");
+    assert_eq!(hashes(json!([false])), [1022552290, 3853748946], "values past the visibility list are shown");
+    assert_eq!(hashes(json!([true, false, true, false, false])), [3159615086, 3853748946], "extra flags are ignored");
+}
@@ -0,0 +1,60 @@
+﻿use rustgie::types::destiny::responses::{usable_profiles, DestinyErrorProfile, DestinyLinkedProfilesResponse};
+use rustgie::types::exceptions::PlatformErrorCodes;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+use serde_json::json;
+
+const BUNGIE_MEMBERSHIP_ID: i64 = 10000001;
+const STEAM_MEMBERSHIP_ID: i64 = 4611686018400000001;
+const STADIA_MEMBERSHIP_ID: i64 = 4611686018400000006;
+
+#[tokio::test]
+async fn defunct_stadia_profile_is_reported_separately() -> anyhow::Result<()> {
+    let (_server, client) = replay("linked_profiles_stadia").await?;
+
+    let linked = client
+        .destiny2_get_linked_profiles(BUNGIE_MEMBERSHIP_ID, BungieMembershipType::BungieNext, Some(true), None)
+        .await?;
+
+    let usable: Vec<(BungieMembershipType, i64)> =
+        usable_profiles(&linked).iter().map(|p| (p.membership_type, p.membership_id)).collect();
+    assert_eq!(usable, [(BungieMembershipType::TigerSteam, STEAM_MEMBERSHIP_ID)], "the PSN profile is overridden by Cross Save");
+
+    assert_eq!(
+        linked.errored_platforms(),
+        [(BungieMembershipType::TigerStadia, PlatformErrorCodes::DestinyAccountNotFound)]
+    );
+    let stadia = linked.profiles_with_errors.as_ref().and_then(|e| e[0].info_card.as_ref()).expect("partial info card");
+    assert_eq!(stadia.membership_id, STADIA_MEMBERSHIP_ID);
+    assert_eq!(stadia.cross_save_override, BungieMembershipType::None);
+    assert!(!stadia.is_public);
+    Ok(())
+}
+
+#[test]
+fn errored_profiles_with_the_same_membership_are_not_usable() -> anyhow::Result<()> {
+    let linked: DestinyLinkedProfilesResponse = serde_json::from_value(json!({
+        "profiles": [{
+            "dateLastPlayed": "2023-06-13T22:41:05Z", "isOverridden": false, "isCrossSavePrimary": false,
+            "crossSaveOverride": 0, "applicableMembershipTypes": [4], "isPublic": true,
+            "membershipType": 4, "membershipId": "4611686018400000007", "displayName": "Guardian"
+        }],
+        "profilesWithErrors": [
+            { "errorCode": 1618, "infoCard": { "membershipType": 4, "membershipId": "4611686018400000007" } },
+            { "errorCode": 1601, "infoCard": null }
+        ]
+    }))?;
+
+    assert!(usable_profiles(&linked).is_empty());
+    assert_eq!(
+        linked.errored_platforms(),
+        [
+            (BungieMembershipType::TigerBlizzard, PlatformErrorCodes::DestinyUnexpectedError),
+            (BungieMembershipType::None, PlatformErrorCodes::DestinyAccountNotFound),
+        ]
+    );
+
+    let bare: DestinyErrorProfile = serde_json::from_value(json!({ "errorCode": 1601 }))?;
+    assert!(bare.info_card.is_none());
+    Ok(())
+}
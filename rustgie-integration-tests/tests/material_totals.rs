@@ -0,0 +1,103 @@
+﻿use rustgie::types::destiny::components::inventory::{material_totals, named_totals, DestinyCurrenciesComponent, NamedTotal};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::inventory::DestinyInventoryComponent;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const GLIMMER: u32 = 3159615086;
+const ENHANCEMENT_CORE: u32 = 3853748946;
+const ASCENDANT_SHARD: u32 = 353704689;
+const MODULE_COMPONENT: u32 = 1498876634;
+
+const GENERAL_BUCKET: u32 = 138197802;
+const CONSUMABLES_BUCKET: u32 = 1469714392;
+
+fn stack(item_hash: u32, quantity: i32, bucket_hash: u32) -> Value {
+    json!({
+        "itemHash": item_hash, "quantity": quantity, "bindStatus": 0, "location": 2, "bucketHash": bucket_hash,
+        "transferStatus": 2, "lockable": false, "state": 0, "dismantlePermission": 0, "isWrapper": false,
+        "tooltipNotificationIndexes": [], "versionNumber": 0
+    })
+}
+
+fn inventory(items: Vec<Value>) -> DestinyInventoryComponent {
+    serde_json::from_value(json!({ "items": items })).expect("valid inventory")
+}
+
+fn currencies() -> DestinyCurrenciesComponent {
+    serde_json::from_value(json!({
+        "itemQuantities": { GLIMMER.to_string(): 250000, ENHANCEMENT_CORE.to_string(): 120 }
+    }))
+    .expect("valid currencies")
+}
+
+/// Item definitions named after the materials, built from the consumable in the definitions fixture.
+fn definitions() -> HashMap<u32, DestinyInventoryItemDefinition> {
+    let fixture: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    [(GLIMMER, "Glimmer"), (ENHANCEMENT_CORE, "Enhancement Core"), (ASCENDANT_SHARD, "Ascendant Shard")]
+        .into_iter()
+        .map(|(hash, name)| {
+            let mut definition = fixture["3487922223"].clone();
+            definition["hash"] = json!(hash);
+            definition["displayProperties"]["name"] = json!(name);
+            (hash, serde_json::from_value(definition).expect("valid definition"))
+        })
+        .collect()
+}
+
+#[test]
+fn sums_across_inventories() {
+    let vault = inventory(vec![stack(ASCENDANT_SHARD, 3, GENERAL_BUCKET), stack(MODULE_COMPONENT, 40, GENERAL_BUCKET)]);
+    let hunter = inventory(vec![stack(ASCENDANT_SHARD, 1, CONSUMABLES_BUCKET)]);
+
+    let totals = material_totals(None, &[&vault, &hunter]);
+
+    assert_eq!(totals, HashMap::from([(ASCENDANT_SHARD, 4), (MODULE_COMPONENT, 40)]));
+}
+
+#[test]
+fn currencies_take_precedence_over_inventory_stacks() {
+    let currencies = currencies();
+    // Bungie also lists these cores as consumable stacks; counting them again would report 170.
+    let profile = inventory(vec![
+        stack(ENHANCEMENT_CORE, 30, CONSUMABLES_BUCKET),
+        stack(ENHANCEMENT_CORE, 20, CONSUMABLES_BUCKET),
+        stack(ASCENDANT_SHARD, 2, GENERAL_BUCKET),
+    ]);
+
+    let totals = material_totals(Some(&currencies), &[&profile]);
+
+    assert_eq!(totals[&ENHANCEMENT_CORE], 120);
+    assert_eq!(totals[&GLIMMER], 250000);
+    assert_eq!(totals[&ASCENDANT_SHARD], 2, "materials the currencies component omits still come from inventories");
+
+    let without_currencies = material_totals(None, &[&profile]);
+    assert_eq!(without_currencies[&ENHANCEMENT_CORE], 50);
+}
+
+#[test]
+fn large_stacks_do_not_overflow() {
+    let stacks = inventory(vec![stack(GLIMMER, i32::MAX, GENERAL_BUCKET), stack(GLIMMER, i32::MAX, GENERAL_BUCKET)]);
+    assert_eq!(material_totals(None, &[&stacks])[&GLIMMER], 2 * i64::from(i32::MAX));
+}
+
+#[test]
+fn named_and_sorted() {
+    let currencies = currencies();
+    let vault = inventory(vec![stack(ASCENDANT_SHARD, 3, GENERAL_BUCKET), stack(MODULE_COMPONENT, 40, GENERAL_BUCKET)]);
+    let definitions = definitions();
+
+    let totals = material_totals(Some(&currencies), &[&vault]);
+    let named = named_totals(&totals, &definitions);
+
+    assert_eq!(
+        named,
+        [
+            NamedTotal { item_hash: GLIMMER, name: Some("Glimmer"), quantity: 250000 },
+            NamedTotal { item_hash: ENHANCEMENT_CORE, name: Some("Enhancement Core"), quantity: 120 },
+            NamedTotal { item_hash: MODULE_COMPONENT, name: None, quantity: 40 },
+            NamedTotal { item_hash: ASCENDANT_SHARD, name: Some("Ascendant Shard"), quantity: 3 },
+        ]
+    );
+}
@@ -0,0 +1,159 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::inventory::{
+    max_power, CHEST_ARMOR_BUCKET_HASH, CLASS_ARMOR_BUCKET_HASH, ENERGY_WEAPONS_BUCKET_HASH, GAUNTLETS_BUCKET_HASH,
+    HELMET_BUCKET_HASH, KINETIC_WEAPONS_BUCKET_HASH, LEG_ARMOR_BUCKET_HASH, POWER_WEAPONS_BUCKET_HASH,
+};
+use rustgie::types::destiny::entities::items::DestinyItemInstanceComponent;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const LEGENDARY: i32 = 5;
+const EXOTIC: i32 = 6;
+const POWER_STAT_HASH: u32 = 1935470627;
+
+/// A definition for `item_hash` in `bucket_hash`, built on Sunshot's definition.
+fn definition(item_hash: u32, bucket_hash: u32, tier_type: i32) -> DestinyInventoryItemDefinition {
+    let definitions: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    let mut item = definitions["2907129557"].clone();
+    item["hash"] = json!(item_hash);
+    item["inventory"]["bucketTypeHash"] = json!(bucket_hash);
+    item["inventory"]["tierType"] = json!(tier_type);
+    serde_json::from_value(item).expect("valid item definition")
+}
+
+fn instance(power: Option<i32>) -> DestinyItemInstanceComponent {
+    serde_json::from_value(json!({
+        "damageType": 1,
+        "primaryStat": power.map(|value| json!({ "statHash": POWER_STAT_HASH, "value": value })),
+        "itemLevel": 0,
+        "quality": 0,
+        "isEquipped": false,
+        "canEquip": true,
+        "equipRequiredLevel": 0,
+        "cannotEquipReason": 0
+    }))
+    .expect("valid instance component")
+}
+
+/// Definitions and instances for `(item_hash, bucket_hash, tier_type, power)`.
+fn account(items: &[(u32, u32, i32, Option<i32>)]) -> (HashMap<u32, DestinyInventoryItemDefinition>, Vec<(u32, DestinyItemInstanceComponent)>) {
+    let defs = items.iter().map(|(hash, bucket, tier, _)| (*hash, definition(*hash, *bucket, *tier))).collect();
+    let instances = items.iter().map(|(hash, _, _, power)| (*hash, instance(*power))).collect();
+    (defs, instances)
+}
+
+fn borrowed(instances: &[(u32, DestinyItemInstanceComponent)]) -> Vec<(u32, &DestinyItemInstanceComponent)> {
+    instances.iter().map(|(hash, instance)| (*hash, instance)).collect()
+}
+
+/// A legendary at 1800 in every slot.
+fn full_legendary_set() -> Vec<(u32, u32, i32, Option<i32>)> {
+    [
+        KINETIC_WEAPONS_BUCKET_HASH,
+        ENERGY_WEAPONS_BUCKET_HASH,
+        POWER_WEAPONS_BUCKET_HASH,
+        HELMET_BUCKET_HASH,
+        GAUNTLETS_BUCKET_HASH,
+        CHEST_ARMOR_BUCKET_HASH,
+        LEG_ARMOR_BUCKET_HASH,
+        CLASS_ARMOR_BUCKET_HASH,
+    ]
+    .iter()
+    .enumerate()
+    .map(|(index, bucket)| (100 + index as u32, *bucket, LEGENDARY, Some(1800)))
+    .collect()
+}
+
+#[test]
+fn best_item_per_slot_without_exotics() {
+    let mut items = full_legendary_set();
+    items.push((200, KINETIC_WEAPONS_BUCKET_HASH, LEGENDARY, Some(1808)));
+    items.push((201, KINETIC_WEAPONS_BUCKET_HASH, LEGENDARY, None));
+    let (defs, instances) = account(&items);
+
+    let result = max_power(&borrowed(&instances), &defs);
+    assert_eq!(result.per_slot.len(), 8);
+    assert_eq!(result.per_slot[&KINETIC_WEAPONS_BUCKET_HASH], (200, 1808));
+    assert_eq!(result.average, 1801.0);
+    assert!(!result.limited_by_exotics);
+}
+
+#[test]
+fn two_armor_exotics_pick_the_better_combination_not_the_higher_exotic() {
+    let mut items = full_legendary_set();
+    // Greedy keeps the 1810 helmet and falls back to the chest's 1750 legendary: 3560 across the two slots.
+    // Keeping the 1805 chest and the 1790 legendary helmet is worth more: 3595.
+    items.retain(|(_, bucket, _, _)| *bucket != HELMET_BUCKET_HASH && *bucket != CHEST_ARMOR_BUCKET_HASH);
+    items.push((300, HELMET_BUCKET_HASH, EXOTIC, Some(1810)));
+    items.push((301, HELMET_BUCKET_HASH, LEGENDARY, Some(1790)));
+    items.push((302, CHEST_ARMOR_BUCKET_HASH, EXOTIC, Some(1805)));
+    items.push((303, CHEST_ARMOR_BUCKET_HASH, LEGENDARY, Some(1750)));
+    let (defs, instances) = account(&items);
+
+    let result = max_power(&borrowed(&instances), &defs);
+    assert_eq!(result.per_slot[&HELMET_BUCKET_HASH], (301, 1790));
+    assert_eq!(result.per_slot[&CHEST_ARMOR_BUCKET_HASH], (302, 1805));
+    assert_eq!(result.average, (6.0 * 1800.0 + 1790.0 + 1805.0) / 8.0);
+    assert!(result.limited_by_exotics);
+}
+
+#[test]
+fn weapons_and_armor_each_get_an_exotic() {
+    let mut items = full_legendary_set();
+    items.push((400, POWER_WEAPONS_BUCKET_HASH, EXOTIC, Some(1805)));
+    items.push((401, LEG_ARMOR_BUCKET_HASH, EXOTIC, Some(1806)));
+    let (defs, instances) = account(&items);
+
+    let result = max_power(&borrowed(&instances), &defs);
+    assert_eq!(result.per_slot[&POWER_WEAPONS_BUCKET_HASH], (400, 1805));
+    assert_eq!(result.per_slot[&LEG_ARMOR_BUCKET_HASH], (401, 1806));
+    assert!(!result.limited_by_exotics, "one exotic per group is allowed");
+}
+
+#[test]
+fn ties_are_broken_by_item_hash_whatever_the_order() {
+    let mut items = full_legendary_set();
+    items.push((90, CLASS_ARMOR_BUCKET_HASH, LEGENDARY, Some(1800)));
+    // Two exotic weapons worth the same: neither beats the legendaries, so no exotic is chosen.
+    items.push((500, KINETIC_WEAPONS_BUCKET_HASH, EXOTIC, Some(1800)));
+    items.push((501, ENERGY_WEAPONS_BUCKET_HASH, EXOTIC, Some(1800)));
+    let (defs, instances) = account(&items);
+
+    let forwards = max_power(&borrowed(&instances), &defs);
+    let mut reversed_instances = borrowed(&instances);
+    reversed_instances.reverse();
+    let backwards = max_power(&reversed_instances, &defs);
+    assert_eq!(forwards, backwards);
+    assert_eq!(forwards.per_slot[&CLASS_ARMOR_BUCKET_HASH], (90, 1800));
+    assert_eq!(forwards.per_slot[&KINETIC_WEAPONS_BUCKET_HASH], (100, 1800));
+    assert_eq!(forwards.per_slot[&ENERGY_WEAPONS_BUCKET_HASH], (101, 1800));
+}
+
+#[test]
+fn slots_only_exotics_can_fill_stay_partly_empty() {
+    let (defs, instances) = account(&[
+        (600, HELMET_BUCKET_HASH, EXOTIC, Some(1700)),
+        (601, GAUNTLETS_BUCKET_HASH, EXOTIC, Some(1750)),
+        (602, KINETIC_WEAPONS_BUCKET_HASH, LEGENDARY, None),
+        (603, 3284755031, LEGENDARY, Some(1800)),
+    ]);
+
+    let result = max_power(&borrowed(&instances), &defs);
+    assert_eq!(result.per_slot, HashMap::from([(GAUNTLETS_BUCKET_HASH, (601, 1750))]));
+    assert_eq!(result.average, 1750.0 / 8.0);
+    assert!(result.limited_by_exotics);
+    assert_eq!(max_power(&[], &defs).average, 0.0);
+}
+
+#[test]
+fn empty_slots_count_against_the_average() {
+    let mut items = full_legendary_set();
+    items.retain(|(_, bucket, _, _)| *bucket != CLASS_ARMOR_BUCKET_HASH);
+    let (defs, instances) = account(&items);
+
+    let result = max_power(&borrowed(&instances), &defs);
+    assert_eq!(result.per_slot.len(), 7);
+    assert!(!result.per_slot.contains_key(&CLASS_ARMOR_BUCKET_HASH));
+    assert_eq!(result.average, 7.0 * 1800.0 / 8.0);
+}
@@ -0,0 +1,67 @@
+﻿use rustgie::types::destiny::entities::inventory::{
+    DestinyInventoryComponent, ItemSource, MergedInventory, LOST_ITEMS_BUCKET_HASH, VAULT_BUCKET_HASH,
+};
+use serde_json::json;
+
+const KINETIC_BUCKET_HASH: u32 = 1498876634;
+const CONSUMABLES_BUCKET_HASH: u32 = 1469714392;
+const ACE_OF_SPADES: u32 = 347366834;
+const ENHANCEMENT_CORE: u32 = 3853748946;
+
+fn inventory(items: &[(u32, u32)]) -> DestinyInventoryComponent {
+    let items: Vec<_> = items
+        .iter()
+        .map(|(item_hash, bucket_hash)| {
+            json!({
+                "itemHash": item_hash,
+                "quantity": 1,
+                "bindStatus": 0,
+                "location": 1,
+                "bucketHash": bucket_hash,
+                "transferStatus": 0,
+                "lockable": false,
+                "state": 0,
+                "isWrapper": false
+            })
+        })
+        .collect();
+    serde_json::from_value(json!({ "items": items })).expect("valid inventory component")
+}
+
+#[test]
+fn same_item_hash_in_several_sources() {
+    let hunter = inventory(&[(ACE_OF_SPADES, KINETIC_BUCKET_HASH), (ACE_OF_SPADES, LOST_ITEMS_BUCKET_HASH)]);
+    let titan = inventory(&[(ACE_OF_SPADES, KINETIC_BUCKET_HASH)]);
+    let profile = inventory(&[(ACE_OF_SPADES, VAULT_BUCKET_HASH), (ENHANCEMENT_CORE, CONSUMABLES_BUCKET_HASH)]);
+
+    let merged: MergedInventory = [
+        (ItemSource::Character(1), &hunter),
+        (ItemSource::Character(2), &titan),
+        (ItemSource::ProfileWide, &profile),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(merged.len(), 5);
+    let sources: Vec<_> = merged.with_item_hash(ACE_OF_SPADES).map(|(source, _)| *source).collect();
+    assert_eq!(
+        sources,
+        [ItemSource::Character(1), ItemSource::Postmaster(1), ItemSource::Character(2), ItemSource::Vault]
+    );
+    assert_eq!(merged.in_bucket(KINETIC_BUCKET_HASH).count(), 2);
+    assert_eq!(
+        merged.with_item_hash(ENHANCEMENT_CORE).map(|(source, _)| *source).collect::<Vec<_>>(),
+        [ItemSource::ProfileWide]
+    );
+}
+
+#[test]
+fn lost_items_bucket_is_postmaster() {
+    let hunter = inventory(&[(ACE_OF_SPADES, LOST_ITEMS_BUCKET_HASH), (ENHANCEMENT_CORE, LOST_ITEMS_BUCKET_HASH)]);
+
+    let mut merged = MergedInventory::new();
+    merged.add(ItemSource::Character(7), &hunter);
+
+    assert_eq!(merged.from_source(ItemSource::Postmaster(7)).count(), 2);
+    assert_eq!(merged.from_source(ItemSource::Character(7)).count(), 0);
+}
@@ -0,0 +1,121 @@
+﻿use rustgie::types::destiny::components::metrics::DestinyMetricsComponent;
+use rustgie::types::destiny::definitions::metrics::{format_metric, top_metrics, DestinyMetricDefinition, FormattedMetric};
+use rustgie::types::destiny::definitions::DestinyObjectiveDefinition;
+use rustgie::types::destiny::DestinyUnlockValueUIStyle;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const RAIDS_NODE: u32 = 1074663644;
+const VAULT_OF_GLASS_SPEEDRUN: u32 = 1765255052;
+const KINGS_FALL_SPEEDRUN: u32 = 3906236573;
+const GRANDMASTER_NIGHTFALLS: u32 = 2506768050;
+const NIGHTFALLS_COMPLETED: u32 = 871184140;
+
+struct Fixture {
+    metrics: HashMap<u32, DestinyMetricDefinition>,
+    objectives: HashMap<u32, DestinyObjectiveDefinition>,
+    component: DestinyMetricsComponent,
+}
+
+fn fixture() -> anyhow::Result<Fixture> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/metrics.json"))?;
+    let by_hash = |key: &str| -> anyhow::Result<HashMap<String, Value>> { Ok(serde_json::from_value(fixture[key].clone())?) };
+    Ok(Fixture {
+        metrics: by_hash("metricDefinitions")?
+            .into_iter()
+            .map(|(hash, def)| Ok((hash.parse()?, serde_json::from_value(def)?)))
+            .collect::<anyhow::Result<_>>()?,
+        objectives: by_hash("objectiveDefinitions")?
+            .into_iter()
+            .map(|(hash, def)| Ok((hash.parse()?, serde_json::from_value(def)?)))
+            .collect::<anyhow::Result<_>>()?,
+        component: serde_json::from_value(fixture["metrics"].clone())?,
+    })
+}
+
+fn formatted(fixture: &Fixture, metric_hash: u32) -> FormattedMetric<'_> {
+    let def = &fixture.metrics[&metric_hash];
+    let live = &fixture.component.metrics.as_ref().expect("metrics")[&metric_hash];
+    format_metric(live, def, fixture.objectives.get(&def.tracking_objective_hash))
+}
+
+#[test]
+fn time_style_metric() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let speedrun = formatted(&fixture, VAULT_OF_GLASS_SPEEDRUN);
+
+    assert_eq!(
+        speedrun,
+        FormattedMetric {
+            name: Some("Vault of Glass Speedrun"),
+            value_text: "1:30:32".to_string(),
+            raw_value: 5432,
+            hidden: false,
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn count_style_metric() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let grandmasters = formatted(&fixture, GRANDMASTER_NIGHTFALLS);
+    assert_eq!(grandmasters.value_text, "1,234,567");
+    assert_eq!(grandmasters.raw_value, 1234567);
+    assert!(!grandmasters.hidden);
+
+    let def = &fixture.metrics[&GRANDMASTER_NIGHTFALLS];
+    let live = &fixture.component.metrics.as_ref().expect("metrics")[&GRANDMASTER_NIGHTFALLS];
+    assert_eq!(format_metric(live, def, None).value_text, "1,234,567", "without the objective it's a plain count");
+    Ok(())
+}
+
+#[test]
+fn metrics_are_hidden_until_nonzero() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let unplayed = formatted(&fixture, KINGS_FALL_SPEEDRUN);
+
+    assert!(unplayed.hidden);
+    assert_eq!(unplayed.value_text, "0:00");
+    Ok(())
+}
+
+#[test]
+fn top_metrics_filter_by_parent_node() -> anyhow::Result<()> {
+    let fixture = fixture()?;
+
+    let all: Vec<u32> = top_metrics(&fixture.component, &fixture.metrics, None, 10).iter().map(|(def, _)| def.hash).collect();
+    assert_eq!(all, [GRANDMASTER_NIGHTFALLS, NIGHTFALLS_COMPLETED, VAULT_OF_GLASS_SPEEDRUN], "hidden and invisible metrics are left out");
+
+    let limited: Vec<u32> = top_metrics(&fixture.component, &fixture.metrics, None, 1).iter().map(|(def, _)| def.hash).collect();
+    assert_eq!(limited, [GRANDMASTER_NIGHTFALLS]);
+
+    let raids: Vec<u32> =
+        top_metrics(&fixture.component, &fixture.metrics, Some(RAIDS_NODE), 10).iter().map(|(def, _)| def.hash).collect();
+    assert_eq!(raids, [VAULT_OF_GLASS_SPEEDRUN]);
+    Ok(())
+}
+
+#[test]
+fn unlock_value_styles() {
+    let cases = [
+        (DestinyUnlockValueUIStyle::TimeDuration, 754, 0, "12:34"),
+        (DestinyUnlockValueUIStyle::TimeDuration, 3600, 0, "1:00:00"),
+        (DestinyUnlockValueUIStyle::Integer, -1234, 0, "-1,234"),
+        (DestinyUnlockValueUIStyle::Automatic, 999, 0, "999"),
+        (DestinyUnlockValueUIStyle::Fraction, 1500, 2000, "1,500/2,000"),
+        (DestinyUnlockValueUIStyle::Percentage, 51, 200, "25%"),
+        (DestinyUnlockValueUIStyle::Percentage, 51, 0, "0%"),
+        (DestinyUnlockValueUIStyle::ExplicitPercentage, 51, 0, "51%"),
+        (DestinyUnlockValueUIStyle::RawFloat, 452, 0, "4.52"),
+        (DestinyUnlockValueUIStyle::Checkbox, 1, 1, "Complete"),
+        (DestinyUnlockValueUIStyle::DateTime, 1686708000, 0, "2023-06-14 02:00:00 UTC"),
+        (DestinyUnlockValueUIStyle::Hidden, 7, 0, ""),
+    ];
+    for (style, value, completion_value, expected) in cases {
+        assert_eq!(style.format_value(value, completion_value), expected, "{style:?} {value}");
+    }
+}
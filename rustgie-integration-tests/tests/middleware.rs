@@ -0,0 +1,101 @@
+﻿use rustgie::{Middleware, MiddlewareFuture, Next, RustgieClientBuilder};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Default)]
+struct Counting {
+    requests: AtomicUsize,
+}
+
+impl Middleware for Counting {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        next.run(request)
+    }
+}
+
+/// Records its name on the way in and out, and stamps the request so later middlewares can see it ran.
+struct Named {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Named {
+    fn handle<'a>(&'a self, mut request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let seen = request.headers().get_all("X-Middleware").iter().count();
+            self.log.lock().expect("log poisoned").push(format!("{} before, saw {seen}", self.name));
+            request.headers_mut().append("X-Middleware", self.name.parse()?);
+            let response = next.run(request).await;
+            self.log.lock().expect("log poisoned").push(format!("{} after", self.name));
+            response
+        })
+    }
+}
+
+#[tokio::test]
+async fn counts_api_and_oauth_requests() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "en": "en" })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/App/OAuth/Token/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "scrubbed-access",
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "membership_id": "10000001"
+        })))
+        .mount(&server)
+        .await;
+    let counting = Arc::new(Counting::default());
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_oauth_client_id(12345)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_middleware(counting.clone())
+        .build()?;
+
+    client.get_available_locales(None).await?;
+    client.get_available_locales(None).await?;
+    client.oauth_get_auth_token_("auth-code").await?;
+
+    assert_eq!(counting.requests.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn middlewares_run_in_registration_order() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "en": "en" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_middleware(Arc::new(Named { name: "outer", log: log.clone() }))
+        .with_middleware(Arc::new(Named { name: "inner", log: log.clone() }))
+        .build()?;
+
+    client.get_available_locales(None).await?;
+
+    assert_eq!(
+        *log.lock().expect("log poisoned"),
+        ["outer before, saw 0", "inner before, saw 1", "inner after", "outer after"]
+    );
+    let requests = server.received_requests().await.expect("recording enabled");
+    let stamps: Vec<_> = requests[0].headers.get_all("X-Middleware").iter().map(|value| value.to_str().expect("ASCII")).collect();
+    assert_eq!(stamps, ["outer", "inner"]);
+    Ok(())
+}
@@ -0,0 +1,119 @@
+﻿use rustgie_types::destiny::config::DestinyManifest;
+use rustgie_types::destiny::definitions::seasons::DestinySeasonDefinition;
+use rustgie_types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie_types::minimal;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::process::Command;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// The crates `rustgie-minimal-check` pulls in when it's resolved on its own, as a build script depending on `rustgie_types` with only `minimal` would be.
+fn minimal_dependencies() -> anyhow::Result<BTreeSet<String>> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(["tree", "--offline", "--quiet", "-p", "rustgie-minimal-check", "-e", "normal", "--prefix", "none", "--format", "{p}"])
+        // Run from the workspace root; from inside a member, cargo would fold that member's features in too.
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .output()?;
+    anyhow::ensure!(output.status.success(), "cargo tree failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    Ok(String::from_utf8(output.stdout)?
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect())
+}
+
+#[test]
+fn minimal_feature_leaves_out_the_heavy_dependencies() -> anyhow::Result<()> {
+    let dependencies = minimal_dependencies()?;
+    assert!(dependencies.contains("rustgie_types"), "{dependencies:?}");
+    assert!(dependencies.contains("serde"), "{dependencies:?}");
+    for heavy in ["anyhow", "enumflags2", "serde_json", "serde_with", "time", "url"] {
+        assert!(!dependencies.contains(heavy), "{heavy} in {dependencies:?}");
+    }
+    Ok(())
+}
+
+/// Whether two leaves hold the same value. Minimal keeps dates as the strings Bungie sent, so those are compared as instants.
+fn same_leaf(minimal: &Value, full: &Value) -> bool {
+    if minimal == full {
+        return true;
+    }
+    match (minimal.as_str(), full.as_str()) {
+        (Some(minimal), Some(full)) => match (OffsetDateTime::parse(minimal, &Rfc3339), OffsetDateTime::parse(full, &Rfc3339)) {
+            (Ok(minimal), Ok(full)) => minimal == full,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks every field the minimal type kept against the same field of the full type, returning how many leaves were compared.
+fn assert_fields_match(minimal: &Value, full: &Value, path: &str) -> usize {
+    match (minimal, full) {
+        (Value::Object(minimal), Value::Object(full)) => minimal
+            .iter()
+            .map(|(key, value)| {
+                let path = format!("{path}.{key}");
+                let full = full.get(key).unwrap_or_else(|| panic!("{path} is missing from the full type"));
+                assert_fields_match(value, full, &path)
+            })
+            .sum(),
+        (Value::Array(minimal), Value::Array(full)) => {
+            assert_eq!(minimal.len(), full.len(), "{path} has a different length");
+            minimal.iter().zip(full).enumerate().map(|(index, (minimal, full))| assert_fields_match(minimal, full, &format!("{path}[{index}]"))).sum()
+        }
+        (minimal, full) => {
+            assert!(same_leaf(minimal, full), "{path}: minimal read {minimal}, full read {full}");
+            1
+        }
+    }
+}
+
+/// Deserializes each definition in a `hash -> definition` table through both the minimal and the full type and compares what minimal kept.
+fn compare_table<M: DeserializeOwned + Serialize, F: DeserializeOwned + Serialize>(json: &str) -> usize {
+    let minimal: HashMap<String, M> = serde_json::from_str(json).expect("minimal reads the fixture");
+    let full: HashMap<String, F> = serde_json::from_str(json).expect("full reads the fixture");
+    assert_eq!(minimal.len(), full.len());
+    minimal
+        .iter()
+        .map(|(hash, definition)| {
+            let minimal = serde_json::to_value(definition).expect("serializable");
+            let full = serde_json::to_value(&full[hash]).expect("serializable");
+            assert_fields_match(&minimal, &full, hash)
+        })
+        .sum()
+}
+
+#[test]
+fn minimal_item_definitions_match_the_full_ones() {
+    let compared = compare_table::<minimal::DestinyInventoryItemDefinition, DestinyInventoryItemDefinition>(include_str!(
+        "../fixtures/inventory_item_definitions.json"
+    ));
+    assert!(compared > 100, "only {compared} fields compared");
+}
+
+#[test]
+fn minimal_season_definitions_match_the_full_ones() {
+    let compared =
+        compare_table::<minimal::DestinySeasonDefinition, DestinySeasonDefinition>(include_str!("../fixtures/season_definitions.json"));
+    assert!(compared > 10, "only {compared} fields compared");
+}
+
+#[test]
+fn minimal_manifest_matches_the_full_one() {
+    let envelope: Value = serde_json::from_str(include_str!("../fixtures/bom_manifest.json").trim_start_matches('\u{feff}')).expect("valid JSON");
+    let minimal: minimal::DestinyManifest = serde_json::from_value(envelope["Response"].clone()).expect("minimal reads the manifest");
+    let full: DestinyManifest = serde_json::from_value(envelope["Response"].clone()).expect("full reads the manifest");
+
+    let compared = assert_fields_match(
+        &serde_json::to_value(minimal).expect("serializable"),
+        &serde_json::to_value(full).expect("serializable"),
+        "manifest",
+    );
+    assert!(compared > 1, "only {compared} fields compared");
+}
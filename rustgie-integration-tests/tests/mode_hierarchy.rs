@@ -0,0 +1,62 @@
+﻿use rustgie::types::destiny::definitions::mode_hierarchy::ModeTree;
+use rustgie::types::destiny::definitions::DestinyActivityModeDefinition;
+use rustgie::types::destiny::historical_stats::definitions::DestinyActivityModeType;
+use serde_json::json;
+use std::collections::HashMap;
+
+const CONTROL: u32 = 3199098680;
+const ALL_PVP: u32 = 1164760493;
+
+/// A trimmed copy of the activity mode table. Nightfall also lists a parent hash that isn't in it.
+fn definitions() -> HashMap<u32, DestinyActivityModeDefinition> {
+    serde_json::from_str(include_str!("../fixtures/activity_mode_definitions.json")).expect("valid definitions")
+}
+
+#[test]
+fn descendants_through_aggregate_modes() -> anyhow::Result<()> {
+    let tree = ModeTree::build(&definitions())?;
+
+    assert!(tree.is_descendant(DestinyActivityModeType::Control, DestinyActivityModeType::AllPvP));
+    assert!(tree.is_descendant(DestinyActivityModeType::Control, DestinyActivityModeType::None));
+    assert!(tree.is_descendant(DestinyActivityModeType::Nightfall, DestinyActivityModeType::AllPvE));
+    assert!(!tree.is_descendant(DestinyActivityModeType::Nightfall, DestinyActivityModeType::AllPvP));
+    assert!(!tree.is_descendant(DestinyActivityModeType::AllPvP, DestinyActivityModeType::Control));
+    assert!(!tree.is_descendant(DestinyActivityModeType::AllPvP, DestinyActivityModeType::AllPvP));
+    assert!(!tree.is_descendant(DestinyActivityModeType::Raid, DestinyActivityModeType::None), "modes missing from the table have no ancestors");
+    Ok(())
+}
+
+#[test]
+fn ancestors_nearest_first() -> anyhow::Result<()> {
+    let tree = ModeTree::build(&definitions())?;
+
+    assert_eq!(
+        tree.ancestors(DestinyActivityModeType::Nightfall),
+        [DestinyActivityModeType::AllStrikes, DestinyActivityModeType::AllPvE, DestinyActivityModeType::None]
+    );
+    assert_eq!(
+        tree.ancestors(DestinyActivityModeType::IronBanner),
+        [DestinyActivityModeType::AllPvP, DestinyActivityModeType::None],
+        "a grandparent also listed as a parent appears once"
+    );
+    assert!(tree.ancestors(DestinyActivityModeType::None).is_empty());
+    assert!(tree.ancestors(DestinyActivityModeType::Raid).is_empty());
+    Ok(())
+}
+
+#[test]
+fn cycles_are_an_error() {
+    let mut definitions = definitions();
+    definitions.get_mut(&ALL_PVP).expect("AllPvP").parent_hashes = Some(vec![CONTROL]);
+    assert!(ModeTree::build(&definitions).is_err());
+
+    let mut looping: DestinyActivityModeDefinition = serde_json::from_value(json!({
+        "modeType": 3, "activityModeCategory": 1, "isTeamBased": true, "isAggregateMode": false,
+        "parentHashes": [1], "display": true, "order": 0, "hash": 1, "index": 0, "redacted": false
+    }))
+    .expect("valid definition");
+    assert!(ModeTree::build(&HashMap::from([(1, looping.clone())])).is_err(), "a mode can't be its own parent");
+
+    looping.parent_hashes = None;
+    assert!(ModeTree::build(&HashMap::from([(1, looping)])).is_ok());
+}
@@ -0,0 +1,6 @@
+﻿/// Dropping an endpoint's future without awaiting it, or a builder method's result, is a compile error under `deny(unused_must_use)`.
+#[test]
+fn discarded_futures_and_builders_are_flagged() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,50 @@
+﻿use rustgie::types::api_response_::{BungieTokenResponse, OAuthScope};
+use rustgie::types::applications::ApplicationScopes;
+
+/// A confidential client's token response as captured from `/Platform/App/OAuth/token/`, with the tokens replaced.
+const CAPTURED_TOKEN_RESPONSE: &str = r#"{
+    "access_token": "CKbRBBKGAgAgx5vRfR1Zm8f+scrubbed",
+    "token_type": "Bearer",
+    "expires_in": 3600,
+    "refresh_token": "CKbRBBKGAgAgo4SlfYNp1Bs+scrubbed",
+    "refresh_expires_in": 7776000,
+    "membership_id": "10000001",
+    "scope": "ReadBasicUserProfile MoveEquipDestinyItems ReadDestinyInventoryAndVault ReadLoadouts"
+}"#;
+
+#[test]
+fn captured_token_response() {
+    let token: BungieTokenResponse = serde_json::from_str(CAPTURED_TOKEN_RESPONSE).expect("valid token response");
+    let received_at = time::macros::datetime!(2023-06-14 02:00:00 UTC);
+
+    assert_eq!(token.membership_id, Some(10000001));
+    assert_eq!(token.access_token_lifetime(), Some(time::Duration::hours(1)));
+    assert_eq!(token.refresh_token_lifetime(), Some(time::Duration::days(90)));
+    assert_eq!(token.access_expires_at(received_at), time::macros::datetime!(2023-06-14 03:00:00 UTC));
+    assert_eq!(token.refresh_expires_at(received_at), Some(time::macros::datetime!(2023-09-12 02:00:00 UTC)));
+    assert_eq!(
+        token.scopes(),
+        [
+            OAuthScope::Known(ApplicationScopes::ReadBasicUserProfile),
+            OAuthScope::Known(ApplicationScopes::MoveEquipDestinyItems),
+            OAuthScope::Known(ApplicationScopes::ReadDestinyInventoryAndVault),
+            OAuthScope::Unknown("ReadLoadouts".to_string()),
+        ]
+    );
+
+    let reserialized = serde_json::to_value(&token).expect("serializable");
+    assert_eq!(reserialized["membership_id"], "10000001");
+}
+
+#[test]
+fn public_client_token_response() {
+    let token: BungieTokenResponse = serde_json::from_str(
+        r#"{"access_token": "scrubbed", "token_type": "Bearer", "expires_in": 3600, "membership_id": "10000001"}"#,
+    )
+    .expect("valid token response");
+    let received_at = time::macros::datetime!(2023-06-14 02:00:00 UTC);
+
+    assert_eq!(token.refresh_expires_at(received_at), None);
+    assert!(token.scopes().is_empty());
+    assert!(serde_json::to_value(&token).expect("serializable").get("scope").is_none());
+}
@@ -0,0 +1,143 @@
+﻿use rustgie::RustgieClientBuilder;
+use rustgie_integration_tests::TEST_API_KEY;
+use serde_json::json;
+use std::collections::BTreeMap;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+async fn token_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/App/OAuth/Token/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "scrubbed",
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "refresh_token": "scrubbed-refresh",
+            "refresh_expires_in": 7776000,
+            "membership_id": "10000001"
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn only_request(server: &MockServer) -> Request {
+    let mut requests = server.received_requests().await.expect("request recording is enabled");
+    assert_eq!(requests.len(), 1);
+    requests.remove(0)
+}
+
+fn form(request: &Request) -> BTreeMap<String, String> {
+    std::str::from_utf8(&request.body)
+        .expect("form bodies are UTF-8")
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn pairs(expected: &[(&str, &str)]) -> BTreeMap<String, String> {
+    expected.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn builder(server: &MockServer) -> RustgieClientBuilder {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_oauth_client_id(12345)
+        .with_oauth_client_secret("s3cret")
+        .with_oauth_base_url(&server.uri())
+        .dangerously_allow_http(true)
+}
+
+#[tokio::test]
+async fn auth_code_sends_credentials_as_form_fields_by_default() -> anyhow::Result<()> {
+    let server = token_server().await;
+    let client = builder(&server).build()?;
+
+    let token = client.oauth_get_auth_token_("abc123").await?;
+    assert_eq!(token.membership_id, Some(10000001));
+
+    let request = only_request(&server).await;
+    assert!(request.headers.get("Authorization").is_none());
+    assert_eq!(
+        form(&request),
+        pairs(&[
+            ("client_id", "12345"),
+            ("client_secret", "s3cret"),
+            ("code", "abc123"),
+            ("grant_type", "authorization_code"),
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_sends_credentials_as_form_fields_by_default() -> anyhow::Result<()> {
+    let server = token_server().await;
+    let client = builder(&server).build()?;
+
+    client.oauth_refresh_auth_token_("refresh456").await?;
+
+    assert_eq!(
+        form(&only_request(&server).await),
+        pairs(&[
+            ("client_id", "12345"),
+            ("client_secret", "s3cret"),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", "refresh456"),
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn basic_auth_moves_credentials_into_the_header() -> anyhow::Result<()> {
+    let server = token_server().await;
+    let client = builder(&server).with_oauth_basic_auth(true).build()?;
+
+    client.oauth_refresh_auth_token_("refresh456").await?;
+
+    let request = only_request(&server).await;
+    assert_eq!(
+        request.headers.get("Authorization").and_then(|v| v.to_str().ok()),
+        Some("Basic MTIzNDU6czNjcmV0")
+    );
+    assert_eq!(
+        form(&request),
+        pairs(&[("grant_type", "refresh_token"), ("refresh_token", "refresh456")])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn public_client_keeps_client_id_in_the_form() -> anyhow::Result<()> {
+    let server = token_server().await;
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_oauth_client_id(12345)
+        .with_oauth_basic_auth(true)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .build()?;
+
+    client.oauth_get_auth_token_("abc123").await?;
+    assert!(client.oauth_refresh_auth_token_("refresh456").await.is_err());
+
+    let request = only_request(&server).await;
+    assert!(request.headers.get("Authorization").is_none());
+    assert_eq!(
+        form(&request),
+        pairs(&[("client_id", "12345"), ("code", "abc123"), ("grant_type", "authorization_code")])
+    );
+    Ok(())
+}
+
+#[test]
+fn plain_http_base_urls_need_the_test_override() {
+    let plain = || RustgieClientBuilder::new().with_api_key(TEST_API_KEY).with_base_url("http://127.0.0.1:8080");
+    assert!(plain().build().is_err());
+    assert!(plain().dangerously_allow_http(true).build().is_ok());
+    assert!(RustgieClientBuilder::new().with_api_key(TEST_API_KEY).with_oauth_base_url("http://127.0.0.1:8080").build().is_err());
+    assert!(RustgieClientBuilder::new().with_api_key(TEST_API_KEY).with_base_url("https://proxy.example").build().is_ok());
+}
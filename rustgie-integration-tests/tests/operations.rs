@@ -0,0 +1,127 @@
+﻿use reqwest::Method;
+use rustgie::{match_url, BungieHost, OPERATIONS};
+use std::collections::{HashMap, HashSet};
+
+fn matched(method: Method, url: &str) -> Option<(&'static str, HashMap<&'static str, String>)> {
+    match_url(&method, url).map(|(operation, parameters)| (operation.id, parameters))
+}
+
+fn parameters(pairs: &[(&'static str, &str)]) -> HashMap<&'static str, String> {
+    pairs.iter().map(|(name, value)| (*name, value.to_string())).collect()
+}
+
+/// A template with its parameter names blanked out, e.g. `/GroupV2/{}/Members/`.
+fn shape(template: &str) -> String {
+    template
+        .split('/')
+        .map(|segment| if segment.starts_with('{') { "{}" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[test]
+fn matches_full_urls_with_query_strings() {
+    let profile = parameters(&[("membershipType", "3"), ("destinyMembershipId", "4611686018467284386")]);
+    assert_eq!(
+        matched(Method::GET, "https://www.bungie.net/Platform/Destiny2/3/Profile/4611686018467284386/?components=100,200"),
+        Some(("Destiny2.GetProfile", profile.clone()))
+    );
+    assert_eq!(
+        matched(Method::GET, "/Platform/Destiny2/3/Profile/4611686018467284386?components=100#fragment"),
+        Some(("Destiny2.GetProfile", profile))
+    );
+    assert_eq!(
+        matched(Method::GET, "/Platform/Destiny2/Manifest/?next=https://x").map(|m| m.0),
+        Some("Destiny2.GetDestinyManifest"),
+        "a URL in the query isn't the scheme"
+    );
+    assert_eq!(
+        matched(Method::GET, "https://www.bungie.net/Platform/Destiny2/Manifest/#https://x/GroupV2/4027654/Members/").map(|m| m.0),
+        Some("Destiny2.GetDestinyManifest")
+    );
+}
+
+#[test]
+fn tolerates_missing_prefix_slashes_and_case() {
+    let expected = Some(("GroupV2.GetMembersOfGroup", parameters(&[("groupId", "4027654")])));
+    for url in [
+        "/Platform/GroupV2/4027654/Members/",
+        "/Platform/GroupV2/4027654/Members",
+        "/GroupV2/4027654/Members/",
+        "//platform//groupv2/4027654/members/",
+    ] {
+        assert_eq!(matched(Method::GET, url), expected, "{url}");
+    }
+}
+
+#[test]
+fn prefers_literal_segments_but_backtracks_to_parameters() {
+    assert_eq!(matched(Method::GET, "/Platform/Destiny2/Manifest/").map(|m| m.0), Some("Destiny2.GetDestinyManifest"));
+    assert_eq!(
+        matched(Method::GET, "/Platform/Destiny2/Stats/Definition/").map(|m| m.0),
+        Some("Destiny2.GetHistoricalStatsDefinition")
+    );
+    assert_eq!(
+        matched(Method::GET, "/Platform/Destiny2/Manifest/DestinyInventoryItemDefinition/1363886209/"),
+        Some((
+            "Destiny2.GetDestinyEntityDefinition",
+            parameters(&[("entityType", "DestinyInventoryItemDefinition"), ("hashIdentifier", "1363886209")])
+        ))
+    );
+    // `Application` is a literal in one history template and a parameter value in the other.
+    assert_eq!(
+        matched(Method::GET, "/Platform/Tokens/Partner/History/20/Application/").map(|m| m.0),
+        Some("Tokens.GetPartnerOfferSkuHistory")
+    );
+    assert_eq!(
+        matched(Method::GET, "/Platform/Tokens/Partner/History/20/Application/7/"),
+        Some((
+            "Tokens.GetPartnerRewardHistory",
+            parameters(&[("targetBnetMembershipId", "20"), ("partnerApplicationId", "7")])
+        ))
+    );
+}
+
+#[test]
+fn matches_stats_host_and_post_endpoints() {
+    let (operation, parameters) =
+        match_url(&Method::GET, "https://stats.bungie.net/Platform/Destiny2/Stats/PostGameCarnageReport/12685770593/").unwrap();
+    assert_eq!(operation.id, "Destiny2.GetPostGameCarnageReport");
+    assert_eq!(operation.host, BungieHost::Stats);
+    assert_eq!(parameters["activityId"], "12685770593");
+
+    assert_eq!(
+        matched(Method::POST, "https://www.bungie.net/Platform/Destiny2/Actions/Items/TransferItem/").map(|m| m.0),
+        Some("Destiny2.TransferItem")
+    );
+}
+
+#[test]
+fn rejects_unknown_paths_and_methods() {
+    assert_eq!(matched(Method::POST, "/Platform/Destiny2/3/Profile/4611686018467284386/"), None);
+    assert_eq!(matched(Method::GET, "/Platform/Destiny2/3/Profile/"), None);
+    assert_eq!(matched(Method::GET, "/Platform/Destiny2/3/Profile/4611686018467284386/Extra/Segments/"), None);
+    assert_eq!(matched(Method::GET, "https://www.bungie.net/"), None);
+    assert_eq!(matched(Method::GET, ""), None);
+}
+
+#[test]
+fn every_endpoint_has_an_operation() {
+    let source = include_str!("../../rustgie/src/endpoints.rs");
+    let endpoint_paths: HashSet<String> = source
+        .split("\"https://")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .filter_map(|url| url.split_once("/Platform").map(|(_, path)| shape(path)))
+        .collect();
+    let operation_paths: HashSet<String> = OPERATIONS.iter().map(|operation| shape(operation.path_template)).collect();
+
+    assert_eq!(source.matches("pub async fn ").count(), OPERATIONS.len());
+    assert_eq!(endpoint_paths, operation_paths);
+
+    let ids: HashSet<&str> = OPERATIONS.iter().map(|operation| operation.id).collect();
+    assert_eq!(ids.len(), OPERATIONS.len());
+    for operation in OPERATIONS {
+        assert_eq!(match_url(&operation.method, operation.path_template).map(|m| m.0), Some(operation), "{}", operation.id);
+    }
+}
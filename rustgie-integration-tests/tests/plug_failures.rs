@@ -0,0 +1,58 @@
+﻿use rustgie::types::destiny::definitions::items::DestinyItemPlugDefinition;
+use rustgie::types::destiny::sockets::{PlugState, UNKNOWN_PLUG_FAILURE};
+use rustgie::types::destiny::DestinyComponentType;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+use serde_json::{json, Value};
+
+fn plug_definition(insertion_rules: Value, enabled_rules: Value) -> DestinyItemPlugDefinition {
+    serde_json::from_value(json!({
+        "insertionRules": insertion_rules,
+        "plugCategoryIdentifier": "v400.weapon.mod_damage",
+        "plugCategoryHash": 2947756142u32,
+        "onActionRecreateSelf": false,
+        "insertionMaterialRequirementHash": 0,
+        "previewItemOverrideHash": 0,
+        "enabledMaterialRequirementHash": 0,
+        "enabledRules": enabled_rules,
+        "plugStyle": 0,
+        "plugAvailability": 0,
+        "alternatePlugStyle": 0,
+        "isDummyPlug": false
+    }))
+    .expect("valid plug definition")
+}
+
+#[tokio::test]
+async fn weapon_with_unmet_mod_requirement() -> anyhow::Result<()> {
+    let (_server, client) = replay("item_reusable_plugs_unmet").await?;
+    let item = client
+        .destiny2_get_item(
+            4611686018400000001,
+            6917529876543210002,
+            BungieMembershipType::TigerSteam,
+            Some(vec![DestinyComponentType::ItemReusablePlugs]),
+            None,
+        )
+        .await?;
+    let plugs = item.reusable_plugs.and_then(|c| c.data).and_then(|d| d.plugs).expect("reusable plugs");
+
+    let adept_mod = plug_definition(
+        json!([{ "failureMessage": "Requires an Adept weapon" }, { "failureMessage": "Requires a Masterworked weapon" }]),
+        json!([{ "failureMessage": "Disabled in this activity" }]),
+    );
+    let backup_mag = &plugs[&3][0];
+    assert!(backup_mag.can_insert() && backup_mag.is_enabled());
+    assert!(backup_mag.insert_failures(&adept_mod).is_empty());
+
+    let adept_big_ones = &plugs[&3][1];
+    assert!(!adept_big_ones.can_insert());
+    assert!(!adept_big_ones.is_enabled());
+    assert_eq!(adept_big_ones.insert_failures(&adept_mod), ["Requires an Adept weapon", UNKNOWN_PLUG_FAILURE]);
+    assert_eq!(adept_big_ones.enable_failures(&adept_mod), ["Disabled in this activity"], "enable indexes use enabledRules");
+
+    let without_rules = plug_definition(Value::Null, json!([{}, {}]));
+    assert_eq!(adept_big_ones.insert_failures(&without_rules), [UNKNOWN_PLUG_FAILURE, UNKNOWN_PLUG_FAILURE]);
+    assert_eq!(plugs[&4][0].enable_failures(&without_rules), [UNKNOWN_PLUG_FAILURE], "a rule with no message");
+    Ok(())
+}
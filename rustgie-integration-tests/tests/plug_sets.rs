@@ -0,0 +1,72 @@
+﻿use rustgie::types::destiny::components::plug_sets::{all_unlocked_plug_hashes, available_plugs, DestinyPlugSetsComponent};
+use rustgie::types::destiny::responses::DestinyProfileResponse;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+const ARMOR_MODS: u32 = 1081029832;
+const CHARACTER_ID: i64 = 2305843009300000001;
+const CONFLICTING: u32 = 2979161761;
+
+fn profile() -> anyhow::Result<DestinyProfileResponse> {
+    Ok(serde_json::from_str(include_str!("../fixtures/plug_sets.json"))?)
+}
+
+fn scopes(profile: &DestinyProfileResponse) -> (Option<&DestinyPlugSetsComponent>, Option<&DestinyPlugSetsComponent>) {
+    (
+        profile.profile_plug_sets.as_ref().and_then(|p| p.data.as_ref()),
+        profile.character_plug_sets.as_ref().and_then(|c| c.data.as_ref()).and_then(|c| c.get(&CHARACTER_ID)),
+    )
+}
+
+#[test]
+fn merges_scopes_without_duplicates() -> anyhow::Result<()> {
+    let profile = profile()?;
+    let (profile_scope, character_scope) = scopes(&profile);
+
+    let plugs = available_plugs(ARMOR_MODS, profile_scope, character_scope);
+
+    let hashes: Vec<u32> = plugs.iter().map(|p| p.plug_item_hash).collect();
+    assert_eq!(hashes, [3192552688, CONFLICTING, 1484685884, 4048838440], "profile plugs first, each plug once");
+    assert!(plugs.iter().all(|p| p.insert_fail_indexes.is_some() && p.enable_fail_indexes.is_some()));
+    assert!(matches!(plugs[0], Cow::Borrowed(_)));
+    Ok(())
+}
+
+#[test]
+fn conflicting_scopes_are_combined_conservatively() -> anyhow::Result<()> {
+    let profile = profile()?;
+    let (profile_scope, character_scope) = scopes(&profile);
+
+    let plugs = available_plugs(ARMOR_MODS, profile_scope, character_scope);
+    let conflicting = plugs.iter().find(|p| p.plug_item_hash == CONFLICTING).expect("listed in both scopes");
+
+    assert!(!conflicting.can_insert, "the character scope can't insert it");
+    assert!(conflicting.enabled);
+    assert_eq!(conflicting.insert_fail_indexes.as_deref(), Some(&[1][..]));
+    assert!(matches!(conflicting, Cow::Owned(_)));
+
+    let profile_only = available_plugs(ARMOR_MODS, profile_scope, None);
+    assert!(profile_only.iter().find(|p| p.plug_item_hash == CONFLICTING).is_some_and(|p| p.can_insert));
+    Ok(())
+}
+
+#[test]
+fn response_helper_picks_the_character() -> anyhow::Result<()> {
+    let profile = profile()?;
+
+    assert_eq!(profile.available_plugs(ARMOR_MODS, CHARACTER_ID).len(), 4);
+    assert_eq!(profile.available_plugs(ARMOR_MODS, 2305843009300000002).len(), 3, "other characters only see profile plugs");
+    assert!(profile.available_plugs(1, CHARACTER_ID).is_empty());
+    Ok(())
+}
+
+#[test]
+fn unlocked_plug_hashes() -> anyhow::Result<()> {
+    let profile = profile()?;
+    let (profile_scope, character_scope) = scopes(&profile);
+
+    let unlocked = all_unlocked_plug_hashes(profile_scope, character_scope);
+
+    assert_eq!(unlocked, HashSet::from([3192552688, 4048838440, 3906243079]));
+    Ok(())
+}
@@ -0,0 +1,107 @@
+﻿use rustgie::types::destiny::definitions::sockets::{plug_allowed, sockets_accepting, DestinySocketTypeDefinition};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const SUNSHOT: &str = "2907129557";
+const WEAPON_MOD_SOCKET: u32 = 1007;
+const PERK_SOCKET: u32 = 1003;
+const TRACKER_SOCKET: u32 = 1008;
+
+fn socket_types() -> HashMap<u32, DestinySocketTypeDefinition> {
+    serde_json::from_str(include_str!("../fixtures/socket_types.json")).expect("valid socket type definitions")
+}
+
+fn item_fixture(hash: &str) -> Value {
+    let definitions: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    definitions[hash].clone()
+}
+
+/// Sunshot, with the socket entries from the socket fixture.
+fn weapon() -> DestinyInventoryItemDefinition {
+    let sockets: Value = serde_json::from_str(include_str!("../fixtures/socket_categories.json")).expect("valid JSON");
+    let mut item = item_fixture(SUNSHOT);
+    item["sockets"] = sockets["sunshot"].clone();
+    serde_json::from_value(item).expect("valid item definition")
+}
+
+/// An item with the plug block `plug`, built on the weapon's definition. A `plug` of `Value::Null` gives an item that isn't a plug.
+fn plug(name: &str, plug: Value) -> DestinyInventoryItemDefinition {
+    let mut item = item_fixture(SUNSHOT);
+    item["displayProperties"]["name"] = json!(name);
+    item["sockets"] = Value::Null;
+    item["plug"] = plug;
+    serde_json::from_value(item).expect("valid item definition")
+}
+
+fn plug_block(identifier: &str, category_hash: u32) -> Value {
+    json!({
+        "plugCategoryIdentifier": identifier,
+        "plugCategoryHash": category_hash,
+        "onActionRecreateSelf": false,
+        "insertionMaterialRequirementHash": 0,
+        "previewItemOverrideHash": 0,
+        "enabledMaterialRequirementHash": 0,
+        "plugStyle": 0,
+        "plugAvailability": 0,
+        "alternatePlugStyle": 0,
+        "isDummyPlug": false
+    })
+}
+
+#[test]
+fn whitelist_entries_are_fully_deserialized() {
+    let socket_types = socket_types();
+    let whitelist = socket_types[&TRACKER_SOCKET].plug_whitelist.as_deref().expect("whitelist");
+    assert_eq!(whitelist.len(), 2);
+    assert_eq!(whitelist[1].category_hash, 2109207426);
+    assert_eq!(whitelist[1].category_identifier.as_deref(), Some("v400.plugs.weapons.masterworks.trackers"));
+    assert_eq!(whitelist[1].reinitialization_possible_plug_hashes.as_deref(), Some(&[38912240, 2240097604][..]));
+}
+
+#[test]
+fn weapon_mod_fits_the_mod_socket_but_not_a_perk_socket() {
+    let socket_types = socket_types();
+    let backup_mag = plug("Backup Mag", plug_block("v400.weapon.mod_guns", 2947756142));
+
+    assert!(plug_allowed(&socket_types[&WEAPON_MOD_SOCKET], &backup_mag));
+    assert!(!plug_allowed(&socket_types[&PERK_SOCKET], &backup_mag));
+    assert_eq!(sockets_accepting(&backup_mag, &weapon(), &socket_types), [7]);
+}
+
+#[test]
+fn perks_fit_every_socket_whitelisting_their_category() {
+    let socket_types = socket_types();
+    let perk = plug("Incandescent", plug_block("frames", 7906839));
+
+    assert!(!plug_allowed(&socket_types[&WEAPON_MOD_SOCKET], &perk));
+    assert_eq!(sockets_accepting(&perk, &weapon(), &socket_types), [3, 4, 8]);
+}
+
+#[test]
+fn empty_or_missing_whitelists_accept_nothing_and_non_plugs_are_never_allowed() {
+    let mut socket_types = socket_types();
+    let backup_mag = plug("Backup Mag", plug_block("v400.weapon.mod_guns", 2947756142));
+    let not_a_plug = plug("Sunshot", Value::Null);
+
+    assert!(!plug_allowed(&socket_types[&WEAPON_MOD_SOCKET], &not_a_plug));
+    assert!(sockets_accepting(&not_a_plug, &weapon(), &socket_types).is_empty());
+
+    socket_types.get_mut(&WEAPON_MOD_SOCKET).expect("weapon mod socket").plug_whitelist = Some(Vec::new());
+    assert!(!plug_allowed(&socket_types[&WEAPON_MOD_SOCKET], &backup_mag));
+    assert!(sockets_accepting(&backup_mag, &weapon(), &socket_types).is_empty());
+
+    socket_types.get_mut(&WEAPON_MOD_SOCKET).expect("weapon mod socket").plug_whitelist = None;
+    assert!(!plug_allowed(&socket_types[&WEAPON_MOD_SOCKET], &backup_mag));
+    assert!(sockets_accepting(&backup_mag, &weapon(), &socket_types).is_empty());
+}
+
+#[test]
+fn unknown_socket_types_are_skipped() {
+    let mut socket_types = socket_types();
+    let perk = plug("Incandescent", plug_block("frames", 7906839));
+
+    socket_types.remove(&PERK_SOCKET);
+    assert_eq!(sockets_accepting(&perk, &weapon(), &socket_types), [4, 8]);
+}
@@ -0,0 +1,75 @@
+﻿use rustgie::types::components::ComponentPrivacySetting;
+use rustgie::types::destiny::responses::ProfileAccessState;
+use rustgie::types::destiny::DestinyComponentType;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+
+const MEMBERSHIP_ID: i64 = 4611686018400000002;
+
+#[tokio::test]
+async fn fully_private_account() -> anyhow::Result<()> {
+    let (_server, client) = replay("profile_private").await?;
+    let profile = client
+        .destiny2_get_profile(
+            MEMBERSHIP_ID,
+            BungieMembershipType::TigerSteam,
+            Some(vec![
+                DestinyComponentType::ProfileInventories,
+                DestinyComponentType::CharacterInventories,
+                DestinyComponentType::CharacterEquipment,
+            ]),
+            None,
+        )
+        .await?;
+
+    assert_eq!(profile.access_state(), ProfileAccessState::Private);
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_without_characters() -> anyhow::Result<()> {
+    let (_server, client) = replay("profile_no_characters").await?;
+    let profile = client
+        .destiny2_get_profile(
+            MEMBERSHIP_ID,
+            BungieMembershipType::TigerSteam,
+            Some(vec![DestinyComponentType::Profiles, DestinyComponentType::Characters]),
+            None,
+        )
+        .await?;
+
+    assert_eq!(profile.access_state(), ProfileAccessState::NoCharacters);
+    Ok(())
+}
+
+#[tokio::test]
+async fn public_profile_with_private_characters() -> anyhow::Result<()> {
+    let (_server, client) = replay("player_profile_item").await?;
+    let mut profile = client
+        .destiny2_get_profile(
+            4611686018400000001,
+            BungieMembershipType::TigerSteam,
+            Some(vec![
+                DestinyComponentType::Profiles,
+                DestinyComponentType::Characters,
+                DestinyComponentType::CharacterEquipment,
+            ]),
+            None,
+        )
+        .await?;
+    assert_eq!(profile.access_state(), ProfileAccessState::Ok);
+
+    let equipment = profile.character_equipment.as_mut().expect("characterEquipment component");
+    equipment.privacy = ComponentPrivacySetting::Private;
+    equipment.data = None;
+
+    assert_eq!(
+        profile.access_state(),
+        ProfileAccessState::Mixed(vec![
+            (DestinyComponentType::Profiles, ComponentPrivacySetting::Public),
+            (DestinyComponentType::Characters, ComponentPrivacySetting::Public),
+            (DestinyComponentType::CharacterEquipment, ComponentPrivacySetting::Private),
+        ])
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+﻿use rustgie::types::destiny::responses::{
+    account_power, total_playtime, triumph_score, AccountPower, DestinyProfileResponse, MissingComponents, ProfileSummary,
+    TriumphScore,
+};
+use rustgie::types::destiny::DestinyComponentType;
+
+fn profile() -> anyhow::Result<DestinyProfileResponse> {
+    Ok(serde_json::from_str(include_str!("../fixtures/profile_summary.json"))?)
+}
+
+#[test]
+fn summarizes_the_landing_page_numbers() -> anyhow::Result<()> {
+    let summary = ProfileSummary::try_from(&profile()?)?;
+
+    assert_eq!(summary.triumph_score, TriumphScore { active: 30158, lifetime: 178032 });
+    assert_eq!(summary.power, Some(AccountPower { base: 1813, artifact_bonus: 18, total: 1831 }));
+    assert_eq!(summary.playtime, time::Duration::minutes(61234 + 20510 + 3012));
+    assert_eq!(summary.playtime.whole_hours(), 1412);
+    Ok(())
+}
+
+#[test]
+fn standalone_computations() -> anyhow::Result<()> {
+    let profile = profile()?;
+    let characters = profile.characters.as_ref().and_then(|c| c.data.as_ref()).expect("characters");
+    let records = profile.profile_records.as_ref().and_then(|r| r.data.as_ref()).expect("records");
+
+    assert_eq!(triumph_score(records).lifetime, 178032);
+    assert_eq!(account_power(characters.values(), None), Some(AccountPower { base: 1831, artifact_bonus: 0, total: 1831 }));
+    assert_eq!(account_power([], None), None);
+    assert_eq!(total_playtime(characters.values().take(0)), time::Duration::ZERO);
+    Ok(())
+}
+
+#[test]
+fn lists_every_missing_component() -> anyhow::Result<()> {
+    let mut profile = profile()?;
+    profile.profile_records = None;
+    profile.profile_progression.as_mut().expect("progression").data = None;
+
+    let error = ProfileSummary::try_from(&profile).expect_err("components are missing");
+
+    assert_eq!(error, MissingComponents(vec![DestinyComponentType::Records, DestinyComponentType::ProfileProgression]));
+    assert_eq!(error.to_string(), "The profile response is missing the components Records, ProfileProgression");
+    Ok(())
+}
@@ -0,0 +1,84 @@
+﻿use rustgie::types::destiny::responses::DestinyProfileResponse;
+use rustgie::types::destiny::DestinyComponentType;
+use rustgie_integration_tests::{envelope_response, Session};
+use serde_json::json;
+
+const CHARACTER_ID: i64 = 2305843009200000001;
+
+/// The recorded profile, widened with records and item components so there is something to trim.
+fn full_profile() -> DestinyProfileResponse {
+    let session = Session::load("player_profile_item").expect("session fixture");
+    let mut profile = envelope_response(&session.interactions[1].body).expect("profile envelope").clone();
+
+    profile["profileRecords"] = json!({
+        "data": {
+            "score": 41250, "activeScore": 12840, "legacyScore": 28410, "lifetimeScore": 41250,
+            "records": { "3457216609": { "state": 67, "intervalsRedeemedCount": 0 } },
+            "recordCategoriesRootNodeHash": 3790247699u32, "recordSealsRootNodeHash": 616318467
+        },
+        "privacy": 1
+    });
+    profile["characterRecords"] = json!({
+        "data": {
+            CHARACTER_ID.to_string(): {
+                "featuredRecordHashes": [],
+                "records": {},
+                "recordCategoriesRootNodeHash": 3790247699u32, "recordSealsRootNodeHash": 616318467
+            }
+        },
+        "privacy": 1
+    });
+    profile["itemComponents"] = json!({
+        "instances": {
+            "data": {
+                "6917529876543210001": {
+                    "damageType": 1, "itemLevel": 181, "quality": 0, "isEquipped": true, "canEquip": true,
+                    "equipRequiredLevel": 0, "cannotEquipReason": 0
+                }
+            },
+            "privacy": 1
+        },
+        "objectives": { "data": {}, "privacy": 1 }
+    });
+    profile["characterUninstancedItemComponents"] = json!({
+        CHARACTER_ID.to_string(): { "objectives": { "data": {}, "privacy": 1 } }
+    });
+
+    serde_json::from_value(profile).expect("widened profile still parses")
+}
+
+#[test]
+fn retaining_characters_drops_everything_else() {
+    let mut profile = full_profile();
+    let before = profile.estimated_size();
+
+    profile.retain_components(&[DestinyComponentType::Characters]);
+
+    assert!(profile.item_components.is_none());
+    assert!(profile.character_uninstanced_item_components.is_none());
+    assert!(profile.profile_records.is_none());
+    assert!(profile.character_records.is_none());
+    assert!(profile.profile.is_none());
+    assert!(profile.character_equipment.is_none());
+
+    let characters = profile.characters.as_ref().and_then(|c| c.data.as_ref()).expect("characters survive");
+    assert_eq!(characters.len(), 2);
+    assert_eq!(characters[&CHARACTER_ID].light, 1806);
+
+    assert!(profile.estimated_size() < before);
+}
+
+#[test]
+fn retaining_one_item_component_keeps_the_item_set() {
+    let mut profile = full_profile();
+
+    profile.retain_components(&[DestinyComponentType::ItemInstances, DestinyComponentType::Records]);
+
+    let items = profile.item_components.as_ref().expect("item set survives");
+    assert!(items.instances.is_some());
+    assert!(items.objectives.is_none());
+    assert!(profile.character_uninstanced_item_components.is_none());
+    assert!(profile.profile_records.is_some());
+    assert!(profile.character_records.is_some());
+    assert!(profile.characters.is_none());
+}
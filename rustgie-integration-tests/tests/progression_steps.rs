@@ -0,0 +1,72 @@
+﻿use rustgie::types::destiny::definitions::progression::{current_step, next_rewards, percent_to_next, weekly_progress_remaining};
+use rustgie::types::destiny::definitions::DestinyProgressionDefinition;
+use rustgie::types::destiny::DestinyProgression;
+use serde_json::json;
+
+fn definition(repeat_last_step: bool) -> DestinyProgressionDefinition {
+    let step = |name: &str, reward: u32| {
+        json!({
+            "stepName": name, "displayEffectType": 0, "progressTotal": 1000, "icon": format!("/{name}.png"),
+            "rewardItems": [{ "itemHash": reward, "quantity": 1, "hasConditionalVisibility": false }]
+        })
+    };
+    serde_json::from_value(json!({
+        "displayProperties": { "displayUnitsName": "Valor", "name": "Valor", "hasIcon": false },
+        "scope": 1,
+        "repeatLastStep": repeat_last_step,
+        "steps": [step("Guardian", 101), step("Brave", 102), step("Heroic", 103)],
+        "visible": true,
+        "hash": 2083746873_u32, "index": 0, "redacted": false
+    }))
+    .expect("valid definition")
+}
+
+fn live(level: i32, step_index: i32, progress_to_next_level: i32, next_level_at: i32) -> DestinyProgression {
+    serde_json::from_value(json!({
+        "progressionHash": 2083746873_u32, "dailyProgress": 0, "dailyLimit": 0, "weeklyProgress": 1200, "weeklyLimit": 2000,
+        "currentProgress": level * 1000 + progress_to_next_level, "level": level, "levelCap": -1, "stepIndex": step_index,
+        "progressToNextLevel": progress_to_next_level, "nextLevelAt": next_level_at
+    }))
+    .expect("valid progression")
+}
+
+#[test]
+fn steps_and_rewards() {
+    let cases = [
+        ("level 0", live(0, 0, 0, 1000), false, Some("Guardian"), vec![101], 0.0),
+        ("mid-range", live(1, 1, 250, 1000), false, Some("Brave"), vec![102], 25.0),
+        ("last step", live(2, 2, 999, 1000), false, Some("Heroic"), vec![103], 99.9),
+        ("beyond, repeating", live(7, 7, 500, 1000), true, Some("Heroic"), vec![103], 50.0),
+        ("beyond, not repeating", live(3, 3, 0, 0), false, None, vec![], 0.0),
+        ("negative step", live(0, -1, 0, 1000), true, None, vec![], 0.0),
+    ];
+
+    for (name, live, repeat, step, rewards, percent) in cases {
+        let definition = definition(repeat);
+        assert_eq!(current_step(&live, &definition).and_then(|s| s.step_name.as_deref()), step, "{name}");
+        let reward_hashes: Vec<u32> = next_rewards(&live, &definition).iter().map(|r| r.item_hash).collect();
+        assert_eq!(reward_hashes, rewards, "{name}");
+        assert!((percent_to_next(&live) - percent).abs() < 0.01, "{name}: {}", percent_to_next(&live));
+    }
+}
+
+#[test]
+fn capped_progressions_have_nothing_next() {
+    let mut capped = live(2, 2, 0, 0);
+    capped.level_cap = 2;
+
+    assert!(next_rewards(&capped, &definition(true)).is_empty());
+    assert_eq!(percent_to_next(&capped), 0.0, "nextLevelAt 0 doesn't divide by zero");
+}
+
+#[test]
+fn weekly_limits() {
+    let mut progression = live(1, 1, 0, 1000);
+    assert_eq!(weekly_progress_remaining(&progression), Some(800));
+
+    progression.weekly_progress = 2500;
+    assert_eq!(weekly_progress_remaining(&progression), Some(0));
+
+    progression.weekly_limit = 0;
+    assert_eq!(weekly_progress_remaining(&progression), None);
+}
@@ -0,0 +1,208 @@
+﻿use enumflags2::{BitFlag, BitFlags};
+use proptest::prelude::*;
+use rustgie::types::destiny::components::metrics::DestinyMetricsComponent;
+use rustgie::types::destiny::entities::characters::DestinyCharacterComponent;
+use rustgie::types::destiny::DestinyItemQuantity;
+use rustgie::types::user::UserInfoCard;
+use rustgie_integration_tests::api_snapshot::types_source_dir;
+use rustgie_integration_tests::bitflags_enums;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::path::Path;
+
+const HASH_BOUNDARIES: [u32; 3] = [0, 1, u32::MAX];
+
+// The largest integer a JavaScript number holds exactly. Ids past it only survive because they're sent as strings.
+const JS_SAFE_LIMIT: i64 = 1 << 53;
+const ID_BOUNDARIES: [i64; 8] =
+    [0, JS_SAFE_LIMIT - 1, JS_SAFE_LIMIT, JS_SAFE_LIMIT + 1, -JS_SAFE_LIMIT, -JS_SAFE_LIMIT - 1, i64::MAX, i64::MIN];
+
+/// Picks the flags of `T` whose position in declaration order is set in `seed`, and checks that the combination
+/// serializes to its bits and deserializes back to itself, and that every single flag round-trips on its own.
+fn check_flags<T>(seed: u64) -> Result<(), TestCaseError>
+where
+    T: BitFlag + Serialize + DeserializeOwned + Debug,
+    T::Numeric: Into<u64>,
+    BitFlags<T>: Serialize + DeserializeOwned,
+{
+    let mut flags = BitFlags::<T>::empty();
+    for (index, flag) in BitFlags::<T>::all().iter().enumerate() {
+        if seed & (1 << (index % 64)) != 0 {
+            flags |= flag;
+        }
+        let single = serde_json::to_value(flag).map_err(|e| TestCaseError::fail(e.to_string()))?;
+        let parsed: T = serde_json::from_value(single).map_err(|e| TestCaseError::fail(e.to_string()))?;
+        prop_assert_eq!(BitFlags::from_flag(parsed), BitFlags::from_flag(flag));
+    }
+    let value = serde_json::to_value(flags).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(&value, &json!(flags.bits().into()));
+    let parsed: BitFlags<T> = serde_json::from_value(value).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(parsed, flags);
+    Ok(())
+}
+
+macro_rules! check_all_flags {
+    ($($flags:path),*) => {
+        fn check_all_flags(seed: u64) -> Result<(), TestCaseError> {
+            $(check_flags::<$flags>(seed)?;)*
+            Ok(())
+        }
+
+        fn registered_flags() -> BTreeSet<String> {
+            let paths = [$(stringify!($flags)),*].map(|path| path.split_whitespace().collect::<String>());
+            paths.iter().map(|path| path.replacen("rustgie::types::", "", 1)).collect()
+        }
+    };
+}
+
+bitflags_enums!(check_all_flags);
+
+fn hash_strategy() -> impl Strategy<Value = u32> {
+    prop_oneof![prop::sample::select(HASH_BOUNDARIES.to_vec()), any::<u32>()]
+}
+
+fn id_strategy() -> impl Strategy<Value = i64> {
+    prop_oneof![prop::sample::select(ID_BOUNDARIES.to_vec()), any::<i64>()]
+}
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<T, TestCaseError> {
+    let text = serde_json::to_string(value).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    serde_json::from_str(&text).map_err(|e| TestCaseError::fail(format!("{e} in {text}")))
+}
+
+fn user(membership_id: i64, display_name: Option<String>, global_name: Option<String>) -> UserInfoCard {
+    serde_json::from_value(json!({
+        "membershipType": 3,
+        "membershipId": membership_id.to_string(),
+        "displayName": display_name,
+        "bungieGlobalDisplayName": global_name,
+        "bungieGlobalDisplayNameCode": 42,
+    }))
+    .unwrap()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { failure_persistence: None, ..ProptestConfig::default() })]
+
+    #[test]
+    fn flag_combinations_round_trip(seed in any::<u64>()) {
+        check_all_flags(seed)?;
+    }
+
+    #[test]
+    fn hashes_round_trip_as_fields_and_map_keys(hash in hash_strategy(), quantity in any::<i32>()) {
+        let item = DestinyItemQuantity { item_hash: hash, item_instance_id: None, quantity, has_conditional_visibility: false };
+        prop_assert_eq!(serde_json::to_value(&item).unwrap()["itemHash"].as_u64(), Some(u64::from(hash)));
+        prop_assert_eq!(round_trip(&item)?, item);
+
+        let metrics: DestinyMetricsComponent = serde_json::from_value(json!({
+            "metrics": { hash.to_string(): { "invisible": false } },
+            "metricsRootNodeHash": hash,
+        }))
+        .unwrap();
+        let parsed = round_trip(&metrics)?;
+        prop_assert_eq!(parsed.metrics_root_node_hash, hash);
+        prop_assert!(parsed.metrics.as_ref().is_some_and(|m| m.contains_key(&hash)));
+    }
+
+    #[test]
+    fn ids_round_trip_as_strings(membership_id in id_strategy(), character_id in id_strategy(), instance_id in id_strategy()) {
+        let card = user(membership_id, None, None);
+        prop_assert_eq!(card.membership_id, membership_id);
+        let value = serde_json::to_value(&card).unwrap();
+        prop_assert_eq!(&value["membershipId"], &json!(membership_id.to_string()));
+        prop_assert_eq!(round_trip(&card)?, card);
+
+        let item = DestinyItemQuantity { item_hash: 1, item_instance_id: Some(instance_id), quantity: 1, has_conditional_visibility: false };
+        prop_assert_eq!(round_trip(&item)?, item);
+
+        let character: DestinyCharacterComponent = serde_json::from_value(json!({
+            "membershipId": membership_id.to_string(),
+            "membershipType": 3,
+            "characterId": character_id.to_string(),
+            "dateLastPlayed": "2024-01-02T03:04:05Z",
+            "minutesPlayedThisSession": "0",
+            "minutesPlayedTotal": "0",
+            "light": 0,
+            "stats": {},
+            "raceHash": 0,
+            "genderHash": 0,
+            "classHash": 0,
+            "raceType": 0,
+            "classType": 0,
+            "genderType": 0,
+            "emblemColor": { "red": 0, "green": 0, "blue": 0, "alpha": 0 },
+            "levelProgression": {
+                "progressionHash": 0, "dailyProgress": 0, "dailyLimit": 0, "weeklyProgress": 0, "weeklyLimit": 0,
+                "currentProgress": 0, "level": 0, "levelCap": 0, "stepIndex": 0, "progressToNextLevel": 0,
+                "nextLevelAt": 0,
+            },
+            "emblemHash": 0,
+            "baseCharacterLevel": 0,
+            "percentToNextLevel": 0.0,
+        }))
+        .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        prop_assert_eq!(character.character_id, character_id);
+        let parsed = round_trip(&character)?;
+        prop_assert_eq!(parsed.character_id, character_id);
+        prop_assert_eq!(parsed.membership_id, membership_id);
+    }
+
+    #[test]
+    fn unicode_display_names_round_trip(display_name in any::<Option<String>>(), global_name in "\\PC{0,26}") {
+        let card = user(1, display_name.clone(), Some(global_name.clone()));
+        prop_assert_eq!(card.display_name.as_deref(), display_name.as_deref());
+        prop_assert_eq!(card.bungie_global_display_name.as_deref(), Some(global_name.as_str()));
+        prop_assert_eq!(round_trip(&card)?, card);
+    }
+}
+
+#[test]
+fn emoji_and_combining_display_names_round_trip() {
+    for name in ["Guardian🚀", "👨‍👩‍👧‍👦", "Zoë", "Zoe\u{308}", "守护者", "مرحبا", "\u{200b}", "a\"b\\c\n"] {
+        let card = user(1, Some(name.to_string()), Some(name.to_string()));
+        assert_eq!(round_trip(&card).unwrap(), card, "{name:?}");
+    }
+}
+
+fn collect_bitflags(dir: &Path, module: &str, found: &mut BTreeSet<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        if path.is_dir() {
+            collect_bitflags(&path, &format!("{module}{name}::"), found);
+            continue;
+        }
+        if path.extension().is_none_or(|e| e != "rs") {
+            continue;
+        }
+        let module = match name.as_str() {
+            "mod" | "lib" => module.to_string(),
+            _ => format!("{module}{name}::"),
+        };
+        let source = std::fs::read_to_string(&path).unwrap();
+        let parsed = syn::parse_file(source.trim_start_matches('\u{feff}')).unwrap();
+        for item in parsed.items {
+            if let syn::Item::Enum(e) = item {
+                if e.attrs.iter().any(|a| a.path().is_ident("bitflags")) {
+                    found.insert(format!("{module}{}", e.ident));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn registry_lists_every_bitflags_enum() {
+    let mut found = BTreeSet::new();
+    collect_bitflags(&types_source_dir(), "", &mut found);
+    let registered = registered_flags();
+    let missing: Vec<_> = found.difference(&registered).collect();
+    let stale: Vec<_> = registered.difference(&found).collect();
+    assert!(missing.is_empty(), "add these to `bitflags_enums!` in src/enum_registry.rs: {missing:?}");
+    assert!(stale.is_empty(), "these are listed in `bitflags_enums!` but aren't bitflags enums: {stale:?}");
+    assert_eq!(found.len(), 28);
+}
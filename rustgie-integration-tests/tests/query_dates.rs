@@ -0,0 +1,102 @@
+﻿use rustgie::types::dates::BungieDate;
+use rustgie::types::destiny::historical_stats::definitions::PeriodType;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::json;
+use time::macros::{date, datetime};
+use time::Month;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer};
+
+async fn recording_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(success_envelope(json!({})))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn only_query(server: &MockServer) -> String {
+    let requests = server.received_requests().await.expect("request recording is enabled");
+    assert_eq!(requests.len(), 1);
+    requests[0].url.query().unwrap_or_default().to_string()
+}
+
+#[tokio::test]
+async fn stats_date_range_is_sent_as_days() -> anyhow::Result<()> {
+    let server = recording_server().await;
+    let client = client_for(&server)?;
+
+    client
+        .destiny2_get_historical_stats(
+            2305843009200000001,
+            4611686018400000001,
+            BungieMembershipType::TigerSteam,
+            Some(BungieDate::from(date!(2023 - 06 - 14))),
+            Some(BungieDate::from_calendar_date(2023, Month::June, 1)?),
+            None,
+            None,
+            Some(PeriodType::Daily),
+            None,
+        )
+        .await?;
+
+    assert_eq!(only_query(&server).await, "dayend=2023-06-14&daystart=2023-06-01&periodType=1");
+    Ok(())
+}
+
+#[tokio::test]
+async fn day_params_never_carry_a_time() -> anyhow::Result<()> {
+    let server = recording_server().await;
+    let client = client_for(&server)?;
+
+    let late_evening_pacific = datetime!(2023-06-14 23:30:00 -07:00);
+    client
+        .destiny2_get_historical_stats(
+            2305843009200000001,
+            4611686018400000001,
+            BungieMembershipType::TigerSteam,
+            Some(late_evening_pacific.into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await?;
+
+    let query = only_query(&server).await;
+    assert_eq!(query, "dayend=2023-06-15", "the UTC day is sent");
+    assert!(!query.contains('T') && !query.contains("%3A"), "no RFC3339 timestamp in {query}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn api_usage_range_is_sent_as_rfc3339() -> anyhow::Result<()> {
+    let server = recording_server().await;
+    let client = client_for(&server)?;
+
+    client
+        .app_get_application_api_usage(
+            12345,
+            Some(datetime!(2023-06-14 02:00:00 UTC)),
+            Some(datetime!(2023-06-13 02:00:00 UTC)),
+            None,
+        )
+        .await?;
+
+    assert_eq!(only_query(&server).await, "end=2023-06-14T02%3A00%3A00Z&start=2023-06-13T02%3A00%3A00Z");
+    Ok(())
+}
+
+#[test]
+fn bungie_date_round_trips() -> anyhow::Result<()> {
+    let day: BungieDate = "2023-06-01".parse()?;
+    assert_eq!(day, BungieDate::from(date!(2023 - 06 - 01)));
+    assert_eq!(day.to_string(), "2023-06-01");
+    assert!("2023-06-01T00:00:00Z".parse::<BungieDate>().is_err());
+    assert!("2023-6-1".parse::<BungieDate>().is_err());
+    assert!("2023-02-30".parse::<BungieDate>().is_err());
+    Ok(())
+}
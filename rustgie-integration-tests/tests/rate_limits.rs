@@ -0,0 +1,130 @@
+﻿use rustgie::{BungieHost, FixedClock, RateLimit, RustgieClient, RustgieClientBuilder};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use time::macros::datetime;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer};
+
+const PGCR_PATH: &str = "/Platform/Destiny2/Stats/PostGameCarnageReport/12685770593/";
+
+/// A www.bungie.net stand-in serving locales, and a stats.bungie.net stand-in serving one PGCR.
+async fn servers() -> (MockServer, MockServer) {
+    let platform = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/GetAvailableLocales/"))
+        .respond_with(success_envelope(json!({ "en": "en" })))
+        .mount(&platform)
+        .await;
+    let stats = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(PGCR_PATH))
+        .respond_with(success_envelope(json!({ "period": "2023-06-14T02:00:00Z", "entries": [], "teams": [] })))
+        .mount(&stats)
+        .await;
+    (platform, stats)
+}
+
+fn client(platform: &MockServer, stats: &MockServer, clock: &Arc<FixedClock>) -> anyhow::Result<RustgieClient> {
+    RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&platform.uri())
+        .with_stats_base_url(&stats.uri())
+        .dangerously_allow_http(true)
+        .with_clock(clock.clone())
+        .with_sleeper(clock.clone())
+        .with_rate_limit(BungieHost::Platform, Some(RateLimit::per_second(2)))
+        .with_rate_limit(BungieHost::Stats, Some(RateLimit::per_second(2)))
+        .build()
+}
+
+async fn requests(server: &MockServer) -> usize {
+    server.received_requests().await.expect("request recording is enabled").len()
+}
+
+#[tokio::test]
+async fn saturated_platform_does_not_delay_stats() -> anyhow::Result<()> {
+    let (platform, stats) = servers().await;
+    let clock = Arc::new(FixedClock::new(datetime!(2023-06-14 02:00:00 UTC)));
+    let client = client(&platform, &stats, &clock)?;
+
+    client.get_available_locales(None).await?;
+    client.get_available_locales(None).await?;
+    client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    assert!(clock.sleeps().is_empty(), "the stats host has its own budget");
+
+    client.get_available_locales(None).await?;
+    assert_eq!(clock.sleeps(), [Duration::from_millis(500)]);
+    assert_eq!((requests(&platform).await, requests(&stats).await), (3, 1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn saturated_stats_does_not_delay_platform() -> anyhow::Result<()> {
+    let (platform, stats) = servers().await;
+    let clock = Arc::new(FixedClock::new(datetime!(2023-06-14 02:00:00 UTC)));
+    let client = client(&platform, &stats, &clock)?;
+
+    client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    client.get_available_locales(None).await?;
+    assert!(clock.sleeps().is_empty(), "the platform host has its own budget");
+
+    client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    assert_eq!(clock.sleeps(), [Duration::from_millis(500)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn limits_are_configured_per_host() -> anyhow::Result<()> {
+    let (platform, stats) = servers().await;
+    let clock = Arc::new(FixedClock::new(datetime!(2023-06-14 02:00:00 UTC)));
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&platform.uri())
+        .with_stats_base_url(&stats.uri())
+        .dangerously_allow_http(true)
+        .with_clock(clock.clone())
+        .with_sleeper(clock.clone())
+        .with_rate_limit(BungieHost::Platform, Some(RateLimit::new(1, Duration::from_secs(10))))
+        .with_rate_limit(BungieHost::Stats, None)
+        .build()?;
+
+    for _ in 0..30 {
+        client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    }
+    assert!(clock.sleeps().is_empty(), "the stats host isn't limited");
+
+    client.get_available_locales(None).await?;
+    client.get_available_locales(None).await?;
+    assert_eq!(clock.sleeps(), [Duration::from_secs(10)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn stats_requests_follow_the_base_url_by_default() -> anyhow::Result<()> {
+    let (_platform, stats) = servers().await;
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&stats.uri())
+        .dangerously_allow_http(true)
+        .build()?;
+
+    client.destiny2_get_post_game_carnage_report(12685770593, None).await?;
+    assert_eq!(requests(&stats).await, 1);
+
+    let https_only = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_stats_base_url("http://stats.example.com")
+        .build();
+    assert!(https_only.is_err(), "the stats base URL is held to the same scheme rules");
+    Ok(())
+}
+
+#[test]
+fn default_limits() {
+    assert_eq!(BungieHost::Platform.default_rate_limit(), RateLimit::per_second(25));
+    assert_eq!(BungieHost::Stats.origin(), "https://stats.bungie.net");
+    assert_eq!(RateLimit::new(0, Duration::ZERO), RateLimit::new(1, Duration::from_millis(1)));
+}
@@ -0,0 +1,83 @@
+﻿use rustgie::types::destiny::components::records::DestinyRecordComponent;
+use rustgie::types::destiny::definitions::records::{record_tiers, DestinyRecordDefinition, RecordTierState};
+use serde_json::{json, Value};
+
+const INTERVAL_RECORD: &str = "1060780635";
+const PLAIN_RECORD: &str = "2648109757";
+
+fn definition(hash: &str) -> DestinyRecordDefinition {
+    let definitions: Value = serde_json::from_str(include_str!("../fixtures/record_definitions.json")).expect("valid JSON");
+    serde_json::from_value(definitions[hash].clone()).expect("valid record definition")
+}
+
+fn objective(hash: u32, progress: i32, completion_value: i32) -> Value {
+    json!({
+        "objectiveHash": hash, "progress": progress, "completionValue": completion_value,
+        "complete": progress >= completion_value, "visible": true
+    })
+}
+
+#[test]
+fn partially_completed_interval_record() {
+    let record = definition(INTERVAL_RECORD);
+    let live: DestinyRecordComponent = serde_json::from_value(json!({
+        "state": 4,
+        "objectives": [],
+        "intervalObjectives": [
+            objective(3008002760, 650, 100),
+            objective(3008002761, 650, 500),
+            objective(3008002762, 650, 1000)
+        ],
+        "intervalsRedeemedCount": 1
+    }))
+    .expect("valid record component");
+
+    let tiers = record_tiers(&record, Some(&live));
+
+    let summary: Vec<(u32, Option<i32>, RecordTierState, i32)> =
+        tiers.iter().map(|t| (t.objective_hashes[0], t.threshold(), t.state, t.score)).collect();
+    assert_eq!(
+        summary,
+        [
+            (3008002760, Some(100), RecordTierState::Redeemed, 5),
+            (3008002761, Some(500), RecordTierState::Complete, 10),
+            (3008002762, Some(1000), RecordTierState::Incomplete, 20),
+        ]
+    );
+    assert_eq!(tiers[0].reward_items[0].quantity, 5000);
+    assert_eq!(tiers[1].reward_items[0].item_hash, 3853748946);
+    assert!(tiers[2].reward_items.is_empty());
+}
+
+#[test]
+fn interval_record_without_live_data() {
+    let record = definition(INTERVAL_RECORD);
+    let tiers = record_tiers(&record, None);
+    assert_eq!(tiers.len(), 3);
+    assert!(tiers.iter().all(|t| t.state == RecordTierState::Incomplete && t.threshold().is_none()));
+}
+
+#[test]
+fn plain_record_is_one_tier() {
+    let record = definition(PLAIN_RECORD);
+    let live: DestinyRecordComponent = serde_json::from_value(json!({
+        "state": 0,
+        "objectives": [objective(1582949834, 1, 1), objective(1582949833, 3, 3)],
+        "intervalsRedeemedCount": 0
+    }))
+    .expect("valid record component");
+
+    let tiers = record_tiers(&record, Some(&live));
+
+    assert_eq!(tiers.len(), 1);
+    let tier = &tiers[0];
+    assert_eq!(tier.objective_hashes, [1582949833, 1582949834]);
+    assert_eq!(tier.threshold(), Some(3), "progress follows the definition's objective order");
+    assert_eq!(tier.state, RecordTierState::Complete);
+    assert_eq!(tier.score, 15);
+    assert_eq!(tier.reward_items[0].item_hash, 353704689);
+
+    let redeemed: DestinyRecordComponent =
+        serde_json::from_value(json!({ "state": 1, "intervalsRedeemedCount": 0 })).expect("valid record component");
+    assert_eq!(record_tiers(&record, Some(&redeemed))[0].state, RecordTierState::Redeemed);
+}
@@ -0,0 +1,202 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::{DestinyClass, DestinyComponentType};
+use rustgie::types::user::ExactSearchRequest;
+use rustgie::types::BungieMembershipType;
+use rustgie::types::exceptions::PlatformErrorCodes;
+use rustgie::{ApiKeyStatus, RustgieClientBuilder, RustgieError};
+use rustgie_integration_tests::{replay, Session, TEST_API_KEY};
+use std::collections::HashMap;
+use wiremock::MockServer;
+
+const MEMBERSHIP_ID: i64 = 4611686018400000001;
+const CHARACTER_ID: i64 = 2305843009200000001;
+
+#[tokio::test]
+async fn player_profile_item_manifest() -> anyhow::Result<()> {
+    let (server, client) = replay("player_profile_item").await?;
+
+    let players = client
+        .destiny2_search_destiny_player_by_bungie_name(
+            BungieMembershipType::All,
+            ExactSearchRequest {
+                display_name: Some("Rustgie".to_string()),
+                display_name_code: 1234,
+            },
+            None,
+        )
+        .await?;
+    assert_eq!(players.len(), 1);
+    assert_eq!(players[0].membership_id, MEMBERSHIP_ID);
+    assert_eq!(players[0].membership_type, BungieMembershipType::TigerSteam);
+
+    let profile = client
+        .destiny2_get_profile(
+            players[0].membership_id,
+            players[0].membership_type,
+            Some(vec![
+                DestinyComponentType::Profiles,
+                DestinyComponentType::Characters,
+                DestinyComponentType::CharacterEquipment,
+            ]),
+            None,
+        )
+        .await?;
+    assert!(profile.degraded_components().is_empty());
+
+    let profile_data = profile.profile.as_ref().and_then(|p| p.data.as_ref()).expect("profile component");
+    assert_eq!(profile_data.character_ids.as_deref(), Some(&[CHARACTER_ID, CHARACTER_ID + 1][..]));
+
+    let characters = profile.characters.as_ref().and_then(|c| c.data.as_ref()).expect("characters component");
+    let hunter = &characters[&CHARACTER_ID];
+    assert_eq!(hunter.light, 1806);
+    assert_eq!(hunter.class_type, DestinyClass::Hunter);
+
+    let equipment = profile
+        .character_equipment
+        .as_ref()
+        .and_then(|e| e.data.as_ref())
+        .and_then(|e| e[&CHARACTER_ID].items.as_ref())
+        .expect("characterEquipment component");
+    assert_eq!(equipment.len(), 3);
+    let weapon = &equipment[0];
+
+    let item = client
+        .destiny2_get_item(
+            MEMBERSHIP_ID,
+            weapon.item_instance_id.expect("equipped items are instanced"),
+            BungieMembershipType::TigerSteam,
+            Some(vec![
+                DestinyComponentType::ItemInstances,
+                DestinyComponentType::ItemStats,
+                DestinyComponentType::ItemSockets,
+            ]),
+            None,
+        )
+        .await?;
+    assert_eq!(item.character_id, Some(CHARACTER_ID));
+    let instance = item.instance.as_ref().and_then(|i| i.data.as_ref()).expect("instance component");
+    assert!(instance.is_equipped);
+    assert_eq!(instance.primary_stat.as_ref().map(|s| s.value), Some(1810));
+    let sockets = item.sockets.as_ref().and_then(|s| s.data.as_ref()).and_then(|s| s.sockets.as_ref());
+    assert_eq!(sockets.map(Vec::len), Some(3));
+
+    let manifest = client.destiny2_get_destiny_manifest(None).await?;
+    let table_path = &manifest.json_world_component_content_paths.as_ref().expect("component paths")["en"]
+        ["DestinyInventoryItemDefinition"];
+    let definitions: HashMap<u32, DestinyInventoryItemDefinition> = reqwest::get(format!("{}{table_path}", server.uri()))
+        .await?
+        .json()
+        .await?;
+
+    let names: Vec<_> = equipment
+        .iter()
+        .map(|i| definitions[&i.item_hash].display_properties.as_ref().and_then(|d| d.name.as_deref()))
+        .collect();
+    assert_eq!(names, [Some("Ace of Spades"), Some("Sunshot"), Some("Gjallarhorn")]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn degraded_components_lists_disabled_components_once_and_skips_private_ones() -> anyhow::Result<()> {
+    let (_server, client) = replay("profile_degraded_components").await?;
+    let profile = client
+        .destiny2_get_profile(
+            MEMBERSHIP_ID,
+            BungieMembershipType::TigerSteam,
+            Some(vec![
+                DestinyComponentType::Profiles,
+                DestinyComponentType::ProfileInventories,
+                DestinyComponentType::CharacterInventories,
+                DestinyComponentType::CharacterProgressions,
+                DestinyComponentType::CharacterEquipment,
+                DestinyComponentType::ItemSockets,
+                DestinyComponentType::Records,
+            ]),
+            None,
+        )
+        .await?;
+
+    assert!(profile.profile.as_ref().and_then(|p| p.data.as_ref()).is_some());
+    assert_eq!(
+        profile.degraded_components(),
+        [
+            DestinyComponentType::ProfileInventories,
+            DestinyComponentType::CharacterEquipment,
+            DestinyComponentType::ItemSockets,
+            DestinyComponentType::Records,
+        ],
+        "private components without data aren't degraded, and ItemSockets and Records are disabled on more than one field"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_api_key_reports_invalid_key() -> anyhow::Result<()> {
+    let (_server, client) = replay("api_key_invalid").await?;
+    assert_eq!(client.verify_api_key().await?, ApiKeyStatus::InvalidKey);
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_api_key_reports_origin_mismatch() -> anyhow::Result<()> {
+    let (_server, client) = replay("api_key_origin_mismatch").await?;
+    assert_eq!(client.verify_api_key().await?, ApiKeyStatus::OriginMismatch);
+    Ok(())
+}
+
+fn origin_rejection(error: &anyhow::Error) -> Option<&RustgieError> {
+    error.downcast_ref::<RustgieError>().filter(|e| matches!(e, RustgieError::ApiKeyOriginRejected { .. }))
+}
+
+#[tokio::test]
+async fn origin_rejection_envelope_on_any_endpoint() -> anyhow::Result<()> {
+    let (_server, client) = replay("api_key_origin_rejected_envelope").await?;
+
+    let error = client
+        .destiny2_get_profile(MEMBERSHIP_ID, BungieMembershipType::TigerSteam, Some(vec![DestinyComponentType::Profiles]), None)
+        .await
+        .expect_err("the key's origin restrictions reject the request");
+
+    let rejection = origin_rejection(&error).expect("classified as an origin rejection");
+    assert!(!rejection.is_throttled());
+    assert_eq!(rejection.error_code(), Some(PlatformErrorCodes::OriginHeaderDoesNotMatchKey));
+    assert!(rejection.to_string().contains("origin"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn origin_rejection_html_page() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Session::load("api_key_origin_rejected_html")?.mount(&server).await;
+    let client = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_origin("https://rustgie.example")
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .build()?;
+
+    let error = client.destiny2_get_destiny_manifest(None).await.expect_err("Bungie sent its 401 page");
+    let rejection = origin_rejection(&error).expect("classified as an origin rejection");
+    assert_eq!(rejection.error_code(), None);
+    assert!(!rejection.is_throttled());
+
+    assert_eq!(client.verify_api_key().await?, ApiKeyStatus::OriginMismatch);
+    Ok(())
+}
+
+#[tokio::test]
+async fn html_401_without_an_origin_is_an_invalid_key() -> anyhow::Result<()> {
+    // The same page is all Bungie sends for an invalid or expired key; with no Origin sent, it can't be an origin rejection.
+    let (_server, client) = replay("api_key_origin_rejected_html").await?;
+
+    let error = client.destiny2_get_destiny_manifest(None).await.expect_err("Bungie sent its 401 page");
+    assert!(origin_rejection(&error).is_none());
+    assert!(matches!(
+        error.downcast_ref::<RustgieError>(),
+        Some(RustgieError::HttpStatus { status }) if *status == reqwest::StatusCode::UNAUTHORIZED
+    ));
+
+    assert_eq!(client.verify_api_key().await?, ApiKeyStatus::InvalidKey);
+    Ok(())
+}
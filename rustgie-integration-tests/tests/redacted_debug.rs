@@ -0,0 +1,70 @@
+﻿use rustgie::types::api_response_::{BungieTokenResponse, Redacted};
+use rustgie::{OAuthSession, RustgieClientBuilder};
+use rustgie_integration_tests::TEST_API_KEY;
+use serde_json::json;
+use time::macros::datetime;
+
+const ACCESS_TOKEN: &str = "CKnJARKGAgAg4a3bcd9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f";
+const REFRESH_TOKEN: &str = "CKnJARKGAgAgRefreshTokenValue0123456789abcdefWXYZ";
+
+fn token() -> BungieTokenResponse {
+    serde_json::from_value(json!({
+        "access_token": ACCESS_TOKEN,
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "refresh_token": REFRESH_TOKEN,
+        "refresh_expires_in": 7776000,
+        "membership_id": "10000001"
+    }))
+    .expect("valid token")
+}
+
+#[test]
+fn token_response_masks_tokens() {
+    let debug = format!("{:?}", token());
+
+    assert!(!debug.contains(ACCESS_TOKEN), "{debug}");
+    assert!(!debug.contains(REFRESH_TOKEN), "{debug}");
+    assert!(debug.contains(r#"access_token: Some("***3e4f")"#), "{debug}");
+    assert!(debug.contains(r#"refresh_token: Some("***WXYZ")"#), "{debug}");
+    assert!(debug.contains("membership_id: Some(10000001)"), "other fields print as usual: {debug}");
+
+    let pretty = format!("{:#?}", token());
+    assert!(!pretty.contains(ACCESS_TOKEN) && pretty.contains("***3e4f"));
+}
+
+#[test]
+fn short_secrets_are_fully_masked() {
+    assert_eq!(format!("{:?}", Redacted("s3cret")), r#""***""#);
+    assert_eq!(format!("{:?}", Redacted("")), r#""***""#);
+    assert_eq!(format!("{:?}", Redacted("123456789")), r#""***6789""#);
+}
+
+#[test]
+fn session_masks_held_tokens() {
+    let session = OAuthSession::new(token(), datetime!(2023-06-14 02:00:00 UTC));
+    let debug = format!("{session:?}");
+
+    assert!(!debug.contains(ACCESS_TOKEN) && !debug.contains(REFRESH_TOKEN), "{debug}");
+    assert!(debug.contains("***WXYZ"), "{debug}");
+}
+
+#[test]
+fn builder_and_client_mask_credentials() -> anyhow::Result<()> {
+    let secret = "oauth-client-secret-9876";
+    let builder = RustgieClientBuilder::new()
+        .with_api_key(TEST_API_KEY)
+        .with_oauth_client_id(12345)
+        .with_oauth_client_secret(secret);
+
+    let debug = format!("{builder:?}");
+    assert!(!debug.contains(TEST_API_KEY), "{debug}");
+    assert!(!debug.contains(secret) && debug.contains(r#"oauth_client_secret: Some("***9876")"#), "{debug}");
+
+    let client = builder.build()?;
+    let debug = format!("{client:?}");
+    assert!(!debug.contains(TEST_API_KEY), "{debug}");
+    assert!(!debug.contains(secret) && debug.contains("***9876"), "{debug}");
+    assert!(debug.contains(r#"oauth_client_id: Some("12345")"#), "{debug}");
+    Ok(())
+}
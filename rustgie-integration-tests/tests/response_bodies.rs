@@ -0,0 +1,124 @@
+﻿use rustgie::{DefinitionRegistry, Outcome, RustgieError};
+use rustgie_integration_tests::client_for;
+use serde_json::json;
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BOM_MANIFEST: &[u8] = include_bytes!("../fixtures/bom_manifest.json");
+const INVALID_CONTINUATION: &[u8] = include_bytes!("../fixtures/invalid_continuation.json");
+const ITEM_HASH: u32 = 1363886209;
+
+fn json_bytes(body: &[u8]) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(body.to_vec(), "application/json; charset=utf-8")
+}
+
+fn malformed(error: &anyhow::Error) -> Option<(usize, u8)> {
+    match error.downcast_ref::<RustgieError>()? {
+        RustgieError::MalformedBody { offset, byte } => Some((*offset, *byte)),
+        _ => None,
+    }
+}
+
+/// Serves the BOM-prefixed manifest, `items` as the item table, and empty tables for everything else.
+async fn manifest_server(items: &[u8]) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(json_bytes(BOM_MANIFEST)).mount(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/common/destiny2_content/json/en/DestinyInventoryItemDefinition-bom.json"))
+        .respond_with(json_bytes(items))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/common/destiny2_content/json/en/"))
+        .respond_with(json_bytes(b"\xEF\xBB\xBF{}"))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn byte_order_mark_is_stripped_from_api_responses() -> anyhow::Result<()> {
+    let server = manifest_server(b"{}").await;
+    let client = client_for(&server)?;
+
+    let manifest = client.destiny2_get_destiny_manifest(None).await?;
+    assert_eq!(manifest.version.as_deref(), Some("bom.1"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_utf8_names_the_offending_byte() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let mut body = b"\xEF\xBB\xBF".to_vec();
+    body.extend_from_slice(br#"{"Response": {"version": "caf"#);
+    let offset = body.len();
+    body.extend_from_slice(b"\xE9\"}, \"ErrorCode\": 1, \"ThrottleSeconds\": 0, \"ErrorStatus\": \"Success\", \"Message\": \"Ok\"}");
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(json_bytes(&body)).mount(&server).await;
+    let client = client_for(&server)?;
+
+    let error = client.destiny2_get_destiny_manifest(None).await.expect_err("Latin-1 isn't UTF-8");
+    assert_eq!(malformed(&error), Some((offset, 0xE9)), "{error:?}");
+    assert!(format!("{error:#}").contains(&format!("invalid byte 0xE9 at offset {offset}")), "{error:#}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_characters_name_the_offending_byte() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    let body = json!({
+        "Response": { "version": "tab\u{1}bed" },
+        "ErrorCode": 1,
+        "ThrottleSeconds": 0,
+        "ErrorStatus": "Success",
+        "Message": "Ok"
+    })
+    .to_string()
+    .replace("\\u0001", "\u{1}");
+    let offset = body.find('\u{1}').expect("raw control character");
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(json_bytes(body.as_bytes())).mount(&server).await;
+    let client = client_for(&server)?;
+
+    let error = client.destiny2_get_destiny_manifest(None).await.expect_err("raw control characters aren't JSON");
+    assert_eq!(malformed(&error), Some((offset, 0x01)), "{error:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn other_syntax_errors_keep_serdes_message() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).and(path("/Platform/Destiny2/Manifest/")).respond_with(json_bytes(b"{\"Response\": ")).mount(&server).await;
+    let client = client_for(&server)?;
+
+    let error = client.destiny2_get_destiny_manifest(None).await.expect_err("truncated body");
+    assert_eq!(malformed(&error), None);
+    assert!(error.downcast_ref::<serde_json::Error>().is_some(), "{error:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn manifest_tables_with_invalid_utf8_fail_by_default() -> anyhow::Result<()> {
+    let server = manifest_server(INVALID_CONTINUATION).await;
+    let client = client_for(&server)?;
+    let registry = DefinitionRegistry::new();
+
+    let error = registry.refresh(&client, "en").await.expect_err("strict by default");
+    let offset = INVALID_CONTINUATION.iter().position(|byte| *byte == 0xC3).expect("fixture has a broken sequence");
+    assert_eq!(malformed(&error), Some((offset, 0xC3)), "{error:?}");
+    assert_eq!(registry.snapshot().generation(), 0, "nothing was swapped in");
+    Ok(())
+}
+
+#[tokio::test]
+async fn lossy_registry_replaces_invalid_utf8() -> anyhow::Result<()> {
+    let server = manifest_server(INVALID_CONTINUATION).await;
+    let client = client_for(&server)?;
+    let registry = DefinitionRegistry::new().with_lossy_utf8(true);
+
+    assert_eq!(registry.refresh(&client, "en").await?, Outcome::Completed(true));
+    let snapshot = registry.snapshot();
+    let display = snapshot.inventory_items()[&ITEM_HASH].display_properties.as_ref().expect("display properties");
+    assert_eq!(display.name.as_deref(), Some("Gjallarhorn"));
+    assert_eq!(display.description.as_deref(), Some("\u{FFFD}(Wolfpack Rounds"));
+    Ok(())
+}
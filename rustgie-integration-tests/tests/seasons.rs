@@ -0,0 +1,77 @@
+﻿use rustgie::types::common::models::CoreSettingsConfiguration;
+use rustgie::types::destiny::definitions::seasons::{current_season, days_remaining, season_by_settings, DestinySeasonDefinition};
+use serde_json::json;
+use std::collections::HashMap;
+use time::macros::datetime;
+
+const DEEP: u32 = 2809059425;
+const WITCH: u32 = 2809059424;
+const WISH: u32 = 2809059431;
+
+/// Seasons 21 and 22, which meet at the 2023-08-22 reset, and two future seasons whose dates are null or missing.
+fn definitions() -> HashMap<u32, DestinySeasonDefinition> {
+    serde_json::from_str(include_str!("../fixtures/season_definitions.json")).expect("valid definitions")
+}
+
+#[test]
+fn future_seasons_parse_without_dates() {
+    let definitions = definitions();
+    assert!(definitions.values().filter(|season| season.season_number >= 23).all(|season| season.start_date.is_none() && season.end_date.is_none()));
+    assert_eq!(definitions[&WITCH].start_date, Some(datetime!(2023-08-22 17:00:00 UTC)));
+}
+
+#[test]
+fn season_boundary() {
+    let definitions = definitions();
+    let season_at = |now| current_season(&definitions, now).map(|season| season.hash);
+
+    assert_eq!(season_at(datetime!(2023-08-22 16:59:59 UTC)), Some(DEEP));
+    assert_eq!(season_at(datetime!(2023-08-22 17:00:00 UTC)), Some(WITCH), "the end date is exclusive");
+    assert_eq!(season_at(datetime!(2023-05-23 16:59:59 UTC)), None);
+    assert_eq!(season_at(datetime!(2023-11-28 17:00:00 UTC)), None, "future seasons without dates are never current");
+}
+
+#[test]
+fn overlapping_seasons_prefer_the_latest_start() {
+    let mut definitions = definitions();
+    definitions.get_mut(&DEEP).expect("Deep").end_date = Some(datetime!(2023-09-01 17:00:00 UTC));
+    definitions.get_mut(&WISH).expect("Wish").start_date = Some(datetime!(2023-08-22 17:00:00 UTC));
+
+    let current = current_season(&definitions, datetime!(2023-08-25 00:00:00 UTC)).expect("a season");
+    assert_eq!(current.hash, WISH, "Wish is open-ended and ties with Witch on start date, so the higher season number wins");
+}
+
+#[test]
+fn days_left() {
+    let definitions = definitions();
+    let witch = &definitions[&WITCH];
+
+    assert_eq!(days_remaining(witch, datetime!(2023-08-22 17:00:00 UTC)), Some(98));
+    assert_eq!(days_remaining(witch, datetime!(2023-11-27 18:00:00 UTC)), Some(0), "less than a full day left");
+    assert_eq!(days_remaining(witch, datetime!(2024-01-01 00:00:00 UTC)), Some(0));
+    assert_eq!(days_remaining(&definitions[&WISH], datetime!(2023-08-22 17:00:00 UTC)), None);
+}
+
+#[test]
+fn current_season_from_live_settings() {
+    let definitions = definitions();
+    let settings = |current_season_hash: Option<u32>| -> CoreSettingsConfiguration {
+        let mut core = json!({
+            "collectionRootNode": 0, "badgesRootNode": 0, "recordsRootNode": 0, "medalsRootNode": 0, "metricsRootNode": 0,
+            "activeTriumphsRootNodeHash": 0, "activeSealsRootNodeHash": 0, "legacyTriumphsRootNodeHash": 0,
+            "legacySealsRootNodeHash": 0, "medalsRootNodeHash": 0, "exoticCatalystsRootNodeHash": 0, "loreRootNodeHash": 0,
+            "craftingRootNodeHash": 0, "loadoutConstantsHash": 0, "guardianRankConstantsHash": 0,
+            "guardianRanksRootNodeHash": 0, "currentSeasonalArtifactHash": 0
+        });
+        if let Some(hash) = current_season_hash {
+            core["currentSeasonHash"] = json!(hash);
+        }
+        serde_json::from_value(json!({ "destiny2CoreSettings": core })).expect("valid settings")
+    };
+
+    assert_eq!(season_by_settings(&settings(Some(WITCH)), &definitions).map(|season| season.hash), Some(WITCH));
+    assert!(season_by_settings(&settings(Some(1)), &definitions).is_none(), "a hash missing from the definitions");
+    assert!(season_by_settings(&settings(None), &definitions).is_none());
+    let without_core: CoreSettingsConfiguration = serde_json::from_value(json!({})).expect("valid settings");
+    assert!(season_by_settings(&without_core, &definitions).is_none());
+}
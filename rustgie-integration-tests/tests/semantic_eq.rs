@@ -0,0 +1,59 @@
+﻿use rustgie::types::destiny::responses::DestinyProfileResponse;
+use rustgie::types::destiny::DestinyComponentType;
+use rustgie::types::queries::SearchResult;
+use rustgie::types::semantic_eq::SemanticEq;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+use serde_json::json;
+
+const MEMBERSHIP_ID: i64 = 4611686018400000002;
+
+async fn capture(session: &str) -> anyhow::Result<DestinyProfileResponse> {
+    let (_server, client) = replay(session).await?;
+    client
+        .destiny2_get_profile(
+            MEMBERSHIP_ID,
+            BungieMembershipType::TigerSteam,
+            Some(vec![DestinyComponentType::Profiles, DestinyComponentType::Characters]),
+            None,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn unchanged_profile_captured_minutes_apart() -> anyhow::Result<()> {
+    let first = capture("profile_unchanged_0310").await?;
+    let second = capture("profile_unchanged_0314").await?;
+
+    assert_ne!(first, second, "the minted timestamps differ");
+    assert!(first.eq_ignoring_volatile(&second));
+    assert!(second.eq_ignoring_volatile(&first));
+    assert!(Some(first.clone()).eq_ignoring_volatile(&Some(second.clone())));
+
+    let mut ranked_up = second.clone();
+    let profile = ranked_up.profile.as_mut().and_then(|p| p.data.as_mut()).expect("profile component");
+    profile.current_guardian_rank = 2;
+    assert!(!first.eq_ignoring_volatile(&ranked_up), "a real change is still noticed");
+    assert_eq!(second.response_minted_timestamp, ranked_up.response_minted_timestamp, "the inputs are left alone");
+    Ok(())
+}
+
+#[test]
+fn paged_results_ignore_query_echo_and_continuation_token() {
+    let page = |token: &str, page: i32| -> SearchResult {
+        serde_json::from_value(json!({
+            "totalResults": 1,
+            "hasMore": false,
+            "query": { "itemsPerPage": 50, "currentPage": page, "requestContinuationToken": token },
+            "replacementContinuationToken": token,
+            "useTotalResults": true
+        }))
+        .expect("valid search result")
+    };
+
+    assert!(page("a1", 1).eq_ignoring_volatile(&page("b2", 2)));
+
+    let mut more = page("a1", 1);
+    more.has_more = true;
+    assert!(!page("a1", 1).eq_ignoring_volatile(&more));
+}
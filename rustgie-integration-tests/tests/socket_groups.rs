@@ -0,0 +1,103 @@
+﻿use rustgie::types::destiny::definitions::sockets::{grouped_sockets, DestinySocketCategoryDefinition};
+use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::types::destiny::entities::items::DestinyItemSocketsComponent;
+use rustgie::types::destiny::DestinySocketCategoryStyle;
+use rustgie::types::diagnostics::with_diagnostics;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const SUNSHOT: &str = "2907129557";
+const SWORDFLIGHT: &str = "2262062209";
+
+fn socket_fixture() -> Value {
+    serde_json::from_str(include_str!("../fixtures/socket_categories.json")).expect("valid JSON")
+}
+
+fn categories() -> HashMap<u32, DestinySocketCategoryDefinition> {
+    serde_json::from_value(socket_fixture()["categories"].take()).expect("valid category definitions")
+}
+
+/// An item from the definitions fixture with the socket block from the socket fixture.
+fn item(hash: &str, sockets: &str) -> DestinyInventoryItemDefinition {
+    let definitions: Value =
+        serde_json::from_str(include_str!("../fixtures/inventory_item_definitions.json")).expect("valid JSON");
+    let mut item = definitions[hash].clone();
+    item["sockets"] = socket_fixture()[sockets].take();
+    serde_json::from_value(item).expect("valid item definition")
+}
+
+fn summary(item: &DestinyInventoryItemDefinition, categories: &HashMap<u32, DestinySocketCategoryDefinition>) -> Vec<(Option<String>, DestinySocketCategoryStyle, Vec<usize>)> {
+    grouped_sockets(item, categories)
+        .into_iter()
+        .map(|group| (group.name.map(str::to_string), group.style, group.socket_indexes))
+        .collect()
+}
+
+#[test]
+fn weapon_perks_mods_and_cosmetics() {
+    let categories = categories();
+    let sunshot = item(SUNSHOT, "sunshot");
+
+    assert_eq!(
+        summary(&sunshot, &categories),
+        [
+            (Some("INTRINSIC TRAITS".to_string()), DestinySocketCategoryStyle::LargePerk, vec![0]),
+            (Some("WEAPON PERKS".to_string()), DestinySocketCategoryStyle::Reusable, vec![1, 2, 3, 4, 8]),
+            (Some("WEAPON COSMETICS".to_string()), DestinySocketCategoryStyle::Reusable, vec![6, 5]),
+            (Some("WEAPON MODS".to_string()), DestinySocketCategoryStyle::Reusable, vec![7]),
+        ]
+    );
+}
+
+#[test]
+fn armor_mods_and_energy() {
+    let categories = categories();
+    let swordflight = item(SWORDFLIGHT, "swordflight");
+
+    let groups = grouped_sockets(&swordflight, &categories);
+    assert_eq!(groups[0].name, Some("ARMOR TIER"));
+    assert_eq!(groups[0].style, DestinySocketCategoryStyle::EnergyMeter);
+    assert_eq!(groups[1].socket_indexes, [0, 1, 2, 3]);
+
+    let live: DestinyItemSocketsComponent = serde_json::from_value(json!({
+        "sockets": (0..7).map(|i| json!({ "plugHash": 3000 + i, "isEnabled": true, "isVisible": true })).collect::<Vec<_>>()
+    }))
+    .expect("valid sockets component");
+    let energy = groups[0].live_sockets(&live);
+    assert_eq!(energy, [None], "the component is missing the energy socket");
+    let mods: Vec<Option<u32>> = groups[1].live_sockets(&live).into_iter().map(|s| s.and_then(|s| s.plug_hash)).collect();
+    assert_eq!(mods, [Some(3000), Some(3001), Some(3002), Some(3003)]);
+}
+
+#[test]
+fn missing_category_definitions_are_kept() {
+    let mut categories = categories();
+    categories.remove(&2048875504);
+    let sunshot = item(SUNSHOT, "sunshot");
+
+    let groups = summary(&sunshot, &categories);
+    assert_eq!(groups[2], (None, DestinySocketCategoryStyle::Unknown, vec![6, 5]));
+}
+
+#[test]
+fn category_styles_parse_current_values() {
+    for (value, style) in [
+        (0, DestinySocketCategoryStyle::Unknown),
+        (1, DestinySocketCategoryStyle::Reusable),
+        (2, DestinySocketCategoryStyle::Consumable),
+        (3, DestinySocketCategoryStyle::Unlockable),
+        (4, DestinySocketCategoryStyle::Intrinsic),
+        (5, DestinySocketCategoryStyle::EnergyMeter),
+        (6, DestinySocketCategoryStyle::LargePerk),
+        (7, DestinySocketCategoryStyle::Abilities),
+        (8, DestinySocketCategoryStyle::Supers),
+    ] {
+        assert_eq!(serde_json::from_value::<DestinySocketCategoryStyle>(json!(value)).expect("known style"), style);
+    }
+
+    let mut future = socket_fixture()["categories"]["4241085061"].take();
+    future["categoryStyle"] = json!(9);
+    let (parsed, events) = with_diagnostics(|| serde_json::from_value::<DestinySocketCategoryDefinition>(future));
+    assert_eq!(parsed.expect("an unknown style doesn't fail the definition").category_style, DestinySocketCategoryStyle::Unknown);
+    assert_eq!(events.len(), 1);
+}
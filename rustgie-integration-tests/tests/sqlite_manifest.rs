@@ -0,0 +1,124 @@
+﻿use rustgie::types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie::{DefinitionProvider, DefinitionTable, SqliteManifest};
+use std::path::PathBuf;
+
+fn manifest() -> anyhow::Result<SqliteManifest> {
+    SqliteManifest::open(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/world_sql_content.sqlite3"))
+}
+
+fn items() -> DefinitionTable {
+    DefinitionTable::of::<DestinyInventoryItemDefinition>()
+}
+
+#[test]
+fn lists_tables() -> anyhow::Result<()> {
+    let tables = manifest()?.tables()?;
+    assert_eq!(
+        tables,
+        [DefinitionTable::new("DestinyHistoricalStatsDefinition"), DefinitionTable::new("DestinyInventoryItemDefinition")]
+    );
+    Ok(())
+}
+
+#[test]
+fn hashes_above_i32_max_are_stored_signed() -> anyhow::Result<()> {
+    let manifest = manifest()?;
+
+    let pattern_core: DestinyInventoryItemDefinition = manifest.get(&items(), 3487922223)?.expect("stored as -807045073");
+    assert_eq!(pattern_core.hash, 3487922223);
+    assert_eq!(pattern_core.display_properties.and_then(|d| d.name).as_deref(), Some("Datura Pattern Core"));
+
+    let blob: Option<serde_json::Value> = manifest.get(&items(), 1363886209)?;
+    assert!(blob.is_some(), "older manifests store the JSON as a blob");
+    assert!(manifest.get::<serde_json::Value>(&items(), 1)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn iterates_a_table() -> anyhow::Result<()> {
+    let mut hashes = Vec::new();
+    manifest()?.iter_table(&items(), |hash, definition: DestinyInventoryItemDefinition| {
+        assert_eq!(definition.hash, hash);
+        hashes.push(hash);
+        Ok(())
+    })?;
+    hashes.sort_unstable();
+    assert_eq!(hashes, [1363886209, 1907674138, 2262062209, 2907129557, 3487922223]);
+
+    let mut visited = 0;
+    let stopped = manifest()?.iter_table(&items(), |_, _: serde_json::Value| {
+        visited += 1;
+        Err(anyhow::anyhow!("stop"))
+    });
+    assert!(stopped.is_err());
+    assert_eq!(visited, 1);
+    Ok(())
+}
+
+#[test]
+fn visitor_can_read_from_the_same_manifest() -> anyhow::Result<()> {
+    let manifest = manifest()?;
+    let mut names = Vec::new();
+    manifest.iter_table(&items(), |hash, _: serde_json::Value| {
+        let definition: Option<DestinyInventoryItemDefinition> = manifest.get(&items(), hash)?;
+        names.push(definition.and_then(|d| d.display_properties).and_then(|p| p.name));
+        Ok(())
+    })?;
+    assert_eq!(names.len(), 5);
+    assert!(names.iter().all(Option::is_some), "{names:?}");
+    Ok(())
+}
+
+#[test]
+fn iterates_tables_larger_than_one_batch() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("rustgie-iter-batches-{}.sqlite3", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let connection = rusqlite::Connection::open(&path)?;
+    connection.execute("CREATE TABLE DestinyInventoryItemDefinition (id INTEGER PRIMARY KEY NOT NULL, json BLOB)", [])?;
+    // Both sides of zero, so some of the hashes are 2³¹ and up.
+    let expected: Vec<u32> = (-500..500).map(|id: i32| id.wrapping_mul(7919) as u32).collect();
+    for hash in &expected {
+        connection.execute(
+            "INSERT INTO DestinyInventoryItemDefinition (id, json) VALUES (?1, ?2)",
+            rusqlite::params![*hash as i32, format!("{{\"hash\": {hash}}}")],
+        )?;
+    }
+    drop(connection);
+
+    let mut visited = Vec::new();
+    SqliteManifest::open(&path)?.iter_table(&items(), |hash, definition: serde_json::Value| {
+        assert_eq!(definition["hash"], hash);
+        visited.push(hash);
+        Ok(())
+    })?;
+    std::fs::remove_file(&path)?;
+
+    let mut expected = expected;
+    expected.sort_unstable();
+    visited.sort_unstable();
+    assert_eq!(visited, expected, "every row exactly once");
+    Ok(())
+}
+
+#[test]
+fn provides_definitions() -> anyhow::Result<()> {
+    let manifest = manifest()?;
+    let provider: &dyn DefinitionProvider = &manifest;
+
+    let definition = provider.definition::<DestinyInventoryItemDefinition>(3487922223)?;
+    assert_eq!(definition.map(|d| d.hash), Some(3487922223));
+    assert!(provider.definition::<DestinyInventoryItemDefinition>(42)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn rejects_bad_table_names() -> anyhow::Result<()> {
+    let manifest = manifest()?;
+    assert!(manifest.get::<serde_json::Value>(&DefinitionTable::new("x\"; DROP TABLE y; --"), 1).is_err());
+    assert!(
+        manifest.get::<serde_json::Value>(&DefinitionTable::new("DestinyHistoricalStatsDefinition"), 1).is_err(),
+        "string-keyed tables have no id column"
+    );
+    assert!(SqliteManifest::open("/nonexistent/world_sql_content.sqlite3").is_err());
+    Ok(())
+}
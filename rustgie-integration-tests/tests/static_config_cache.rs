@@ -0,0 +1,119 @@
+﻿use rustgie::{FixedClock, RustgieClient};
+use rustgie_integration_tests::{success_envelope, TEST_API_KEY};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use time::macros::datetime;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BANNER_PATH: &str = "/Platform/Destiny2/Clan/ClanBannerDictionary/";
+
+fn banner_source() -> Value {
+    json!({
+        "clanBannerDecals": {
+            "4142223378": { "foregroundPath": "/img/clanbanner/decal_fg_01.png", "backgroundPath": "/img/clanbanner/decal_bg_01.png" }
+        },
+        "clanBannerDecalPrimaryColors": {
+            "3379387794": { "red": 255, "green": 255, "blue": 255, "alpha": 255 }
+        },
+        "clanBannerGonfalons": { "1473910866": "/img/clanbanner/gonfalon_01.png" },
+        "clanBannerGonfalonDetails": {
+            "1047925327": { "foregroundPath": "/img/clanbanner/detail_fg_01.png", "backgroundPath": "/img/clanbanner/detail_bg_01.png" }
+        }
+    })
+}
+
+async fn mount_banner_source(server: &MockServer, times: u64) {
+    Mock::given(method("GET"))
+        .and(path(BANNER_PATH))
+        .respond_with(success_envelope(banner_source()))
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+fn client_with_ttl(server: &MockServer, ttl: Duration) -> anyhow::Result<RustgieClient> {
+    RustgieClient::builder()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_static_config_ttl(ttl)
+        .build()
+}
+
+#[tokio::test]
+async fn second_call_within_ttl_is_served_from_the_cache() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_banner_source(&server, 1).await;
+    let client = client_with_ttl(&server, Duration::from_secs(60 * 60))?;
+
+    let first = client.clan_banner_source_cached().await?;
+    let second = client.clan_banner_source_cached().await?;
+
+    assert_eq!(first, second);
+    let decal = &first.clan_banner_decals.as_ref().unwrap()[&4142223378];
+    assert_eq!(decal.foreground_path.as_deref(), Some("/img/clanbanner/decal_fg_01.png"));
+    assert_eq!(first.clan_banner_decal_primary_colors.as_ref().unwrap()[&3379387794].alpha, 255);
+    assert_eq!(
+        first.clan_banner_gonfalons.as_ref().unwrap()[&1473910866],
+        "/img/clanbanner/gonfalon_01.png"
+    );
+    assert!(first.clan_banner_standards.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn uncached_endpoint_and_clearing_bypass_the_cache() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_banner_source(&server, 3).await;
+    let client = client_with_ttl(&server, Duration::from_secs(60 * 60))?;
+
+    client.clan_banner_source_cached().await?;
+    client.destiny2_get_clan_banner_source(None).await?;
+    client.clan_banner_source_cached().await?;
+    client.clear_static_config_cache();
+    client.clan_banner_source_cached().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_entries_are_fetched_again() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    mount_banner_source(&server, 2).await;
+    let clock = Arc::new(FixedClock::new(datetime!(2023-06-14 02:00:00 UTC)));
+    let client = RustgieClient::builder()
+        .with_api_key(TEST_API_KEY)
+        .with_base_url(&server.uri())
+        .dangerously_allow_http(true)
+        .with_static_config_ttl(Duration::from_secs(60 * 60))
+        .with_clock(clock.clone())
+        .build()?;
+
+    client.clan_banner_source_cached().await?;
+    clock.advance(Duration::from_secs(59 * 60));
+    client.clan_banner_source_cached().await?;
+    assert_eq!(server.received_requests().await.expect("recording enabled").len(), 1, "still within the TTL");
+
+    clock.advance(Duration::from_secs(60));
+    client.clan_banner_source_cached().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_fetches_are_not_cached() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(BANNER_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_banner_source(&server, 1).await;
+    let client = client_with_ttl(&server, Duration::from_secs(60 * 60))?;
+
+    assert!(client.clan_banner_source_cached().await.is_err());
+    assert!(client.clan_banner_source_cached().await?.clan_banner_decals.is_some());
+    Ok(())
+}
@@ -0,0 +1,90 @@
+﻿use rustgie::types::streaming::DropStateEnum;
+use rustgie::types::{active_stream, GlobalAlert, GlobalAlertType};
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BUNGIE_NET_MEMBERSHIP_ID: i64 = 20000001;
+const PARTNER_APPLICATION_ID: i32 = 12345;
+
+fn fixture() -> Value {
+    serde_json::from_str(include_str!("../fixtures/global_alerts.json")).expect("valid JSON")
+}
+
+fn alerts() -> Vec<GlobalAlert> {
+    serde_json::from_value(fixture()["Response"].take()).expect("valid global alerts")
+}
+
+#[test]
+fn streaming_alert_with_a_channel_is_the_active_stream() {
+    let alerts = alerts();
+    let stream = active_stream(&alerts).expect("the fixture has a live stream");
+    assert_eq!(stream.channel_name.as_deref(), Some("bungie"), "the alert without a channel name is skipped");
+
+    let without_streams: Vec<GlobalAlert> =
+        alerts.into_iter().filter(|alert| alert.alert_type == GlobalAlertType::GlobalAlert).collect();
+    assert_eq!(active_stream(&without_streams), None);
+}
+
+#[tokio::test]
+async fn client_asks_for_streaming_alerts() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/GlobalAlerts/"))
+        .and(query_param("includestreaming", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let stream = client.active_stream().await?;
+    assert_eq!(stream.and_then(|s| s.channel_name).as_deref(), Some("bungie"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn claiming_a_drop_sends_the_offer_with_the_token() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Tokens/Partner/ClaimOffer/"))
+        .and(header("Authorization", "Bearer drops-token"))
+        .and(body_json(json!({
+            "PartnerOfferId": "twitch-drop-emblem",
+            "BungieNetMembershipId": BUNGIE_NET_MEMBERSHIP_ID.to_string(),
+            "TransactionId": "claim-0001"
+        })))
+        .respond_with(success_envelope(json!(true)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    assert!(client.claim_partner_offer("twitch-drop-emblem", BUNGIE_NET_MEMBERSHIP_ID, "claim-0001", "drops-token").await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_history_has_typed_claim_states() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/Platform/Tokens/Partner/History/{BUNGIE_NET_MEMBERSHIP_ID}/Application/{PARTNER_APPLICATION_ID}/")))
+        .and(header("Authorization", "Bearer drops-token"))
+        .respond_with(success_envelope(json!({
+            "PartnerOffers": [],
+            "TwitchDrops": [
+                { "Title": "Emblem drop", "Description": "Watch 30 minutes", "CreatedAt": "2026-10-13T17:30:00Z", "ClaimState": 2 },
+                { "Title": "Shader drop", "Description": "Watch 60 minutes", "CreatedAt": "2026-10-13T18:00:00Z", "ClaimState": 0 },
+                { "Title": "Ghost shell drop", "Description": "Watch 90 minutes", "CreatedAt": "2026-10-13T18:30:00Z", "ClaimState": null }
+            ]
+        })))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let drops = client.twitch_drop_history(PARTNER_APPLICATION_ID, BUNGIE_NET_MEMBERSHIP_ID, "drops-token").await?;
+    let states: Vec<Option<DropStateEnum>> = drops.iter().map(|drop| drop.claim_state).collect();
+    assert_eq!(states, [Some(DropStateEnum::Fulfilled), Some(DropStateEnum::Claimed), None]);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+﻿use rustgie::types::destiny::components::records::{DestinyCharacterRecordsComponent, DestinyProfileRecordsComponent};
+use rustgie::types::destiny::definitions::records::{active_title, DestinyRecordDefinition, ResolvedTitle};
+use rustgie::types::destiny::entities::characters::DestinyCharacterComponent;
+use rustgie::types::destiny::DestinyRecordState;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const CONQUEROR: u32 = 1983630873;
+const CONQUEROR_GILDING: u32 = 3145627334;
+const FLAWLESS: u32 = 2126152885;
+
+struct Account {
+    character: DestinyCharacterComponent,
+    profile_records: DestinyProfileRecordsComponent,
+    character_records: DestinyCharacterRecordsComponent,
+}
+
+fn fixture(account: &str) -> anyhow::Result<(HashMap<u32, DestinyRecordDefinition>, Account)> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/titles.json"))?;
+    let account = &fixture[account];
+    Ok((
+        serde_json::from_value(fixture["recordDefinitions"].clone())?,
+        Account {
+            character: serde_json::from_value(account["character"].clone())?,
+            profile_records: serde_json::from_value(account["profileRecords"].clone())?,
+            character_records: serde_json::from_value(account["characterRecords"].clone())?,
+        },
+    ))
+}
+
+#[test]
+fn gilded_title_equipped() -> anyhow::Result<()> {
+    let (defs, account) = fixture("gilded")?;
+
+    let title = active_title(&account.character, &account.profile_records, Some(&account.character_records), &defs);
+
+    assert_eq!(
+        title,
+        Some(ResolvedTitle { record_hash: CONQUEROR, name: "Conqueror", gilded: true, gild_count: 3 })
+    );
+    Ok(())
+}
+
+#[test]
+fn no_title_equipped() -> anyhow::Result<()> {
+    let (defs, account) = fixture("untitled")?;
+
+    assert_eq!(active_title(&account.character, &account.profile_records, Some(&account.character_records), &defs), None);
+    Ok(())
+}
+
+#[test]
+fn gilding_not_completed_this_season_keeps_the_count() -> anyhow::Result<()> {
+    let (defs, mut account) = fixture("gilded")?;
+    let gilding = account.profile_records.records.as_mut().unwrap().get_mut(&CONQUEROR_GILDING).unwrap();
+    gilding.state = DestinyRecordState::ObjectiveNotCompleted.into();
+    gilding.completed_count = Some(2);
+
+    let title = active_title(&account.character, &account.profile_records, None, &defs).expect("title is equipped");
+
+    assert_eq!((title.gilded, title.gild_count), (false, 2));
+    Ok(())
+}
+
+#[test]
+fn gilding_record_may_be_on_the_character() -> anyhow::Result<()> {
+    let (defs, mut account) = fixture("gilded")?;
+    let gilding = account.profile_records.records.as_mut().unwrap().remove(&CONQUEROR_GILDING).unwrap();
+    account.character_records.records.as_mut().unwrap().insert(CONQUEROR_GILDING, gilding);
+
+    let title = active_title(&account.character, &account.profile_records, Some(&account.character_records), &defs).expect("title is equipped");
+    assert_eq!((title.gilded, title.gild_count), (true, 3));
+
+    let without_character_records = active_title(&account.character, &account.profile_records, None, &defs).expect("title is equipped");
+    assert_eq!((without_character_records.gilded, without_character_records.gild_count), (false, 0));
+    Ok(())
+}
+
+#[test]
+fn records_without_a_title_resolve_to_none() -> anyhow::Result<()> {
+    let (defs, mut account) = fixture("gilded")?;
+
+    account.character.title_record_hash = Some(FLAWLESS);
+    assert_eq!(active_title(&account.character, &account.profile_records, None, &defs), None);
+
+    account.character.title_record_hash = Some(1);
+    assert_eq!(active_title(&account.character, &account.profile_records, None, &defs), None);
+    Ok(())
+}
@@ -0,0 +1,118 @@
+﻿use rustgie_integration_tests::type_changes::diff_specs;
+use rustgie_types::{changes, ChangeKind, ChangeTarget, TypeChange, CHANGES};
+use serde_json::{json, Value};
+
+fn spec(schemas: Value) -> Value {
+    json!({ "openapi": "3.0.0", "components": { "schemas": schemas } })
+}
+
+fn enum_schema(values: &[(&str, &str)]) -> Value {
+    json!({
+        "type": "integer",
+        "format": "int32",
+        "enum": values.iter().map(|(_, value)| *value).collect::<Vec<_>>(),
+        "x-enum-values": values.iter().map(|(identifier, value)| json!({ "numericValue": value, "identifier": identifier })).collect::<Vec<_>>()
+    })
+}
+
+fn previous() -> Value {
+    spec(json!({
+        "Destiny.DestinyComponentType": enum_schema(&[("None", "0"), ("Profiles", "100"), ("Kiosks", "500")]),
+        "Destiny.Definitions.DestinyItemValueBlockDefinition": {
+            "type": "object",
+            "properties": {
+                "itemValue": { "type": "array", "items": { "$ref": "#/components/schemas/Destiny.DestinyItemQuantity" } },
+                "valueDescription": { "type": "string" }
+            }
+        },
+        "Destiny.Definitions.DestinyLegacyBlock": { "type": "object", "properties": {} },
+        "Destiny.DestinyItemQuantity": {
+            "type": "object",
+            "properties": { "itemHash": { "type": "integer", "format": "uint32" }, "quantity": { "type": "integer", "format": "int32" } }
+        }
+    }))
+}
+
+fn current() -> Value {
+    spec(json!({
+        "Destiny.DestinyComponentType": enum_schema(&[("None", "0"), ("Profiles", "100"), ("Kiosks", "501"), ("Craftables", "1300")]),
+        "Destiny.Definitions.DestinyItemValueBlockDefinition": {
+            "type": "object",
+            "properties": {
+                "itemValue": { "type": "array", "items": { "$ref": "#/components/schemas/Destiny.DestinyItemQuantity" } },
+                "valueDescription": { "type": "string", "nullable": true },
+                "valueHashes": {
+                    "type": "object",
+                    "additionalProperties": { "type": "integer", "format": "int32" },
+                    "x-dictionary-key": { "type": "integer", "format": "uint32" }
+                }
+            }
+        },
+        "Destiny.DestinyItemQuantity": {
+            "type": "object",
+            "properties": { "itemHash": { "type": "integer", "format": "uint32" }, "quantity": { "type": "integer", "format": "int64" } }
+        },
+        "Destiny.DestinyNewThing": enum_schema(&[("None", "0")])
+    }))
+}
+
+fn entry(kind: ChangeKind, target: ChangeTarget, type_name: &str, member: Option<&str>, before: Option<&str>, after: Option<&str>) -> TypeChange {
+    TypeChange {
+        kind,
+        target,
+        type_name: type_name.to_string(),
+        member: member.map(str::to_string),
+        before: before.map(str::to_string),
+        after: after.map(str::to_string),
+    }
+}
+
+#[test]
+fn embedded_changes_parse() {
+    let parsed: Vec<TypeChange> = serde_json::from_str(CHANGES).expect("types-changes.json parses into type changes");
+    assert_eq!(changes(), parsed.as_slice());
+}
+
+#[test]
+fn identical_specs_have_no_changes() -> anyhow::Result<()> {
+    assert!(diff_specs(&current(), &current())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn changed_specs_list_types_fields_and_variants() -> anyhow::Result<()> {
+    use ChangeKind::{Added, Changed, Removed};
+    use ChangeTarget::{EnumVariant, Field, Type};
+
+    let changes = diff_specs(&previous(), &current())?;
+    assert_eq!(
+        changes,
+        [
+            entry(Changed, Field, "Destiny.Definitions.DestinyItemValueBlockDefinition", Some("valueDescription"), Some("string"), Some("string?")),
+            entry(Added, Field, "Destiny.Definitions.DestinyItemValueBlockDefinition", Some("valueHashes"), None, Some("{integer:uint32: integer:int32}")),
+            entry(Removed, Type, "Destiny.Definitions.DestinyLegacyBlock", None, None, None),
+            entry(Added, EnumVariant, "Destiny.DestinyComponentType", Some("Craftables"), None, Some("1300")),
+            entry(Changed, EnumVariant, "Destiny.DestinyComponentType", Some("Kiosks"), Some("500"), Some("501")),
+            entry(Changed, Field, "Destiny.DestinyItemQuantity", Some("quantity"), Some("integer:int32"), Some("integer:int64")),
+            entry(Added, Type, "Destiny.DestinyNewThing", None, None, None),
+        ]
+    );
+
+    // What the types_changes tool writes has to be what `rustgie_types::changes()` reads.
+    let written = serde_json::to_string_pretty(&changes)?;
+    let read: Vec<TypeChange> = serde_json::from_str(&written)?;
+    assert_eq!(read, changes);
+    assert_eq!(serde_json::from_str::<Value>(&written)?[4]["target"], "enumVariant");
+    Ok(())
+}
+
+#[test]
+fn a_type_switching_between_object_and_enum_is_one_change() -> anyhow::Result<()> {
+    let before = spec(json!({ "Destiny.DestinyFlip": { "type": "object", "properties": { "a": { "type": "string" } } } }));
+    let after = spec(json!({ "Destiny.DestinyFlip": enum_schema(&[("A", "1")]) }));
+    assert_eq!(
+        diff_specs(&before, &after)?,
+        [entry(ChangeKind::Changed, ChangeTarget::Type, "Destiny.DestinyFlip", None, Some("object"), Some("enum"))]
+    );
+    Ok(())
+}
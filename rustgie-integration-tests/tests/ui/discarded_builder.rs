@@ -0,0 +1,6 @@
+﻿#![deny(unused_must_use)]
+
+fn main() {
+    let builder = rustgie::RustgieClientBuilder::new();
+    builder.with_api_key("key");
+}
@@ -0,0 +1,7 @@
+﻿#![deny(unused_must_use)]
+
+async fn locales(client: &rustgie::RustgieClient) {
+    client.get_available_locales(None);
+}
+
+fn main() {}
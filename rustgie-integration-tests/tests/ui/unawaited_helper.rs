@@ -0,0 +1,7 @@
+﻿#![deny(unused_must_use)]
+
+async fn check(client: &rustgie::RustgieClient) {
+    client.verify_api_key();
+}
+
+fn main() {}
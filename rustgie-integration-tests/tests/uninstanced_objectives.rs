@@ -0,0 +1,44 @@
+﻿use rustgie::types::destiny::responses::uninstanced_objectives_for;
+use rustgie::types::destiny::DestinyComponentType;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::replay;
+
+const MEMBERSHIP_ID: i64 = 4611686018400000001;
+const CHARACTER_ID: i64 = 2305843009300000001;
+const BOUNTY_HASH: u32 = 2232443426;
+
+#[tokio::test]
+async fn bounty_objectives_are_keyed_by_character_and_hash() -> anyhow::Result<()> {
+    let (_server, client) = replay("profile_uninstanced_bounty").await?;
+    let profile = client
+        .destiny2_get_profile(
+            MEMBERSHIP_ID,
+            BungieMembershipType::TigerSteam,
+            Some(vec![DestinyComponentType::CharacterInventories, DestinyComponentType::ItemObjectives]),
+            None,
+        )
+        .await?;
+
+    let bounty = profile
+        .character_inventories
+        .as_ref()
+        .and_then(|c| c.data.as_ref())
+        .and_then(|c| c.get(&CHARACTER_ID))
+        .and_then(|inventory| inventory.items.as_ref())
+        .and_then(|items| items.iter().find(|item| item.item_hash == BOUNTY_HASH))
+        .expect("the bounty is in the character's inventory");
+    assert_eq!(bounty.item_instance_id, None, "bounties aren't instanced, so itemComponents can't have them");
+
+    let objectives = uninstanced_objectives_for(&profile, CHARACTER_ID, BOUNTY_HASH).expect("objectives for the bounty");
+    let progress: Vec<(u32, Option<i32>, bool)> = objectives
+        .objectives
+        .iter()
+        .flatten()
+        .map(|o| (o.objective_hash, o.progress, o.complete))
+        .collect();
+    assert_eq!(progress, [(3062403435, Some(7), false), (2815312155, Some(1), true)]);
+
+    assert!(uninstanced_objectives_for(&profile, CHARACTER_ID, 1363886209).is_none(), "instanced items aren't in this component");
+    assert!(uninstanced_objectives_for(&profile, 2305843009300000002, BOUNTY_HASH).is_none());
+    Ok(())
+}
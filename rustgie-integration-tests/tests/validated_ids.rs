@@ -0,0 +1,86 @@
+﻿use rustgie::types::destiny::entities::characters::{CharacterId, DestinyCharacterActivitiesComponent};
+use rustgie::types::groups_v2::GroupId;
+use rustgie_integration_tests::{client_for, success_envelope};
+use serde_json::json;
+use std::collections::HashMap;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer};
+
+#[test]
+fn parses_valid_ids() -> anyhow::Result<()> {
+    let group = GroupId::try_from("4321")?;
+    assert_eq!(group.get(), 4321);
+    assert_eq!(group, "4321".parse()?);
+    assert_eq!(GroupId::new(4321), Some(group));
+
+    let character = CharacterId::try_from("2305843009300000001")?;
+    assert_eq!(i64::from(character), 2305843009300000001);
+    assert_eq!(character.to_string(), "2305843009300000001");
+    assert_eq!(CharacterId::try_from(i64::MAX.to_string().as_str())?.get(), i64::MAX);
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_ids() {
+    for input in ["0", "-1", "-2305843009300000001", "9223372036854775808", "", " 12", "12a", "1.5"] {
+        assert!(GroupId::try_from(input).is_err(), "{input:?} was accepted as a group ID");
+        assert!(CharacterId::try_from(input).is_err(), "{input:?} was accepted as a character ID");
+    }
+    for id in [0, -1, i64::MIN] {
+        assert_eq!(GroupId::new(id), None);
+        assert_eq!(CharacterId::new(id), None);
+    }
+
+    let error = GroupId::try_from("0").unwrap_err().to_string();
+    assert!(error.contains("must be positive"), "{error}");
+    let error = CharacterId::try_from("abc").unwrap_err().to_string();
+    assert!(error.contains("'abc'"), "{error}");
+}
+
+#[test]
+fn serializes_as_strings_and_rejects_invalid_json() {
+    assert_eq!(serde_json::to_value(GroupId::from(4321)).unwrap(), json!("4321"));
+    assert_eq!(serde_json::from_value::<GroupId>(json!(4321)).unwrap().get(), 4321);
+    assert_eq!(serde_json::from_value::<CharacterId>(json!("17")).unwrap().get(), 17);
+    for invalid in [json!("0"), json!(-3), json!(u64::MAX), json!(1.5), json!(null)] {
+        assert!(serde_json::from_value::<CharacterId>(invalid.clone()).is_err(), "{invalid} was accepted");
+    }
+}
+
+#[test]
+fn character_maps_round_trip_with_id_keys() -> anyhow::Result<()> {
+    let data = json!({
+        "2305843009300000001": { "dateActivityStarted": "2024-01-02T03:04:05Z", "availableActivities": [], "currentActivityHash": 0, "currentActivityModeHash": 0, "currentPlaylistActivityHash": null, "lastCompletedStoryHash": 0 },
+        "2305843009300000002": { "dateActivityStarted": "2024-01-02T03:04:05Z", "availableActivities": [], "currentActivityHash": 1, "currentActivityModeHash": 0, "currentPlaylistActivityHash": null, "lastCompletedStoryHash": 0 }
+    });
+
+    let map: HashMap<CharacterId, DestinyCharacterActivitiesComponent> = serde_json::from_value(data.clone())?;
+    let second = CharacterId::new(2305843009300000002).unwrap();
+    assert_eq!(map[&second].current_activity_hash, 1);
+
+    let reparsed: HashMap<CharacterId, DestinyCharacterActivitiesComponent> = serde_json::from_str(&serde_json::to_string(&map)?)?;
+    assert_eq!(reparsed, map);
+    let raw: HashMap<i64, DestinyCharacterActivitiesComponent> = serde_json::from_value(data)?;
+    assert!(raw.keys().all(|id| map.contains_key(&CharacterId::from(*id))));
+
+    let invalid_key = json!({ "0": map[&second] });
+    assert!(serde_json::from_value::<HashMap<CharacterId, DestinyCharacterActivitiesComponent>>(invalid_key).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn endpoints_accept_ids_and_plain_integers() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/Platform/Fireteam/Clan/4321/ActiveCount/"))
+        .respond_with(success_envelope(json!(2)))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let group: GroupId = "4321".parse()?;
+    assert_eq!(client.fireteam_get_active_private_clan_fireteam_count(group, None).await?, 2);
+    assert_eq!(client.fireteam_get_active_private_clan_fireteam_count(4321, None).await?, 2);
+    Ok(())
+}
@@ -0,0 +1,96 @@
+﻿use rustgie::types::destiny::definitions::vendors::{current_location, ResolvedLocation};
+use rustgie::types::destiny::definitions::{DestinyDestinationDefinition, DestinyPlaceDefinition, DestinyVendorDefinition};
+use rustgie::types::destiny::entities::vendors::DestinyVendorComponent;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const XUR: u32 = 2190858386;
+const RAHOOL: u32 = 3361454721;
+const EDZ: u32 = 1199524104;
+const NESSUS: u32 = 3607432451;
+
+struct Definitions {
+    vendors: HashMap<u32, DestinyVendorDefinition>,
+    destinations: HashMap<u32, DestinyDestinationDefinition>,
+    places: HashMap<u32, DestinyPlaceDefinition>,
+}
+
+fn table<T: DeserializeOwned>(fixture: &Value, name: &str) -> anyhow::Result<HashMap<u32, T>> {
+    Ok(serde_json::from_value(fixture[name].clone())?)
+}
+
+fn definitions() -> anyhow::Result<Definitions> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/vendor_locations.json"))?;
+    Ok(Definitions {
+        vendors: table(&fixture, "vendors")?,
+        destinations: table(&fixture, "destinations")?,
+        places: table(&fixture, "places")?,
+    })
+}
+
+fn live(vendor_hash: u32, vendor_location_index: i32) -> DestinyVendorComponent {
+    serde_json::from_value(json!({
+        "canPurchase": true,
+        "vendorLocationIndex": vendor_location_index,
+        "vendorHash": vendor_hash,
+        "nextRefreshDate": "2023-06-16T17:00:00Z",
+        "enabled": true
+    }))
+    .expect("valid vendor component")
+}
+
+#[test]
+fn xur_moves_between_destinations() -> anyhow::Result<()> {
+    let defs = definitions()?;
+    let xur = &defs.vendors[&XUR];
+
+    let edz = current_location(&live(XUR, 1), xur, &defs.destinations, &defs.places);
+    assert_eq!(
+        edz,
+        Some(ResolvedLocation {
+            destination_hash: EDZ,
+            destination_name: Some("European Dead Zone"),
+            place_name: Some("Earth"),
+            background_image: Some("/common/destiny2_content/icons/xur_edz.jpg"),
+        })
+    );
+
+    let nessus = current_location(&live(XUR, 2), xur, &defs.destinations, &defs.places).expect("Xûr is somewhere");
+    assert_eq!((nessus.destination_hash, nessus.place_name), (NESSUS, Some("Nessus")));
+    assert_eq!(nessus.background_image, None, "empty image paths are treated as missing");
+    Ok(())
+}
+
+#[test]
+fn out_of_range_indexes_resolve_to_nothing() -> anyhow::Result<()> {
+    let defs = definitions()?;
+    let xur = &defs.vendors[&XUR];
+
+    assert_eq!(current_location(&live(XUR, 3), xur, &defs.destinations, &defs.places), None);
+    assert_eq!(current_location(&live(XUR, -1), xur, &defs.destinations, &defs.places), None);
+    Ok(())
+}
+
+#[test]
+fn static_tower_vendor_has_no_location() -> anyhow::Result<()> {
+    let defs = definitions()?;
+
+    let rahool = current_location(&live(RAHOOL, 0), &defs.vendors[&RAHOOL], &defs.destinations, &defs.places);
+
+    assert_eq!(rahool, None);
+    Ok(())
+}
+
+#[test]
+fn missing_definitions_leave_names_empty() -> anyhow::Result<()> {
+    let defs = definitions()?;
+
+    let no_destinations = HashMap::new();
+
+    let location =
+        current_location(&live(XUR, 1), &defs.vendors[&XUR], &no_destinations, &defs.places).expect("located");
+
+    assert_eq!((location.destination_hash, location.destination_name, location.place_name), (EDZ, None, None));
+    Ok(())
+}
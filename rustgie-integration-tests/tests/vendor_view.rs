@@ -0,0 +1,115 @@
+﻿use rustgie::types::destiny::responses::{DestinyPublicVendorsResponse, DestinyVendorResponse};
+use rustgie::types::destiny::vendors::VendorView;
+use rustgie::types::destiny::VendorItemStatus;
+use serde_json::Value;
+
+const RAHOOL: u32 = 3361454721;
+const GLIMMER: u32 = 3159615086;
+
+fn responses() -> anyhow::Result<(DestinyPublicVendorsResponse, DestinyVendorResponse)> {
+    let fixture: Value = serde_json::from_str(include_str!("../fixtures/vendor_sales.json"))?;
+    Ok((serde_json::from_value(fixture["public"].clone())?, serde_json::from_value(fixture["authed"].clone())?))
+}
+
+fn indexes(view: &VendorView<'_>) -> Vec<i32> {
+    view.sales.iter().map(|sale| sale.vendor_item_index).collect()
+}
+
+#[test]
+fn public_view_has_no_character_detail() -> anyhow::Result<()> {
+    let (public, _) = responses()?;
+    let view = VendorView::from_public(&public, RAHOOL).expect("Rahool is in the public response");
+
+    assert_eq!(view.vendor_hash, RAHOOL);
+    assert!(view.enabled);
+    assert_eq!(view.categories.len(), 2);
+    assert_eq!(indexes(&view), vec![0, 1, 2]);
+    assert_eq!(view.sales[0].costs[0].item_hash, GLIMMER);
+    assert_eq!(view.sales[0].costs[0].quantity, 10000);
+
+    assert_eq!(view.can_purchase, None);
+    assert!(view.progression.is_none());
+    assert_eq!(view.seasonal_rank, None);
+    assert_eq!(view.vendor_location_index, None);
+    for sale in &view.sales {
+        assert!(sale.personal.is_none());
+        assert_eq!(sale.character_costs(), None);
+        assert_eq!(sale.sale_status(), None);
+        assert_eq!(sale.failure_indexes(), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn public_view_of_a_missing_vendor_is_none() -> anyhow::Result<()> {
+    let (public, _) = responses()?;
+    assert!(VendorView::from_public(&public, 2190858386).is_none());
+    Ok(())
+}
+
+#[test]
+fn authed_view_has_character_detail() -> anyhow::Result<()> {
+    let (_, authed) = responses()?;
+    let view = VendorView::from_authed(&authed).expect("vendor component was requested");
+
+    assert_eq!(view.vendor_hash, RAHOOL);
+    assert_eq!(view.can_purchase, Some(true));
+    assert_eq!(view.progression.map(|progression| progression.level), Some(6));
+    assert_eq!(view.seasonal_rank, Some(6));
+    assert_eq!(view.vendor_location_index, Some(0));
+    assert_eq!(indexes(&view), vec![0, 1, 3]);
+
+    let discounted = &view.sales[0];
+    assert_eq!(discounted.costs[0].quantity, 7500);
+    assert_eq!(discounted.character_costs().map(|costs| costs[0].quantity), Some(7500));
+    assert_eq!(discounted.sale_status().map(|status| status.is_empty()), Some(true));
+    assert_eq!(discounted.failure_indexes(), Some(&[][..]));
+
+    let unaffordable = &view.sales[1];
+    assert_eq!(unaffordable.sale_status().map(|status| status.contains(VendorItemStatus::NoFunds)), Some(true));
+    assert_eq!(unaffordable.failure_indexes(), Some(&[3][..]));
+    Ok(())
+}
+
+#[test]
+fn authed_view_without_vendor_component_is_none() -> anyhow::Result<()> {
+    let (_, mut authed) = responses()?;
+    authed.vendor = None;
+    assert!(VendorView::from_authed(&authed).is_none());
+    Ok(())
+}
+
+#[test]
+fn merge_overlays_character_detail_on_public_sales() -> anyhow::Result<()> {
+    let (public, authed) = responses()?;
+    let public_view = VendorView::from_public(&public, RAHOOL).expect("public view");
+    let authed_view = VendorView::from_authed(&authed).expect("authed view");
+    let view = VendorView::merge(public_view.clone(), authed_view);
+
+    assert_eq!(view.can_purchase, Some(true));
+    assert_eq!(view.seasonal_rank, Some(6));
+    assert_eq!(view.vendor_location_index, Some(0));
+    assert_eq!(view.categories, public_view.categories);
+    // The public skeleton is kept: the character-only sale 3 is left out.
+    assert_eq!(indexes(&view), vec![0, 1, 2]);
+
+    let discounted = &view.sales[0];
+    assert_eq!(discounted.costs[0].quantity, 10000);
+    assert_eq!(discounted.character_costs().map(|costs| costs[0].quantity), Some(7500));
+    assert_eq!(view.sales[1].failure_indexes(), Some(&[3][..]));
+    // Sale 2 isn't in the character's response, so it has no detail to lay over it.
+    assert!(view.sales[2].personal.is_none());
+    Ok(())
+}
+
+#[test]
+fn merge_of_different_vendors_keeps_public() -> anyhow::Result<()> {
+    let (public, mut authed) = responses()?;
+    if let Some(vendor) = authed.vendor.as_mut().and_then(|vendor| vendor.data.as_mut()) {
+        vendor.vendor_hash = 2190858386;
+    }
+    let public_view = VendorView::from_public(&public, RAHOOL).expect("public view");
+    let authed_view = VendorView::from_authed(&authed).expect("authed view");
+    assert_eq!(VendorView::merge(public_view.clone(), authed_view), public_view);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+﻿use rustgie::types::destiny::requests::actions::{DestinyItemActionRequest, DestinyItemStateRequest};
+use rustgie::types::destiny::requests::DestinyItemTransferRequest;
+use rustgie::types::BungieMembershipType;
+use rustgie_integration_tests::{client_for, replay};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ITEM_ID: i64 = 6917529000000000001;
+const CHARACTER_ID: i64 = 2305843009300000001;
+
+fn equip_request() -> DestinyItemActionRequest {
+    DestinyItemActionRequest {
+        item_id: ITEM_ID,
+        character_id: CHARACTER_ID,
+        membership_type: BungieMembershipType::TigerSteam,
+    }
+}
+
+#[tokio::test]
+async fn transfer_success() -> anyhow::Result<()> {
+    let (_server, client) = replay("transfer_item_success").await?;
+    client
+        .destiny2_transfer_item(
+            DestinyItemTransferRequest {
+                item_reference_hash: 2907129557,
+                stack_size: 1,
+                transfer_to_vault: true,
+                item_id: ITEM_ID,
+                character_id: CHARACTER_ID,
+                membership_type: BungieMembershipType::TigerSteam,
+            },
+            Some("access-token"),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn equip_success_with_and_without_response() -> anyhow::Result<()> {
+    let (_server, client) = replay("equip_item_success").await?;
+    client.destiny2_equip_item(equip_request(), Some("access-token")).await?;
+    client.destiny2_equip_item(equip_request(), Some("access-token")).await
+}
+
+#[tokio::test]
+async fn empty_body_success() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/SetLockState/"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    client
+        .destiny2_set_item_lock_state(
+            DestinyItemStateRequest {
+                state: true,
+                item_id: ITEM_ID,
+                character_id: CHARACTER_ID,
+                membership_type: BungieMembershipType::TigerSteam,
+            },
+            Some("access-token"),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn action_errors_still_surface() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/Platform/Destiny2/Actions/Items/EquipItem/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ErrorCode": 1671,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "DestinyCannotPerformActionAtThisLocation",
+            "Message": "You cannot perform this action at your current location.",
+            "MessageData": {}
+        })))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    assert!(client.destiny2_equip_item(equip_request(), Some("access-token")).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn data_endpoints_still_require_a_response() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ErrorCode": 1,
+            "ThrottleSeconds": 0,
+            "ErrorStatus": "Success",
+            "Message": "Ok",
+            "MessageData": {}
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/Platform/GetAvailableLocales/"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let client = client_for(&server)?;
+
+    let missing = client.get_available_locales(None).await.expect_err("no Response");
+    assert_eq!(missing.to_string(), "The Bungie API did not include a response");
+    assert!(client.get_available_locales(None).await.is_err(), "an empty body has no data either");
+    Ok(())
+}
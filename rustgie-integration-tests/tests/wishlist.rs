@@ -0,0 +1,120 @@
+﻿use rustgie::types::destiny::components::items::DestinyItemReusablePlugsComponent;
+use rustgie::types::destiny::entities::items::DestinyItemSocketsComponent;
+use rustgie::types::destiny::items::wishlist::{entries_for, matches, parse_wishlist, MatchResult, WishlistEntry};
+use rustgie::types::destiny::items::{resolve_sockets, ResolvedSocket};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const FATEBRINGER: u32 = 2171478765;
+const OTHER_ITEM: u32 = 4255268456;
+const ARROWHEAD_BRAKE: u32 = 839105230;
+const TACTICAL_MAG: u32 = 1087426260;
+const EXPLOSIVE_PAYLOAD: u32 = 3038247973;
+const FIREFLY: u32 = 3824105627;
+const ENHANCED_FIREFLY: u32 = 2896038713;
+const OPENING_SHOT: u32 = 47981717;
+const RAMPAGE: u32 = 3425386926;
+const KILL_CLIP: u32 = 1015611457;
+const OUTLAW: u32 = 2420895100;
+
+fn wishlist() -> Vec<WishlistEntry> {
+    parse_wishlist(include_str!("../fixtures/wishlist.txt"))
+}
+
+fn rolls() -> Value {
+    serde_json::from_str(include_str!("../fixtures/wishlist_rolls.json")).expect("valid JSON")
+}
+
+fn roll(name: &str) -> anyhow::Result<Vec<ResolvedSocket>> {
+    let roll = &rolls()[name];
+    let sockets: DestinyItemSocketsComponent = serde_json::from_value(roll["sockets"].clone())?;
+    let plugs: DestinyItemReusablePlugsComponent = serde_json::from_value(roll["reusablePlugs"].clone())?;
+    Ok(resolve_sockets(&sockets, Some(&plugs)))
+}
+
+fn enhanced_perks() -> HashMap<u32, u32> {
+    serde_json::from_value(rolls()["enhancedPerks"].clone()).expect("valid mapping")
+}
+
+fn entry(entries: &[WishlistEntry], item_hash: Option<u32>, trash: bool) -> &WishlistEntry {
+    entries.iter().find(|e| e.item_hash == item_hash && e.trash == trash).expect("entry is in the wish list")
+}
+
+#[test]
+fn parses_entries_and_skips_everything_else() {
+    let entries = wishlist();
+
+    let summary: Vec<(Option<u32>, bool, usize)> =
+        entries.iter().map(|e| (e.item_hash, e.trash, e.desired_perk_sets.len())).collect();
+    assert_eq!(summary, vec![(Some(FATEBRINGER), false, 2), (Some(FATEBRINGER), true, 1), (None, false, 1), (Some(OTHER_ITEM), false, 1)]);
+
+    let fatebringer = entry(&entries, Some(FATEBRINGER), false);
+    assert_eq!(fatebringer.desired_perk_sets[0], vec![ARROWHEAD_BRAKE, TACTICAL_MAG, EXPLOSIVE_PAYLOAD, FIREFLY]);
+    assert_eq!(fatebringer.notes, vec!["PvE god roll", "PvP roll"]);
+    assert_eq!(entry(&entries, Some(FATEBRINGER), true).desired_perk_sets, vec![vec![KILL_CLIP, OUTLAW]]);
+    assert_eq!(entry(&entries, Some(OTHER_ITEM), false).desired_perk_sets, vec![Vec::<u32>::new()]);
+}
+
+#[test]
+fn entries_for_an_item_include_the_wildcard() {
+    let entries = wishlist();
+
+    let hashes: Vec<(Option<u32>, bool)> = entries_for(&entries, FATEBRINGER).map(|e| (e.item_hash, e.trash)).collect();
+    assert_eq!(hashes, vec![(Some(FATEBRINGER), false), (Some(FATEBRINGER), true), (None, false)]);
+    assert_eq!(entries_for(&entries, 1).count(), 1);
+}
+
+#[test]
+fn resolves_inserted_and_reusable_plugs() -> anyhow::Result<()> {
+    let sockets = roll("godRoll")?;
+
+    assert_eq!(sockets.len(), 6);
+    assert_eq!(sockets[4].plug_hash, Some(ENHANCED_FIREFLY));
+    assert_eq!(sockets[4].plug_hashes(), vec![ENHANCED_FIREFLY, RAMPAGE]);
+    assert_eq!((sockets[5].plug_hash, sockets[5].reusable_plug_hashes.is_empty()), (None, true));
+    Ok(())
+}
+
+#[test]
+fn enhanced_perks_count_as_their_base_perk() -> anyhow::Result<()> {
+    let entries = wishlist();
+    let sockets = roll("godRoll")?;
+    let fatebringer = entry(&entries, Some(FATEBRINGER), false);
+
+    let result = matches(fatebringer, &sockets, &enhanced_perks());
+    assert_eq!(
+        result,
+        MatchResult {
+            matched: true,
+            matched_perks: vec![ARROWHEAD_BRAKE, TACTICAL_MAG, EXPLOSIVE_PAYLOAD, FIREFLY],
+            missing_perks: vec![],
+        }
+    );
+    assert!(matches(entry(&entries, None, false), &sockets, &enhanced_perks()).matched);
+
+    // Without the mapping the enhanced perk is a different hash, but the PvP set can still be rolled.
+    let exact = matches(fatebringer, &sockets, &HashMap::new());
+    assert_eq!(exact.matched_perks, vec![ARROWHEAD_BRAKE, TACTICAL_MAG, OPENING_SHOT, RAMPAGE]);
+    assert!(!matches(entry(&entries, None, false), &sockets, &HashMap::new()).matched);
+    Ok(())
+}
+
+#[test]
+fn partial_match_reports_the_closest_set() -> anyhow::Result<()> {
+    let entries = wishlist();
+    let sockets = roll("partialRoll")?;
+
+    let result = matches(entry(&entries, Some(FATEBRINGER), false), &sockets, &enhanced_perks());
+
+    assert_eq!(
+        result,
+        MatchResult {
+            matched: false,
+            matched_perks: vec![ARROWHEAD_BRAKE, TACTICAL_MAG, EXPLOSIVE_PAYLOAD],
+            missing_perks: vec![FIREFLY],
+        }
+    );
+    assert!(matches(entry(&entries, Some(FATEBRINGER), true), &sockets, &enhanced_perks()).matched, "it's a trash roll");
+    assert!(matches(entry(&entries, Some(OTHER_ITEM), false), &sockets, &enhanced_perks()).matched);
+    Ok(())
+}
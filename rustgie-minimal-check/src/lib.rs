@@ -0,0 +1,6 @@
+﻿#![forbid(unsafe_code)]
+
+//! Depends on `rustgie_types` with only the `minimal` feature, so `cargo build -p rustgie-minimal-check` checks that subset compiles without the rest of the crate.
+//! Building the whole workspace unifies features and turns `full` back on; `rustgie-integration-tests` checks this crate's dependency tree on its own.
+
+pub use rustgie_types::minimal;
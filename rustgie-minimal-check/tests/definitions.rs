@@ -0,0 +1,107 @@
+﻿use rustgie_minimal_check::minimal::{
+    DamageType, DestinyClass, DestinyInventoryItemDefinition, DestinyItemSubType, DestinyItemType, DestinyManifest, DestinySeasonDefinition, TierType,
+};
+use serde_json::json;
+
+#[test]
+fn reads_an_item_definition_ignoring_fields_outside_the_subset() -> Result<(), serde_json::Error> {
+    let definition: DestinyInventoryItemDefinition = serde_json::from_value(json!({
+        "displayProperties": { "description": "", "name": "Gjallarhorn", "icon": "/common/destiny2_content/icons/gjallarhorn.jpg", "hasIcon": true },
+        "itemTypeDisplayName": "Rocket Launcher",
+        "inventory": {
+            "maxStackSize": 1, "bucketTypeHash": 953998645, "recoveryBucketTypeHash": 215593132, "tierTypeHash": 2759499571_u32,
+            "isInstanceItem": true, "tierTypeName": "Exotic", "tierType": 6, "suppressExpirationWhenObjectivesComplete": true
+        },
+        "stats": {
+            "disablePrimaryStatDisplay": false, "statGroupHash": 1338927646, "hasDisplayableStats": true, "primaryBaseStatHash": 1935470627,
+            "stats": { "4284893193": { "statHash": 4284893193_u32, "value": 15, "minimum": 0, "maximum": 0, "displayMaximum": 100 } }
+        },
+        "equippingBlock": { "uniqueLabel": "exotic_weapon", "uniqueLabelHash": 2361869208_u32, "equipmentSlotTypeHash": 953998645, "attributes": 0, "ammoType": 3 },
+        "sockets": {
+            "detail": "",
+            "socketEntries": [{
+                "socketTypeHash": 3956125808_u32, "singleInitialItemHash": 3044093306_u32, "reusablePlugItems": [{ "plugItemHash": 3044093306_u32 }],
+                "preventInitializationOnVendorPurchase": false, "hidePerksInItemTooltip": false, "plugSources": 6, "defaultVisible": true
+            }],
+            "intrinsicSockets": [],
+            "socketCategories": [{ "socketCategoryHash": 4241085061_u32, "socketIndexes": [0] }]
+        },
+        "talentGrid": { "talentGridHash": 0, "itemDetailString": "", "hudDamageType": 0 },
+        "allowActions": true,
+        "nonTransferrable": false,
+        "itemCategoryHashes": [2, 1, 13],
+        "specialItemType": 0,
+        "itemType": 3,
+        "itemSubType": 10,
+        "classType": 3,
+        "breakerType": 0,
+        "equippable": true,
+        "defaultDamageType": 3,
+        "isWrapper": false,
+        "hash": 1363886209,
+        "index": 4012,
+        "redacted": false,
+        "blacklisted": false
+    }))?;
+
+    assert_eq!(definition.display_properties.and_then(|d| d.name).as_deref(), Some("Gjallarhorn"));
+    assert_eq!(definition.item_type, DestinyItemType::Weapon);
+    assert_eq!(definition.item_sub_type, DestinyItemSubType::RocketLauncher);
+    assert_eq!(definition.class_type, DestinyClass::Unknown);
+    assert_eq!(definition.default_damage_type, DamageType::Thermal);
+    assert_eq!(definition.inventory.map(|i| i.tier_type), Some(TierType::Exotic));
+    assert_eq!(definition.stats.and_then(|s| s.stats).map(|s| s[&4284893193].value), Some(15));
+    let sockets = definition.sockets.unwrap();
+    assert_eq!(sockets.socket_entries.unwrap()[0].plug_sources, 6);
+    Ok(())
+}
+
+#[test]
+fn unknown_item_types_fall_back_to_the_default() -> Result<(), serde_json::Error> {
+    let definition: DestinyInventoryItemDefinition = serde_json::from_value(json!({
+        "nonTransferrable": false, "itemType": 9000, "itemSubType": 9000, "classType": 3, "equippable": false,
+        "defaultDamageType": 0, "isWrapper": false, "hash": 1, "index": 0, "redacted": false
+    }))?;
+    assert_eq!(definition.item_type, DestinyItemType::default());
+    assert_eq!(definition.item_sub_type, DestinyItemSubType::default());
+    Ok(())
+}
+
+#[test]
+fn keeps_dates_and_ids_as_bungie_sent_them() -> Result<(), serde_json::Error> {
+    let season: DestinySeasonDefinition = serde_json::from_value(json!({
+        "displayProperties": { "description": "", "name": "Season of the Wish", "hasIcon": false },
+        "seasonNumber": 23,
+        "startDate": "2023-11-28T17:00:00Z",
+        "endDate": "2024-06-04T17:00:00Z",
+        "seasonPassHash": 3813563328_u32,
+        "hash": 2758726572_u32,
+        "index": 23,
+        "redacted": false
+    }))?;
+    assert_eq!(season.start_date.as_deref(), Some("2023-11-28T17:00:00Z"));
+    assert_eq!(season.end_date.as_deref(), Some("2024-06-04T17:00:00Z"));
+
+    let quantity: rustgie_minimal_check::minimal::DestinyItemQuantity = serde_json::from_value(json!({
+        "itemHash": 1363886209, "itemInstanceId": "6917529862012347520", "quantity": 1, "hasConditionalVisibility": false
+    }))?;
+    assert_eq!(quantity.item_instance_id, Some(6917529862012347520));
+    assert_eq!(serde_json::to_value(&quantity)?["itemInstanceId"], "6917529862012347520");
+    Ok(())
+}
+
+#[test]
+fn reads_the_manifest_content_paths() -> Result<(), serde_json::Error> {
+    let manifest: DestinyManifest = serde_json::from_value(json!({
+        "version": "228744.24.06.05.1730-1-bnet.55712",
+        "mobileAssetContentPath": "/common/destiny2_content/sqlite/asset/asset_sql_content.content",
+        "jsonWorldComponentContentPaths": {
+            "en": { "DestinyInventoryItemDefinition": "/common/destiny2_content/json/en/DestinyInventoryItemDefinition.json" }
+        }
+    }))?;
+    assert_eq!(
+        manifest.json_world_component_content_paths.unwrap()["en"]["DestinyInventoryItemDefinition"],
+        "/common/destiny2_content/json/en/DestinyInventoryItemDefinition.json"
+    );
+    Ok(())
+}
@@ -0,0 +1,119 @@
+﻿//! Activity history across all of an account's characters.
+
+use crate::cancellation::{CancellationToken, Outcome};
+use crate::RustgieClient;
+use anyhow::{Context, Result};
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use rustgie_types::destiny::entities::characters::CharacterId;
+use rustgie_types::destiny::historical_stats::definitions::DestinyActivityModeType;
+use rustgie_types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup;
+use rustgie_types::BungieMembershipType;
+use std::collections::VecDeque;
+
+/// The most activities Bungie returns in one page of history.
+const MAX_PAGE_SIZE: usize = 250;
+
+/// How many characters' first pages are requested at once.
+const FIRST_PAGE_CONCURRENCY: usize = 3;
+
+/// One character's history, read a page at a time as the merge reaches its end.
+struct Cursor {
+    character_id: CharacterId,
+    next_page: i32,
+    pending: VecDeque<DestinyHistoricalStatsPeriodGroup>,
+    exhausted: bool,
+}
+
+impl Cursor {
+    fn new(character_id: CharacterId, first_page: Vec<DestinyHistoricalStatsPeriodGroup>, page_size: usize) -> Cursor {
+        Cursor {
+            character_id,
+            next_page: 1,
+            exhausted: first_page.len() < page_size,
+            pending: first_page.into(),
+        }
+    }
+}
+
+impl RustgieClient {
+    /// The account's `count` most recent activities across `character_ids`, newest first, each tagged with the character that played it.
+    ///
+    /// Bungie only keeps history per character, so this reads every character's first page (a few at a time) and merges them by period.
+    /// A character's next page is only requested once the merge has used up the page before it and still needs more.
+    ///
+    /// `cancellation` is checked before each page is requested. Cancelled while the first pages load, the history is empty,
+    /// since nothing can be ordered before every character has been heard from; after that, it's the activities merged so far.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn account_activity_history(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        character_ids: &[CharacterId],
+        mode: Option<DestinyActivityModeType>,
+        count: usize,
+        cancellation: &CancellationToken,
+    ) -> Result<Outcome<Vec<(CharacterId, DestinyHistoricalStatsPeriodGroup)>>> {
+        if count == 0 {
+            return Ok(Outcome::Completed(Vec::new()));
+        }
+        let page_size = count.min(MAX_PAGE_SIZE);
+        let page = |character_id: CharacterId, page: i32| {
+            self.activity_history_page(membership_type, membership_id, character_id, mode, page_size, page)
+        };
+
+        let first_pages: Vec<Option<Cursor>> = stream::iter(character_ids)
+            .map(|character_id| async move {
+                if cancellation.is_cancelled() {
+                    return Ok::<_, anyhow::Error>(None);
+                }
+                Ok(Some(Cursor::new(*character_id, page(*character_id, 0).await?, page_size)))
+            })
+            .buffered(FIRST_PAGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        let mut cursors: Vec<Cursor> = match first_pages.into_iter().collect() {
+            Some(cursors) => cursors,
+            None => return Ok(Outcome::Cancelled(Vec::new())),
+        };
+
+        let mut merged = Vec::with_capacity(count);
+        while merged.len() < count {
+            for cursor in cursors.iter_mut().filter(|cursor| cursor.pending.is_empty() && !cursor.exhausted) {
+                if cancellation.is_cancelled() {
+                    return Ok(Outcome::Cancelled(merged));
+                }
+                let activities = page(cursor.character_id, cursor.next_page).await?;
+                cursor.next_page += 1;
+                cursor.exhausted = activities.len() < page_size;
+                cursor.pending = activities.into();
+            }
+
+            // Ties go to the character listed first.
+            let newest = cursors
+                .iter_mut()
+                .filter(|cursor| !cursor.pending.is_empty())
+                .reduce(|newest, cursor| if cursor.pending[0].period > newest.pending[0].period { cursor } else { newest });
+            match newest.and_then(|cursor| Some((cursor.character_id, cursor.pending.pop_front()?))) {
+                Some(entry) => merged.push(entry),
+                None => break,
+            }
+        }
+        Ok(Outcome::Completed(merged))
+    }
+
+    async fn activity_history_page(
+        &self,
+        membership_type: BungieMembershipType,
+        membership_id: i64,
+        character_id: CharacterId,
+        mode: Option<DestinyActivityModeType>,
+        page_size: usize,
+        page: i32,
+    ) -> Result<Vec<DestinyHistoricalStatsPeriodGroup>> {
+        let results = self
+            .destiny2_get_activity_history(character_id, membership_id, membership_type, Some(page_size as i32), mode, Some(page), None)
+            .await
+            .with_context(|| format!("Could not fetch page {page} of character {character_id}'s activity history"))?;
+        Ok(results.activities.unwrap_or_default())
+    }
+}
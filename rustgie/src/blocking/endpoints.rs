@@ -0,0 +1,558 @@
+﻿#![allow(clippy::too_many_arguments)]
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+impl super::RustgieClient {
+    pub fn get_available_locales(&self, access_token: Option<&str>) -> Result<HashMap<String, String>> {
+        self.block_on(self.inner.get_available_locales(access_token))
+    }
+
+    pub fn get_common_settings(&self, access_token: Option<&str>) -> Result<rustgie_types::common::models::CoreSettingsConfiguration> {
+        self.block_on(self.inner.get_common_settings(access_token))
+    }
+
+    pub fn get_global_alerts(&self, includestreaming: Option<bool>, access_token: Option<&str>) -> Result<Vec<rustgie_types::GlobalAlert>> {
+        self.block_on(self.inner.get_global_alerts(includestreaming, access_token))
+    }
+
+    pub fn get_user_system_overrides(&self, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::common::models::CoreSystem>> {
+        self.block_on(self.inner.get_user_system_overrides(access_token))
+    }
+
+    pub fn app_get_application_api_usage(&self, application_id: i32, end: Option<time::OffsetDateTime>, start: Option<time::OffsetDateTime>, access_token: Option<&str>) -> Result<rustgie_types::applications::ApiUsage> {
+        self.block_on(self.inner.app_get_application_api_usage(application_id, end, start, access_token))
+    }
+
+    pub fn app_get_bungie_applications(&self, access_token: Option<&str>) -> Result<Vec<rustgie_types::applications::Application>> {
+        self.block_on(self.inner.app_get_bungie_applications(access_token))
+    }
+
+    pub fn community_content_get_community_content(&self, media_filter: rustgie_types::forum::ForumTopicsCategoryFiltersEnum, page: i32, sort: rustgie_types::forum::CommunityContentSortMode, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.community_content_get_community_content(media_filter, page, sort, access_token))
+    }
+
+    pub fn content_get_content_by_id(&self, id: i64, locale: &str, head: Option<bool>, access_token: Option<&str>) -> Result<rustgie_types::content::ContentItemPublicContract> {
+        self.block_on(self.inner.content_get_content_by_id(id, locale, head, access_token))
+    }
+
+    pub fn content_get_content_by_tag_and_type(&self, locale: &str, tag: &str, r#type: &str, head: Option<bool>, access_token: Option<&str>) -> Result<rustgie_types::content::ContentItemPublicContract> {
+        self.block_on(self.inner.content_get_content_by_tag_and_type(locale, tag, r#type, head, access_token))
+    }
+
+    pub fn content_get_content_type(&self, r#type: &str, access_token: Option<&str>) -> Result<rustgie_types::content::models::ContentTypeDescription> {
+        self.block_on(self.inner.content_get_content_type(r#type, access_token))
+    }
+
+    pub fn content_rss_news_articles(&self, page_token: &str, categoryfilter: Option<&str>, includebody: Option<bool>, access_token: Option<&str>) -> Result<rustgie_types::content::NewsArticleRssResponse> {
+        self.block_on(self.inner.content_rss_news_articles(page_token, categoryfilter, includebody, access_token))
+    }
+
+    pub fn content_search_content_by_tag_and_type(&self, locale: &str, tag: &str, r#type: &str, currentpage: Option<i32>, head: Option<bool>, itemsperpage: Option<i32>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfContentItemPublicContract> {
+        self.block_on(self.inner.content_search_content_by_tag_and_type(locale, tag, r#type, currentpage, head, itemsperpage, access_token))
+    }
+
+    pub fn content_search_content_with_text(&self, locale: &str, ctype: Option<&str>, currentpage: Option<i32>, head: Option<bool>, searchtext: Option<&str>, source: Option<&str>, tag: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfContentItemPublicContract> {
+        self.block_on(self.inner.content_search_content_with_text(locale, ctype, currentpage, head, searchtext, source, tag, access_token))
+    }
+
+    pub fn content_search_help_articles(&self, searchtext: &str, size: &str, access_token: Option<&str>) -> Result<rustgie_types::destiny::definitions::DestinyDefinition> {
+        self.block_on(self.inner.content_search_help_articles(searchtext, size, access_token))
+    }
+
+    pub fn destiny2_awa_get_action_token(&self, correlation_id: &str, access_token: Option<&str>) -> Result<rustgie_types::destiny::advanced::AwaAuthorizationResult> {
+        self.block_on(self.inner.destiny2_awa_get_action_token(correlation_id, access_token))
+    }
+
+    pub fn destiny2_awa_initialize_request(&self, request_body: rustgie_types::destiny::advanced::AwaPermissionRequested, access_token: Option<&str>) -> Result<rustgie_types::destiny::advanced::AwaInitializeResponse> {
+        self.block_on(self.inner.destiny2_awa_initialize_request(request_body, access_token))
+    }
+
+    pub fn destiny2_awa_provide_authorization_result(&self, request_body: rustgie_types::destiny::advanced::AwaUserResponse, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.destiny2_awa_provide_authorization_result(request_body, access_token))
+    }
+
+    pub fn destiny2_clear_loadout(&self, request_body: rustgie_types::destiny::requests::actions::DestinyLoadoutActionRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_clear_loadout(request_body, access_token))
+    }
+
+    pub fn destiny2_equip_item(&self, request_body: rustgie_types::destiny::requests::actions::DestinyItemActionRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_equip_item(request_body, access_token))
+    }
+
+    pub fn destiny2_equip_items(&self, request_body: rustgie_types::destiny::requests::actions::DestinyItemSetActionRequest, access_token: Option<&str>) -> Result<rustgie_types::destiny::DestinyEquipItemResults> {
+        self.block_on(self.inner.destiny2_equip_items(request_body, access_token))
+    }
+
+    pub fn destiny2_equip_loadout(&self, request_body: rustgie_types::destiny::requests::actions::DestinyLoadoutActionRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_equip_loadout(request_body, access_token))
+    }
+
+    pub fn destiny2_get_activity_history(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, count: Option<i32>, mode: Option<rustgie_types::destiny::historical_stats::definitions::DestinyActivityModeType>, page: Option<i32>, access_token: Option<&str>) -> Result<rustgie_types::destiny::historical_stats::DestinyActivityHistoryResults> {
+        self.block_on(self.inner.destiny2_get_activity_history(character_id, destiny_membership_id, membership_type, count, mode, page, access_token))
+    }
+
+    pub fn destiny2_get_character(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyCharacterResponse> {
+        self.block_on(self.inner.destiny2_get_character(character_id, destiny_membership_id, membership_type, components, access_token))
+    }
+
+    pub fn destiny2_get_clan_aggregate_stats(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, modes: Option<&str>, access_token: Option<&str>) -> Result<Vec<rustgie_types::destiny::historical_stats::DestinyClanAggregateStat>> {
+        self.block_on(self.inner.destiny2_get_clan_aggregate_stats(group_id, modes, access_token))
+    }
+
+    pub fn destiny2_get_clan_banner_source(&self, access_token: Option<&str>) -> Result<rustgie_types::config::clan_banner::ClanBannerSource> {
+        self.block_on(self.inner.destiny2_get_clan_banner_source(access_token))
+    }
+
+    pub fn destiny2_get_clan_leaderboards(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, maxtop: Option<i32>, modes: Option<&str>, statid: impl Into<Option<rustgie_types::destiny::historical_stats::definitions::HistoricalStatId>>, access_token: Option<&str>) -> Result<HashMap<String, HashMap<String, rustgie_types::destiny::historical_stats::DestinyLeaderboard>>> {
+        self.block_on(self.inner.destiny2_get_clan_leaderboards(group_id, maxtop, modes, statid, access_token))
+    }
+
+    pub fn destiny2_get_clan_weekly_reward_state(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::destiny::milestones::DestinyMilestone> {
+        self.block_on(self.inner.destiny2_get_clan_weekly_reward_state(group_id, access_token))
+    }
+
+    pub fn destiny2_get_collectible_node_details(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, collectible_presentation_node_hash: u32, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyCollectibleNodeDetailResponse> {
+        self.block_on(self.inner.destiny2_get_collectible_node_details(character_id, collectible_presentation_node_hash, destiny_membership_id, membership_type, components, access_token))
+    }
+
+    pub fn destiny2_get_destiny_aggregate_activity_stats(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::destiny::historical_stats::DestinyAggregateActivityResults> {
+        self.block_on(self.inner.destiny2_get_destiny_aggregate_activity_stats(character_id, destiny_membership_id, membership_type, access_token))
+    }
+
+    pub fn destiny2_get_destiny_entity_definition(&self, entity_type: &str, hash_identifier: u32, access_token: Option<&str>) -> Result<rustgie_types::destiny::definitions::DestinyDefinition> {
+        self.block_on(self.inner.destiny2_get_destiny_entity_definition(entity_type, hash_identifier, access_token))
+    }
+
+    pub fn destiny2_get_destiny_manifest(&self, access_token: Option<&str>) -> Result<rustgie_types::destiny::config::DestinyManifest> {
+        self.block_on(self.inner.destiny2_get_destiny_manifest(access_token))
+    }
+
+    pub fn destiny2_get_historical_stats(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, dayend: Option<rustgie_types::dates::BungieDate>, daystart: Option<rustgie_types::dates::BungieDate>, groups: Option<Vec<rustgie_types::destiny::historical_stats::definitions::DestinyStatsGroupType>>, modes: Option<Vec<rustgie_types::destiny::historical_stats::definitions::DestinyActivityModeType>>, period_type: Option<rustgie_types::destiny::historical_stats::definitions::PeriodType>, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::destiny::historical_stats::DestinyHistoricalStatsByPeriod>> {
+        self.block_on(self.inner.destiny2_get_historical_stats(character_id, destiny_membership_id, membership_type, dayend, daystart, groups, modes, period_type, access_token))
+    }
+
+    pub fn destiny2_get_historical_stats_definition(&self, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::destiny::historical_stats::definitions::DestinyHistoricalStatsDefinition>> {
+        self.block_on(self.inner.destiny2_get_historical_stats_definition(access_token))
+    }
+
+    pub fn destiny2_get_historical_stats_for_account(&self, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, groups: Option<Vec<rustgie_types::destiny::historical_stats::definitions::DestinyStatsGroupType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::historical_stats::DestinyHistoricalStatsAccountResult> {
+        self.block_on(self.inner.destiny2_get_historical_stats_for_account(destiny_membership_id, membership_type, groups, access_token))
+    }
+
+    pub fn destiny2_get_item(&self, destiny_membership_id: i64, item_instance_id: i64, membership_type: rustgie_types::BungieMembershipType, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyItemResponse> {
+        self.block_on(self.inner.destiny2_get_item(destiny_membership_id, item_instance_id, membership_type, components, access_token))
+    }
+
+    pub fn destiny2_get_leaderboards(&self, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, maxtop: Option<i32>, modes: Option<&str>, statid: impl Into<Option<rustgie_types::destiny::historical_stats::definitions::HistoricalStatId>>, access_token: Option<&str>) -> Result<HashMap<String, HashMap<String, rustgie_types::destiny::historical_stats::DestinyLeaderboard>>> {
+        self.block_on(self.inner.destiny2_get_leaderboards(destiny_membership_id, membership_type, maxtop, modes, statid, access_token))
+    }
+
+    pub fn destiny2_get_leaderboards_for_character(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, maxtop: Option<i32>, modes: Option<&str>, statid: impl Into<Option<rustgie_types::destiny::historical_stats::definitions::HistoricalStatId>>, access_token: Option<&str>) -> Result<HashMap<String, HashMap<String, rustgie_types::destiny::historical_stats::DestinyLeaderboard>>> {
+        self.block_on(self.inner.destiny2_get_leaderboards_for_character(character_id, destiny_membership_id, membership_type, maxtop, modes, statid, access_token))
+    }
+
+    pub fn destiny2_get_linked_profiles(&self, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, get_all_memberships: Option<bool>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyLinkedProfilesResponse> {
+        self.block_on(self.inner.destiny2_get_linked_profiles(membership_id, membership_type, get_all_memberships, access_token))
+    }
+
+    pub fn destiny2_get_post_game_carnage_report(&self, activity_id: impl Into<rustgie_types::destiny::historical_stats::ActivityInstanceId>, access_token: Option<&str>) -> Result<rustgie_types::destiny::historical_stats::DestinyPostGameCarnageReportData> {
+        self.block_on(self.inner.destiny2_get_post_game_carnage_report(activity_id, access_token))
+    }
+
+    pub fn destiny2_get_profile(&self, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyProfileResponse> {
+        self.block_on(self.inner.destiny2_get_profile(destiny_membership_id, membership_type, components, access_token))
+    }
+
+    pub fn destiny2_get_public_milestone_content(&self, milestone_hash: u32, access_token: Option<&str>) -> Result<rustgie_types::destiny::milestones::DestinyMilestoneContent> {
+        self.block_on(self.inner.destiny2_get_public_milestone_content(milestone_hash, access_token))
+    }
+
+    pub fn destiny2_get_public_milestones(&self, access_token: Option<&str>) -> Result<HashMap<u32, rustgie_types::destiny::milestones::DestinyPublicMilestone>> {
+        self.block_on(self.inner.destiny2_get_public_milestones(access_token))
+    }
+
+    pub fn destiny2_get_public_vendors(&self, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyPublicVendorsResponse> {
+        self.block_on(self.inner.destiny2_get_public_vendors(components, access_token))
+    }
+
+    pub fn destiny2_get_unique_weapon_history(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::destiny::historical_stats::DestinyHistoricalWeaponStatsData> {
+        self.block_on(self.inner.destiny2_get_unique_weapon_history(character_id, destiny_membership_id, membership_type, access_token))
+    }
+
+    pub fn destiny2_get_vendor(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, vendor_hash: u32, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyVendorResponse> {
+        self.block_on(self.inner.destiny2_get_vendor(character_id, destiny_membership_id, membership_type, vendor_hash, components, access_token))
+    }
+
+    pub fn destiny2_get_vendors(&self, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, destiny_membership_id: i64, membership_type: rustgie_types::BungieMembershipType, components: Option<Vec<rustgie_types::destiny::DestinyComponentType>>, filter: Option<rustgie_types::destiny::DestinyVendorFilter>, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyVendorsResponse> {
+        self.block_on(self.inner.destiny2_get_vendors(character_id, destiny_membership_id, membership_type, components, filter, access_token))
+    }
+
+    pub fn destiny2_insert_socket_plug(&self, request_body: rustgie_types::destiny::requests::actions::DestinyInsertPlugsActionRequest, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyItemChangeResponse> {
+        self.block_on(self.inner.destiny2_insert_socket_plug(request_body, access_token))
+    }
+
+    pub fn destiny2_insert_socket_plug_free(&self, request_body: rustgie_types::destiny::requests::actions::DestinyInsertPlugsFreeActionRequest, access_token: Option<&str>) -> Result<rustgie_types::destiny::responses::DestinyItemChangeResponse> {
+        self.block_on(self.inner.destiny2_insert_socket_plug_free(request_body, access_token))
+    }
+
+    pub fn destiny2_pull_from_postmaster(&self, request_body: rustgie_types::destiny::requests::actions::DestinyPostmasterTransferRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_pull_from_postmaster(request_body, access_token))
+    }
+
+    pub fn destiny2_report_offensive_post_game_carnage_report_player(&self, activity_id: impl Into<rustgie_types::destiny::historical_stats::ActivityInstanceId>, request_body: rustgie_types::destiny::reporting::requests::DestinyReportOffensePgcrRequest, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.destiny2_report_offensive_post_game_carnage_report_player(activity_id, request_body, access_token))
+    }
+
+    pub fn destiny2_search_destiny_entities(&self, search_term: &str, r#type: &str, page: Option<i32>, access_token: Option<&str>) -> Result<rustgie_types::destiny::definitions::DestinyEntitySearchResult> {
+        self.block_on(self.inner.destiny2_search_destiny_entities(search_term, r#type, page, access_token))
+    }
+
+    pub fn destiny2_search_destiny_player_by_bungie_name(&self, membership_type: rustgie_types::BungieMembershipType, request_body: rustgie_types::user::ExactSearchRequest, access_token: Option<&str>) -> Result<Vec<rustgie_types::user::UserInfoCard>> {
+        self.block_on(self.inner.destiny2_search_destiny_player_by_bungie_name(membership_type, request_body, access_token))
+    }
+
+    pub fn destiny2_set_item_lock_state(&self, request_body: rustgie_types::destiny::requests::actions::DestinyItemStateRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_set_item_lock_state(request_body, access_token))
+    }
+
+    pub fn destiny2_set_quest_tracked_state(&self, request_body: rustgie_types::destiny::requests::actions::DestinyItemStateRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_set_quest_tracked_state(request_body, access_token))
+    }
+
+    pub fn destiny2_snapshot_loadout(&self, request_body: rustgie_types::destiny::requests::actions::DestinyLoadoutUpdateActionRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_snapshot_loadout(request_body, access_token))
+    }
+
+    pub fn destiny2_transfer_item(&self, request_body: rustgie_types::destiny::requests::DestinyItemTransferRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_transfer_item(request_body, access_token))
+    }
+
+    pub fn destiny2_update_loadout_identifiers(&self, request_body: rustgie_types::destiny::requests::actions::DestinyLoadoutUpdateActionRequest, access_token: Option<&str>) -> Result<()> {
+        self.block_on(self.inner.destiny2_update_loadout_identifiers(request_body, access_token))
+    }
+
+    pub fn fireteam_get_active_private_clan_fireteam_count(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.fireteam_get_active_private_clan_fireteam_count(group_id, access_token))
+    }
+
+    pub fn fireteam_get_available_clan_fireteams(&self, activity_type: i32, date_range: rustgie_types::fireteam::FireteamDateRange, group_id: impl Into<rustgie_types::groups_v2::GroupId>, page: i32, platform: rustgie_types::fireteam::FireteamPlatform, public_only: rustgie_types::fireteam::FireteamPublicSearchOption, slot_filter: rustgie_types::fireteam::FireteamSlotSearch, exclude_immediate: Option<bool>, lang_filter: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfFireteamSummary> {
+        self.block_on(self.inner.fireteam_get_available_clan_fireteams(activity_type, date_range, group_id, page, platform, public_only, slot_filter, exclude_immediate, lang_filter, access_token))
+    }
+
+    pub fn fireteam_get_clan_fireteam(&self, fireteam_id: i64, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::fireteam::FireteamResponse> {
+        self.block_on(self.inner.fireteam_get_clan_fireteam(fireteam_id, group_id, access_token))
+    }
+
+    pub fn fireteam_get_my_clan_fireteams(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, include_closed: bool, page: i32, platform: rustgie_types::fireteam::FireteamPlatform, group_filter: Option<bool>, lang_filter: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfFireteamResponse> {
+        self.block_on(self.inner.fireteam_get_my_clan_fireteams(group_id, include_closed, page, platform, group_filter, lang_filter, access_token))
+    }
+
+    pub fn fireteam_search_public_available_clan_fireteams(&self, activity_type: i32, date_range: rustgie_types::fireteam::FireteamDateRange, page: i32, platform: rustgie_types::fireteam::FireteamPlatform, slot_filter: rustgie_types::fireteam::FireteamSlotSearch, exclude_immediate: Option<bool>, lang_filter: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfFireteamSummary> {
+        self.block_on(self.inner.fireteam_search_public_available_clan_fireteams(activity_type, date_range, page, platform, slot_filter, exclude_immediate, lang_filter, access_token))
+    }
+
+    pub fn forum_get_core_topics_paged(&self, category_filter: impl Into<enumflags2::BitFlags<rustgie_types::forum::ForumTopicsCategoryFiltersEnum>>, page: i32, quick_date: rustgie_types::forum::ForumTopicsQuickDateEnum, sort: rustgie_types::forum::ForumTopicsSortEnum, locales: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_core_topics_paged(category_filter, page, quick_date, sort, locales, access_token))
+    }
+
+    pub fn forum_get_forum_tag_suggestions(&self, partialtag: Option<&str>, access_token: Option<&str>) -> Result<Vec<rustgie_types::tags::models::contracts::TagResponse>> {
+        self.block_on(self.inner.forum_get_forum_tag_suggestions(partialtag, access_token))
+    }
+
+    pub fn forum_get_poll(&self, topic_id: i64, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_poll(topic_id, access_token))
+    }
+
+    pub fn forum_get_post_and_parent(&self, child_post_id: i64, showbanned: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_post_and_parent(child_post_id, showbanned, access_token))
+    }
+
+    pub fn forum_get_post_and_parent_awaiting_approval(&self, child_post_id: i64, showbanned: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_post_and_parent_awaiting_approval(child_post_id, showbanned, access_token))
+    }
+
+    pub fn forum_get_posts_threaded_paged(&self, get_parent_post: bool, page: i32, page_size: i32, parent_post_id: i64, reply_size: i32, root_thread_mode: bool, sort_mode: rustgie_types::forum::ForumPostSortEnum, showbanned: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_posts_threaded_paged(get_parent_post, page, page_size, parent_post_id, reply_size, root_thread_mode, sort_mode, showbanned, access_token))
+    }
+
+    pub fn forum_get_posts_threaded_paged_from_child(&self, child_post_id: i64, page: i32, page_size: i32, reply_size: i32, root_thread_mode: bool, sort_mode: rustgie_types::forum::ForumPostSortEnum, showbanned: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_posts_threaded_paged_from_child(child_post_id, page, page_size, reply_size, root_thread_mode, sort_mode, showbanned, access_token))
+    }
+
+    pub fn forum_get_recruitment_thread_summaries(&self, request_body: Vec<i64>, access_token: Option<&str>) -> Result<Vec<rustgie_types::forum::ForumRecruitmentDetail>> {
+        self.block_on(self.inner.forum_get_recruitment_thread_summaries(request_body, access_token))
+    }
+
+    pub fn forum_get_topic_for_content(&self, content_id: i64, access_token: Option<&str>) -> Result<i64> {
+        self.block_on(self.inner.forum_get_topic_for_content(content_id, access_token))
+    }
+
+    pub fn forum_get_topics_paged(&self, category_filter: impl Into<enumflags2::BitFlags<rustgie_types::forum::ForumTopicsCategoryFiltersEnum>>, group: i64, page: i32, page_size: i32, quick_date: rustgie_types::forum::ForumTopicsQuickDateEnum, sort: rustgie_types::forum::ForumTopicsSortEnum, locales: Option<&str>, tagstring: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::forum::PostSearchResponse> {
+        self.block_on(self.inner.forum_get_topics_paged(category_filter, group, page, page_size, quick_date, sort, locales, tagstring, access_token))
+    }
+
+    pub fn group_v2_abdicate_foundership(&self, founder_id_new: i64, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.group_v2_abdicate_foundership(founder_id_new, group_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_add_optional_conversation(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupOptionalConversationAddRequest, access_token: Option<&str>) -> Result<i64> {
+        self.block_on(self.inner.group_v2_add_optional_conversation(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_approve_all_pending(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupApplicationRequest, access_token: Option<&str>) -> Result<Vec<rustgie_types::entities::EntityActionResult>> {
+        self.block_on(self.inner.group_v2_approve_all_pending(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_approve_pending(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, request_body: rustgie_types::groups_v2::GroupApplicationRequest, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.group_v2_approve_pending(group_id, membership_id, membership_type, request_body, access_token))
+    }
+
+    pub fn group_v2_approve_pending_for_list(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupApplicationListRequest, access_token: Option<&str>) -> Result<Vec<rustgie_types::entities::EntityActionResult>> {
+        self.block_on(self.inner.group_v2_approve_pending_for_list(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_ban_member(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, request_body: rustgie_types::groups_v2::GroupBanRequest, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_ban_member(group_id, membership_id, membership_type, request_body, access_token))
+    }
+
+    pub fn group_v2_deny_all_pending(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupApplicationRequest, access_token: Option<&str>) -> Result<Vec<rustgie_types::entities::EntityActionResult>> {
+        self.block_on(self.inner.group_v2_deny_all_pending(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_deny_pending_for_list(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupApplicationListRequest, access_token: Option<&str>) -> Result<Vec<rustgie_types::entities::EntityActionResult>> {
+        self.block_on(self.inner.group_v2_deny_pending_for_list(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_edit_clan_banner(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::ClanBanner, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_edit_clan_banner(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_edit_founder_options(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupOptionsEditAction, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_edit_founder_options(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_edit_group(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupEditAction, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_edit_group(group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_edit_group_membership(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, member_type: rustgie_types::groups_v2::RuntimeGroupMemberType, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_edit_group_membership(group_id, membership_id, membership_type, member_type, access_token))
+    }
+
+    pub fn group_v2_edit_optional_conversation(&self, conversation_id: i64, group_id: impl Into<rustgie_types::groups_v2::GroupId>, request_body: rustgie_types::groups_v2::GroupOptionalConversationEditRequest, access_token: Option<&str>) -> Result<i64> {
+        self.block_on(self.inner.group_v2_edit_optional_conversation(conversation_id, group_id, request_body, access_token))
+    }
+
+    pub fn group_v2_get_admins_and_founder_of_group(&self, currentpage: i32, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfGroupMember> {
+        self.block_on(self.inner.group_v2_get_admins_and_founder_of_group(currentpage, group_id, access_token))
+    }
+
+    pub fn group_v2_get_available_avatars(&self, access_token: Option<&str>) -> Result<HashMap<i32, String>> {
+        self.block_on(self.inner.group_v2_get_available_avatars(access_token))
+    }
+
+    pub fn group_v2_get_available_themes(&self, access_token: Option<&str>) -> Result<Vec<rustgie_types::config::GroupTheme>> {
+        self.block_on(self.inner.group_v2_get_available_themes(access_token))
+    }
+
+    pub fn group_v2_get_banned_members_of_group(&self, currentpage: i32, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfGroupBan> {
+        self.block_on(self.inner.group_v2_get_banned_members_of_group(currentpage, group_id, access_token))
+    }
+
+    pub fn group_v2_get_group(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupResponse> {
+        self.block_on(self.inner.group_v2_get_group(group_id, access_token))
+    }
+
+    pub fn group_v2_get_group_by_name(&self, group_name: &str, group_type: rustgie_types::groups_v2::GroupType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupResponse> {
+        self.block_on(self.inner.group_v2_get_group_by_name(group_name, group_type, access_token))
+    }
+
+    pub fn group_v2_get_group_by_name_v2(&self, request_body: rustgie_types::groups_v2::GroupNameSearchRequest, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupResponse> {
+        self.block_on(self.inner.group_v2_get_group_by_name_v2(request_body, access_token))
+    }
+
+    pub fn group_v2_get_group_optional_conversations(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<Vec<rustgie_types::groups_v2::GroupOptionalConversation>> {
+        self.block_on(self.inner.group_v2_get_group_optional_conversations(group_id, access_token))
+    }
+
+    pub fn group_v2_get_groups_for_member(&self, filter: rustgie_types::groups_v2::GroupsForMemberFilter, group_type: rustgie_types::groups_v2::GroupType, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GetGroupsForMemberResponse> {
+        self.block_on(self.inner.group_v2_get_groups_for_member(filter, group_type, membership_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_get_invited_individuals(&self, currentpage: i32, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfGroupMemberApplication> {
+        self.block_on(self.inner.group_v2_get_invited_individuals(currentpage, group_id, access_token))
+    }
+
+    pub fn group_v2_get_members_of_group(&self, currentpage: i32, group_id: impl Into<rustgie_types::groups_v2::GroupId>, member_type: Option<rustgie_types::groups_v2::RuntimeGroupMemberType>, name_search: Option<&str>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfGroupMember> {
+        self.block_on(self.inner.group_v2_get_members_of_group(currentpage, group_id, member_type, name_search, access_token))
+    }
+
+    pub fn group_v2_get_pending_memberships(&self, currentpage: i32, group_id: impl Into<rustgie_types::groups_v2::GroupId>, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfGroupMemberApplication> {
+        self.block_on(self.inner.group_v2_get_pending_memberships(currentpage, group_id, access_token))
+    }
+
+    pub fn group_v2_get_potential_groups_for_member(&self, filter: rustgie_types::groups_v2::GroupPotentialMemberStatus, group_type: rustgie_types::groups_v2::GroupType, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupPotentialMembershipSearchResponse> {
+        self.block_on(self.inner.group_v2_get_potential_groups_for_member(filter, group_type, membership_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_get_recommended_groups(&self, create_date_range: rustgie_types::groups_v2::GroupDateRange, group_type: rustgie_types::groups_v2::GroupType, access_token: Option<&str>) -> Result<Vec<rustgie_types::groups_v2::GroupV2Card>> {
+        self.block_on(self.inner.group_v2_get_recommended_groups(create_date_range, group_type, access_token))
+    }
+
+    pub fn group_v2_get_user_clan_invite_setting(&self, m_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.group_v2_get_user_clan_invite_setting(m_type, access_token))
+    }
+
+    pub fn group_v2_group_search(&self, request_body: rustgie_types::groups_v2::GroupQuery, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupSearchResponse> {
+        self.block_on(self.inner.group_v2_group_search(request_body, access_token))
+    }
+
+    pub fn group_v2_individual_group_invite(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, request_body: rustgie_types::groups_v2::GroupApplicationRequest, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupApplicationResponse> {
+        self.block_on(self.inner.group_v2_individual_group_invite(group_id, membership_id, membership_type, request_body, access_token))
+    }
+
+    pub fn group_v2_individual_group_invite_cancel(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupApplicationResponse> {
+        self.block_on(self.inner.group_v2_individual_group_invite_cancel(group_id, membership_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_kick_member(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupMemberLeaveResult> {
+        self.block_on(self.inner.group_v2_kick_member(group_id, membership_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_recover_group_for_founder(&self, group_type: rustgie_types::groups_v2::GroupType, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupMembershipSearchResponse> {
+        self.block_on(self.inner.group_v2_recover_group_for_founder(group_type, membership_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_request_group_membership(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_type: rustgie_types::BungieMembershipType, request_body: rustgie_types::groups_v2::GroupApplicationRequest, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupApplicationResponse> {
+        self.block_on(self.inner.group_v2_request_group_membership(group_id, membership_type, request_body, access_token))
+    }
+
+    pub fn group_v2_rescind_group_membership(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::groups_v2::GroupMemberLeaveResult> {
+        self.block_on(self.inner.group_v2_rescind_group_membership(group_id, membership_type, access_token))
+    }
+
+    pub fn group_v2_unban_member(&self, group_id: impl Into<rustgie_types::groups_v2::GroupId>, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<i32> {
+        self.block_on(self.inner.group_v2_unban_member(group_id, membership_id, membership_type, access_token))
+    }
+
+    pub fn ignore_ignore_item(&self, request_body: rustgie_types::ignores::IgnoreItemRequest, access_token: &str) -> Result<rustgie_types::ignores::IgnoreResponse> {
+        self.block_on(self.inner.ignore_ignore_item(request_body, access_token))
+    }
+
+    pub fn ignore_unignore_item(&self, request_body: rustgie_types::ignores::IgnoreItemRequest, access_token: &str) -> Result<rustgie_types::ignores::IgnoreResponse> {
+        self.block_on(self.inner.ignore_unignore_item(request_body, access_token))
+    }
+
+    pub fn social_accept_friend_request(&self, membership_id: &str, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.social_accept_friend_request(membership_id, access_token))
+    }
+
+    pub fn social_decline_friend_request(&self, membership_id: &str, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.social_decline_friend_request(membership_id, access_token))
+    }
+
+    pub fn social_get_friend_list(&self, access_token: Option<&str>) -> Result<rustgie_types::social::friends::BungieFriendListResponse> {
+        self.block_on(self.inner.social_get_friend_list(access_token))
+    }
+
+    pub fn social_get_friend_request_list(&self, access_token: Option<&str>) -> Result<rustgie_types::social::friends::BungieFriendRequestListResponse> {
+        self.block_on(self.inner.social_get_friend_request_list(access_token))
+    }
+
+    pub fn social_get_platform_friend_list(&self, friend_platform: rustgie_types::social::friends::PlatformFriendType, page: &str, access_token: Option<&str>) -> Result<rustgie_types::social::friends::PlatformFriendResponse> {
+        self.block_on(self.inner.social_get_platform_friend_list(friend_platform, page, access_token))
+    }
+
+    pub fn social_issue_friend_request(&self, membership_id: &str, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.social_issue_friend_request(membership_id, access_token))
+    }
+
+    pub fn social_remove_friend(&self, membership_id: &str, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.social_remove_friend(membership_id, access_token))
+    }
+
+    pub fn social_remove_friend_request(&self, membership_id: &str, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.social_remove_friend_request(membership_id, access_token))
+    }
+
+    pub fn tokens_apply_missing_partner_offers_without_claim(&self, partner_application_id: i32, target_bnet_membership_id: i64, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.tokens_apply_missing_partner_offers_without_claim(partner_application_id, target_bnet_membership_id, access_token))
+    }
+
+    pub fn tokens_claim_partner_offer(&self, request_body: rustgie_types::tokens::PartnerOfferClaimRequest, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.tokens_claim_partner_offer(request_body, access_token))
+    }
+
+    pub fn tokens_force_drops_repair(&self, access_token: Option<&str>) -> Result<bool> {
+        self.block_on(self.inner.tokens_force_drops_repair(access_token))
+    }
+
+    pub fn tokens_get_bungie_rewards_for_platform_user(&self, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::tokens::BungieRewardDisplay>> {
+        self.block_on(self.inner.tokens_get_bungie_rewards_for_platform_user(membership_id, membership_type, access_token))
+    }
+
+    pub fn tokens_get_bungie_rewards_for_user(&self, membership_id: i64, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::tokens::BungieRewardDisplay>> {
+        self.block_on(self.inner.tokens_get_bungie_rewards_for_user(membership_id, access_token))
+    }
+
+    pub fn tokens_get_bungie_rewards_list(&self, access_token: Option<&str>) -> Result<HashMap<String, rustgie_types::tokens::BungieRewardDisplay>> {
+        self.block_on(self.inner.tokens_get_bungie_rewards_list(access_token))
+    }
+
+    pub fn tokens_get_partner_offer_sku_history(&self, partner_application_id: i32, target_bnet_membership_id: i64, access_token: Option<&str>) -> Result<Vec<rustgie_types::tokens::PartnerOfferSkuHistoryResponse>> {
+        self.block_on(self.inner.tokens_get_partner_offer_sku_history(partner_application_id, target_bnet_membership_id, access_token))
+    }
+
+    pub fn tokens_get_partner_reward_history(&self, partner_application_id: i32, target_bnet_membership_id: i64, access_token: Option<&str>) -> Result<rustgie_types::tokens::PartnerRewardHistoryResponse> {
+        self.block_on(self.inner.tokens_get_partner_reward_history(partner_application_id, target_bnet_membership_id, access_token))
+    }
+
+    pub fn trending_get_trending_categories(&self, access_token: Option<&str>) -> Result<rustgie_types::trending::TrendingCategories> {
+        self.block_on(self.inner.trending_get_trending_categories(access_token))
+    }
+
+    pub fn trending_get_trending_category(&self, category_id: &str, page_number: i32, access_token: Option<&str>) -> Result<rustgie_types::SearchResultOfTrendingEntry> {
+        self.block_on(self.inner.trending_get_trending_category(category_id, page_number, access_token))
+    }
+
+    pub fn trending_get_trending_entry_detail(&self, identifier: &str, trending_entry_type: rustgie_types::trending::TrendingEntryType, access_token: Option<&str>) -> Result<rustgie_types::trending::TrendingDetail> {
+        self.block_on(self.inner.trending_get_trending_entry_detail(identifier, trending_entry_type, access_token))
+    }
+
+    pub fn user_get_available_themes(&self, access_token: Option<&str>) -> Result<Vec<rustgie_types::config::UserTheme>> {
+        self.block_on(self.inner.user_get_available_themes(access_token))
+    }
+
+    pub fn user_get_bungie_net_user_by_id(&self, id: i64, access_token: Option<&str>) -> Result<rustgie_types::user::GeneralUser> {
+        self.block_on(self.inner.user_get_bungie_net_user_by_id(id, access_token))
+    }
+
+    pub fn user_get_credential_types_for_target_account(&self, membership_id: i64, access_token: Option<&str>) -> Result<Vec<rustgie_types::user::models::GetCredentialTypesForAccountResponse>> {
+        self.block_on(self.inner.user_get_credential_types_for_target_account(membership_id, access_token))
+    }
+
+    pub fn user_get_membership_data_by_id(&self, membership_id: i64, membership_type: rustgie_types::BungieMembershipType, access_token: Option<&str>) -> Result<rustgie_types::user::UserMembershipData> {
+        self.block_on(self.inner.user_get_membership_data_by_id(membership_id, membership_type, access_token))
+    }
+
+    pub fn user_get_membership_data_for_current_user(&self, access_token: Option<&str>) -> Result<rustgie_types::user::UserMembershipData> {
+        self.block_on(self.inner.user_get_membership_data_for_current_user(access_token))
+    }
+
+    pub fn user_get_membership_from_hard_linked_credential(&self, credential: &str, cr_type: rustgie_types::BungieCredentialType, access_token: Option<&str>) -> Result<rustgie_types::user::HardLinkedUserMembership> {
+        self.block_on(self.inner.user_get_membership_from_hard_linked_credential(credential, cr_type, access_token))
+    }
+
+    pub fn user_get_sanitized_platform_display_names(&self, membership_id: i64, access_token: Option<&str>) -> Result<HashMap<u8, String>> {
+        self.block_on(self.inner.user_get_sanitized_platform_display_names(membership_id, access_token))
+    }
+
+    pub fn user_search_by_global_name_post(&self, page: i32, request_body: rustgie_types::user::UserSearchPrefixRequest, access_token: Option<&str>) -> Result<rustgie_types::user::UserSearchResponse> {
+        self.block_on(self.inner.user_search_by_global_name_post(page, request_body, access_token))
+    }
+
+    pub fn user_search_by_global_name_prefix(&self, display_name_prefix: &str, page: i32, access_token: Option<&str>) -> Result<rustgie_types::user::UserSearchResponse> {
+        self.block_on(self.inner.user_search_by_global_name_prefix(display_name_prefix, page, access_token))
+    }
+}
@@ -0,0 +1,131 @@
+﻿//! A blocking facade over [`crate::RustgieClient`], enabled by the `blocking` feature.
+//!
+//! Each client owns a Tokio runtime and blocks on the async client's futures, so it must not be used from within an async context.
+//! Calls made from inside a runtime return an error rather than panicking; use the async client there instead.
+
+mod endpoints;
+
+use crate::ApiKeyStatus;
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::{Handle, Runtime};
+
+/// A blocking Bungie.net API client with the same endpoints as the async [`crate::RustgieClient`].
+pub struct RustgieClient {
+    inner: crate::RustgieClient,
+    runtime: Option<Runtime>,
+}
+
+impl RustgieClient {
+    /// Wraps an async client, starting the runtime its calls will block on.
+    pub fn new(inner: crate::RustgieClient) -> Result<RustgieClient> {
+        let runtime = Runtime::new().with_context(|| "Could not start the blocking client's runtime")?;
+        Ok(RustgieClient {
+            inner,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// The async client this wraps, for helpers that take one.
+    pub fn inner(&self) -> &crate::RustgieClient {
+        &self.inner
+    }
+
+    pub fn now(&self) -> time::OffsetDateTime {
+        self.inner.now()
+    }
+
+    pub fn verify_api_key(&self) -> Result<ApiKeyStatus> {
+        self.block_on(self.inner.verify_api_key())
+    }
+
+    pub fn oauth_get_authorization_url_(&self, language_code: &str, state: Option<&str>) -> Result<String> {
+        self.inner.oauth_get_authorization_url_(language_code, state)
+    }
+
+    pub fn oauth_get_auth_token_(&self, auth_code: &str) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
+        self.block_on(self.inner.oauth_get_auth_token_(auth_code))
+    }
+
+    pub fn oauth_refresh_auth_token_(&self, refresh_token: &str) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
+        self.block_on(self.inner.oauth_refresh_auth_token_(refresh_token))
+    }
+
+    pub fn clan_banner_source_cached(&self) -> Result<Arc<rustgie_types::config::clan_banner::ClanBannerSource>> {
+        self.block_on(self.inner.clan_banner_source_cached())
+    }
+
+    pub fn common_settings_cached(&self) -> Result<Arc<rustgie_types::common::models::CoreSettingsConfiguration>> {
+        self.block_on(self.inner.common_settings_cached())
+    }
+
+    pub fn available_locales_cached(&self) -> Result<Arc<HashMap<String, String>>> {
+        self.block_on(self.inner.available_locales_cached())
+    }
+
+    pub fn clear_static_config_cache(&self) {
+        self.inner.clear_static_config_cache()
+    }
+
+    pub fn set_item_locked(&self, item: &mut rustgie_types::destiny::entities::items::DestinyItemComponent, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, membership_type: rustgie_types::BungieMembershipType, locked: bool, access_token: &str) -> Result<()> {
+        self.block_on(self.inner.set_item_locked(item, character_id, membership_type, locked, access_token))
+    }
+
+    pub fn set_quest_tracked(&self, item: &mut rustgie_types::destiny::entities::items::DestinyItemComponent, character_id: impl Into<rustgie_types::destiny::entities::characters::CharacterId>, membership_type: rustgie_types::BungieMembershipType, tracked: bool, access_token: &str) -> Result<()> {
+        self.block_on(self.inner.set_quest_tracked(item, character_id, membership_type, tracked, access_token))
+    }
+
+    pub fn account_activity_history(&self, membership_type: rustgie_types::BungieMembershipType, membership_id: i64, character_ids: &[rustgie_types::destiny::entities::characters::CharacterId], mode: Option<rustgie_types::destiny::historical_stats::definitions::DestinyActivityModeType>, count: usize, cancellation: &crate::CancellationToken) -> Result<crate::Outcome<Vec<(rustgie_types::destiny::entities::characters::CharacterId, rustgie_types::destiny::historical_stats::DestinyHistoricalStatsPeriodGroup)>>> {
+        self.block_on(self.inner.account_activity_history(membership_type, membership_id, character_ids, mode, count, cancellation))
+    }
+
+    pub fn active_stream(&self) -> Result<Option<rustgie_types::StreamInfo>> {
+        self.block_on(self.inner.active_stream())
+    }
+
+    pub fn claim_partner_offer(&self, partner_offer_id: &str, bungie_net_membership_id: i64, transaction_id: &str, access_token: &str) -> Result<bool> {
+        self.block_on(self.inner.claim_partner_offer(partner_offer_id, bungie_net_membership_id, transaction_id, access_token))
+    }
+
+    pub fn twitch_drop_history(&self, partner_application_id: i32, target_bnet_membership_id: i64, access_token: &str) -> Result<Vec<rustgie_types::tokens::TwitchDropHistoryResponse>> {
+        self.block_on(self.inner.twitch_drop_history(partner_application_id, target_bnet_membership_id, access_token))
+    }
+
+    fn block_on<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        if Handle::try_current().is_ok() {
+            return Err(anyhow!(
+                "The blocking client cannot be used from within an async runtime; use the async RustgieClient instead"
+            ));
+        }
+
+        self.runtime
+            .as_ref()
+            .expect("the runtime is only taken on drop")
+            .block_on(future)
+    }
+}
+
+impl Drop for RustgieClient {
+    /// Dropping a runtime from an async context panics, and a client can end up dropped there even though it can't be used there.
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl crate::RustgieClientBuilder {
+    /// Builds a [`RustgieClient`], verifying the API key first if `verify_key_on_build` is set.
+    pub fn build_blocking(self) -> Result<RustgieClient> {
+        let verify = self.verify_key_on_build;
+        let client = RustgieClient::new(self.verify_key_on_build(false).build()?)?;
+
+        if verify {
+            client.verify_api_key()?.into_result()?;
+        }
+
+        Ok(client)
+    }
+}
@@ -0,0 +1,47 @@
+﻿//! Turning response bodies into values.
+//!
+//! Now and then Bungie serves JSON, mostly static content and manifest components from its CDN, that starts with a UTF-8 byte order
+//! mark or holds bytes `serde_json` rejects. The mark is dropped; anything else is reported as a [`RustgieError::MalformedBody`]
+//! naming where it is, instead of serde's line and column.
+
+use crate::errors::RustgieError;
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// `body` without a leading byte order mark.
+pub(crate) fn strip_byte_order_mark(body: &[u8]) -> &[u8] {
+    body.strip_prefix(BYTE_ORDER_MARK).unwrap_or(body)
+}
+
+/// Parses a JSON body. With `lossy_utf8`, invalid UTF-8 sequences become U+FFFD instead of failing the whole body.
+pub(crate) fn parse_json<T: DeserializeOwned>(body: &[u8], lossy_utf8: bool) -> Result<T> {
+    let text = decode(body, lossy_utf8)?;
+    serde_json::from_str::<T>(&text).map_err(|error| match (error.classify(), first_control_byte(body)) {
+        (serde_json::error::Category::Syntax, Some((offset, byte))) => RustgieError::MalformedBody { offset, byte }.into(),
+        _ => error.into(),
+    })
+}
+
+fn decode(body: &[u8], lossy_utf8: bool) -> Result<Cow<'_, str>> {
+    let skipped = body.len() - strip_byte_order_mark(body).len();
+    let content = &body[skipped..];
+    match std::str::from_utf8(content) {
+        Ok(text) => Ok(Cow::Borrowed(text)),
+        Err(_) if lossy_utf8 => Ok(String::from_utf8_lossy(content)),
+        Err(error) => {
+            let offset = skipped + error.valid_up_to();
+            Err(RustgieError::MalformedBody { offset, byte: body[offset] }.into())
+        }
+    }
+}
+
+/// The first byte JSON never allows unescaped: a control character other than tab, line feed, or carriage return.
+/// These are all ASCII, so the offset is the same whether or not the rest of the body was decoded lossily.
+fn first_control_byte(body: &[u8]) -> Option<(usize, u8)> {
+    body.iter()
+        .position(|byte| *byte < 0x20 && !matches!(byte, b'\t' | b'\n' | b'\r'))
+        .map(|offset| (offset, body[offset]))
+}
@@ -0,0 +1,36 @@
+﻿//! Stopping long-running helpers early.
+//!
+//! Helpers that make more than one request take a [`CancellationToken`] through a `with_cancellation` option and check it between requests.
+//! A request that's already in flight is always allowed to finish, so nothing is abandoned halfway, and a cancelled run reports what it got done as [`Outcome::Cancelled`] rather than an error.
+
+pub use tokio_util::sync::CancellationToken;
+
+/// How a cancellable helper finished. Both variants carry the work that was done.
+#[must_use]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// The helper ran to the end.
+    Completed(T),
+    /// The token was cancelled first; the value holds the partial results.
+    Cancelled(T),
+}
+
+impl<T> Outcome<T> {
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Outcome::Cancelled(_))
+    }
+
+    /// The results, whether or not the run was cut short.
+    pub fn into_inner(self) -> T {
+        match self {
+            Outcome::Completed(value) | Outcome::Cancelled(value) => value,
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Outcome<U> {
+        match self {
+            Outcome::Completed(value) => Outcome::Completed(f(value)),
+            Outcome::Cancelled(value) => Outcome::Cancelled(f(value)),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+﻿//! Time sources for the client's time-dependent logic, so token refresh and throttle backoff can be tested without real clocks or real waiting.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Where the client gets the current time from.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> OffsetDateTime;
+}
+
+/// How the client waits between retries.
+pub trait Sleeper: Send + Sync {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+/// The system clock. This is the default.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// Sleeps on the tokio timer. This is the default.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A clock that only moves when told to, for tests.
+///
+/// It is also a [`Sleeper`]: sleeping returns immediately and advances the clock by the requested duration, and every sleep is recorded.
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<OffsetDateTime>,
+    sleeps: Mutex<Vec<Duration>>,
+}
+
+impl FixedClock {
+    pub fn new(now: OffsetDateTime) -> FixedClock {
+        FixedClock {
+            now: Mutex::new(now),
+            sleeps: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn set(&self, now: OffsetDateTime) {
+        *self.now.lock().expect("fixed clock poisoned") = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().expect("fixed clock poisoned");
+        *now += by;
+    }
+
+    /// Every duration passed to [`Sleeper::sleep`], in order.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.lock().expect("fixed clock poisoned").clone()
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> OffsetDateTime {
+        *self.now.lock().expect("fixed clock poisoned")
+    }
+}
+
+impl Sleeper for FixedClock {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.sleeps.lock().expect("fixed clock poisoned").push(duration);
+        self.advance(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
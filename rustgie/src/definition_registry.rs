@@ -0,0 +1,225 @@
+﻿use crate::cancellation::{CancellationToken, Outcome};
+use crate::entity_definitions::{DefinitionProvider, DefinitionTable, EntityDefinition};
+use crate::RustgieClient;
+use anyhow::{anyhow, Context, Result};
+use rustgie_types::destiny::definitions::presentation::DestinyPresentationNodeDefinition;
+use rustgie_types::destiny::definitions::records::DestinyRecordDefinition;
+use rustgie_types::destiny::definitions::{
+    DestinyActivityDefinition, DestinyInventoryItemDefinition, DestinyVendorDefinition,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// One table's definitions by hash, and the manifest path they were downloaded from.
+struct Table<T> {
+    path: Option<String>,
+    definitions: Arc<HashMap<u32, T>>,
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Table {
+            path: self.path.clone(),
+            definitions: self.definitions.clone(),
+        }
+    }
+}
+
+impl<T> Default for Table<T> {
+    fn default() -> Self {
+        Table {
+            path: None,
+            definitions: Arc::new(HashMap::new()),
+        }
+    }
+}
+
+/// One generation of the [`DefinitionRegistry`]'s tables. Every table in a snapshot comes from the same manifest version,
+/// and a snapshot never changes once taken; cloning one only clones `Arc`s.
+#[derive(Clone, Default)]
+pub struct DefinitionSnapshot {
+    generation: u64,
+    version: Option<String>,
+    locale: Option<String>,
+    inventory_items: Table<DestinyInventoryItemDefinition>,
+    activities: Table<DestinyActivityDefinition>,
+    vendors: Table<DestinyVendorDefinition>,
+    records: Table<DestinyRecordDefinition>,
+    presentation_nodes: Table<DestinyPresentationNodeDefinition>,
+}
+
+impl DefinitionSnapshot {
+    /// Counts swaps, starting from 0 for the empty registry.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The manifest version the tables were downloaded for, or `None` before the first refresh.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn inventory_items(&self) -> &HashMap<u32, DestinyInventoryItemDefinition> {
+        &self.inventory_items.definitions
+    }
+
+    pub fn activities(&self) -> &HashMap<u32, DestinyActivityDefinition> {
+        &self.activities.definitions
+    }
+
+    pub fn vendors(&self) -> &HashMap<u32, DestinyVendorDefinition> {
+        &self.vendors.definitions
+    }
+
+    pub fn records(&self) -> &HashMap<u32, DestinyRecordDefinition> {
+        &self.records.definitions
+    }
+
+    pub fn presentation_nodes(&self) -> &HashMap<u32, DestinyPresentationNodeDefinition> {
+        &self.presentation_nodes.definitions
+    }
+}
+
+/// Snapshots serve the tables they hold, so helpers taking a `&dyn DefinitionProvider` accept a `&DefinitionSnapshot`.
+/// Other tables have no definitions.
+impl DefinitionProvider for DefinitionSnapshot {
+    fn raw_definition(&self, table: &DefinitionTable, hash: u32) -> Result<Option<serde_json::Value>> {
+        fn raw<T: Serialize>(definitions: &HashMap<u32, T>, hash: u32) -> Result<Option<serde_json::Value>> {
+            definitions.get(&hash).map(serde_json::to_value).transpose().map_err(Into::into)
+        }
+
+        match table.name() {
+            DestinyInventoryItemDefinition::ENTITY_TYPE => raw(self.inventory_items(), hash),
+            DestinyActivityDefinition::ENTITY_TYPE => raw(self.activities(), hash),
+            DestinyVendorDefinition::ENTITY_TYPE => raw(self.vendors(), hash),
+            DestinyRecordDefinition::ENTITY_TYPE => raw(self.records(), hash),
+            DestinyPresentationNodeDefinition::ENTITY_TYPE => raw(self.presentation_nodes(), hash),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Parsed manifest tables shared by a long-running service, swapped for a new generation when Bungie publishes a new manifest.
+///
+/// Readers take a [`DefinitionSnapshot`], which keeps the generation it was taken from alive for as long as it's held,
+/// so a refresh never blocks or changes lookups already in flight.
+pub struct DefinitionRegistry {
+    current: RwLock<DefinitionSnapshot>,
+    refreshing: tokio::sync::Mutex<()>,
+    lossy_utf8: bool,
+    cancellation: CancellationToken,
+}
+
+impl DefinitionRegistry {
+    /// An empty registry. Its tables are filled by the first [`refresh`](DefinitionRegistry::refresh).
+    pub fn new() -> DefinitionRegistry {
+        DefinitionRegistry {
+            current: RwLock::new(DefinitionSnapshot::default()),
+            refreshing: tokio::sync::Mutex::new(()),
+            lossy_utf8: false,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Replaces invalid UTF-8 in downloaded tables with U+FFFD instead of failing the refresh.
+    /// Display strings in the manifest occasionally carry a broken sequence, and a mangled name is usually better than no tables.
+    pub fn with_lossy_utf8(mut self, lossy_utf8: bool) -> DefinitionRegistry {
+        self.lossy_utf8 = lossy_utf8;
+        self
+    }
+
+    /// Stops a refresh before its next download once `cancellation` is cancelled. A table that's already downloading is finished,
+    /// but a cancelled refresh never swaps in the tables it got.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> DefinitionRegistry {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// The current generation.
+    pub fn snapshot(&self) -> DefinitionSnapshot {
+        self.current.read().expect("definition registry poisoned").clone()
+    }
+
+    /// Fetches the manifest and, if any table's content path changed since the current generation, downloads the changed
+    /// tables in `locale` and swaps them in as one new generation. Unchanged tables are shared with the previous generation.
+    ///
+    /// Returns whether a new generation was swapped in. Concurrent refreshes run one at a time. If any download fails,
+    /// or the refresh is cancelled, nothing is swapped.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn refresh(&self, client: &RustgieClient, locale: &str) -> Result<Outcome<bool>> {
+        let _refreshing = self.refreshing.lock().await;
+        if self.cancellation.is_cancelled() {
+            return Ok(Outcome::Cancelled(false));
+        }
+        let manifest = client.destiny2_get_destiny_manifest(None).await?;
+        let paths = manifest
+            .json_world_component_content_paths
+            .as_ref()
+            .and_then(|locales| locales.get(locale))
+            .ok_or_else(|| anyhow!("The manifest has no definitions for locale {locale}"))?;
+
+        let current = self.snapshot();
+        let mut next = DefinitionSnapshot {
+            generation: current.generation + 1,
+            version: manifest.version.clone(),
+            locale: Some(locale.to_string()),
+            ..current.clone()
+        };
+        let updates = [
+            self.update(client, paths, &mut next.inventory_items).await?,
+            self.update(client, paths, &mut next.activities).await?,
+            self.update(client, paths, &mut next.vendors).await?,
+            self.update(client, paths, &mut next.records).await?,
+            self.update(client, paths, &mut next.presentation_nodes).await?,
+        ];
+        if updates.iter().any(Outcome::is_cancelled) {
+            return Ok(Outcome::Cancelled(false));
+        }
+        if !updates.contains(&Outcome::Completed(true)) {
+            return Ok(Outcome::Completed(false));
+        }
+
+        *self.current.write().expect("definition registry poisoned") = next;
+        Ok(Outcome::Completed(true))
+    }
+
+    /// Downloads `table` again if the manifest lists a different path for it, returning whether it did.
+    /// Once cancelled, it's left as it was.
+    async fn update<T: EntityDefinition>(
+        &self,
+        client: &RustgieClient,
+        paths: &HashMap<String, String>,
+        table: &mut Table<T>,
+    ) -> Result<Outcome<bool>> {
+        let path = paths
+            .get(T::ENTITY_TYPE)
+            .ok_or_else(|| anyhow!("The manifest has no content path for {}", T::ENTITY_TYPE))?;
+        if table.path.as_deref() == Some(path.as_str()) {
+            return Ok(Outcome::Completed(false));
+        }
+        if self.cancellation.is_cancelled() {
+            return Ok(Outcome::Cancelled(false));
+        }
+
+        let definitions = client
+            .bungie_content_get::<HashMap<u32, T>>(path, self.lossy_utf8)
+            .await
+            .with_context(|| format!("Could not download {}", T::ENTITY_TYPE))?;
+        *table = Table {
+            path: Some(path.clone()),
+            definitions: Arc::new(definitions),
+        };
+        Ok(Outcome::Completed(true))
+    }
+}
+
+impl Default for DefinitionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,224 @@
+﻿//! Downloading large static content files to disk, such as the zipped mobile world content database, with progress reports and resume.
+//!
+//! A download is written to `<destination>.part` and only renamed into place once complete. If it's interrupted, the partial file is kept
+//! along with the server's `ETag` in `<destination>.part.etag`, and the next download to the same destination asks for just the rest with
+//! a `Range` request. The rest is only appended when the server answers `206 Partial Content` for the same `ETag` starting where the partial
+//! file ends; anything else starts the file over. Nothing here checks the bytes themselves: for the manifest, a bad splice fails the zip's
+//! own CRC checks when it's extracted.
+
+use crate::{BungieHost, RustgieClient, RustgieError};
+use anyhow::{anyhow, Context, Result};
+use reqwest::header::{HeaderValue, ACCEPT_ENCODING, CONTENT_RANGE, ETAG, IF_RANGE, RANGE};
+use reqwest::{StatusCode, Url};
+use rustgie_types::destiny::config::DestinyManifest;
+use std::ffi::OsString;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Called as a download makes progress with the bytes of the file written so far, counting any kept from an interrupted attempt,
+/// and the file's full size when the server says.
+pub type DownloadProgress<'a> = Box<dyn FnMut(u64, Option<u64>) + Send + 'a>;
+
+/// How a finished [`ContentDownload`] went.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DownloadReport {
+    /// The size of the finished file.
+    pub bytes: u64,
+    /// How many bytes were kept from an interrupted attempt, or 0 if the file was downloaded from the start.
+    pub resumed_from: u64,
+}
+
+/// Downloads one file from Bungie's static content to disk. Made with [`RustgieClient::download_content`] or [`RustgieClient::download_world_content`].
+pub struct ContentDownload<'a> {
+    client: &'a RustgieClient,
+    path: String,
+    destination: PathBuf,
+    progress: Option<DownloadProgress<'a>>,
+}
+
+impl<'a> ContentDownload<'a> {
+    /// Reports progress to `progress` after every chunk written, and once before the first with what was kept from an interrupted attempt.
+    #[must_use]
+    pub fn with_progress(mut self, progress: impl FnMut(u64, Option<u64>) + Send + 'a) -> ContentDownload<'a> {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Downloads the file, resuming an interrupted attempt at the same destination when the server allows it.
+    /// If this download is interrupted in turn, the error is returned and the partial file is left for the next attempt.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn run(mut self) -> Result<DownloadReport> {
+        let part = with_suffix(&self.destination, ".part");
+        let etag_path = with_suffix(&self.destination, ".part.etag");
+
+        let partial = partial_download(&part, &etag_path).await;
+        let mut response = self.client.content_response(&self.path, partial.as_ref()).await?;
+        let mut resumed_from = 0;
+        if let Some((offset, etag)) = &partial {
+            if continues_from(&response, *offset, etag) {
+                resumed_from = *offset;
+            } else if response.status() != StatusCode::OK {
+                // The server sent part of something else, or refused the range; start over from a plain request.
+                response = self.client.content_response(&self.path, None).await?;
+            }
+        }
+        if !response.status().is_success() {
+            return Err(RustgieError::HttpStatus { status: response.status() }.into());
+        }
+
+        let total = match response.status() {
+            StatusCode::PARTIAL_CONTENT => content_range_total(&response),
+            _ => response.content_length(),
+        };
+        let mut file = if resumed_from > 0 {
+            tokio::fs::OpenOptions::new().append(true).open(&part).await
+        } else {
+            tokio::fs::File::create(&part).await
+        }
+        .with_context(|| format!("Could not open {}", part.display()))?;
+        if resumed_from == 0 {
+            match response.headers().get(ETAG).and_then(|etag| etag.to_str().ok()) {
+                Some(etag) => tokio::fs::write(&etag_path, etag).await?,
+                None => remove_if_present(&etag_path).await?,
+            }
+        }
+
+        let mut written = resumed_from;
+        self.report(written, total);
+        let streamed = loop {
+            match response.chunk().await {
+                Ok(Some(chunk)) => {
+                    if let Err(error) = file.write_all(&chunk).await {
+                        break Err(anyhow::Error::new(error).context(format!("Could not write to {}", part.display())));
+                    }
+                    written += chunk.len() as u64;
+                    self.report(written, total);
+                }
+                Ok(None) => break Ok(()),
+                Err(error) => {
+                    break Err(anyhow::Error::new(error)
+                        .context(format!("The download of {} was interrupted after {written} bytes", self.path)))
+                }
+            }
+        };
+        // Flush even when interrupted, so the next attempt resumes from everything that arrived.
+        file.flush().await.with_context(|| format!("Could not write to {}", part.display()))?;
+        drop(file);
+        streamed?;
+        if let Some(total) = total {
+            if written != total {
+                return Err(anyhow!("The download of {} ended after {written} of {total} bytes", self.path));
+            }
+        }
+
+        tokio::fs::rename(&part, &self.destination)
+            .await
+            .with_context(|| format!("Could not move the download to {}", self.destination.display()))?;
+        remove_if_present(&etag_path).await?;
+        Ok(DownloadReport {
+            bytes: written,
+            resumed_from,
+        })
+    }
+
+    fn report(&mut self, written: u64, total: Option<u64>) {
+        if let Some(progress) = self.progress.as_mut() {
+            progress(written, total);
+        }
+    }
+}
+
+impl RustgieClient {
+    /// Downloads the static content file at `path`, relative to `https://www.bungie.net` as given in the manifest, to `destination`.
+    #[must_use]
+    pub fn download_content(&self, path: &str, destination: impl Into<PathBuf>) -> ContentDownload<'_> {
+        ContentDownload {
+            client: self,
+            path: path.to_string(),
+            destination: destination.into(),
+            progress: None,
+        }
+    }
+
+    /// Downloads the zipped mobile world content database for `locale` from `manifest` to `destination`.
+    /// It unzips to the SQLite database `SqliteManifest` reads, with the `sqlite-manifest` feature.
+    pub fn download_world_content(
+        &self,
+        manifest: &DestinyManifest,
+        locale: &str,
+        destination: impl Into<PathBuf>,
+    ) -> Result<ContentDownload<'_>> {
+        let path = manifest
+            .mobile_world_content_paths
+            .as_ref()
+            .and_then(|paths| paths.get(locale))
+            .ok_or_else(|| anyhow!("The manifest has no mobile world content for locale {locale:?}"))?;
+        Ok(self.download_content(path, destination))
+    }
+
+    /// Requests a static content file, asking for only the bytes after `partial`'s offset when it's given.
+    async fn content_response(&self, path: &str, partial: Option<&(u64, String)>) -> Result<reqwest::Response> {
+        let url = Url::parse(&format!("{}{path}", BungieHost::Platform.origin())).with_context(|| "Error parsing URL")?;
+        let (url, host) = self.rebase_url(url)?;
+        // Byte ranges are offsets into the file as stored, so it mustn't come back compressed.
+        let mut request = self.client.get(url).header(ACCEPT_ENCODING, HeaderValue::from_static("identity"));
+        if let Some((offset, etag)) = partial {
+            request = request.header(RANGE, format!("bytes={offset}-")).header(IF_RANGE, etag.as_str());
+        }
+        let request = request.build().with_context(|| "There was an error building the request")?;
+        self.execute_content(request, host).await
+    }
+}
+
+/// The size and `ETag` of an interrupted download's partial file, if there's one worth resuming.
+async fn partial_download(part: &Path, etag_path: &Path) -> Option<(u64, String)> {
+    let offset = tokio::fs::metadata(part).await.ok()?.len();
+    let etag = tokio::fs::read_to_string(etag_path).await.ok()?;
+    if offset == 0 || etag.is_empty() {
+        return None;
+    }
+    Some((offset, etag))
+}
+
+/// Whether `response` is the rest of the same file, starting at `offset`.
+fn continues_from(response: &reqwest::Response, offset: u64, etag: &str) -> bool {
+    let headers = response.headers();
+    response.status() == StatusCode::PARTIAL_CONTENT
+        && headers.get(ETAG).and_then(|value| value.to_str().ok()) == Some(etag)
+        && headers
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|range| range.strip_prefix("bytes "))
+            .and_then(|range| range.split('-').next())
+            .and_then(|start| start.parse::<u64>().ok())
+            == Some(offset)
+}
+
+/// The full size from a `Content-Range: bytes start-end/total` header, unless the server sent `*`.
+fn content_range_total(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .rsplit('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+async fn remove_if_present(path: &Path) -> Result<()> {
+    match tokio::fs::remove_file(path).await {
+        Err(error) if error.kind() != ErrorKind::NotFound => {
+            Err(anyhow::Error::new(error).context(format!("Could not remove {}", path.display())))
+        }
+        _ => Ok(()),
+    }
+}
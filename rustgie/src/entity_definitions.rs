@@ -0,0 +1,248 @@
+﻿use crate::RustgieClient;
+use anyhow::{anyhow, Context, Result};
+use rustgie_types::destiny::definitions;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
+
+/// A definition table that can be fetched through `GetDestinyEntityDefinition`.
+pub trait EntityDefinition: serde::de::DeserializeOwned {
+    /// The entityType path segment, which is also the definition's type name.
+    const ENTITY_TYPE: &'static str;
+}
+
+macro_rules! entity_definitions {
+    ($($definition:ty => $entity_type:literal;)*) => {
+        $(impl EntityDefinition for $definition {
+            const ENTITY_TYPE: &'static str = $entity_type;
+        })*
+    };
+}
+
+entity_definitions! {
+    definitions::DestinyActivityDefinition => "DestinyActivityDefinition";
+    definitions::DestinyActivityModeDefinition => "DestinyActivityModeDefinition";
+    definitions::DestinyClassDefinition => "DestinyClassDefinition";
+    definitions::DestinyDamageTypeDefinition => "DestinyDamageTypeDefinition";
+    definitions::DestinyGenderDefinition => "DestinyGenderDefinition";
+    definitions::DestinyInventoryBucketDefinition => "DestinyInventoryBucketDefinition";
+    definitions::DestinyInventoryItemDefinition => "DestinyInventoryItemDefinition";
+    definitions::DestinyObjectiveDefinition => "DestinyObjectiveDefinition";
+    definitions::DestinyProgressionDefinition => "DestinyProgressionDefinition";
+    definitions::DestinyRaceDefinition => "DestinyRaceDefinition";
+    definitions::DestinySandboxPerkDefinition => "DestinySandboxPerkDefinition";
+    definitions::DestinyStatDefinition => "DestinyStatDefinition";
+    definitions::DestinyVendorDefinition => "DestinyVendorDefinition";
+    definitions::collectibles::DestinyCollectibleDefinition => "DestinyCollectibleDefinition";
+    definitions::milestones::DestinyMilestoneDefinition => "DestinyMilestoneDefinition";
+    definitions::presentation::DestinyPresentationNodeDefinition => "DestinyPresentationNodeDefinition";
+    definitions::records::DestinyRecordDefinition => "DestinyRecordDefinition";
+    definitions::seasons::DestinySeasonDefinition => "DestinySeasonDefinition";
+}
+
+/// A definition table by name, which is also the definition's type name, e.g. `DestinyInventoryItemDefinition`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefinitionTable(Cow<'static, str>);
+
+impl DefinitionTable {
+    pub fn new(name: impl Into<String>) -> DefinitionTable {
+        DefinitionTable(Cow::Owned(name.into()))
+    }
+
+    /// The table `T` is stored in.
+    pub fn of<T: EntityDefinition>() -> DefinitionTable {
+        DefinitionTable(Cow::Borrowed(T::ENTITY_TYPE))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for DefinitionTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Somewhere definitions can be looked up synchronously, such as a downloaded manifest.
+pub trait DefinitionProvider: Send + Sync {
+    /// One definition as raw JSON, or `None` if the table has no definition with that hash.
+    fn raw_definition(&self, table: &DefinitionTable, hash: u32) -> Result<Option<serde_json::Value>>;
+}
+
+impl dyn DefinitionProvider + '_ {
+    /// Looks a definition up and parses it into its specific definition struct.
+    pub fn definition<T: EntityDefinition>(&self, hash: u32) -> Result<Option<T>> {
+        self.raw_definition(&DefinitionTable::of::<T>(), hash)?
+            .map(|raw| {
+                serde_json::from_value::<T>(raw)
+                    .with_context(|| format!("Definition {hash} is not a valid {}", T::ENTITY_TYPE))
+            })
+            .transpose()
+    }
+}
+
+type EntityKey = (String, u32);
+
+struct CacheEntry {
+    /// The fetch time and raw definition. Concurrent misses on the same key all wait on this cell, so only one request is made.
+    cell: OnceCell<(Instant, Arc<serde_json::Value>)>,
+}
+
+/// Memoizes `GetDestinyEntityDefinition` for apps that can't ship the manifest.
+///
+/// Definitions are cached by (entityType, hash) for the configured TTL, and at most `capacity` of them are kept; when full,
+/// the oldest fetched definition is dropped first. Concurrent requests for the same uncached definition share one API call.
+/// A failed fetch is not cached, so the next caller (including callers already waiting on it) tries again.
+pub struct CachedEntityDefinitions<'a> {
+    client: &'a RustgieClient,
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<HashMap<EntityKey, Arc<CacheEntry>>>,
+}
+
+impl<'a> CachedEntityDefinitions<'a> {
+    pub fn new(client: &'a RustgieClient) -> CachedEntityDefinitions<'a> {
+        CachedEntityDefinitions {
+            client,
+            capacity: 1024,
+            ttl: Duration::from_secs(60 * 60),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The most definitions kept at once. Defaults to 1024.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> CachedEntityDefinitions<'a> {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// How long a fetched definition is reused. Defaults to one hour; definitions only change when the manifest version does.
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> CachedEntityDefinitions<'a> {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Fetches a definition and parses it into its specific definition struct.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn definition<T: EntityDefinition>(&self, hash: u32) -> Result<T> {
+        let raw = self.raw(T::ENTITY_TYPE, hash).await?;
+        serde_json::from_value::<T>((*raw).clone())
+            .with_context(|| format!("Definition {hash} is not a valid {}", T::ENTITY_TYPE))
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn inventory_item(&self, hash: u32) -> Result<definitions::DestinyInventoryItemDefinition> {
+        self.definition(hash).await
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn activity(&self, hash: u32) -> Result<definitions::DestinyActivityDefinition> {
+        self.definition(hash).await
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn stat(&self, hash: u32) -> Result<definitions::DestinyStatDefinition> {
+        self.definition(hash).await
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn vendor(&self, hash: u32) -> Result<definitions::DestinyVendorDefinition> {
+        self.definition(hash).await
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn record(&self, hash: u32) -> Result<definitions::records::DestinyRecordDefinition> {
+        self.definition(hash).await
+    }
+
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn collectible(&self, hash: u32) -> Result<definitions::collectibles::DestinyCollectibleDefinition> {
+        self.definition(hash).await
+    }
+
+    /// Fetches a definition from any table as raw JSON, for tables without an [`EntityDefinition`] impl.
+    ///
+    /// Returns an error if Bungie answers with a definition whose hash isn't the one requested.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn raw(&self, entity_type: &str, hash: u32) -> Result<Arc<serde_json::Value>> {
+        let entry = self.entry(entity_type, hash);
+        let (_, value) = entry
+            .cell
+            .get_or_try_init(|| self.fetch(entity_type, hash))
+            .await?;
+        Ok(value.clone())
+    }
+
+    /// The number of definitions currently held, including ones still being fetched.
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("entity definition cache poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().expect("entity definition cache poisoned").clear();
+    }
+
+    fn entry(&self, entity_type: &str, hash: u32) -> Arc<CacheEntry> {
+        let mut entries = self.entries.lock().expect("entity definition cache poisoned");
+        let key = (entity_type.to_string(), hash);
+        let now = Instant::now();
+        let expired = |entry: &CacheEntry| {
+            entry.cell.get().map_or(false, |(fetched, _)| now.duration_since(*fetched) >= self.ttl)
+        };
+
+        if let Some(entry) = entries.get(&key) {
+            if !expired(entry) {
+                return entry.clone();
+            }
+            entries.remove(&key);
+        }
+
+        if entries.len() >= self.capacity {
+            entries.retain(|_, entry| !expired(entry));
+        }
+        if entries.len() >= self.capacity {
+            let oldest = entries
+                .iter()
+                .filter_map(|(key, entry)| entry.cell.get().map(|(fetched, _)| (*fetched, key.clone())))
+                .min();
+            if let Some((_, oldest)) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries
+            .entry(key)
+            .or_insert_with(|| Arc::new(CacheEntry { cell: OnceCell::new() }))
+            .clone()
+    }
+
+    async fn fetch(&self, entity_type: &str, hash: u32) -> Result<(Instant, Arc<serde_json::Value>)> {
+        let value = self
+            .client
+            .bungie_api_get::<serde_json::Value>(
+                reqwest::Url::parse(&format!("https://www.bungie.net/Platform/Destiny2/Manifest/{entity_type}/{hash}/"))
+                    .with_context(|| "Error parsing URL")?,
+                None,
+            )
+            .await?;
+
+        match value.get("hash").and_then(serde_json::Value::as_u64) {
+            Some(returned) if returned == u64::from(hash) => Ok((Instant::now(), Arc::new(value))),
+            Some(returned) => Err(anyhow!(
+                "Requested {entity_type} {hash} but the Bungie API returned definition {returned}"
+            )),
+            None => Err(anyhow!("The Bungie API returned a {entity_type} {hash} without a hash")),
+        }
+    }
+}
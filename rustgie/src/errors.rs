@@ -0,0 +1,196 @@
+﻿use rustgie_types::exceptions::PlatformErrorCodes;
+use std::fmt::{Display, Formatter};
+
+/// Errors produced by the client that callers may want to match on.
+///
+/// Every fallible client method returns an [`anyhow::Error`]; when the failure is one of these,
+/// it can be recovered with `error.downcast_ref::<RustgieError>()`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RustgieError {
+    /// The Bungie API responded with an envelope whose ErrorCode was not `Success`.
+    PlatformError {
+        error_code: PlatformErrorCodes,
+        error_status: String,
+        message: String,
+        throttle_seconds: i32,
+    },
+    /// The Bungie API responded with a non-success HTTP status and no JSON body to explain it.
+    HttpStatus { status: reqwest::StatusCode },
+    /// Bungie refused the API key for this request because of the origin restrictions set on the key's application.
+    /// Reported either as `OriginHeaderDoesNotMatchKey` in an envelope (`error_code` is set) or as a bare 401 HTML page to a request that sent an `Origin`.
+    ApiKeyOriginRejected {
+        error_code: Option<PlatformErrorCodes>,
+        status: reqwest::StatusCode,
+    },
+    /// The request never reached Bungie: the host couldn't be resolved, connected to, or negotiated TLS with in time.
+    /// Only these failures move a request on to a fallback base URL.
+    Connect {
+        failure: ConnectFailure,
+        source: reqwest::Error,
+    },
+    /// The response body isn't JSON text: `byte`, at `offset` bytes into the body as received, is invalid UTF-8
+    /// or a control character JSON doesn't allow.
+    MalformedBody { offset: usize, byte: u8 },
+}
+
+/// Which step of opening a connection failed, for [`RustgieError::Connect`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectFailure {
+    /// The host name didn't resolve.
+    Dns,
+    /// The TCP connection was refused, reset, or the network was unreachable.
+    Tcp,
+    /// The TCP connection opened, but the TLS handshake failed.
+    Tls,
+    /// Connecting took longer than the connect timeout.
+    Timeout,
+}
+
+impl RustgieError {
+    /// The PlatformErrorCode returned by Bungie, if the error came from a JSON envelope.
+    pub fn error_code(&self) -> Option<PlatformErrorCodes> {
+        match self {
+            RustgieError::PlatformError { error_code, .. } => Some(*error_code),
+            RustgieError::HttpStatus { .. } => None,
+            RustgieError::ApiKeyOriginRejected { error_code, .. } => *error_code,
+            RustgieError::Connect { .. } | RustgieError::MalformedBody { .. } => None,
+        }
+    }
+
+    /// Whether Bungie rejected the request for exceeding a rate limit.
+    pub fn is_throttled(&self) -> bool {
+        self.error_code().map_or(false, is_throttle_code)
+    }
+
+    /// The number of seconds Bungie asked the caller to wait, or 0 when it didn't say.
+    pub fn throttle_seconds(&self) -> i32 {
+        match self {
+            RustgieError::PlatformError { throttle_seconds, .. } => *throttle_seconds,
+            RustgieError::HttpStatus { .. }
+            | RustgieError::ApiKeyOriginRejected { .. }
+            | RustgieError::Connect { .. }
+            | RustgieError::MalformedBody { .. } => 0,
+        }
+    }
+
+    /// Whether Bungie refused an ignore because the item is already ignored.
+    pub fn is_already_ignored(&self) -> bool {
+        self.error_code() == Some(PlatformErrorCodes::IgnoreUserIgnored)
+    }
+
+    /// Which step of connecting failed, if the request never reached Bungie.
+    pub fn connect_failure(&self) -> Option<ConnectFailure> {
+        match self {
+            RustgieError::Connect { failure, .. } => Some(*failure),
+            _ => None,
+        }
+    }
+}
+
+impl Display for RustgieError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RustgieError::PlatformError { error_code, .. } => {
+                write!(f, "The Bungie API returned a PlatformErrorCode of {error_code}")
+            }
+            RustgieError::HttpStatus { status } => {
+                write!(f, "The Bungie API returned HTTP status {status} without a JSON response")
+            }
+            RustgieError::ApiKeyOriginRejected { .. } => write!(
+                f,
+                "The Bungie API rejected the API key for this request; this is not rate limiting. \
+                 The likely cause is the origin header restriction in the key's application settings, which blocks server-side use"
+            ),
+            RustgieError::Connect { failure, .. } => {
+                let step = match failure {
+                    ConnectFailure::Dns => "the host name didn't resolve",
+                    ConnectFailure::Tcp => "the TCP connection failed",
+                    ConnectFailure::Tls => "the TLS handshake failed",
+                    ConnectFailure::Timeout => "connecting timed out",
+                };
+                write!(f, "Could not connect to the Bungie API: {step}")
+            }
+            RustgieError::MalformedBody { offset, byte } => {
+                write!(f, "The response body has an invalid byte 0x{byte:02X} at offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RustgieError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RustgieError::Connect { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Classifies a reqwest error that happened while connecting, or `None` if the connection was made.
+///
+/// reqwest doesn't expose which step failed, so this looks for an [`std::io::Error`] in the source chain whose kind names the step.
+/// Failing that, it goes by hyper's connector messages: its HTTP connector reports "dns error" and "tcp connect error"
+/// (or another "tcp ..." step), and any other connect error comes from the TLS layer wrapped around it.
+pub(crate) fn classify_connect_error(error: &reqwest::Error) -> Option<ConnectFailure> {
+    if !error.is_connect() {
+        return None;
+    }
+    if error.is_timeout() {
+        return Some(ConnectFailure::Timeout);
+    }
+
+    let causes = || std::iter::successors(std::error::Error::source(error), |cause| cause.source());
+    let from_io_kind = causes()
+        .filter_map(|cause| cause.downcast_ref::<std::io::Error>())
+        .find_map(|io_error| io_kind_failure(io_error.kind()));
+    if from_io_kind.is_some() {
+        return from_io_kind;
+    }
+
+    for cause in causes() {
+        let message = cause.to_string();
+        if message.starts_with("dns error") {
+            return Some(ConnectFailure::Dns);
+        }
+        if message.starts_with("tcp ") {
+            return Some(ConnectFailure::Tcp);
+        }
+    }
+    Some(ConnectFailure::Tls)
+}
+
+/// The connect step an I/O error of this kind comes from, if the kind alone says so.
+///
+/// Name resolution failures don't get a kind of their own, so they're left to the message check.
+fn io_kind_failure(kind: std::io::ErrorKind) -> Option<ConnectFailure> {
+    use std::io::ErrorKind;
+
+    match kind {
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::NotConnected
+        | ErrorKind::AddrNotAvailable => Some(ConnectFailure::Tcp),
+        ErrorKind::TimedOut => Some(ConnectFailure::Timeout),
+        _ => None,
+    }
+}
+
+/// Whether `error_code` means Bungie rejected the request for exceeding a rate limit.
+pub(crate) fn is_throttle_code(error_code: PlatformErrorCodes) -> bool {
+    matches!(
+        error_code,
+        PlatformErrorCodes::ThrottleLimitExceeded
+            | PlatformErrorCodes::ThrottleLimitExceededMinutes
+            | PlatformErrorCodes::ThrottleLimitExceededMomentarily
+            | PlatformErrorCodes::ThrottleLimitExceededSeconds
+            | PlatformErrorCodes::PerEndpointRequestThrottleExceeded
+            | PlatformErrorCodes::PerApplicationThrottleExceeded
+            | PlatformErrorCodes::PerApplicationAnonymousThrottleExceeded
+            | PlatformErrorCodes::PerApplicationAuthenticatedThrottleExceeded
+            | PlatformErrorCodes::PerUserThrottleExceeded
+            | PlatformErrorCodes::DestinyThrottledByGameServer
+    )
+}
@@ -0,0 +1,61 @@
+﻿//! Falling back to mirror hosts when www.bungie.net can't be reached at all.
+//!
+//! Only failures to connect ([`RustgieError::Connect`](crate::RustgieError::Connect)) move a request on to the next base URL; anything a host answered,
+//! even with an HTTP or Platform error, is returned as is. Once a fallback answers, requests keep going to it until the cooldown passes,
+//! then the primary is tried first again.
+
+use crate::clock::Clock;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// The fallback base URLs for Platform requests and which of them, if any, is standing in for the primary.
+pub(crate) struct Failover {
+    fallbacks: Vec<String>,
+    cooldown: Duration,
+    clock: Arc<dyn Clock>,
+    /// The position (1-based into `fallbacks`) of the fallback that last answered, and when it did.
+    active: Mutex<Option<(usize, OffsetDateTime)>>,
+}
+
+impl Failover {
+    pub(crate) const DEFAULT_COOLDOWN: Duration = Duration::from_secs(5 * 60);
+
+    /// `fallbacks` must already be trimmed of trailing slashes.
+    pub(crate) fn new(fallbacks: Vec<String>, cooldown: Duration, clock: Arc<dyn Clock>) -> Failover {
+        Failover { fallbacks, cooldown, clock, active: Mutex::new(None) }
+    }
+
+    /// The positions to try in order, where 0 is the primary and 1.. are the fallbacks: from the primary, or from the
+    /// active fallback during its cooldown, wrapping around so every base URL is tried once.
+    pub(crate) fn order(&self) -> Vec<usize> {
+        let hosts = self.fallbacks.len() + 1;
+        let start = match *self.active.lock().expect("failover state poisoned") {
+            Some((position, since)) if self.clock.now() < since + self.cooldown => position,
+            _ => 0,
+        };
+        (0..hosts).map(|offset| (start + offset) % hosts).collect()
+    }
+
+    /// The base URL at `position`, with `primary` at 0.
+    pub(crate) fn base_url<'a>(&'a self, position: usize, primary: &'a str) -> &'a str {
+        match position.checked_sub(1) {
+            None => primary,
+            Some(index) => &self.fallbacks[index],
+        }
+    }
+
+    /// Notes that the base URL at `position` answered, so later requests start there until the cooldown passes.
+    pub(crate) fn answered(&self, position: usize) {
+        let now = self.clock.now();
+        let mut active = self.active.lock().expect("failover state poisoned");
+        *active = match position {
+            0 => None,
+            // Traffic through the active fallback doesn't extend its cooldown, or the primary would never be retried.
+            position => match *active {
+                Some((current, since)) if current == position && now < since + self.cooldown => Some((current, since)),
+                _ => Some((position, now)),
+            },
+        };
+    }
+}
@@ -0,0 +1,157 @@
+﻿use crate::cancellation::{CancellationToken, Outcome};
+use crate::pages::collect_pages;
+use crate::RustgieClient;
+use anyhow::Result;
+use enumflags2::BitFlags;
+use rustgie_types::forum::{
+    ForumTopicsCategoryFiltersEnum, ForumTopicsQuickDateEnum, ForumTopicsSortEnum, PostResponse, PostSearchResponse,
+};
+use rustgie_types::groups_v2::GroupId;
+
+/// Common forum listings, without spelling out the long parameter lists of `GetTopicsPaged` and `GetCoreTopicsPaged`.
+pub struct ForumBrowser<'a> {
+    client: &'a RustgieClient,
+    page_size: i32,
+    locales: Option<String>,
+    cancellation: CancellationToken,
+}
+
+impl<'a> ForumBrowser<'a> {
+    pub fn new(client: &'a RustgieClient) -> ForumBrowser<'a> {
+        ForumBrowser {
+            client,
+            page_size: 25,
+            locales: None,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Topics per page for group and tag listings. The core topics listing has a fixed page size. Defaults to 25.
+    #[must_use]
+    pub fn with_page_size(mut self, page_size: i32) -> ForumBrowser<'a> {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Comma-separated locales to list topics from, such as `"en,fr"`. Bungie uses the caller's locale by default.
+    #[must_use]
+    pub fn with_locales(mut self, locales: &str) -> ForumBrowser<'a> {
+        self.locales = Some(locales.to_string());
+        self
+    }
+
+    /// Stops [`ForumQuery::collect`] once `cancellation` is cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> ForumBrowser<'a> {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// The most recently replied-to topics in a group's forum, of every category and age.
+    pub fn latest_in_group(&self, group_id: impl Into<GroupId>) -> ForumQuery<'_> {
+        let listing = ForumListing::Group { group_id: group_id.into().get(), tag: None };
+        self.query(listing, ForumTopicsSortEnum::LastReplied, ForumTopicsQuickDateEnum::All)
+    }
+
+    /// The most popular core topics from the last week.
+    pub fn trending(&self) -> ForumQuery<'_> {
+        self.query(ForumListing::Core, ForumTopicsSortEnum::Popularity, ForumTopicsQuickDateEnum::LastWeek)
+    }
+
+    /// The most recently replied-to topics with `tag`, across all groups. A leading `#` is added if `tag` lacks one.
+    pub fn by_tag(&self, tag: &str) -> ForumQuery<'_> {
+        let tag = if tag.starts_with('#') { tag.to_string() } else { format!("#{}", tag) };
+        let listing = ForumListing::Group { group_id: 0, tag: Some(tag) };
+        self.query(listing, ForumTopicsSortEnum::LastReplied, ForumTopicsQuickDateEnum::All)
+    }
+
+    fn query(&self, listing: ForumListing, sort: ForumTopicsSortEnum, quick_date: ForumTopicsQuickDateEnum) -> ForumQuery<'_> {
+        ForumQuery {
+            browser: self,
+            listing,
+            sort,
+            quick_date,
+            categories: BitFlags::empty(),
+        }
+    }
+}
+
+enum ForumListing {
+    /// `GetTopicsPaged`. Group 0 lists topics from every group.
+    Group { group_id: i64, tag: Option<String> },
+    /// `GetCoreTopicsPaged`.
+    Core,
+}
+
+/// A forum listing from one of the [`ForumBrowser`] presets. The sort, date range and categories can be adjusted before fetching.
+pub struct ForumQuery<'a> {
+    browser: &'a ForumBrowser<'a>,
+    listing: ForumListing,
+    sort: ForumTopicsSortEnum,
+    quick_date: ForumTopicsQuickDateEnum,
+    categories: BitFlags<ForumTopicsCategoryFiltersEnum>,
+}
+
+impl<'a> ForumQuery<'a> {
+    #[must_use]
+    pub fn with_sort(mut self, sort: ForumTopicsSortEnum) -> ForumQuery<'a> {
+        self.sort = sort;
+        self
+    }
+
+    #[must_use]
+    pub fn with_quick_date(mut self, quick_date: ForumTopicsQuickDateEnum) -> ForumQuery<'a> {
+        self.quick_date = quick_date;
+        self
+    }
+
+    /// Only lists topics in these categories, for example `ForumTopicsCategoryFiltersEnum::Links | ForumTopicsCategoryFiltersEnum::Media`.
+    /// No categories, the default, lists every topic.
+    #[must_use]
+    pub fn with_categories(mut self, categories: impl Into<BitFlags<ForumTopicsCategoryFiltersEnum>>) -> ForumQuery<'a> {
+        self.categories = categories.into();
+        self
+    }
+
+    /// One page of the listing. Forum pages are numbered from 0.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn page(&self, page: i32) -> Result<PostSearchResponse> {
+        let client = self.browser.client;
+        let locales = self.browser.locales.as_deref();
+        match &self.listing {
+            ForumListing::Group { group_id, tag } => {
+                client
+                    .forum_get_topics_paged(
+                        self.categories,
+                        *group_id,
+                        page,
+                        self.browser.page_size,
+                        self.quick_date,
+                        self.sort,
+                        locales,
+                        tag.as_deref(),
+                        None,
+                    )
+                    .await
+            }
+            ForumListing::Core => {
+                client.forum_get_core_topics_paged(self.categories, page, self.quick_date, self.sort, locales, None).await
+            }
+        }
+    }
+
+    /// The topics on up to `max_pages` pages, starting from page 0 and stopping early once a page reports no more results.
+    ///
+    /// Pages are requested by number; the `replacementContinuationToken` Bungie returns isn't accepted by these endpoints.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn collect(&self, max_pages: i32) -> Result<Outcome<Vec<PostResponse>>> {
+        if max_pages <= 0 {
+            return Ok(Outcome::Completed(Vec::new()));
+        }
+        collect_pages(0, &self.browser.cancellation, |page| async move {
+            let results = self.page(page).await?;
+            Ok((results.results.unwrap_or_default(), results.has_more && page + 1 < max_pages))
+        })
+        .await
+    }
+}
@@ -0,0 +1,466 @@
+﻿use crate::cancellation::{CancellationToken, Outcome};
+use crate::pages::collect_pages;
+use crate::{RustgieClient, RustgieError};
+use anyhow::Result;
+use rustgie_types::exceptions::PlatformErrorCodes;
+use rustgie_types::groups_v2::{
+    GroupMember, GroupMemberApplication, GroupNameSearchRequest, GroupPotentialMemberStatus, GroupPotentialMembership,
+    GroupId, GroupType, RuntimeGroupMemberType,
+};
+use rustgie_types::BungieMembershipType;
+use std::fmt::{Display, Formatter};
+use time::OffsetDateTime;
+
+/// Why someone shows up in [`ClanAdmin::pending_overview`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PendingKind {
+    /// An admin invited them and they haven't responded.
+    Invited,
+    /// They applied and no admin has responded.
+    Applied,
+    /// They applied and were separately invited.
+    Both,
+}
+
+/// One person with an outstanding invitation or application to a group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingMember {
+    /// The Destiny membership from the invitation or application, or the bungie.net membership when no Destiny card was returned.
+    /// `(BungieMembershipType::None, 0)` when neither was; such entries are never merged with each other.
+    pub membership: (BungieMembershipType, i64),
+    pub kind: PendingKind,
+    pub invitation: Option<GroupMemberApplication>,
+    pub application: Option<GroupMemberApplication>,
+}
+
+impl PendingMember {
+    /// When the earlier of the invitation and the application was created.
+    pub fn since(&self) -> Option<OffsetDateTime> {
+        let invited = self.invitation.as_ref().map(|i| i.creation_date);
+        let applied = self.application.as_ref().map(|a| a.creation_date);
+        match (invited, applied) {
+            (Some(invited), Some(applied)) => Some(invited.min(applied)),
+            (invited, applied) => invited.or(applied),
+        }
+    }
+
+    fn identity(&self) -> Identity {
+        self.invitation
+            .as_ref()
+            .or(self.application.as_ref())
+            .map_or((None, None), application_identity)
+    }
+}
+
+/// The group admin's side of the invitation flow.
+pub struct ClanAdmin<'a> {
+    client: &'a RustgieClient,
+    access_token: &'a str,
+    cancellation: CancellationToken,
+}
+
+impl<'a> ClanAdmin<'a> {
+    pub fn new(client: &'a RustgieClient, access_token: &'a str) -> ClanAdmin<'a> {
+        ClanAdmin {
+            client,
+            access_token,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stops paging once `cancellation` is cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> ClanAdmin<'a> {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Everyone invited to or applying to `group_id`, one entry per person, oldest first.
+    /// An invitation and an application are the same person's when their bungie.net memberships match, or, without one on both, their Destiny memberships.
+    /// Walks every page of `GetInvitedIndividuals` and `GetPendingMemberships`.
+    ///
+    /// A cancelled overview only covers the pages fetched so far, so it can be missing people or list someone who also applied as only invited.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn pending_overview(&self, group_id: impl Into<GroupId>) -> Result<Outcome<Vec<PendingMember>>> {
+        let group_id = group_id.into();
+        let mut overview: Vec<PendingMember> = Vec::new();
+
+        let invitations = self.all_pages(group_id, true).await?;
+        let cancelled = invitations.is_cancelled();
+        for invitation in invitations.into_inner() {
+            match overview.iter_mut().find(|m| same_person(m.identity(), application_identity(&invitation))) {
+                Some(existing) => existing.invitation = Some(invitation),
+                None => overview.push(PendingMember {
+                    membership: application_membership(&invitation),
+                    kind: PendingKind::Invited,
+                    invitation: Some(invitation),
+                    application: None,
+                }),
+            }
+        }
+
+        let applications = if cancelled {
+            Outcome::Cancelled(Vec::new())
+        } else {
+            self.all_pages(group_id, false).await?
+        };
+        let cancelled = applications.is_cancelled();
+        for application in applications.into_inner() {
+            match overview.iter_mut().find(|m| same_person(m.identity(), application_identity(&application))) {
+                Some(existing) => {
+                    existing.kind = PendingKind::Both;
+                    existing.application = Some(application);
+                }
+                None => overview.push(PendingMember {
+                    membership: application_membership(&application),
+                    kind: PendingKind::Applied,
+                    invitation: None,
+                    application: Some(application),
+                }),
+            }
+        }
+
+        overview.sort_by_key(|m| (m.since(), m.membership.1));
+        Ok(if cancelled {
+            Outcome::Cancelled(overview)
+        } else {
+            Outcome::Completed(overview)
+        })
+    }
+
+    async fn all_pages(&self, group_id: GroupId, invited: bool) -> Result<Outcome<Vec<GroupMemberApplication>>> {
+        collect_pages(1, &self.cancellation, |page| async move {
+            let results = if invited {
+                self.client.group_v2_get_invited_individuals(page, group_id, Some(self.access_token)).await?
+            } else {
+                self.client.group_v2_get_pending_memberships(page, group_id, Some(self.access_token)).await?
+            };
+            Ok((results.results.unwrap_or_default(), results.has_more))
+        })
+        .await
+    }
+}
+
+/// The applicant's side of the invitation flow.
+pub struct Applicant<'a> {
+    client: &'a RustgieClient,
+    membership_type: BungieMembershipType,
+    membership_id: i64,
+}
+
+impl<'a> Applicant<'a> {
+    pub fn new(client: &'a RustgieClient, membership_type: BungieMembershipType, membership_id: i64) -> Applicant<'a> {
+        Applicant {
+            client,
+            membership_type,
+            membership_id,
+        }
+    }
+
+    /// The clans this membership has applied to and is still waiting on.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn my_pending_clans(&self, access_token: &str) -> Result<Vec<GroupPotentialMembership>> {
+        let response = self
+            .client
+            .group_v2_get_potential_groups_for_member(
+                GroupPotentialMemberStatus::Applicant,
+                GroupType::Clan,
+                self.membership_id,
+                self.membership_type,
+                Some(access_token),
+            )
+            .await?;
+
+        let mut pending = response.results.unwrap_or_default();
+        pending.retain(|potential| {
+            potential
+                .member
+                .as_ref()
+                .map_or(false, |m| m.potential_status == GroupPotentialMemberStatus::Applicant)
+        });
+        Ok(pending)
+    }
+}
+
+/// Why [`ClanRoster::can_promote`] or [`ClanRoster::abdicate`] refused a change before sending it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PromotionError {
+    /// Founders are only made through [`ClanRoster::abdicate`], and acting founders only by Bungie when a founder goes inactive.
+    FounderViaEdit,
+    /// Removing someone from the group is a kick, not a member type change.
+    NoneViaEdit,
+    /// Only admins, acting founders and founders can change member types.
+    ActorNotAdmin,
+    /// Nobody can change their own member type.
+    SelfEdit,
+    /// The two members aren't in the same group.
+    DifferentGroups,
+    /// The target's member type is the same as or above the actor's.
+    TargetNotBelowActor,
+    /// Admins can't make other admins; only a founder or acting founder can.
+    AboveActorRank,
+    /// The target already has that member type.
+    Unchanged,
+    /// The membership isn't on the roster.
+    NotOnRoster,
+    /// Foundership can only be handed to an admin.
+    TargetNotAdmin,
+    /// The roster has no founder to abdicate.
+    NoFounder,
+    /// The target was returned without a Destiny or bungie.net membership to address it by.
+    NoMembership,
+}
+
+impl Display for PromotionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            PromotionError::FounderViaEdit => "founders can only be made by abdicating foundership",
+            PromotionError::NoneViaEdit => "members can't be edited to None; kick them instead",
+            PromotionError::ActorNotAdmin => "only admins and founders can change member types",
+            PromotionError::SelfEdit => "members can't change their own member type",
+            PromotionError::DifferentGroups => "the members are in different groups",
+            PromotionError::TargetNotBelowActor => "the target's member type isn't below the actor's",
+            PromotionError::AboveActorRank => "only founders can make admins",
+            PromotionError::Unchanged => "the target already has that member type",
+            PromotionError::NotOnRoster => "the membership isn't on the clan roster",
+            PromotionError::TargetNotAdmin => "foundership can only be handed to an admin",
+            PromotionError::NoFounder => "the clan roster has no founder",
+            PromotionError::NoMembership => "the target has no membership to edit",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl std::error::Error for PromotionError {}
+
+/// Where a member type ranks for edit permissions. Acting founders have a founder's permissions.
+fn rank(member_type: RuntimeGroupMemberType) -> i32 {
+    match member_type {
+        RuntimeGroupMemberType::ActingFounder => RuntimeGroupMemberType::Founder as i32,
+        other => other as i32,
+    }
+}
+
+/// Every member of a group, for checking member type changes before Bungie rejects them with an unhelpful error.
+pub struct ClanRoster<'a> {
+    client: &'a RustgieClient,
+    access_token: &'a str,
+    group_id: GroupId,
+    members: Vec<GroupMember>,
+}
+
+impl<'a> ClanRoster<'a> {
+    /// A roster for `group_id` from members already fetched with `GetMembersOfGroup`.
+    pub fn new(
+        client: &'a RustgieClient,
+        access_token: &'a str,
+        group_id: impl Into<GroupId>,
+        members: Vec<GroupMember>,
+    ) -> ClanRoster<'a> {
+        ClanRoster {
+            client,
+            access_token,
+            group_id: group_id.into(),
+            members,
+        }
+    }
+
+    /// Walks every page of `GetMembersOfGroup`. A cancelled fetch gives a roster of the pages fetched so far.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn fetch(
+        client: &'a RustgieClient,
+        access_token: &'a str,
+        group_id: impl Into<GroupId>,
+        cancellation: &CancellationToken,
+    ) -> Result<Outcome<ClanRoster<'a>>> {
+        let group_id = group_id.into();
+        let members = collect_pages(1, cancellation, |page| async move {
+            let results = client.group_v2_get_members_of_group(page, group_id, None, None, Some(access_token)).await?;
+            Ok((results.results.unwrap_or_default(), results.has_more))
+        })
+        .await?;
+        Ok(match members {
+            Outcome::Completed(members) => Outcome::Completed(ClanRoster::new(client, access_token, group_id, members)),
+            Outcome::Cancelled(members) => Outcome::Cancelled(ClanRoster::new(client, access_token, group_id, members)),
+        })
+    }
+
+    pub fn members(&self) -> &[GroupMember] {
+        &self.members
+    }
+
+    /// The member with this Destiny membership (or bungie.net membership, for members without a Destiny card).
+    pub fn member(&self, membership: (BungieMembershipType, i64)) -> Option<&GroupMember> {
+        self.members.iter().find(|m| member_membership(m) == Some(membership))
+    }
+
+    pub fn founder(&self) -> Option<&GroupMember> {
+        self.members.iter().find(|m| m.member_type == RuntimeGroupMemberType::Founder)
+    }
+
+    /// Whether `actor` may change `target`'s member type to `to` with `EditGroupMembership`, going by Bungie's documented rules.
+    pub fn can_promote(actor: &GroupMember, target: &GroupMember, to: RuntimeGroupMemberType) -> Result<(), PromotionError> {
+        match to {
+            RuntimeGroupMemberType::Founder | RuntimeGroupMemberType::ActingFounder => return Err(PromotionError::FounderViaEdit),
+            RuntimeGroupMemberType::None => return Err(PromotionError::NoneViaEdit),
+            _ => {}
+        }
+        if rank(actor.member_type) < RuntimeGroupMemberType::Admin as i32 {
+            return Err(PromotionError::ActorNotAdmin);
+        }
+        if same_person(member_identity(actor), member_identity(target)) {
+            return Err(PromotionError::SelfEdit);
+        }
+        if actor.group_id != target.group_id {
+            return Err(PromotionError::DifferentGroups);
+        }
+        if rank(target.member_type) >= rank(actor.member_type) {
+            return Err(PromotionError::TargetNotBelowActor);
+        }
+        if to as i32 >= rank(actor.member_type) {
+            return Err(PromotionError::AboveActorRank);
+        }
+        if target.member_type == to {
+            return Err(PromotionError::Unchanged);
+        }
+        Ok(())
+    }
+
+    /// Changes `target`'s member type to `to` if [`ClanRoster::can_promote`] allows it. `actor` must be the member the access token belongs to.
+    /// Refused changes are returned as a [`PromotionError`] without being sent.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn edit_member_type(
+        &self,
+        actor: &GroupMember,
+        target: &GroupMember,
+        to: RuntimeGroupMemberType,
+    ) -> Result<()> {
+        ClanRoster::can_promote(actor, target, to)?;
+        let (membership_type, membership_id) = member_membership(target).ok_or(PromotionError::NoMembership)?;
+        self.client
+            .group_v2_edit_group_membership(self.group_id, membership_id, membership_type, to, Some(self.access_token))
+            .await?;
+        Ok(())
+    }
+
+    /// Hands foundership to the admin with `to_membership`. The access token must be the current founder's.
+    /// Members who aren't on the roster or aren't admins are refused with a [`PromotionError`] without sending anything.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn abdicate(&self, to_membership: (BungieMembershipType, i64)) -> Result<bool> {
+        let founder = self.founder().ok_or(PromotionError::NoFounder)?;
+        let target = self.member(to_membership).ok_or(PromotionError::NotOnRoster)?;
+        if member_membership(founder) == Some(to_membership) {
+            return Err(PromotionError::SelfEdit.into());
+        }
+        if target.member_type != RuntimeGroupMemberType::Admin {
+            return Err(PromotionError::TargetNotAdmin.into());
+        }
+
+        self.client
+            .group_v2_abdicate_foundership(to_membership.1, self.group_id, to_membership.0, Some(self.access_token))
+            .await
+    }
+}
+
+/// Shortest and longest group names Bungie accepts, in characters.
+const GROUP_NAME_LENGTH: std::ops::RangeInclusive<usize> = 2..=25;
+
+/// The answer from [`RustgieClient::clan_name_available`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NameAvailability {
+    Available,
+    /// A group of that type already has the name. `group_id` is `None` when Bungie's answer left out the group's details.
+    Taken { group_id: Option<GroupId> },
+    /// The name breaks Bungie's formatting rules, so it was never sent.
+    InvalidFormat { reason: &'static str },
+}
+
+/// Trims a group name and collapses runs of whitespace to a single space, the form Bungie stores names in.
+pub fn normalize_group_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// The local subset of Bungie's group name rules. Profanity and reserved names are only checked server-side.
+fn group_name_format_error(normalized: &str) -> Option<&'static str> {
+    if !GROUP_NAME_LENGTH.contains(&normalized.chars().count()) {
+        return Some("Group names must be between 2 and 25 characters long");
+    }
+    if !normalized.chars().all(|c| c.is_alphanumeric() || c == ' ' || c.is_ascii_punctuation()) {
+        return Some("Group names may only contain letters, numbers, spaces and punctuation");
+    }
+    None
+}
+
+impl RustgieClient {
+    /// Whether a group of `group_type` could be created with `name`, after [normalizing](normalize_group_name) it.
+    /// Invalid names are rejected locally; everything else is looked up with `GetGroupByNameV2`.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn clan_name_available(&self, name: &str, group_type: GroupType) -> Result<NameAvailability> {
+        let normalized = normalize_group_name(name);
+        if let Some(reason) = group_name_format_error(&normalized) {
+            return Ok(NameAvailability::InvalidFormat { reason });
+        }
+
+        let request = GroupNameSearchRequest {
+            group_name: Some(normalized),
+            group_type,
+        };
+        match self.group_v2_get_group_by_name_v2(request, None).await {
+            Ok(found) => Ok(NameAvailability::Taken {
+                group_id: found.detail.map(|group| GroupId::from(group.group_id)),
+            }),
+            Err(error)
+                if error
+                    .downcast_ref::<RustgieError>()
+                    .map_or(false, |e| e.error_code() == Some(PlatformErrorCodes::GroupNotFound)) =>
+            {
+                Ok(NameAvailability::Available)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The bungie.net membership ID and the Destiny membership someone was returned with, either of which can be missing.
+type Identity = (Option<i64>, Option<(BungieMembershipType, i64)>);
+
+/// Whether two identities are the same person: by bungie.net membership ID when both have one, otherwise by Destiny membership.
+/// Identities with nothing to compare are never the same person.
+fn same_person(a: Identity, b: Identity) -> bool {
+    match (a, b) {
+        ((Some(a), _), (Some(b), _)) => a == b,
+        ((_, Some(a)), (_, Some(b))) => a == b,
+        _ => false,
+    }
+}
+
+fn member_identity(member: &GroupMember) -> Identity {
+    (
+        member.bungie_net_user_info.as_ref().map(|bungie_net| bungie_net.membership_id),
+        member.destiny_user_info.as_ref().map(|destiny| (destiny.membership_type, destiny.membership_id)),
+    )
+}
+
+fn application_identity(application: &GroupMemberApplication) -> Identity {
+    (
+        application.bungie_net_user_info.as_ref().map(|bungie_net| bungie_net.membership_id),
+        application.destiny_user_info.as_ref().map(|destiny| (destiny.membership_type, destiny.membership_id)),
+    )
+}
+
+/// The Destiny membership, or the bungie.net membership for members without a Destiny card.
+fn member_membership(member: &GroupMember) -> Option<(BungieMembershipType, i64)> {
+    match (&member.destiny_user_info, &member.bungie_net_user_info) {
+        (Some(destiny), _) => Some((destiny.membership_type, destiny.membership_id)),
+        (None, Some(bungie_net)) => Some((bungie_net.membership_type, bungie_net.membership_id)),
+        (None, None) => None,
+    }
+}
+
+fn application_membership(application: &GroupMemberApplication) -> (BungieMembershipType, i64) {
+    match (&application.destiny_user_info, &application.bungie_net_user_info) {
+        (Some(destiny), _) => (destiny.membership_type, destiny.membership_id),
+        (None, Some(bungie_net)) => (bungie_net.membership_type, bungie_net.membership_id),
+        (None, None) => (BungieMembershipType::None, 0),
+    }
+}
@@ -0,0 +1,135 @@
+﻿//! Everything an item detail view shows about one instanced item, fetched and resolved in one call.
+
+use crate::entity_definitions::DefinitionProvider;
+use crate::RustgieClient;
+use anyhow::{Context, Result};
+use rustgie_types::components::{ComponentAccess, ComponentResponseState};
+use rustgie_types::destiny::components::items::DestinyItemPlugObjectivesComponent;
+use rustgie_types::destiny::definitions::DestinyInventoryItemDefinition;
+use rustgie_types::destiny::entities::characters::CharacterId;
+use rustgie_types::destiny::entities::items::{DestinyItemComponent, DestinyItemInstanceComponent, DestinyItemStatsComponent};
+use rustgie_types::destiny::items::{resolve_sockets, ResolvedSocket};
+use rustgie_types::destiny::DestinyComponentType;
+use rustgie_types::BungieMembershipType;
+
+/// The components [`RustgieClient::item_details`] asks for.
+const ITEM_DETAIL_COMPONENTS: [DestinyComponentType; 6] = [
+    DestinyComponentType::ItemCommonData,
+    DestinyComponentType::ItemInstances,
+    DestinyComponentType::ItemStats,
+    DestinyComponentType::ItemSockets,
+    DestinyComponentType::ItemReusablePlugs,
+    DestinyComponentType::ItemPlugObjectives,
+];
+
+/// One socket of an [`ItemDetails`], with the definitions of its plugs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetailedSocket {
+    pub socket: ResolvedSocket,
+    /// The inserted plug's definition, or `None` if the socket is empty or the provider doesn't have it.
+    pub plug: Option<DestinyInventoryItemDefinition>,
+    /// The definitions the provider has of `socket.reusable_plug_hashes`, in that order.
+    pub reusable_plugs: Vec<DestinyInventoryItemDefinition>,
+}
+
+/// One instanced item as an item detail view needs it, from [`RustgieClient::item_details`].
+///
+/// Each section is `None` when its component came back private, disabled or empty, so one hidden component doesn't
+/// cost the rest of the view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemDetails {
+    /// The character holding the item, or `None` if it's in the vault or the response didn't say.
+    pub character_id: Option<CharacterId>,
+    /// The item's hash, quantity, state and other data every item has (ItemCommonData).
+    pub item: Option<DestinyItemComponent>,
+    /// The item's definition, or `None` if `item` is missing or the provider doesn't have it.
+    pub definition: Option<DestinyInventoryItemDefinition>,
+    /// Power, damage type, energy and other per-instance data (ItemInstances).
+    pub instance: Option<DestinyItemInstanceComponent>,
+    /// The item's computed stats (ItemStats).
+    pub stats: Option<DestinyItemStatsComponent>,
+    /// Every socket with its plugs resolved (ItemSockets, with ItemReusablePlugs when it's available).
+    pub sockets: Option<Vec<DetailedSocket>>,
+    /// Progress on the inserted plugs' objectives, such as kill trackers and crafted weapon levels (ItemPlugObjectives).
+    pub plug_objectives: Option<DestinyItemPlugObjectivesComponent>,
+}
+
+impl ItemDetails {
+    /// The stats to compare this item with another through [`rustgie_types::destiny::entities::items::compare_items`]:
+    /// the computed stats, or the definition's when the ItemStats component is missing.
+    pub fn comparable_stats(&self) -> Option<DestinyItemStatsComponent> {
+        self.stats.clone().or_else(|| {
+            let stats = self.definition.as_ref()?.stats.as_ref()?;
+            Some(DestinyItemStatsComponent::from_definition(stats))
+        })
+    }
+}
+
+/// The component's data, unless it's private, disabled or missing.
+fn available<C: ComponentResponseState, T: Clone>(component: Option<&C>, data: impl FnOnce(&C) -> Option<&T>) -> Option<T> {
+    component.filter(|component| component.access() == ComponentAccess::Available).and_then(data).cloned()
+}
+
+impl RustgieClient {
+    /// Fetches the item `item_instance_id` with every component an item detail view needs, and looks up its definition and the
+    /// definitions of its plugs in `defs`. Private or disabled components leave their sections of the result `None` rather than failing it.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn item_details(
+        &self,
+        membership_type: BungieMembershipType,
+        destiny_membership_id: i64,
+        item_instance_id: i64,
+        defs: &dyn DefinitionProvider,
+        access_token: Option<&str>,
+    ) -> Result<ItemDetails> {
+        let response = self
+            .destiny2_get_item(destiny_membership_id, item_instance_id, membership_type, Some(ITEM_DETAIL_COMPONENTS.to_vec()), access_token)
+            .await?;
+
+        let item = available(response.item.as_ref(), |component| component.data.as_ref());
+        let definition = match &item {
+            Some(item) => defs
+                .definition::<DestinyInventoryItemDefinition>(item.item_hash)
+                .with_context(|| format!("Could not look up the definition of item {item_instance_id}"))?,
+            None => None,
+        };
+        let reusable_plugs = available(response.reusable_plugs.as_ref(), |component| component.data.as_ref());
+        let sockets = match available(response.sockets.as_ref(), |component| component.data.as_ref()) {
+            Some(sockets) => Some(
+                resolve_sockets(&sockets, reusable_plugs.as_ref())
+                    .into_iter()
+                    .map(|socket| detailed_socket(socket, defs))
+                    .collect::<Result<Vec<DetailedSocket>>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(ItemDetails {
+            character_id: response.character_id.and_then(CharacterId::new),
+            item,
+            definition,
+            instance: available(response.instance.as_ref(), |component| component.data.as_ref()),
+            stats: available(response.stats.as_ref(), |component| component.data.as_ref()),
+            sockets,
+            plug_objectives: available(response.plug_objectives.as_ref(), |component| component.data.as_ref()),
+        })
+    }
+}
+
+fn detailed_socket(socket: ResolvedSocket, defs: &dyn DefinitionProvider) -> Result<DetailedSocket> {
+    let plug = match socket.plug_hash {
+        Some(hash) => defs.definition::<DestinyInventoryItemDefinition>(hash)?,
+        None => None,
+    };
+    let mut reusable_plugs = Vec::new();
+    for hash in &socket.reusable_plug_hashes {
+        if let Some(definition) = defs.definition::<DestinyInventoryItemDefinition>(*hash)? {
+            reusable_plugs.push(definition);
+        }
+    }
+    Ok(DetailedSocket {
+        socket,
+        plug,
+        reusable_plugs,
+    })
+}
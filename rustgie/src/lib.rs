@@ -1,37 +1,128 @@
 ﻿#![forbid(unsafe_code)]
 
+#[cfg(all(feature = "test-utils", not(debug_assertions), not(feature = "test-utils-in-release")))]
+compile_error!(
+    "The test-utils feature allows plain-HTTP requests and must not ship in release builds; \
+     enable test-utils-in-release as well if this is really a test build"
+);
+
+mod activity_history;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod body;
+pub mod cancellation;
+pub mod clock;
+pub mod definition_registry;
+pub mod downloads;
 pub mod endpoints;
-
+pub mod entity_definitions;
+pub mod errors;
+mod failover;
+pub mod forums;
+pub mod groups;
+pub mod item_details;
+pub mod middleware;
+pub mod oauth_session;
+pub mod operations;
+pub mod pages;
+pub mod rate_limit;
+#[cfg(feature = "sqlite-manifest")]
+pub mod sqlite_manifest;
+mod static_config;
+mod streaming;
+pub mod transfers;
+
+pub use cancellation::{CancellationToken, Outcome};
+pub use clock::{Clock, FixedClock, Sleeper, SystemClock, TokioSleeper};
+pub use definition_registry::{DefinitionRegistry, DefinitionSnapshot};
+pub use downloads::{ContentDownload, DownloadProgress, DownloadReport};
+pub use entity_definitions::{CachedEntityDefinitions, DefinitionProvider, DefinitionTable};
+pub use errors::{ConnectFailure, RustgieError};
+pub use forums::{ForumBrowser, ForumQuery};
+pub use groups::{
+    normalize_group_name, Applicant, ClanAdmin, ClanRoster, NameAvailability, PendingKind, PendingMember, PromotionError,
+};
+pub use item_details::{DetailedSocket, ItemDetails};
+pub use middleware::{Middleware, MiddlewareFuture, Next, ThrottleRetry};
+pub use oauth_session::OAuthSession;
+pub use operations::{match_url, Operation, OPERATIONS};
+pub use rate_limit::{BungieHost, RateLimit};
+#[cfg(feature = "sqlite-manifest")]
+pub use sqlite_manifest::SqliteManifest;
+pub use transfers::{BulkTransfer, TransferReport};
 pub use rustgie_types as types;
 
 use anyhow::{anyhow, Context, Result};
 use reqwest::Url;
-use rustgie_types::api_response_::BungieApiResponse;
+use serde::de::IgnoredAny;
+use rustgie_types::api_response_::{BungieApiResponse, Redacted};
+use rustgie_types::exceptions::PlatformErrorCodes;
+use rate_limit::RateLimiter;
+use failover::Failover;
+use static_config::StaticConfigCache;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
-#[must_use]
 pub struct RustgieClientBuilder {
     api_key: Option<String>,
     user_agent: Option<String>,
+    origin: Option<String>,
     oauth_client_id: Option<String>,
     oauth_client_secret: Option<String>,
+    base_url: Option<String>,
+    stats_base_url: Option<String>,
+    oauth_base_url: Option<String>,
+    oauth_basic_auth: bool,
+    allow_http: bool,
+    verify_key_on_build: bool,
+    clock: Arc<dyn Clock>,
+    sleeper: Arc<dyn Sleeper>,
+    throttle_retries: u32,
+    platform_rate_limit: Option<RateLimit>,
+    stats_rate_limit: Option<RateLimit>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    static_config_ttl: std::time::Duration,
+    fallback_base_urls: Vec<Url>,
+    failover_cooldown: std::time::Duration,
+    connect_timeout: Option<std::time::Duration>,
 }
 
 impl RustgieClientBuilder {
+    #[must_use]
     pub fn new() -> RustgieClientBuilder {
         RustgieClientBuilder {
             api_key: None,
             user_agent: None,
+            origin: None,
             oauth_client_id: None,
             oauth_client_secret: None,
+            base_url: None,
+            stats_base_url: None,
+            oauth_base_url: None,
+            oauth_basic_auth: false,
+            allow_http: false,
+            verify_key_on_build: false,
+            clock: Arc::new(SystemClock),
+            sleeper: Arc::new(TokioSleeper),
+            throttle_retries: 0,
+            platform_rate_limit: Some(BungieHost::Platform.default_rate_limit()),
+            stats_rate_limit: Some(BungieHost::Stats.default_rate_limit()),
+            middlewares: Vec::new(),
+            static_config_ttl: StaticConfigCache::DEFAULT_TTL,
+            fallback_base_urls: Vec::new(),
+            failover_cooldown: Failover::DEFAULT_COOLDOWN,
+            connect_timeout: None,
         }
     }
 
+    #[must_use]
     pub fn with_api_key(mut self, api_key: &str) -> RustgieClientBuilder {
         self.api_key = Option::from(api_key.to_string());
         self
     }
 
+    #[must_use]
     pub fn with_user_agent(mut self, user_agent: &str) -> RustgieClientBuilder {
         const RUSTGIE_VERSION: &str = env!("CARGO_PKG_VERSION");
         self.user_agent = Option::from(format!(
@@ -40,17 +131,174 @@ impl RustgieClientBuilder {
         self
     }
 
+    /// Sends `origin` as the `Origin` header of every request, for API keys whose application only allows certain origins.
+    /// With an origin set, a bare 401 page from Bungie is reported as [`RustgieError::ApiKeyOriginRejected`] rather than [`RustgieError::HttpStatus`].
+    #[must_use]
+    pub fn with_origin(mut self, origin: &str) -> RustgieClientBuilder {
+        self.origin = Option::from(origin.to_string());
+        self
+    }
+
+    #[must_use]
     pub fn with_oauth_client_id(mut self, client_id: u32) -> RustgieClientBuilder {
         self.oauth_client_id = Option::from(client_id.to_string());
         self
     }
 
+    #[must_use]
     pub fn with_oauth_client_secret(mut self, client_secret: &str) -> RustgieClientBuilder {
         self.oauth_client_secret = Option::from(client_secret.to_string());
         self
     }
 
+    /// Sends every Platform request to `base_url` instead of `https://www.bungie.net`, e.g. for a caching proxy or a mock server.
+    /// The endpoint path (`/Platform/...`) is appended to it unchanged.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> RustgieClientBuilder {
+        self.base_url = Option::from(base_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sends requests for endpoints Bungie serves from `https://stats.bungie.net` (post game carnage reports) to `stats_base_url` instead.
+    /// Defaults to the base URL from [`RustgieClientBuilder::with_base_url`] when that is set.
+    #[must_use]
+    pub fn with_stats_base_url(mut self, stats_base_url: &str) -> RustgieClientBuilder {
+        self.stats_base_url = Option::from(stats_base_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sends OAuth token requests to `oauth_base_url` instead of `https://www.bungie.net`.
+    /// Defaults to the base URL from [`RustgieClientBuilder::with_base_url`] when that is set.
+    #[must_use]
+    pub fn with_oauth_base_url(mut self, oauth_base_url: &str) -> RustgieClientBuilder {
+        self.oauth_base_url = Option::from(oauth_base_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sends the OAuth client ID and secret as an `Authorization: Basic` header instead of form fields.
+    /// Bungie accepts both; some proxies and partner setups only pass the header form through.
+    /// Has no effect for public clients, which have no secret and always send their client ID as a form field.
+    #[must_use]
+    pub fn with_oauth_basic_auth(mut self, basic_auth: bool) -> RustgieClientBuilder {
+        self.oauth_basic_auth = basic_auth;
+        self
+    }
+
+    /// Retries requests Bungie rejects as throttled up to `retries` times, waiting the `ThrottleSeconds` Bungie asks for, or backing off exponentially from one second when it doesn't say.
+    /// Defaults to 0, which returns throttling errors immediately.
+    #[must_use]
+    pub fn with_throttle_retries(mut self, retries: u32) -> RustgieClientBuilder {
+        self.throttle_retries = retries;
+        self
+    }
+
+    /// Paces requests to `host` to stay under `limit`, or stops pacing them with `None`. Each host is paced separately.
+    /// Defaults to [`BungieHost::default_rate_limit`] for both hosts. Requests are paced by where Bungie serves them from, even when a base URL override sends them elsewhere.
+    #[must_use]
+    pub fn with_rate_limit(mut self, host: BungieHost, limit: Option<RateLimit>) -> RustgieClientBuilder {
+        match host {
+            BungieHost::Platform => self.platform_rate_limit = limit,
+            BungieHost::Stats => self.stats_rate_limit = limit,
+        }
+        self
+    }
+
+    /// Replaces the clock used for OAuth token expiry, rate limiting and static config expiry. Defaults to [`SystemClock`].
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> RustgieClientBuilder {
+        self.clock = clock;
+        self
+    }
+
+    /// Replaces how the client waits between throttle retries and for rate limits. Defaults to [`TokioSleeper`].
+    #[must_use]
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> RustgieClientBuilder {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Adds a middleware to the chain every request passes through. Middlewares run in the order they're added, after the client's own throttle retries.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> RustgieClientBuilder {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// How long the `*_cached` static config methods, such as [`RustgieClient::clan_banner_source_cached`], reuse a response.
+    /// Defaults to six hours.
+    #[must_use]
+    pub fn with_static_config_ttl(mut self, ttl: std::time::Duration) -> RustgieClientBuilder {
+        self.static_config_ttl = ttl;
+        self
+    }
+
+    /// Base URLs to try in order when the Platform host (www.bungie.net, or the [`RustgieClientBuilder::with_base_url`] override) can't be connected to,
+    /// e.g. a mirror for when Bungie's edge has regional DNS trouble. Only failures to connect, reported as [`RustgieError::Connect`], move a request on;
+    /// HTTP and Platform errors are returned from whichever host gave them. Stats and OAuth requests don't fail over.
+    #[must_use]
+    pub fn with_fallback_base_urls(mut self, fallback_base_urls: Vec<Url>) -> RustgieClientBuilder {
+        self.fallback_base_urls = fallback_base_urls;
+        self
+    }
+
+    /// How long requests keep going to a fallback base URL after it answers for an unreachable primary, before the primary is tried first again.
+    /// Defaults to five minutes.
+    #[must_use]
+    pub fn with_failover_cooldown(mut self, cooldown: std::time::Duration) -> RustgieClientBuilder {
+        self.failover_cooldown = cooldown;
+        self
+    }
+
+    /// Gives up on connecting to a host after `timeout`, reported as [`ConnectFailure::Timeout`]. Defaults to no limit beyond the operating system's.
+    #[must_use]
+    pub fn with_connect_timeout(mut self, timeout: std::time::Duration) -> RustgieClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Allows plain-HTTP base URLs and requests, e.g. for a local wiremock server without TLS certificates.
+    /// Only compiled in for this crate's tests and the `test-utils` feature; never enable that feature in a production build.
+    #[cfg(any(test, feature = "test-utils"))]
+    #[must_use]
+    pub fn dangerously_allow_http(mut self, allow: bool) -> RustgieClientBuilder {
+        self.allow_http = allow;
+        self
+    }
+
+    /// Checks the API key against Bungie before handing back a client.
+    ///
+    /// Building is synchronous, so the check only runs through [`RustgieClientBuilder::build_async`];
+    /// calling [`RustgieClientBuilder::build`] with this enabled returns an error instead of silently skipping it.
+    #[must_use]
+    pub fn verify_key_on_build(mut self, verify: bool) -> RustgieClientBuilder {
+        self.verify_key_on_build = verify;
+        self
+    }
+
     pub fn build(self) -> Result<RustgieClient> {
+        if self.verify_key_on_build {
+            return Err(anyhow!(
+                "verify_key_on_build requires the client to be built with build_async()"
+            ));
+        }
+
+        self.build_client()
+    }
+
+    /// Builds the client, then runs [`RustgieClient::verify_api_key`] if `verify_key_on_build` is set.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn build_async(self) -> Result<RustgieClient> {
+        let verify = self.verify_key_on_build;
+        let client = self.build_client()?;
+
+        if verify {
+            client.verify_api_key().await?.into_result()?;
+        }
+
+        Ok(client)
+    }
+
+    fn build_client(self) -> Result<RustgieClient> {
         let mut header_map = reqwest::header::HeaderMap::new();
 
         match self.api_key {
@@ -70,7 +318,74 @@ impl RustgieClientBuilder {
             }
         }
 
-        RustgieClient::new(header_map, self.oauth_client_id, self.oauth_client_secret)
+        if let Some(origin) = self.origin {
+            header_map.insert(reqwest::header::ORIGIN, reqwest::header::HeaderValue::try_from(origin)?);
+        }
+
+        if let Some(base_url) = &self.base_url {
+            let url = Url::parse(base_url).with_context(|| "Error parsing base URL")?;
+            check_scheme(&url, self.allow_http)?;
+        }
+
+        let stats_base_url = self.stats_base_url.or_else(|| self.base_url.clone());
+        if let Some(stats_base_url) = &stats_base_url {
+            let url = Url::parse(stats_base_url).with_context(|| "Error parsing stats base URL")?;
+            check_scheme(&url, self.allow_http)?;
+        }
+
+        let oauth_base_url = self.oauth_base_url.or_else(|| self.base_url.clone());
+        if let Some(oauth_base_url) = &oauth_base_url {
+            let url = Url::parse(oauth_base_url).with_context(|| "Error parsing OAuth base URL")?;
+            check_scheme(&url, self.allow_http)?;
+        }
+
+        for fallback_base_url in &self.fallback_base_urls {
+            check_scheme(fallback_base_url, self.allow_http)?;
+        }
+        let failover = match self.fallback_base_urls.is_empty() {
+            true => None,
+            false => Some(Failover::new(
+                self.fallback_base_urls
+                    .iter()
+                    .map(|url| url.as_str().trim_end_matches('/').to_string())
+                    .collect(),
+                self.failover_cooldown,
+                self.clock.clone(),
+            )),
+        };
+
+        let rate_limiter = RateLimiter::new(
+            self.platform_rate_limit,
+            self.stats_rate_limit,
+            self.clock.clone(),
+            self.sleeper.clone(),
+        );
+
+        let mut middlewares = Vec::<Arc<dyn Middleware>>::new();
+        let throttle_retry = self.throttle_retries > 0;
+        if throttle_retry {
+            middlewares.push(Arc::new(ThrottleRetry::new(self.throttle_retries, self.sleeper)));
+        }
+        middlewares.extend(self.middlewares);
+        let static_config = StaticConfigCache::new(self.static_config_ttl, self.clock.clone());
+
+        RustgieClient::new(
+            header_map,
+            self.oauth_client_id,
+            self.oauth_client_secret,
+            self.base_url,
+            stats_base_url,
+            oauth_base_url,
+            self.oauth_basic_auth,
+            !self.allow_http,
+            self.connect_timeout,
+            self.clock,
+            rate_limiter,
+            middlewares,
+            throttle_retry,
+            static_config,
+            failover,
+        )
     }
 }
 
@@ -80,19 +395,121 @@ impl Default for RustgieClientBuilder {
     }
 }
 
+/// The API key and OAuth client secret are [`Redacted`].
+impl fmt::Debug for RustgieClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustgieClientBuilder")
+            .field("api_key", &self.api_key.as_deref().map(Redacted))
+            .field("user_agent", &self.user_agent)
+            .field("origin", &self.origin)
+            .field("oauth_client_id", &self.oauth_client_id)
+            .field("oauth_client_secret", &self.oauth_client_secret.as_deref().map(Redacted))
+            .field("base_url", &self.base_url)
+            .field("stats_base_url", &self.stats_base_url)
+            .field("oauth_base_url", &self.oauth_base_url)
+            .field("oauth_basic_auth", &self.oauth_basic_auth)
+            .field("allow_http", &self.allow_http)
+            .field("verify_key_on_build", &self.verify_key_on_build)
+            .field("throttle_retries", &self.throttle_retries)
+            .field("platform_rate_limit", &self.platform_rate_limit)
+            .field("stats_rate_limit", &self.stats_rate_limit)
+            .field("middlewares", &self.middlewares.len())
+            .field("static_config_ttl", &self.static_config_ttl)
+            .field("fallback_base_urls", &self.fallback_base_urls)
+            .field("failover_cooldown", &self.failover_cooldown)
+            .field("connect_timeout", &self.connect_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The result of [`RustgieClient::verify_api_key`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApiKeyStatus {
+    /// Bungie accepted the key.
+    Valid,
+    /// The key is missing, malformed, expired, or revoked.
+    InvalidKey,
+    /// The key is valid, but its origin header restrictions rejected this request.
+    OriginMismatch,
+}
+
+impl ApiKeyStatus {
+    /// Turns a rejected key into the error building a verified client reports.
+    fn into_result(self) -> Result<()> {
+        match self {
+            ApiKeyStatus::Valid => Ok(()),
+            ApiKeyStatus::InvalidKey => {
+                Err(anyhow!("The Bungie API rejected the API key as invalid or expired"))
+            }
+            ApiKeyStatus::OriginMismatch => Err(anyhow!(
+                "The Bungie API rejected the request because the API key's origin restrictions do not allow it"
+            )),
+        }
+    }
+}
+
 #[must_use]
 pub struct RustgieClient {
     client: reqwest::Client,
     oauth_client_id: Option<String>,
     oauth_client_secret: Option<String>,
+    base_url: Option<String>,
+    stats_base_url: Option<String>,
+    oauth_base_url: Option<String>,
+    oauth_basic_auth: bool,
+    /// Whether requests carry an `Origin` header from [`RustgieClientBuilder::with_origin`].
+    sends_origin: bool,
+    clock: Arc<dyn Clock>,
+    rate_limiter: RateLimiter,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// Whether `middlewares` starts with a [`ThrottleRetry`], which content downloads skip so their bodies still stream.
+    throttle_retry: bool,
+    static_config: StaticConfigCache,
+    failover: Option<Failover>,
+}
+
+/// The API key only lives in the inner HTTP client's default headers, which aren't printed, and the OAuth client
+/// secret is [`Redacted`]. User tokens are never held by the client.
+impl fmt::Debug for RustgieClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustgieClient")
+            .field("oauth_client_id", &self.oauth_client_id)
+            .field("oauth_client_secret", &self.oauth_client_secret.as_deref().map(Redacted))
+            .field("base_url", &self.base_url)
+            .field("stats_base_url", &self.stats_base_url)
+            .field("oauth_base_url", &self.oauth_base_url)
+            .field("oauth_basic_auth", &self.oauth_basic_auth)
+            .field("middlewares", &self.middlewares.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The grant-specific half of an OAuth token request.
+enum GrantParams<'a> {
+    AuthorizationCode(&'a str),
+    RefreshToken(&'a str),
 }
 
 impl RustgieClient {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         default_headers: reqwest::header::HeaderMap,
         client_id: Option<String>,
         client_secret: Option<String>,
+        base_url: Option<String>,
+        stats_base_url: Option<String>,
+        oauth_base_url: Option<String>,
+        oauth_basic_auth: bool,
+        https_only: bool,
+        connect_timeout: Option<std::time::Duration>,
+        clock: Arc<dyn Clock>,
+        rate_limiter: RateLimiter,
+        middlewares: Vec<Arc<dyn Middleware>>,
+        throttle_retry: bool,
+        static_config: StaticConfigCache,
+        failover: Option<Failover>,
     ) -> Result<Self> {
+        let sends_origin = default_headers.contains_key(reqwest::header::ORIGIN);
         Ok(Self {
             client: {
                 let mut builder = reqwest::ClientBuilder::new();
@@ -115,48 +532,187 @@ impl RustgieClient {
                 }
 
                 builder = builder
-                    //.https_only(true)
+                    .https_only(https_only)
                     //.redirect(reqwest::redirect::Policy::none())
                     .default_headers(default_headers);
+                if let Some(connect_timeout) = connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
 
                 builder.build()?
             },
             oauth_client_id: client_id,
             oauth_client_secret: client_secret,
+            base_url,
+            stats_base_url,
+            oauth_base_url,
+            oauth_basic_auth,
+            sends_origin,
+            clock,
+            rate_limiter,
+            middlewares,
+            throttle_retry,
+            static_config,
+            failover,
         })
     }
 
+    #[must_use]
     pub fn builder() -> RustgieClientBuilder {
         RustgieClientBuilder::new()
     }
 
+    /// The current time according to the client's [`Clock`].
+    pub fn now(&self) -> time::OffsetDateTime {
+        self.clock.now()
+    }
+
+    /// Makes a cheap unauthenticated request to find out whether Bungie accepts the configured API key.
+    ///
+    /// Errors unrelated to the key (network failures, maintenance, etc.) are returned as `Err`.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn verify_api_key(&self) -> Result<ApiKeyStatus> {
+        let error = match self.get_available_locales(None).await {
+            Ok(_) => return Ok(ApiKeyStatus::Valid),
+            Err(error) => error,
+        };
+
+        match error.downcast_ref::<RustgieError>() {
+            Some(RustgieError::PlatformError {
+                error_code:
+                    PlatformErrorCodes::ApiInvalidOrExpiredKey
+                    | PlatformErrorCodes::ApiKeyMissingFromRequest
+                    | PlatformErrorCodes::ApplicationDisabled,
+                ..
+            }) => Ok(ApiKeyStatus::InvalidKey),
+            Some(RustgieError::ApiKeyOriginRejected { .. }) => Ok(ApiKeyStatus::OriginMismatch),
+            Some(RustgieError::HttpStatus { status })
+                if *status == reqwest::StatusCode::UNAUTHORIZED =>
+            {
+                Ok(ApiKeyStatus::InvalidKey)
+            }
+            _ => Err(error),
+        }
+    }
+
+    /// Waits for `host`'s rate limit, then sends a request through the middleware chain,
+    /// moving on through the fallback base URLs while Platform hosts can't be connected to.
+    async fn execute(&self, request: reqwest::Request, host: BungieHost) -> Result<reqwest::Response> {
+        self.execute_through(&self.middlewares, request, host).await
+    }
+
+    /// Like [`execute`](RustgieClient::execute), but skipping the [`ThrottleRetry`] middleware, which would read
+    /// the whole body into memory to look for a throttle before any of it could be streamed.
+    async fn execute_content(&self, request: reqwest::Request, host: BungieHost) -> Result<reqwest::Response> {
+        let skip = usize::from(self.throttle_retry);
+        self.execute_through(&self.middlewares[skip..], request, host).await
+    }
+
+    async fn execute_through(
+        &self,
+        middlewares: &[Arc<dyn Middleware>],
+        request: reqwest::Request,
+        host: BungieHost,
+    ) -> Result<reqwest::Response> {
+        self.rate_limiter.acquire(host).await;
+
+        let primary = self.base_url.as_deref().unwrap_or(BungieHost::Platform.origin());
+        let (failover, path) = match (&self.failover, host, request.url().as_str().strip_prefix(primary)) {
+            (Some(failover), BungieHost::Platform, Some(path)) => (failover, path.to_string()),
+            _ => return Next::new(&self.client, middlewares).run(request).await,
+        };
+
+        let order = failover.order();
+        let mut request = request;
+        let mut attempt = 0;
+        loop {
+            let position = order[attempt];
+            let retry = order.get(attempt + 1).and_then(|_| request.try_clone());
+            *request.url_mut() = Url::parse(&format!("{}{path}", failover.base_url(position, primary)))
+                .with_context(|| "Error parsing URL")?;
+
+            let result = Next::new(&self.client, middlewares).run(request).await;
+            let unreachable = matches!(
+                &result,
+                Err(error) if error.downcast_ref::<RustgieError>().and_then(RustgieError::connect_failure).is_some()
+            );
+            if !unreachable {
+                failover.answered(position);
+            }
+            request = match retry {
+                Some(retry) if unreachable => retry,
+                _ => return result,
+            };
+            attempt += 1;
+        }
+    }
+
+    /// Applies the base URL override for the host `url` is on, returning which host that is.
+    fn rebase_url(&self, url: Url) -> Result<(Url, BungieHost)> {
+        for (host, base_url) in [
+            (BungieHost::Platform, &self.base_url),
+            (BungieHost::Stats, &self.stats_base_url),
+        ] {
+            if let Some(path) = url.as_str().strip_prefix(host.origin()) {
+                return match base_url {
+                    None => Ok((url, host)),
+                    Some(base_url) => Url::parse(&format!("{base_url}{path}"))
+                        .with_context(|| "Error parsing URL")
+                        .map(|url| (url, host)),
+                };
+            }
+        }
+        Ok((url, BungieHost::Platform))
+    }
+
     async fn bungie_api_get<T: serde::de::DeserializeOwned>(
         &self,
         url: Url,
         access_token: Option<&str>,
     ) -> Result<T> {
+        let (url, host) = self.rebase_url(url)?;
         let request = self.client.get(url);
 
         match access_token {
-            None => self.process_api_response::<T>(request).await,
+            None => self.process_api_response::<T>(request, host).await,
             Some(at) => {
-                self.process_api_response::<T>(request.bearer_auth(at.to_string()))
+                self.process_api_response::<T>(request.bearer_auth(at.to_string()), host)
                     .await
             }
         }
     }
 
+    /// Downloads a JSON file from Bungie's static content, such as a manifest table. `path` is relative to
+    /// `https://www.bungie.net`, as given in the manifest. The body is the content itself, not an API envelope.
+    /// With `lossy_utf8`, invalid UTF-8 in the file is replaced rather than failing the download.
+    pub(crate) async fn bungie_content_get<T: serde::de::DeserializeOwned>(&self, path: &str, lossy_utf8: bool) -> Result<T> {
+        let url = Url::parse(&format!("{}{path}", BungieHost::Platform.origin())).with_context(|| "Error parsing URL")?;
+        let (url, host) = self.rebase_url(url)?;
+        let request = self.client.get(url).build().with_context(|| "There was an error building the request")?;
+        let http_response = self.execute(request, host).await?;
+
+        if !http_response.status().is_success() {
+            return Err(RustgieError::HttpStatus { status: http_response.status() }.into());
+        }
+        let body = http_response
+            .bytes()
+            .await
+            .with_context(|| format!("There was an error downloading {path}"))?;
+        body::parse_json::<T>(&body, lossy_utf8).with_context(|| format!("There was an error deserializing {path}"))
+    }
+
     async fn bungie_api_post<T: serde::de::DeserializeOwned>(
         &self,
         url: Url,
         access_token: Option<&str>,
     ) -> Result<T> {
+        let (url, host) = self.rebase_url(url)?;
         let request = self.client.post(url);
 
         match access_token {
-            None => self.process_api_response::<T>(request).await,
+            None => self.process_api_response::<T>(request, host).await,
             Some(at) => {
-                self.process_api_response::<T>(request.bearer_auth(at))
+                self.process_api_response::<T>(request.bearer_auth(at), host)
                     .await
             }
         }
@@ -168,57 +724,102 @@ impl RustgieClient {
         request_body: U,
         access_token: Option<&str>,
     ) -> Result<T> {
+        let (url, host) = self.rebase_url(url)?;
         let request = self.client.post(url).json(&request_body);
 
         match access_token {
-            None => self.process_api_response::<T>(request).await,
+            None => self.process_api_response::<T>(request, host).await,
             Some(at) => {
-                self.process_api_response::<T>(request.bearer_auth(at))
+                self.process_api_response::<T>(request.bearer_auth(at), host)
                     .await
             }
         }
     }
 
+    /// Sends a POST for an action whose success envelope carries nothing worth reading.
+    /// Bungie sends these back with a `Response` of 0, null, or no body at all, so none of those are treated as errors.
+    async fn bungie_api_post_void_with_body<U: serde::Serialize>(
+        &self,
+        url: Url,
+        request_body: U,
+        access_token: Option<&str>,
+    ) -> Result<()> {
+        let (url, host) = self.rebase_url(url)?;
+        let request = self.client.post(url).json(&request_body);
+
+        match access_token {
+            None => self.process_optional_api_response::<IgnoredAny>(request, host).await?,
+            Some(at) => {
+                self.process_optional_api_response::<IgnoredAny>(request.bearer_auth(at), host)
+                    .await?
+            }
+        };
+        Ok(())
+    }
+
     async fn process_api_response<T: serde::de::DeserializeOwned>(
         &self,
         request: reqwest::RequestBuilder,
+        host: BungieHost,
     ) -> Result<T> {
-        let http_response = request
-            .send()
-            .await
-            .with_context(|| "There was an error connecting to the Bungie API")?;
-
-        let headers = http_response.headers();
-
-        if !headers.contains_key("Content-Type") {
-            return Err(anyhow!("'Content-Type' header is not present"));
+        match self.process_optional_api_response::<T>(request, host).await? {
+            None => Err(anyhow!("The Bungie API did not include a response")),
+            Some(resp) => Ok(resp),
         }
+    }
 
-        if !headers["Content-Type"]
-            .to_str()
-            .with_context(|| "Could not parse Content-Type header from Bungie API as string")?
-            .starts_with("application/json")
-        {
-            return Err(anyhow!(
-                "'Content-Type' of response was not 'application/json'"
-            ));
+    async fn process_optional_api_response<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+        host: BungieHost,
+    ) -> Result<Option<T>> {
+        let request = request.build().with_context(|| "There was an error building the request")?;
+        #[cfg(feature = "tracing")]
+        let url = request.url().clone();
+        let sends_origin = self.sends_origin || request.headers().contains_key(reqwest::header::ORIGIN);
+        let http_response = self.execute(request, host).await?;
+
+        if http_response.status().is_success() && http_response.content_length() == Some(0) {
+            return Ok(None);
         }
 
-        let deserialized_response = http_response
-            .json::<BungieApiResponse<T>>()
+        check_json_content_type(&http_response, sends_origin)?;
+        let status = http_response.status();
+
+        let body = http_response
+            .bytes()
             .await
+            .with_context(|| "There was an error reading the response body")?;
+
+        #[cfg(not(feature = "tracing"))]
+        let deserialized_response = body::parse_json::<BungieApiResponse<T>>(&body, false)
             .with_context(|| "There was an error deserializing the JSON response")?;
 
+        #[cfg(feature = "tracing")]
+        let deserialized_response = {
+            let (parsed, events) = rustgie_types::diagnostics::with_diagnostics(|| {
+                body::parse_json::<BungieApiResponse<T>>(&body, false)
+            });
+            for event in events {
+                tracing::debug!(%url, ?event, "Tolerated unexpected data in Bungie API response");
+            }
+            parsed.with_context(|| "There was an error deserializing the JSON response")?
+        };
+
         match deserialized_response.error_code {
-            rustgie_types::exceptions::PlatformErrorCodes::Success => {
-                match deserialized_response.response {
-                    None => Err(anyhow!("The Bungie API did not include a response")),
-                    Some(resp) => Ok(resp),
-                }
+            PlatformErrorCodes::Success => Ok(deserialized_response.response),
+            PlatformErrorCodes::OriginHeaderDoesNotMatchKey => Err(RustgieError::ApiKeyOriginRejected {
+                error_code: Some(PlatformErrorCodes::OriginHeaderDoesNotMatchKey),
+                status,
             }
-            error_code => Err(anyhow!(
-                "The Bungie API returned a PlatformErrorCode of {error_code}"
-            )),
+            .into()),
+            error_code => Err(RustgieError::PlatformError {
+                error_code,
+                error_status: deserialized_response.error_status,
+                message: deserialized_response.message,
+                throttle_seconds: deserialized_response.throttle_seconds,
+            }
+            .into()),
         }
     }
 
@@ -247,42 +848,29 @@ impl RustgieClient {
 
         query_params.push(("response_type", "code"));
 
-        return Ok(Url::parse_with_params(
+        Ok(Url::parse_with_params(
             &format!("https://www.bungie.net/{language_code}/OAuth/Authorize/"),
             query_params,
         )
         .with_context(|| "Error parsing OAuth authorization URL")?
-        .to_string());
+        .to_string())
     }
 
     async fn process_oauth_response(
         &self,
         request: reqwest::RequestBuilder,
     ) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
-        let http_response = request
-            .send()
-            .await
-            .with_context(|| "There was an error connecting to the Bungie API")?;
-
-        let headers = http_response.headers();
+        let request = request.build().with_context(|| "There was an error building the request")?;
+        let sends_origin = self.sends_origin || request.headers().contains_key(reqwest::header::ORIGIN);
+        let http_response = self.execute(request, BungieHost::Platform).await?;
 
-        if !headers.contains_key("Content-Type") {
-            return Err(anyhow!("'Content-Type' header is not present"));
-        }
+        check_json_content_type(&http_response, sends_origin)?;
 
-        if !headers["Content-Type"]
-            .to_str()
-            .with_context(|| "Could not parse Content-Type header from Bungie API as string")?
-            .starts_with("application/json")
-        {
-            return Err(anyhow!(
-                "'Content-Type' of response was not 'application/json'"
-            ));
-        }
-
-        let deserialized_response = http_response
-            .json::<rustgie_types::api_response_::BungieTokenResponse>()
+        let body = http_response
+            .bytes()
             .await
+            .with_context(|| "There was an error reading the response body")?;
+        let deserialized_response = body::parse_json::<rustgie_types::api_response_::BungieTokenResponse>(&body, false)
             .with_context(|| "There was an error deserializing the JSON response")?;
 
         match deserialized_response.access_token {
@@ -291,65 +879,118 @@ impl RustgieClient {
         }
     }
 
+    #[must_use = "futures do nothing unless awaited"]
     pub async fn oauth_get_auth_token_(
         &self,
         auth_code: &str,
     ) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
-        let mut form = HashMap::<&str, &str>::new();
+        self.oauth_token_request(GrantParams::AuthorizationCode(auth_code))
+            .await
+    }
 
-        match &self.oauth_client_id {
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn oauth_refresh_auth_token_(
+        &self,
+        refresh_token: &str,
+    ) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
+        self.oauth_token_request(GrantParams::RefreshToken(refresh_token))
+            .await
+    }
+
+    async fn oauth_token_request(
+        &self,
+        grant: GrantParams<'_>,
+    ) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
+        let client_id = match &self.oauth_client_id {
             None => return Err(anyhow!("OAuth client ID is required")),
-            Some(client_id) => {
-                form.insert("client_id", client_id);
+            Some(client_id) => client_id,
+        };
+
+        let client_secret = match (&grant, &self.oauth_client_secret) {
+            (GrantParams::RefreshToken(_), None) => {
+                return Err(anyhow!("OAuth client secret is required"))
+            }
+            (_, client_secret) => client_secret.as_deref(),
+        };
+
+        let mut form = HashMap::<&str, &str>::new();
+        match grant {
+            GrantParams::AuthorizationCode(auth_code) => {
+                form.insert("grant_type", "authorization_code");
+                form.insert("code", auth_code);
+            }
+            GrantParams::RefreshToken(refresh_token) => {
+                form.insert("grant_type", "refresh_token");
+                form.insert("refresh_token", refresh_token);
             }
         }
 
-        match &self.oauth_client_secret {
-            None => {}
-            Some(client_secret) => {
-                form.insert("client_secret", client_secret);
+        let mut request = self.client.post(format!(
+            "{}/Platform/App/OAuth/Token/",
+            self.oauth_base_url
+                .as_deref()
+                .unwrap_or("https://www.bungie.net")
+        ));
+
+        match client_secret {
+            Some(client_secret) if self.oauth_basic_auth => {
+                request = request.basic_auth(client_id, Some(client_secret));
+            }
+            _ => {
+                form.insert("client_id", client_id);
+                if let Some(client_secret) = client_secret {
+                    form.insert("client_secret", client_secret);
+                }
             }
         }
 
-        form.insert("grant_type", "authorization_code");
-        form.insert("code", auth_code);
+        self.process_oauth_response(request.form(&form)).await
+    }
+}
 
-        self.process_oauth_response(
-            self.client
-                .post("https://www.bungie.net/Platform/App/OAuth/Token/")
-                .form(&form),
-        )
-        .await
+/// Base URLs must be HTTPS unless plain HTTP was explicitly allowed for testing.
+fn check_scheme(url: &Url, allow_http: bool) -> Result<()> {
+    match url.scheme() {
+        "https" => Ok(()),
+        "http" if allow_http => Ok(()),
+        scheme => Err(anyhow!(
+            "Base URLs must use https, not {scheme}; plain HTTP is only available through dangerously_allow_http with the test-utils feature"
+        )),
     }
+}
 
-    pub async fn oauth_refresh_auth_token_(
-        &self,
-        refresh_token: &str,
-    ) -> Result<rustgie_types::api_response_::BungieTokenResponse> {
-        let mut form = HashMap::<&str, &str>::new();
+/// Checks that `http_response` holds JSON. `sends_origin` is whether the request carried an `Origin` header,
+/// the only case in which a bare 401 page can be put down to the key's origin restrictions.
+fn check_json_content_type(http_response: &reqwest::Response, sends_origin: bool) -> Result<()> {
+    let headers = http_response.headers();
 
-        match &self.oauth_client_id {
-            None => return Err(anyhow!("OAuth client ID is required")),
-            Some(client_id) => {
-                form.insert("client_id", client_id);
-            }
+    if !headers.contains_key("Content-Type") {
+        if !http_response.status().is_success() {
+            return Err(RustgieError::HttpStatus { status: http_response.status() }.into());
         }
+        return Err(anyhow!("'Content-Type' header is not present"));
+    }
 
-        match &self.oauth_client_secret {
-            None => return Err(anyhow!("OAuth client secret is required")),
-            Some(client_secret) => {
-                form.insert("client_secret", client_secret);
+    let content_type = headers["Content-Type"]
+        .to_str()
+        .with_context(|| "Could not parse Content-Type header from Bungie API as string")?;
+    if !content_type.starts_with("application/json") {
+        // Bungie's front end answers origin-restricted keys on some endpoints with its generic 401 page instead of an envelope,
+        // but it sends the same page for keys that are invalid or expired, so without an origin there's nothing to blame it on.
+        if sends_origin && http_response.status() == reqwest::StatusCode::UNAUTHORIZED && content_type.starts_with("text/html") {
+            return Err(RustgieError::ApiKeyOriginRejected {
+                error_code: None,
+                status: http_response.status(),
             }
+            .into());
         }
-
-        form.insert("grant_type", "refresh_token");
-        form.insert("refresh_token", refresh_token);
-
-        self.process_oauth_response(
-            self.client
-                .post("https://www.bungie.net/Platform/App/OAuth/Token/")
-                .form(&form),
-        )
-        .await
+        if !http_response.status().is_success() {
+            return Err(RustgieError::HttpStatus { status: http_response.status() }.into());
+        }
+        return Err(anyhow!(
+            "'Content-Type' of response was not 'application/json'"
+        ));
     }
+
+    Ok(())
 }
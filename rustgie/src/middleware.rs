@@ -0,0 +1,149 @@
+﻿//! Hooks around every HTTP request the client sends, including OAuth token requests.
+//!
+//! Middlewares run in the order they were registered, each deciding whether and how to call the next one; the last one hands the request to reqwest.
+//! Throttle retries are built on the same chain: [`RustgieClientBuilder::with_throttle_retries`](crate::RustgieClientBuilder::with_throttle_retries) registers a [`ThrottleRetry`] ahead of any middleware of your own.
+
+use crate::body::strip_byte_order_mark;
+use crate::clock::Sleeper;
+use crate::errors::{classify_connect_error, is_throttle_code, RustgieError};
+use anyhow::{Context, Result};
+use reqwest::header::CONTENT_TYPE;
+use rustgie_types::exceptions::PlatformErrorCodes;
+use serde::Deserialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The future a [`Middleware`] returns.
+pub type MiddlewareFuture<'a> = Pin<Box<dyn Future<Output = Result<reqwest::Response>> + Send + 'a>>;
+
+/// Something that sees each request before it's sent and each response before the client reads it.
+pub trait Middleware: Send + Sync {
+    /// Handles `request`, usually by passing it (or a modified copy) to `next`.
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a>;
+}
+
+/// The rest of the chain after the current middleware. It can be run more than once, as long as the request can be cloned.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a reqwest::Client,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a reqwest::Client, middlewares: &'a [Arc<dyn Middleware>]) -> Next<'a> {
+        Next { client, middlewares }
+    }
+
+    /// Sends `request` through the remaining middlewares, then to Bungie.
+    pub fn run(self, request: reqwest::Request) -> MiddlewareFuture<'a> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Next::new(self.client, rest)),
+            None => Box::pin(async move {
+                self.client.execute(request).await.map_err(|error| {
+                    match classify_connect_error(&error) {
+                        Some(failure) => anyhow::Error::new(RustgieError::Connect { failure, source: error }),
+                        None => anyhow::Error::new(error),
+                    }
+                    .context("There was an error connecting to the Bungie API")
+                })
+            }),
+        }
+    }
+}
+
+/// Retries requests Bungie rejects as throttled, waiting the `ThrottleSeconds` Bungie asks for, or backing off exponentially from one second when it doesn't say.
+///
+/// Only JSON responses are read to look for a throttle; anything else, such as a static content download, is passed on untouched and still streams.
+pub struct ThrottleRetry {
+    retries: u32,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+impl ThrottleRetry {
+    pub fn new(retries: u32, sleeper: Arc<dyn Sleeper>) -> ThrottleRetry {
+        ThrottleRetry { retries, sleeper }
+    }
+}
+
+/// Just enough of a Bungie API envelope to tell whether it was throttled.
+#[derive(Deserialize)]
+struct ThrottleEnvelope {
+    #[serde(rename = "ErrorCode")]
+    error_code: PlatformErrorCodes,
+
+    #[serde(rename = "ThrottleSeconds")]
+    #[serde(default)]
+    throttle_seconds: i32,
+}
+
+impl Middleware for ThrottleRetry {
+    fn handle<'a>(&'a self, mut request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let mut attempt = 0;
+            loop {
+                let retry = if attempt < self.retries {
+                    request.try_clone()
+                } else {
+                    None
+                };
+
+                let response = next.run(request).await?;
+                request = match retry {
+                    None => return Ok(response),
+                    Some(retry) => retry,
+                };
+                if !is_json(&response) {
+                    return Ok(response);
+                }
+
+                let status = response.status();
+                let version = response.version();
+                let headers = response.headers().clone();
+                let body = response
+                    .bytes()
+                    .await
+                    .with_context(|| "There was an error reading the response body")?;
+
+                let throttle_seconds = match serde_json::from_slice::<ThrottleEnvelope>(strip_byte_order_mark(&body)) {
+                    Ok(envelope) if is_throttle_code(envelope.error_code) => envelope.throttle_seconds,
+                    _ => {
+                        let mut rebuilt = http::Response::builder().status(status).version(version);
+                        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+                            *rebuilt_headers = headers;
+                        }
+                        return Ok(rebuilt
+                            .body(body)
+                            .with_context(|| "There was an error rebuilding the response")?
+                            .into());
+                    }
+                };
+
+                self.sleeper.sleep(throttle_backoff(attempt, throttle_seconds)).await;
+                attempt += 1;
+            }
+        })
+    }
+}
+
+/// Whether `response` says its body is JSON, and so could be a Bungie API envelope.
+fn is_json(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map_or(false, |mime| {
+            let mime = mime.trim().to_ascii_lowercase();
+            mime == "application/json" || mime.ends_with("+json")
+        })
+}
+
+/// How long to wait before retry number `attempt` (from 0) of a throttled request: what Bungie asked for, or 1, 2, 4... seconds capped at 32 when it didn't say.
+fn throttle_backoff(attempt: u32, throttle_seconds: i32) -> Duration {
+    match u64::try_from(throttle_seconds) {
+        Ok(seconds) if seconds > 0 => Duration::from_secs(seconds),
+        _ => Duration::from_secs(1 << attempt.min(5)),
+    }
+}
@@ -0,0 +1,83 @@
+﻿use crate::RustgieClient;
+use anyhow::{anyhow, Result};
+use rustgie_types::api_response_::BungieTokenResponse;
+use time::{Duration, OffsetDateTime};
+
+/// Holds a user's OAuth token and refreshes it through the client shortly before the access token expires.
+///
+/// Expiry is judged by the client's [`crate::Clock`]. Concurrent callers share a single refresh. The tokens are
+/// redacted in the `Debug` output.
+#[derive(Debug)]
+pub struct OAuthSession {
+    state: tokio::sync::Mutex<SessionToken>,
+    refresh_leeway: Duration,
+}
+
+#[derive(Debug)]
+struct SessionToken {
+    token: BungieTokenResponse,
+    received_at: OffsetDateTime,
+}
+
+impl OAuthSession {
+    /// Wraps a token received at `received_at`. Refreshes start 60 seconds before the access token expires.
+    pub fn new(token: BungieTokenResponse, received_at: OffsetDateTime) -> OAuthSession {
+        OAuthSession {
+            state: tokio::sync::Mutex::new(SessionToken { token, received_at }),
+            refresh_leeway: Duration::seconds(60),
+        }
+    }
+
+    /// Exchanges an authorization code for a token and starts a session with it.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn from_authorization_code(client: &RustgieClient, code: &str) -> Result<OAuthSession> {
+        let token = client.oauth_get_auth_token_(code).await?;
+        Ok(OAuthSession::new(token, client.now()))
+    }
+
+    /// How long before the access token expires to start refreshing it.
+    #[must_use]
+    pub fn with_refresh_leeway(mut self, leeway: Duration) -> OAuthSession {
+        self.refresh_leeway = leeway;
+        self
+    }
+
+    /// A current access token, refreshing first if the held one expires within the leeway.
+    /// Errors when a refresh is needed but there is no refresh token or it has expired, in which case the user must authorize again.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn access_token(&self, client: &RustgieClient) -> Result<String> {
+        let mut state = self.state.lock().await;
+        let now = client.now();
+
+        if now + self.refresh_leeway < state.token.access_expires_at(state.received_at) {
+            if let Some(access_token) = &state.token.access_token {
+                return Ok(access_token.clone());
+            }
+        }
+
+        let refresh_token = match &state.token.refresh_token {
+            None => return Err(anyhow!("The access token has expired and the session has no refresh token")),
+            Some(refresh_token) => refresh_token,
+        };
+        if state.token.refresh_expires_at(state.received_at).map_or(false, |expires_at| now >= expires_at) {
+            return Err(anyhow!("The refresh token has expired; the user must authorize again"));
+        }
+
+        let token = client.oauth_refresh_auth_token_(refresh_token).await?;
+        let access_token = token
+            .access_token
+            .clone()
+            .ok_or_else(|| anyhow!("The Bungie API did not include an access token"))?;
+        *state = SessionToken {
+            token,
+            received_at: client.now(),
+        };
+        Ok(access_token)
+    }
+
+    /// A copy of the token currently held.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn token(&self) -> BungieTokenResponse {
+        self.state.lock().await.token.clone()
+    }
+}
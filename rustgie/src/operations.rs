@@ -0,0 +1,240 @@
+﻿//! Bungie's names for the endpoints the client calls, and matching raw URLs back to them.
+//!
+//! [`OPERATIONS`] lists every endpoint with its OpenAPI operation ID and path template, relative to `/Platform` as in Bungie's spec.
+//! [`match_url`] reverses a URL captured elsewhere (a proxy log, or a [`Middleware`](crate::Middleware) looking at a request) into its operation
+//! and path parameters, which gives metrics a stable label that doesn't depend on membership IDs and hashes.
+
+use crate::rate_limit::BungieHost;
+use once_cell::sync::Lazy;
+use reqwest::Method;
+use std::collections::HashMap;
+
+/// One Bungie API endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Operation {
+    /// The OpenAPI operation ID, e.g. `Destiny2.GetProfile`.
+    pub id: &'static str,
+    pub method: Method,
+    /// The path after `/Platform`, with parameters in braces, e.g. `/Destiny2/{membershipType}/Profile/{destinyMembershipId}/`.
+    pub path_template: &'static str,
+    /// The host Bungie serves the endpoint from.
+    pub host: BungieHost,
+}
+
+/// Every endpoint the client has a method for, ordered by operation ID.
+pub static OPERATIONS: &[Operation] = &[
+    Operation { id: "App.GetApplicationApiUsage", method: Method::GET, path_template: "/App/ApiUsage/{applicationId}/", host: BungieHost::Platform },
+    Operation { id: "App.GetBungieApplications", method: Method::GET, path_template: "/App/FirstParty/", host: BungieHost::Platform },
+    Operation { id: "CommunityContent.GetCommunityContent", method: Method::GET, path_template: "/CommunityContent/Get/{sort}/{mediaFilter}/{page}/", host: BungieHost::Platform },
+    Operation { id: "Content.GetContentById", method: Method::GET, path_template: "/Content/GetContentById/{id}/{locale}/", host: BungieHost::Platform },
+    Operation { id: "Content.GetContentByTagAndType", method: Method::GET, path_template: "/Content/GetContentByTagAndType/{tag}/{type}/{locale}/", host: BungieHost::Platform },
+    Operation { id: "Content.GetContentType", method: Method::GET, path_template: "/Content/GetContentType/{type}/", host: BungieHost::Platform },
+    Operation { id: "Content.RssNewsArticles", method: Method::GET, path_template: "/Content/Rss/NewsArticles/{pageToken}/", host: BungieHost::Platform },
+    Operation { id: "Content.SearchContentByTagAndType", method: Method::GET, path_template: "/Content/SearchContentByTagAndType/{tag}/{type}/{locale}/", host: BungieHost::Platform },
+    Operation { id: "Content.SearchContentWithText", method: Method::GET, path_template: "/Content/Search/{locale}/", host: BungieHost::Platform },
+    Operation { id: "Content.SearchHelpArticles", method: Method::GET, path_template: "/Content/SearchHelpArticles/{searchtext}/{size}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.AwaGetActionToken", method: Method::GET, path_template: "/Destiny2/Awa/GetActionToken/{correlationId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.AwaInitializeRequest", method: Method::POST, path_template: "/Destiny2/Awa/Initialize/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.AwaProvideAuthorizationResult", method: Method::POST, path_template: "/Destiny2/Awa/AwaProvideAuthorizationResult/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.ClearLoadout", method: Method::POST, path_template: "/Destiny2/Actions/Loadouts/ClearLoadout/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.EquipItem", method: Method::POST, path_template: "/Destiny2/Actions/Items/EquipItem/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.EquipItems", method: Method::POST, path_template: "/Destiny2/Actions/Items/EquipItems/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.EquipLoadout", method: Method::POST, path_template: "/Destiny2/Actions/Loadouts/EquipLoadout/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetActivityHistory", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Character/{characterId}/Stats/Activities/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetCharacter", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/Character/{characterId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetClanAggregateStats", method: Method::GET, path_template: "/Destiny2/Stats/AggregateClanStats/{groupId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetClanBannerSource", method: Method::GET, path_template: "/Destiny2/Clan/ClanBannerDictionary/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetClanLeaderboards", method: Method::GET, path_template: "/Destiny2/Stats/Leaderboards/Clans/{groupId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetClanWeeklyRewardState", method: Method::GET, path_template: "/Destiny2/Clan/{groupId}/WeeklyRewardState/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetCollectibleNodeDetails", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/Character/{characterId}/Collectibles/{collectiblePresentationNodeHash}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetDestinyAggregateActivityStats", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Character/{characterId}/Stats/AggregateActivityStats/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetDestinyEntityDefinition", method: Method::GET, path_template: "/Destiny2/Manifest/{entityType}/{hashIdentifier}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetDestinyManifest", method: Method::GET, path_template: "/Destiny2/Manifest/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetHistoricalStats", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Character/{characterId}/Stats/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetHistoricalStatsDefinition", method: Method::GET, path_template: "/Destiny2/Stats/Definition/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetHistoricalStatsForAccount", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Stats/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetItem", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/Item/{itemInstanceId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetLeaderboards", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Stats/Leaderboards/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetLeaderboardsForCharacter", method: Method::GET, path_template: "/Destiny2/Stats/Leaderboards/{membershipType}/{destinyMembershipId}/{characterId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetLinkedProfiles", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{membershipId}/LinkedProfiles/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetPostGameCarnageReport", method: Method::GET, path_template: "/Destiny2/Stats/PostGameCarnageReport/{activityId}/", host: BungieHost::Stats },
+    Operation { id: "Destiny2.GetProfile", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetPublicMilestoneContent", method: Method::GET, path_template: "/Destiny2/Milestones/{milestoneHash}/Content/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetPublicMilestones", method: Method::GET, path_template: "/Destiny2/Milestones/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetPublicVendors", method: Method::GET, path_template: "/Destiny2/Vendors/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetUniqueWeaponHistory", method: Method::GET, path_template: "/Destiny2/{membershipType}/Account/{destinyMembershipId}/Character/{characterId}/Stats/UniqueWeapons/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetVendor", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/Character/{characterId}/Vendors/{vendorHash}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.GetVendors", method: Method::GET, path_template: "/Destiny2/{membershipType}/Profile/{destinyMembershipId}/Character/{characterId}/Vendors/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.InsertSocketPlug", method: Method::POST, path_template: "/Destiny2/Actions/Items/InsertSocketPlug/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.InsertSocketPlugFree", method: Method::POST, path_template: "/Destiny2/Actions/Items/InsertSocketPlugFree/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.PullFromPostmaster", method: Method::POST, path_template: "/Destiny2/Actions/Items/PullFromPostmaster/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.ReportOffensivePostGameCarnageReportPlayer", method: Method::POST, path_template: "/Destiny2/Stats/PostGameCarnageReport/{activityId}/Report/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.SearchDestinyEntities", method: Method::GET, path_template: "/Destiny2/Armory/Search/{type}/{searchTerm}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.SearchDestinyPlayerByBungieName", method: Method::POST, path_template: "/Destiny2/SearchDestinyPlayerByBungieName/{membershipType}/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.SetItemLockState", method: Method::POST, path_template: "/Destiny2/Actions/Items/SetLockState/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.SetQuestTrackedState", method: Method::POST, path_template: "/Destiny2/Actions/Items/SetTrackedState/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.SnapshotLoadout", method: Method::POST, path_template: "/Destiny2/Actions/Loadouts/SnapshotLoadout/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.TransferItem", method: Method::POST, path_template: "/Destiny2/Actions/Items/TransferItem/", host: BungieHost::Platform },
+    Operation { id: "Destiny2.UpdateLoadoutIdentifiers", method: Method::POST, path_template: "/Destiny2/Actions/Loadouts/UpdateLoadoutIdentifiers/", host: BungieHost::Platform },
+    Operation { id: "Fireteam.GetActivePrivateClanFireteamCount", method: Method::GET, path_template: "/Fireteam/Clan/{groupId}/ActiveCount/", host: BungieHost::Platform },
+    Operation { id: "Fireteam.GetAvailableClanFireteams", method: Method::GET, path_template: "/Fireteam/Clan/{groupId}/Available/{platform}/{activityType}/{dateRange}/{slotFilter}/{publicOnly}/{page}/", host: BungieHost::Platform },
+    Operation { id: "Fireteam.GetClanFireteam", method: Method::GET, path_template: "/Fireteam/Clan/{groupId}/Summary/{fireteamId}/", host: BungieHost::Platform },
+    Operation { id: "Fireteam.GetMyClanFireteams", method: Method::GET, path_template: "/Fireteam/Clan/{groupId}/My/{platform}/{includeClosed}/{page}/", host: BungieHost::Platform },
+    Operation { id: "Fireteam.SearchPublicAvailableClanFireteams", method: Method::GET, path_template: "/Fireteam/Search/Available/{platform}/{activityType}/{dateRange}/{slotFilter}/{page}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetCoreTopicsPaged", method: Method::GET, path_template: "/Forum/GetCoreTopicsPaged/{page}/{sort}/{quickDate}/{categoryFilter}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetForumTagSuggestions", method: Method::GET, path_template: "/Forum/GetForumTagSuggestions/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetPoll", method: Method::GET, path_template: "/Forum/Poll/{topicId}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetPostAndParent", method: Method::GET, path_template: "/Forum/GetPostAndParent/{childPostId}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetPostAndParentAwaitingApproval", method: Method::GET, path_template: "/Forum/GetPostAndParentAwaitingApproval/{childPostId}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetPostsThreadedPaged", method: Method::GET, path_template: "/Forum/GetPostsThreadedPaged/{parentPostId}/{page}/{pageSize}/{replySize}/{getParentPost}/{rootThreadMode}/{sortMode}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetPostsThreadedPagedFromChild", method: Method::GET, path_template: "/Forum/GetPostsThreadedPagedFromChild/{childPostId}/{page}/{pageSize}/{replySize}/{rootThreadMode}/{sortMode}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetRecruitmentThreadSummaries", method: Method::POST, path_template: "/Forum/Recruit/Summaries/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetTopicForContent", method: Method::GET, path_template: "/Forum/GetTopicForContent/{contentId}/", host: BungieHost::Platform },
+    Operation { id: "Forum.GetTopicsPaged", method: Method::GET, path_template: "/Forum/GetTopicsPaged/{page}/{pageSize}/{group}/{sort}/{quickDate}/{categoryFilter}/", host: BungieHost::Platform },
+    Operation { id: "GetAvailableLocales", method: Method::GET, path_template: "/GetAvailableLocales/", host: BungieHost::Platform },
+    Operation { id: "GetCommonSettings", method: Method::GET, path_template: "/Settings/", host: BungieHost::Platform },
+    Operation { id: "GetGlobalAlerts", method: Method::GET, path_template: "/GlobalAlerts/", host: BungieHost::Platform },
+    Operation { id: "GetUserSystemOverrides", method: Method::GET, path_template: "/UserSystemOverrides/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.AbdicateFoundership", method: Method::POST, path_template: "/GroupV2/{groupId}/Admin/AbdicateFoundership/{membershipType}/{founderIdNew}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.AddOptionalConversation", method: Method::POST, path_template: "/GroupV2/{groupId}/OptionalConversations/Add/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.ApproveAllPending", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/ApproveAll/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.ApprovePending", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/Approve/{membershipType}/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.ApprovePendingForList", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/ApproveList/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.BanMember", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/{membershipType}/{membershipId}/Ban/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.DenyAllPending", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/DenyAll/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.DenyPendingForList", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/DenyList/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.EditClanBanner", method: Method::POST, path_template: "/GroupV2/{groupId}/EditClanBanner/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.EditFounderOptions", method: Method::POST, path_template: "/GroupV2/{groupId}/EditFounderOptions/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.EditGroup", method: Method::POST, path_template: "/GroupV2/{groupId}/Edit/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.EditGroupMembership", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/{membershipType}/{membershipId}/SetMembershipType/{memberType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.EditOptionalConversation", method: Method::POST, path_template: "/GroupV2/{groupId}/OptionalConversations/Edit/{conversationId}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetAdminsAndFounderOfGroup", method: Method::GET, path_template: "/GroupV2/{groupId}/AdminsAndFounder/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetAvailableAvatars", method: Method::GET, path_template: "/GroupV2/GetAvailableAvatars/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetAvailableThemes", method: Method::GET, path_template: "/GroupV2/GetAvailableThemes/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetBannedMembersOfGroup", method: Method::GET, path_template: "/GroupV2/{groupId}/Banned/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetGroup", method: Method::GET, path_template: "/GroupV2/{groupId}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetGroupByName", method: Method::GET, path_template: "/GroupV2/Name/{groupName}/{groupType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetGroupByNameV2", method: Method::POST, path_template: "/GroupV2/NameV2/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetGroupOptionalConversations", method: Method::GET, path_template: "/GroupV2/{groupId}/OptionalConversations/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetGroupsForMember", method: Method::GET, path_template: "/GroupV2/User/{membershipType}/{membershipId}/{filter}/{groupType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetInvitedIndividuals", method: Method::GET, path_template: "/GroupV2/{groupId}/Members/InvitedIndividuals/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetMembersOfGroup", method: Method::GET, path_template: "/GroupV2/{groupId}/Members/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetPendingMemberships", method: Method::GET, path_template: "/GroupV2/{groupId}/Members/Pending/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetPotentialGroupsForMember", method: Method::GET, path_template: "/GroupV2/User/Potential/{membershipType}/{membershipId}/{filter}/{groupType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetRecommendedGroups", method: Method::POST, path_template: "/GroupV2/Recommended/{groupType}/{createDateRange}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GetUserClanInviteSetting", method: Method::GET, path_template: "/GroupV2/GetUserClanInviteSetting/{mType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.GroupSearch", method: Method::POST, path_template: "/GroupV2/Search/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.IndividualGroupInvite", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/IndividualInvite/{membershipType}/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.IndividualGroupInviteCancel", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/IndividualInviteCancel/{membershipType}/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.KickMember", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/{membershipType}/{membershipId}/Kick/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.RecoverGroupForFounder", method: Method::GET, path_template: "/GroupV2/Recover/{membershipType}/{membershipId}/{groupType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.RequestGroupMembership", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/Apply/{membershipType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.RescindGroupMembership", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/RescindApplication/{membershipType}/", host: BungieHost::Platform },
+    Operation { id: "GroupV2.UnbanMember", method: Method::POST, path_template: "/GroupV2/{groupId}/Members/{membershipType}/{membershipId}/Unban/", host: BungieHost::Platform },
+    Operation { id: "Ignore.IgnoreItem", method: Method::POST, path_template: "/Ignore/Ignore/", host: BungieHost::Platform },
+    Operation { id: "Ignore.UnignoreItem", method: Method::POST, path_template: "/Ignore/Unignore/", host: BungieHost::Platform },
+    Operation { id: "Social.AcceptFriendRequest", method: Method::POST, path_template: "/Social/Friends/Requests/Accept/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Social.DeclineFriendRequest", method: Method::POST, path_template: "/Social/Friends/Requests/Decline/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Social.GetFriendList", method: Method::GET, path_template: "/Social/Friends/", host: BungieHost::Platform },
+    Operation { id: "Social.GetFriendRequestList", method: Method::GET, path_template: "/Social/Friends/Requests/", host: BungieHost::Platform },
+    Operation { id: "Social.GetPlatformFriendList", method: Method::GET, path_template: "/Social/PlatformFriends/{friendPlatform}/{page}/", host: BungieHost::Platform },
+    Operation { id: "Social.IssueFriendRequest", method: Method::POST, path_template: "/Social/Friends/Add/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Social.RemoveFriend", method: Method::POST, path_template: "/Social/Friends/Remove/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Social.RemoveFriendRequest", method: Method::POST, path_template: "/Social/Friends/Requests/Remove/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Tokens.ApplyMissingPartnerOffersWithoutClaim", method: Method::POST, path_template: "/Tokens/Partner/ApplyMissingOffers/{partnerApplicationId}/{targetBnetMembershipId}/", host: BungieHost::Platform },
+    Operation { id: "Tokens.ClaimPartnerOffer", method: Method::POST, path_template: "/Tokens/Partner/ClaimOffer/", host: BungieHost::Platform },
+    Operation { id: "Tokens.ForceDropsRepair", method: Method::POST, path_template: "/Tokens/Partner/ForceDropsRepair/", host: BungieHost::Platform },
+    Operation { id: "Tokens.GetBungieRewardsForPlatformUser", method: Method::GET, path_template: "/Tokens/Rewards/GetRewardsForPlatformUser/{membershipId}/{membershipType}/", host: BungieHost::Platform },
+    Operation { id: "Tokens.GetBungieRewardsForUser", method: Method::GET, path_template: "/Tokens/Rewards/GetRewardsForUser/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "Tokens.GetBungieRewardsList", method: Method::GET, path_template: "/Tokens/Rewards/BungieRewards/", host: BungieHost::Platform },
+    Operation { id: "Tokens.GetPartnerOfferSkuHistory", method: Method::GET, path_template: "/Tokens/Partner/History/{partnerApplicationId}/{targetBnetMembershipId}/", host: BungieHost::Platform },
+    Operation { id: "Tokens.GetPartnerRewardHistory", method: Method::GET, path_template: "/Tokens/Partner/History/{targetBnetMembershipId}/Application/{partnerApplicationId}/", host: BungieHost::Platform },
+    Operation { id: "Trending.GetTrendingCategories", method: Method::GET, path_template: "/Trending/Categories/", host: BungieHost::Platform },
+    Operation { id: "Trending.GetTrendingCategory", method: Method::GET, path_template: "/Trending/Categories/{categoryId}/{pageNumber}/", host: BungieHost::Platform },
+    Operation { id: "Trending.GetTrendingEntryDetail", method: Method::GET, path_template: "/Trending/Details/{trendingEntryType}/{identifier}/", host: BungieHost::Platform },
+    Operation { id: "User.GetAvailableThemes", method: Method::GET, path_template: "/User/GetAvailableThemes/", host: BungieHost::Platform },
+    Operation { id: "User.GetBungieNetUserById", method: Method::GET, path_template: "/User/GetBungieNetUserById/{id}/", host: BungieHost::Platform },
+    Operation { id: "User.GetCredentialTypesForTargetAccount", method: Method::GET, path_template: "/User/GetCredentialTypesForTargetAccount/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "User.GetMembershipDataById", method: Method::GET, path_template: "/User/GetMembershipsById/{membershipId}/{membershipType}/", host: BungieHost::Platform },
+    Operation { id: "User.GetMembershipDataForCurrentUser", method: Method::GET, path_template: "/User/GetMembershipsForCurrentUser/", host: BungieHost::Platform },
+    Operation { id: "User.GetMembershipFromHardLinkedCredential", method: Method::GET, path_template: "/User/GetMembershipFromHardLinkedCredential/{crType}/{credential}/", host: BungieHost::Platform },
+    Operation { id: "User.GetSanitizedPlatformDisplayNames", method: Method::GET, path_template: "/User/GetSanitizedPlatformDisplayNames/{membershipId}/", host: BungieHost::Platform },
+    Operation { id: "User.SearchByGlobalNamePost", method: Method::POST, path_template: "/User/Search/GlobalName/{page}/", host: BungieHost::Platform },
+    Operation { id: "User.SearchByGlobalNamePrefix", method: Method::GET, path_template: "/User/Search/Prefix/{displayNamePrefix}/{page}/", host: BungieHost::Platform },
+];
+
+/// The operation `method` and `url` call, and its path parameters by template name.
+///
+/// `url` may be a full URL or just the path, with or without the `/Platform` prefix, a trailing slash, or a query string.
+/// Literal segments are matched case-insensitively, as Bungie does, and parameter values are returned as they appear in the URL, still percent-encoded.
+pub fn match_url(method: &Method, url: &str) -> Option<(&'static Operation, HashMap<&'static str, String>)> {
+    static TRIE: Lazy<Node> = Lazy::new(|| Node::build(OPERATIONS));
+
+    // Cut the query and fragment first, so a URL in a query parameter isn't taken for the scheme.
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let path = match url.find("://") {
+        Some(scheme_end) => {
+            let after_scheme = &url[scheme_end + 3..];
+            after_scheme.find('/').map_or("", |path_start| &after_scheme[path_start..])
+        }
+        None => url,
+    };
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    let segments = match segments.split_first() {
+        Some((first, rest)) if first.eq_ignore_ascii_case("Platform") => rest,
+        _ => &segments[..],
+    };
+
+    let operation = TRIE.find(method, segments)?;
+    let parameters = operation
+        .path_template
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .zip(segments)
+        .filter_map(|(template, value)| {
+            let name = template.strip_prefix('{')?.strip_suffix('}')?;
+            Some((name, value.to_string()))
+        })
+        .collect();
+    Some((operation, parameters))
+}
+
+/// A segment trie over the path templates, built once. Literal segments are keyed in lowercase; every parameter at a position shares one child,
+/// since templates name the same position differently (`{destinyMembershipId}` and `{membershipId}` under `/Destiny2/{membershipType}/Profile/`).
+#[derive(Default)]
+struct Node {
+    literals: HashMap<String, Node>,
+    parameter: Option<Box<Node>>,
+    /// The operations whose template ends here, one per method.
+    operations: Vec<&'static Operation>,
+}
+
+impl Node {
+    fn build(operations: &'static [Operation]) -> Node {
+        let mut root = Node::default();
+        for operation in operations {
+            let mut node = &mut root;
+            for segment in operation.path_template.split('/').filter(|segment| !segment.is_empty()) {
+                node = if segment.starts_with('{') {
+                    node.parameter.get_or_insert_with(Default::default)
+                } else {
+                    node.literals.entry(segment.to_ascii_lowercase()).or_default()
+                };
+            }
+            node.operations.push(operation);
+        }
+        root
+    }
+
+    /// Prefers a literal match at each segment and falls back to a parameter, backtracking when the literal branch dead-ends.
+    fn find(&self, method: &Method, segments: &[&str]) -> Option<&'static Operation> {
+        let (segment, rest) = match segments.split_first() {
+            None => return self.operations.iter().find(|operation| operation.method == *method).copied(),
+            Some(split) => split,
+        };
+        self.literals
+            .get(&segment.to_ascii_lowercase())
+            .and_then(|node| node.find(method, rest))
+            .or_else(|| self.parameter.as_ref()?.find(method, rest))
+    }
+}
@@ -0,0 +1,30 @@
+﻿//! Walking Bungie's paged search results.
+
+use crate::cancellation::{CancellationToken, Outcome};
+use anyhow::Result;
+use std::future::Future;
+
+/// Fetches pages from `first_page` onwards until a page reports no more results, collecting every item.
+///
+/// `fetch` returns a page's items and its `hasMore` flag. Cancellation is checked before each page is requested;
+/// a page that has started loading is always finished and included.
+#[must_use = "futures do nothing unless awaited"]
+pub async fn collect_pages<T, F, Fut>(first_page: i32, cancellation: &CancellationToken, mut fetch: F) -> Result<Outcome<Vec<T>>>
+where
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, bool)>>,
+{
+    let mut items = Vec::new();
+    let mut page = first_page;
+    loop {
+        if cancellation.is_cancelled() {
+            return Ok(Outcome::Cancelled(items));
+        }
+        let (results, has_more) = fetch(page).await?;
+        items.extend(results);
+        if !has_more {
+            return Ok(Outcome::Completed(items));
+        }
+        page += 1;
+    }
+}
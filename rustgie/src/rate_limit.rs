@@ -0,0 +1,129 @@
+﻿//! Client-side request pacing, kept separately for each Bungie host.
+//!
+//! Bungie serves most of the Platform from www.bungie.net but some stats endpoints (post game carnage reports) from stats.bungie.net,
+//! and the two are throttled independently; sharing one budget would either slow PGCR crawls down for no reason or let them starve everything else.
+//! Each host gets a token bucket that refills continuously and holds up to one second's worth of requests, so short bursts go out immediately.
+
+use crate::clock::{Clock, Sleeper};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// The Bungie hosts the client sends requests to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BungieHost {
+    /// www.bungie.net, which serves almost every endpoint and OAuth.
+    Platform,
+    /// stats.bungie.net, which serves post game carnage reports.
+    Stats,
+}
+
+impl BungieHost {
+    /// The origin Bungie serves this host's endpoints from.
+    pub fn origin(self) -> &'static str {
+        match self {
+            BungieHost::Platform => "https://www.bungie.net",
+            BungieHost::Stats => "https://stats.bungie.net",
+        }
+    }
+
+    /// The limit the client applies unless told otherwise: Bungie asks applications to stay under 25 requests per second.
+    pub fn default_rate_limit(self) -> RateLimit {
+        RateLimit::per_second(25)
+    }
+}
+
+/// How many requests may be sent to one host in a given period.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+}
+
+impl RateLimit {
+    /// Allows `requests` requests every `per`, in bursts of up to `requests`. A limit of zero requests or a zero period is treated as one request per period.
+    pub fn new(requests: u32, per: Duration) -> RateLimit {
+        RateLimit { requests: requests.max(1), per: per.max(Duration::from_millis(1)) }
+    }
+
+    pub fn per_second(requests: u32) -> RateLimit {
+        RateLimit::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    pub fn per(&self) -> Duration {
+        self.per
+    }
+}
+
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    refilled_at: Option<OffsetDateTime>,
+}
+
+impl Bucket {
+    /// Takes a token if one is available, or says how long until one will be.
+    fn try_take(&mut self, now: OffsetDateTime) -> Result<(), Duration> {
+        let capacity = f64::from(self.limit.requests);
+        let per_token = self.limit.per.as_secs_f64() / capacity;
+        self.tokens = match self.refilled_at {
+            None => capacity,
+            Some(refilled_at) => {
+                let elapsed = (now - refilled_at).as_seconds_f64().max(0.0);
+                (self.tokens + elapsed / per_token).min(capacity)
+            }
+        };
+        self.refilled_at = Some(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) * per_token))
+        }
+    }
+}
+
+/// One token bucket per host. Hosts without a limit are never delayed.
+pub(crate) struct RateLimiter {
+    platform: Option<Mutex<Bucket>>,
+    stats: Option<Mutex<Bucket>>,
+    clock: Arc<dyn Clock>,
+    sleeper: Arc<dyn Sleeper>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(
+        platform: Option<RateLimit>,
+        stats: Option<RateLimit>,
+        clock: Arc<dyn Clock>,
+        sleeper: Arc<dyn Sleeper>,
+    ) -> RateLimiter {
+        let bucket = |limit: Option<RateLimit>| limit.map(|limit| Mutex::new(Bucket { limit, tokens: 0.0, refilled_at: None }));
+        RateLimiter { platform: bucket(platform), stats: bucket(stats), clock, sleeper }
+    }
+
+    /// Waits until a request to `host` is allowed.
+    pub(crate) async fn acquire(&self, host: BungieHost) {
+        let bucket = match host {
+            BungieHost::Platform => &self.platform,
+            BungieHost::Stats => &self.stats,
+        };
+        let bucket = match bucket {
+            None => return,
+            Some(bucket) => bucket,
+        };
+
+        loop {
+            let wait = bucket.lock().expect("rate limiter poisoned").try_take(self.clock.now());
+            match wait {
+                Ok(()) => return,
+                Err(wait) => self.sleeper.sleep(wait).await,
+            }
+        }
+    }
+}
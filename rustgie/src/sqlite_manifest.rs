@@ -0,0 +1,140 @@
+﻿//! Reading definitions from the mobile world content database, enabled by the `sqlite-manifest` feature.
+//!
+//! Bungie publishes the manifest as a zipped SQLite database (see `DestinyManifest.mobileWorldContentPaths`) with one table per definition type.
+//! Each table has an `id` column holding the definition's hash reinterpreted as a signed 32-bit integer, and a `json` column holding the definition.
+//! A few tables (`DestinyHistoricalStatsDefinition`) are keyed by a string `key` instead and can't be read with hashes.
+
+use crate::entity_definitions::{DefinitionProvider, DefinitionTable};
+use anyhow::{anyhow, Context, Result};
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// How much of the database SQLite may memory-map instead of reading through its page cache.
+const MMAP_SIZE: i64 = 512 * 1024 * 1024;
+
+/// How many rows [`SqliteManifest::iter_table`] reads under one lock of the connection.
+const ITER_BATCH_SIZE: usize = 256;
+
+/// A read-only, memory-mapped handle on an unzipped mobile world content database.
+pub struct SqliteManifest {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteManifest {
+    pub fn open(path: impl AsRef<Path>) -> Result<SqliteManifest> {
+        let path = path.as_ref();
+        let connection = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .with_context(|| format!("Could not open the manifest database at {}", path.display()))?;
+        connection
+            .pragma_update(None, "mmap_size", MMAP_SIZE)
+            .with_context(|| "Could not memory-map the manifest database")?;
+
+        Ok(SqliteManifest {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Every table in the database, in name order.
+    pub fn tables(&self) -> Result<Vec<DefinitionTable>> {
+        let connection = self.connection.lock().expect("manifest connection poisoned");
+        let mut statement = connection.prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")?;
+        let names = statement.query_map([], |row| row.get::<_, String>(0))?;
+        names
+            .map(|name| Ok(DefinitionTable::new(name?)))
+            .collect::<Result<Vec<DefinitionTable>>>()
+            .with_context(|| "Could not list the manifest's tables")
+    }
+
+    /// One definition, or `None` if `table` has no row for `hash`.
+    pub fn get<T: DeserializeOwned>(&self, table: &DefinitionTable, hash: u32) -> Result<Option<T>> {
+        let connection = self.connection.lock().expect("manifest connection poisoned");
+        let mut statement = connection.prepare(&format!("SELECT json FROM \"{}\" WHERE id = ?1", table_name(table)?))?;
+        let definition = statement
+            .query_row([hash_to_id(hash)], |row| Ok(parse_json::<T>(row.get_ref(0)?)))
+            .optional()
+            .with_context(|| format!("Could not read {table} {hash} from the manifest"))?;
+        definition
+            .transpose()
+            .with_context(|| format!("Definition {hash} is not a valid {table}"))
+    }
+
+    /// Parses each row of `table` in turn and hands it to `visit` with its hash, without loading the whole table at once.
+    /// Rows are read a few hundred at a time and the connection is released before any of them are visited, so `visit` can call
+    /// [`get`](SqliteManifest::get) on the same manifest. Rows come in `id` order, which isn't hash order for hashes of 2³¹ and up.
+    /// Stops at the first error, from either a row that doesn't parse or `visit` itself.
+    pub fn iter_table<T, F>(&self, table: &DefinitionTable, mut visit: F) -> Result<()>
+    where
+        T: DeserializeOwned,
+        F: FnMut(u32, T) -> Result<()>,
+    {
+        let query = format!("SELECT id, json FROM \"{}\" WHERE id > ?1 ORDER BY id LIMIT ?2", table_name(table)?);
+        let mut after = i64::MIN;
+        loop {
+            let batch = self.read_batch(&query, after).with_context(|| format!("Could not read {table} from the manifest"))?;
+            let last = match batch.last() {
+                Some((id, _)) => *id,
+                None => return Ok(()),
+            };
+            let complete = batch.len() < ITER_BATCH_SIZE;
+            for (id, json) in batch {
+                let hash = id_to_hash(id);
+                let definition = parse_json::<T>((&json).into()).with_context(|| format!("Definition {hash} is not a valid {table}"))?;
+                visit(hash, definition)?;
+            }
+            if complete {
+                return Ok(());
+            }
+            after = i64::from(last);
+        }
+    }
+
+    /// Up to [`ITER_BATCH_SIZE`] raw rows with an `id` above `after`, holding the connection only while they're read.
+    fn read_batch(&self, query: &str, after: i64) -> Result<Vec<(i32, Value)>> {
+        let connection = self.connection.lock().expect("manifest connection poisoned");
+        let mut statement = connection.prepare_cached(query)?;
+        let mut rows = statement.query(rusqlite::params![after, ITER_BATCH_SIZE as i64])?;
+        let mut batch = Vec::with_capacity(ITER_BATCH_SIZE);
+        while let Some(row) = rows.next()? {
+            batch.push((row.get(0)?, row.get(1)?));
+        }
+        Ok(batch)
+    }
+}
+
+impl DefinitionProvider for SqliteManifest {
+    fn raw_definition(&self, table: &DefinitionTable, hash: u32) -> Result<Option<serde_json::Value>> {
+        self.get(table, hash)
+    }
+}
+
+/// Table names can't be bound as parameters, so only plain identifiers are let into the query.
+fn table_name(table: &DefinitionTable) -> Result<&str> {
+    let name = table.name();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(anyhow!("{name:?} is not a manifest table name"));
+    }
+    Ok(name)
+}
+
+/// The `id` Bungie stores a hash under: the same 32 bits, read as signed.
+fn hash_to_id(hash: u32) -> i32 {
+    hash as i32
+}
+
+fn id_to_hash(id: i32) -> u32 {
+    id as u32
+}
+
+/// The `json` column is text in current manifests and a blob in some older ones.
+fn parse_json<T: DeserializeOwned>(value: ValueRef<'_>) -> Result<T> {
+    match value {
+        ValueRef::Text(json) | ValueRef::Blob(json) => Ok(serde_json::from_slice(json)?),
+        other => Err(anyhow!("Expected JSON, found a {} value", other.data_type())),
+    }
+}
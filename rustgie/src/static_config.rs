@@ -0,0 +1,104 @@
+﻿use crate::clock::Clock;
+use crate::RustgieClient;
+use anyhow::Result;
+use rustgie_types::common::models::CoreSettingsConfiguration;
+use rustgie_types::config::clan_banner::ClanBannerSource;
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::OnceCell;
+
+type CachedValue = (OffsetDateTime, Arc<dyn Any + Send + Sync>);
+
+/// Memoizes the static config endpoints, whose large dictionaries rarely change, for the lifetime of a client.
+///
+/// Each endpoint is cached for the configured TTL, timed by the client's [`Clock`]. Concurrent misses share one API call,
+/// and a failed fetch is not cached.
+pub(crate) struct StaticConfigCache {
+    ttl: Duration,
+    clock: Arc<dyn Clock>,
+    entries: Mutex<HashMap<&'static str, Arc<OnceCell<CachedValue>>>>,
+}
+
+impl StaticConfigCache {
+    /// How long a static config response is reused unless the builder says otherwise.
+    pub(crate) const DEFAULT_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+    pub(crate) fn new(ttl: Duration, clock: Arc<dyn Clock>) -> StaticConfigCache {
+        StaticConfigCache {
+            ttl,
+            clock,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_or_fetch<T, F>(&self, endpoint: &'static str, fetch: F) -> Result<Arc<T>>
+    where
+        T: Any + Send + Sync,
+        F: Future<Output = Result<T>>,
+    {
+        let cell = self.entry(endpoint);
+        let (_, value) = cell
+            .get_or_try_init(|| async { Ok::<CachedValue, anyhow::Error>((self.clock.now(), Arc::new(fetch.await?))) })
+            .await?;
+        Ok(value
+            .clone()
+            .downcast::<T>()
+            .unwrap_or_else(|_| panic!("static config cache entry {endpoint} holds the wrong type")))
+    }
+
+    fn entry(&self, endpoint: &'static str) -> Arc<OnceCell<CachedValue>> {
+        let mut entries = self.entries.lock().expect("static config cache poisoned");
+        let now = self.clock.now();
+        let expired = entries
+            .get(endpoint)
+            .and_then(|cell| cell.get())
+            .map_or(false, |(fetched, _)| now - *fetched >= self.ttl);
+        if expired {
+            entries.remove(endpoint);
+        }
+        entries.entry(endpoint).or_default().clone()
+    }
+
+    fn clear(&self) {
+        self.entries.lock().expect("static config cache poisoned").clear();
+    }
+}
+
+impl RustgieClient {
+    /// [`RustgieClient::destiny2_get_clan_banner_source`], reused for the client's static config TTL
+    /// (see [`crate::RustgieClientBuilder::with_static_config_ttl`]).
+    ///
+    /// To bypass the cache, call [`RustgieClient::destiny2_get_clan_banner_source`] directly; it always asks Bungie and
+    /// doesn't update the cache. [`RustgieClient::clear_static_config_cache`] makes the next cached call fetch again.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn clan_banner_source_cached(&self) -> Result<Arc<ClanBannerSource>> {
+        self.static_config
+            .get_or_fetch("ClanBannerDictionary", self.destiny2_get_clan_banner_source(None))
+            .await
+    }
+
+    /// [`RustgieClient::get_common_settings`], cached the same way as [`RustgieClient::clan_banner_source_cached`].
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn common_settings_cached(&self) -> Result<Arc<CoreSettingsConfiguration>> {
+        self.static_config
+            .get_or_fetch("Settings", self.get_common_settings(None))
+            .await
+    }
+
+    /// [`RustgieClient::get_available_locales`], cached the same way as [`RustgieClient::clan_banner_source_cached`].
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn available_locales_cached(&self) -> Result<Arc<HashMap<String, String>>> {
+        self.static_config
+            .get_or_fetch("GetAvailableLocales", self.get_available_locales(None))
+            .await
+    }
+
+    /// Forgets every cached static config response, so the next cached call for each fetches it again.
+    pub fn clear_static_config_cache(&self) {
+        self.static_config.clear();
+    }
+}
@@ -0,0 +1,49 @@
+﻿//! Bungie's featured streams and the Twitch drops linked to a Bungie.net account.
+
+use crate::RustgieClient;
+use anyhow::Result;
+use rustgie_types::tokens::{PartnerOfferClaimRequest, TwitchDropHistoryResponse};
+use rustgie_types::StreamInfo;
+
+impl RustgieClient {
+    /// The stream Bungie is featuring right now, if any. See [`rustgie_types::active_stream`].
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn active_stream(&self) -> Result<Option<StreamInfo>> {
+        let alerts = self.get_global_alerts(Some(true), None).await?;
+        Ok(rustgie_types::active_stream(&alerts))
+    }
+
+    /// Claims partner offer `partner_offer_id` (such as a Twitch drop) for the Bungie.net account `bungie_net_membership_id`.
+    /// Needs a token with the PartnerOfferGrant scope. `transaction_id` identifies the claim to the partner, so retrying with the
+    /// same ID doesn't grant the offer twice.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn claim_partner_offer(
+        &self,
+        partner_offer_id: &str,
+        bungie_net_membership_id: i64,
+        transaction_id: &str,
+        access_token: &str,
+    ) -> Result<bool> {
+        let request = PartnerOfferClaimRequest {
+            partner_offer_id: Some(partner_offer_id.to_string()),
+            bungie_net_membership_id,
+            transaction_id: Some(transaction_id.to_string()),
+        };
+        self.tokens_claim_partner_offer(request, Some(access_token)).await
+    }
+
+    /// The Twitch drops the partner application has granted the Bungie.net account `target_bnet_membership_id`, and how far each got.
+    /// Needs a token with the PartnerOfferGrant scope.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn twitch_drop_history(
+        &self,
+        partner_application_id: i32,
+        target_bnet_membership_id: i64,
+        access_token: &str,
+    ) -> Result<Vec<TwitchDropHistoryResponse>> {
+        let history = self
+            .tokens_get_partner_reward_history(partner_application_id, target_bnet_membership_id, Some(access_token))
+            .await?;
+        Ok(history.twitch_drops.unwrap_or_default())
+    }
+}
@@ -0,0 +1,126 @@
+﻿//! Moving many items one request at a time, and locking or tracking cached items in place.
+
+use crate::cancellation::{CancellationToken, Outcome};
+use crate::RustgieClient;
+use anyhow::{anyhow, Result};
+use rustgie_types::destiny::entities::characters::CharacterId;
+use rustgie_types::destiny::entities::items::{apply_lock_state, apply_tracked_state, DestinyItemComponent};
+use rustgie_types::destiny::requests::actions::DestinyItemStateRequest;
+use rustgie_types::destiny::requests::DestinyItemTransferRequest;
+use rustgie_types::BungieMembershipType;
+
+/// What a [`BulkTransfer`] got through. Every request ends up in exactly one of the three lists.
+#[derive(Debug, Default)]
+pub struct TransferReport {
+    pub transferred: Vec<DestinyItemTransferRequest>,
+    pub failed: Vec<(DestinyItemTransferRequest, anyhow::Error)>,
+    /// Requests that were never sent because the run was cancelled.
+    pub remaining: Vec<DestinyItemTransferRequest>,
+}
+
+/// Sends a batch of item transfers in order, one at a time.
+pub struct BulkTransfer<'a> {
+    client: &'a RustgieClient,
+    access_token: &'a str,
+    cancellation: CancellationToken,
+}
+
+impl<'a> BulkTransfer<'a> {
+    pub fn new(client: &'a RustgieClient, access_token: &'a str) -> BulkTransfer<'a> {
+        BulkTransfer {
+            client,
+            access_token,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stops the batch before the next transfer once `cancellation` is cancelled. The transfer in progress always finishes and is recorded.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> BulkTransfer<'a> {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Transfers each item in `requests`. A failed transfer is recorded and the batch carries on.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn run(&self, requests: Vec<DestinyItemTransferRequest>) -> Outcome<TransferReport> {
+        let mut report = TransferReport::default();
+        let mut requests = requests.into_iter();
+
+        while let Some(request) = requests.next() {
+            if self.cancellation.is_cancelled() {
+                report.remaining.push(request);
+                report.remaining.extend(requests);
+                return Outcome::Cancelled(report);
+            }
+
+            match self.client.destiny2_transfer_item(request.clone(), Some(self.access_token)).await {
+                Ok(()) => report.transferred.push(request),
+                Err(error) => report.failed.push((request, error)),
+            }
+        }
+
+        Outcome::Completed(report)
+    }
+}
+
+impl RustgieClient {
+    /// Locks or unlocks `item`, updating its cached `state` flags to match so the inventory doesn't need refetching.
+    /// The flags are updated before the request is sent and put back if it fails.
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn set_item_locked(
+        &self,
+        item: &mut DestinyItemComponent,
+        character_id: impl Into<CharacterId>,
+        membership_type: BungieMembershipType,
+        locked: bool,
+        access_token: &str,
+    ) -> Result<()> {
+        let request = item_state_request(item, character_id.into(), membership_type, locked)?;
+        let previous = item.state;
+        apply_lock_state(item, locked);
+        let result = self.destiny2_set_item_lock_state(request, Some(access_token)).await;
+        if result.is_err() {
+            item.state = previous;
+        }
+        result
+    }
+
+    /// Tracks or untracks the quest `item`, updating its cached `state` flags the same way as [`RustgieClient::set_item_locked`].
+    #[must_use = "futures do nothing unless awaited"]
+    pub async fn set_quest_tracked(
+        &self,
+        item: &mut DestinyItemComponent,
+        character_id: impl Into<CharacterId>,
+        membership_type: BungieMembershipType,
+        tracked: bool,
+        access_token: &str,
+    ) -> Result<()> {
+        let request = item_state_request(item, character_id.into(), membership_type, tracked)?;
+        let previous = item.state;
+        apply_tracked_state(item, tracked);
+        let result = self.destiny2_set_quest_tracked_state(request, Some(access_token)).await;
+        if result.is_err() {
+            item.state = previous;
+        }
+        result
+    }
+}
+
+/// Both state endpoints identify the item by instance ID, so uninstanced items can't be locked or tracked.
+fn item_state_request(
+    item: &DestinyItemComponent,
+    character_id: CharacterId,
+    membership_type: BungieMembershipType,
+    state: bool,
+) -> Result<DestinyItemStateRequest> {
+    match item.item_instance_id {
+        None => Err(anyhow!("Item {} has no instance ID, so its lock and tracked state can't be changed", item.item_hash)),
+        Some(item_id) => Ok(DestinyItemStateRequest {
+            state,
+            item_id,
+            character_id: character_id.get(),
+            membership_type,
+        }),
+    }
+}
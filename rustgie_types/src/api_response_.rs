@@ -1,6 +1,9 @@
 ﻿use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use time::{Duration, OffsetDateTime};
 
 #[derive(Deserialize, Serialize)]
 pub struct BungieApiResponse<T> {
@@ -26,8 +29,26 @@ pub struct BungieApiResponse<T> {
     pub detailed_error_trace: Option<String>,
 }
 
+/// Debug-formats a secret as `***` followed by its last four characters, enough to tell two tokens apart in a log.
+///
+/// Secrets of eight characters or fewer print as `***` alone.
+#[derive(Copy, Clone)]
+pub struct Redacted<'a>(pub &'a str);
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let length = self.0.chars().count();
+        if length <= 8 {
+            return f.write_str("\"***\"");
+        }
+        let tail: String = self.0.chars().skip(length - 4).collect();
+        write!(f, "\"***{}\"", tail)
+    }
+}
+
+/// `Debug` is implemented by hand so the access and refresh tokens are [`Redacted`]; don't add it to the derive list.
 #[serde_as]
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct BungieTokenResponse {
     #[serde(rename = "access_token")]
     pub access_token: Option<String>,
@@ -48,9 +69,71 @@ pub struct BungieTokenResponse {
     #[serde(rename = "membership_id")]
     pub membership_id: Option<i64>,
 
+    /// Space-separated scopes granted to the token. Use [`BungieTokenResponse::scopes`] to read them.
+    #[serde(rename = "scope", default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
     #[serde(rename = "error")]
     pub error: Option<String>,
 
     #[serde(rename = "error_description")]
     pub error_description: Option<String>,
 }
+
+impl fmt::Debug for BungieTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BungieTokenResponse")
+            .field("access_token", &self.access_token.as_deref().map(Redacted))
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("refresh_token", &self.refresh_token.as_deref().map(Redacted))
+            .field("refresh_expires_in", &self.refresh_expires_in)
+            .field("membership_id", &self.membership_id)
+            .field("scope", &self.scope)
+            .field("error", &self.error)
+            .field("error_description", &self.error_description)
+            .finish()
+    }
+}
+
+/// A scope granted to an OAuth token. Scopes this crate doesn't know about are kept as they were sent.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OAuthScope {
+    Known(crate::applications::ApplicationScopes),
+    Unknown(String),
+}
+
+impl BungieTokenResponse {
+    /// How long the access token is valid for, counted from when the response was received.
+    pub fn access_token_lifetime(&self) -> Option<Duration> {
+        self.expires_in.map(|seconds| Duration::seconds(i64::from(seconds)))
+    }
+
+    /// How long the refresh token is valid for, counted from when the response was received. Only confidential clients get one.
+    pub fn refresh_token_lifetime(&self) -> Option<Duration> {
+        self.refresh_expires_in.map(|seconds| Duration::seconds(i64::from(seconds)))
+    }
+
+    /// When the access token expires. A response without `expires_in` is treated as already expired.
+    pub fn access_expires_at(&self, received_at: OffsetDateTime) -> OffsetDateTime {
+        received_at + self.access_token_lifetime().unwrap_or(Duration::ZERO)
+    }
+
+    /// When the refresh token expires, if one was issued.
+    pub fn refresh_expires_at(&self, received_at: OffsetDateTime) -> Option<OffsetDateTime> {
+        self.refresh_token.as_ref()?;
+        self.refresh_token_lifetime().map(|lifetime| received_at + lifetime)
+    }
+
+    pub fn scopes(&self) -> Vec<OAuthScope> {
+        self.scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|scope| match crate::applications::ApplicationScopes::from_str(scope) {
+                Ok(known) => OAuthScope::Known(known),
+                Err(_) => OAuthScope::Unknown(scope.to_string()),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,57 @@
+﻿//! What changed in Bungie's API schema the last time these types were regenerated, enabled by the `changelog` feature.
+//!
+//! The list is computed by diffing the OpenAPI schemas the types were generated from, not the Rust sources, so it names
+//! types, fields, and enum variants by their schema names (`Destiny.Definitions.DestinyInventoryItemDefinition`,
+//! `itemTypeDisplayName`, `Engram`). It's committed as `types-changes.json` next to this crate's manifest, rewritten with
+//!
+//! ```text
+//! cargo run -p rustgie-integration-tests --bin types_changes -- previous-openapi.json openapi.json
+//! ```
+//!
+//! whenever the types are regenerated.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// The committed `types-changes.json`, as JSON.
+pub const CHANGES: &str = include_str!("../types-changes.json");
+
+static PARSED: Lazy<Vec<TypeChange>> =
+    Lazy::new(|| serde_json::from_str(CHANGES).expect("types-changes.json is a list of type changes"));
+
+/// One difference between the previous generation's schema and this one's.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeChange {
+    pub kind: ChangeKind,
+    pub target: ChangeTarget,
+    /// The schema name of the type that changed, or that holds the field or variant that did.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// The field or enum variant, or `None` when the change is to the type as a whole.
+    pub member: Option<String>,
+    /// A short description of the previous shape, for changed and removed entries: a field's type, or a variant's value.
+    pub before: Option<String>,
+    /// The same description of the new shape, for changed and added entries.
+    pub after: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Deserialize, Serialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeTarget {
+    Type,
+    Field,
+    EnumVariant,
+}
+
+/// The committed changes, parsed on first use. Empty when the last regeneration didn't change the schema.
+pub fn changes() -> &'static [TypeChange] {
+    &PARSED
+}
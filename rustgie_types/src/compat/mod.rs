@@ -0,0 +1,149 @@
+﻿//! Parsing definitions saved from older manifests, whose JSON no longer matches the generated types.
+//!
+//! Bungie adds required fields to definitions and moves data between them from season to season, so a definition an app stored a few
+//! years ago can fail to parse with the current types. The `parse_*_lenient` functions run the definition through an adapter for each
+//! known historical change, upgrading it to the current layout, before parsing it. Adapters only touch definitions that still have the
+//! old shape, so current definitions parse exactly as they would with `serde_json::from_value`.
+//!
+//! Only the tables apps most often keep around are covered: inventory items, records and collectibles.
+
+use crate::destiny::definitions::collectibles::DestinyCollectibleDefinition;
+use crate::destiny::definitions::records::DestinyRecordDefinition;
+use crate::destiny::definitions::DestinyInventoryItemDefinition;
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+/// The layout of an inventory item definition, oldest first, as far as the adapters in this module can tell them apart.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ManifestFormatVersion {
+    /// From before Shadowkeep added Champions: items have no `breakerType`.
+    PreBreakerTypes,
+    /// From before Season of Arrivals added power caps: items have a `breakerType`, but their `quality` blocks have no `currentVersion` or `versions`.
+    PrePowerCaps,
+    /// The layout these types were generated from.
+    Current,
+}
+
+impl ManifestFormatVersion {
+    /// The layout `definition`, an inventory item definition, was saved in.
+    /// Items without a `quality` block can't be told apart from current ones once they have a `breakerType`, and are reported as [`ManifestFormatVersion::Current`].
+    pub fn probe(definition: &Value) -> ManifestFormatVersion {
+        if definition.get("breakerType").is_none() {
+            ManifestFormatVersion::PreBreakerTypes
+        } else if definition
+            .get("quality")
+            .and_then(Value::as_object)
+            .map_or(false, |quality| !quality.contains_key("currentVersion"))
+        {
+            ManifestFormatVersion::PrePowerCaps
+        } else {
+            ManifestFormatVersion::Current
+        }
+    }
+
+    /// [`ManifestFormatVersion::probe`] on an inventory item definition's raw JSON, such as the `json` column of the mobile world content database.
+    pub fn probe_blob(blob: &[u8]) -> Result<ManifestFormatVersion> {
+        let definition: Value = serde_json::from_slice(blob).with_context(|| "Definition blob is not valid JSON")?;
+        Ok(ManifestFormatVersion::probe(&definition))
+    }
+
+    /// Whether definitions in this layout need the `parse_*_lenient` functions rather than plain deserialization.
+    pub fn needs_upgrade(self) -> bool {
+        self < ManifestFormatVersion::Current
+    }
+}
+
+/// Parses an inventory item definition saved in any layout [`ManifestFormatVersion`] knows, upgrading it to the current one.
+pub fn parse_item_definition_lenient(json: &Value) -> Result<DestinyInventoryItemDefinition> {
+    let mut definition = json.clone();
+    if let Some(item) = definition.as_object_mut() {
+        upgrade_breaker_type(item);
+        upgrade_quality_versions(item);
+        upgrade_damage_type_hashes(item);
+        upgrade_wrapper_flag(item);
+    }
+    serde_json::from_value(definition).with_context(|| "Could not upgrade the inventory item definition to the current layout")
+}
+
+/// Parses a record definition saved before gilding and large reward icons, upgrading it to the current layout.
+pub fn parse_record_definition_lenient(json: &Value) -> Result<DestinyRecordDefinition> {
+    let mut definition = json.clone();
+    if let Some(record) = definition.as_object_mut() {
+        upgrade_record_flags(record);
+        upgrade_presentation_node_type(record);
+    }
+    serde_json::from_value(definition).with_context(|| "Could not upgrade the record definition to the current layout")
+}
+
+/// Parses a collectible definition saved before presentation node types, upgrading it to the current layout.
+pub fn parse_collectible_definition_lenient(json: &Value) -> Result<DestinyCollectibleDefinition> {
+    let mut definition = json.clone();
+    if let Some(collectible) = definition.as_object_mut() {
+        upgrade_presentation_node_type(collectible);
+    }
+    serde_json::from_value(definition).with_context(|| "Could not upgrade the collectible definition to the current layout")
+}
+
+/// Items from before Shadowkeep have no `breakerType`. Nothing could break a Champion then, so they get `DestinyBreakerType::None`.
+fn upgrade_breaker_type(item: &mut Map<String, Value>) {
+    item.entry("breakerType").or_insert(Value::from(0));
+}
+
+/// Quality blocks from before Season of Arrivals have no `currentVersion`, and no `versions` because nothing was power capped yet.
+/// They get version 0 and are left without versions, which reads as uncapped.
+fn upgrade_quality_versions(item: &mut Map<String, Value>) {
+    if let Some(quality) = item.get_mut("quality").and_then(Value::as_object_mut) {
+        quality.entry("currentVersion").or_insert(Value::from(0));
+    }
+}
+
+/// Items saved before damage types were referenced by definition only carry the `DamageType` enum values in `damageTypes` and `defaultDamageType`.
+/// The matching `DestinyDamageTypeDefinition` hashes are filled in from them; `None` and values this module doesn't know have no definition and are left out.
+fn upgrade_damage_type_hashes(item: &mut Map<String, Value>) {
+    if !item.contains_key("damageTypeHashes") {
+        if let Some(damage_types) = item.get("damageTypes").and_then(Value::as_array) {
+            let hashes: Vec<Value> = damage_types
+                .iter()
+                .filter_map(Value::as_i64)
+                .filter_map(damage_type_hash)
+                .map(Value::from)
+                .collect();
+            item.insert("damageTypeHashes".to_string(), Value::from(hashes));
+        }
+    }
+    if !item.contains_key("defaultDamageTypeHash") {
+        if let Some(hash) = item.get("defaultDamageType").and_then(Value::as_i64).and_then(damage_type_hash) {
+            item.insert("defaultDamageTypeHash".to_string(), Value::from(hash));
+        }
+    }
+}
+
+/// The `DestinyDamageTypeDefinition` hash for a `DamageType` value.
+fn damage_type_hash(damage_type: i64) -> Option<u32> {
+    match damage_type {
+        1 => Some(3373582085),
+        2 => Some(2303181850),
+        3 => Some(1847026933),
+        4 => Some(3454344768),
+        5 => Some(1067729826),
+        6 => Some(151347233),
+        7 => Some(3949783978),
+        _ => None,
+    }
+}
+
+/// Items from before wrapper items existed have no `isWrapper`; none of them were wrappers.
+fn upgrade_wrapper_flag(item: &mut Map<String, Value>) {
+    item.entry("isWrapper").or_insert(Value::from(false));
+}
+
+/// Records from before title gilding have no `forTitleGilding`, and ones from before large reward icons no `shouldShowLargeIcons`. Both were false for every record.
+fn upgrade_record_flags(record: &mut Map<String, Value>) {
+    record.entry("forTitleGilding").or_insert(Value::from(false));
+    record.entry("shouldShowLargeIcons").or_insert(Value::from(false));
+}
+
+/// Records and collectibles from before presentation nodes were typed have no `presentationNodeType`. They get `DestinyPresentationNodeType::Default`.
+fn upgrade_presentation_node_type(definition: &mut Map<String, Value>) {
+    definition.entry("presentationNodeType").or_insert(Value::from(0));
+}
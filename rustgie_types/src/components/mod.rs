@@ -1,6 +1,7 @@
 ﻿use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -41,3 +42,103 @@ impl FromStr for ComponentPrivacySetting {
         }
     }
 }
+
+/// A uniform view over the generated `SingleComponentResponseOf*` and `DictionaryComponentResponseOf*` wrappers, which all share the `data`/`privacy`/`disabled` shape of ComponentResponse.
+pub trait ComponentResponseState {
+    fn privacy_setting(&self) -> ComponentPrivacySetting;
+
+    /// True when Bungie has switched this component off, which usually means rolling maintenance rather than an empty result.
+    fn is_disabled(&self) -> bool;
+
+    fn has_data(&self) -> bool;
+
+    /// How many entries the component holds: the number of keys for dictionary components, or one for a single component with data.
+    fn entry_count(&self) -> usize;
+
+    /// What a caller can do with this component, from [`classify`].
+    fn access(&self) -> ComponentAccess;
+}
+
+/// What a component in a response means for the caller, from [`classify`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentAccess {
+    /// The component has data to read.
+    Available,
+    /// The player has hidden this component from the caller.
+    Private,
+    /// Bungie has switched the component off, usually for maintenance. Asking again later may work.
+    Disabled,
+    /// There's no data and nothing says why, which is what a component that wasn't in the request looks like.
+    NotRequested,
+}
+
+/// Works out what a component's `data`, `privacy` and `disabled` fields add up to.
+///
+/// The first rule that matches wins:
+///
+/// 1. `disabled: Some(true)` is [`ComponentAccess::Disabled`], whether or not there's data or privacy says anything.
+/// 2. Data that came back is [`ComponentAccess::Available`], even when privacy is Private: Bungie still returns a private
+///    component to its owner's own authenticated requests, and marks it Private anyway.
+/// 3. No data with privacy Private is [`ComponentAccess::Private`].
+/// 4. No data otherwise, with privacy None or Public, is [`ComponentAccess::NotRequested`].
+///
+/// `disabled: None` and `Some(false)` are treated the same.
+pub fn classify<T>(data: &Option<T>, privacy: ComponentPrivacySetting, disabled: Option<bool>) -> ComponentAccess {
+    if disabled == Some(true) {
+        ComponentAccess::Disabled
+    } else if data.is_some() {
+        ComponentAccess::Available
+    } else if privacy == ComponentPrivacySetting::Private {
+        ComponentAccess::Private
+    } else {
+        ComponentAccess::NotRequested
+    }
+}
+
+/// The entries of a dictionary component, from `entries()` on the generated `DictionaryComponentResponseOf*` wrappers.
+///
+/// Iterating yields nothing both when `data` is missing (the component wasn't requested, or is private) and when it's an
+/// empty map (it was requested and there's nothing in it), so code that only wants what's there needs one call.
+/// [`was_populated`](ComponentEntries::was_populated) still tells the two apart, and `len` counts what's left.
+/// A disabled component yields nothing and counts as unpopulated even if Bungie sent data, as [`classify`] reports it Disabled.
+#[derive(Debug)]
+pub struct ComponentEntries<'a, K, V> {
+    entries: Option<std::collections::hash_map::Iter<'a, K, V>>,
+}
+
+impl<'a, K, V> ComponentEntries<'a, K, V> {
+    pub(crate) fn new(data: Option<&'a HashMap<K, V>>, disabled: Option<bool>) -> ComponentEntries<'a, K, V> {
+        ComponentEntries {
+            entries: if disabled == Some(true) { None } else { data.map(HashMap::iter) },
+        }
+    }
+
+    /// Whether Bungie sent the component's data, even if it was an empty map.
+    pub fn was_populated(&self) -> bool {
+        self.entries.is_some()
+    }
+}
+
+impl<'a, K, V> Clone for ComponentEntries<'a, K, V> {
+    fn clone(&self) -> Self {
+        ComponentEntries {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for ComponentEntries<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.as_mut()?.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.as_ref().map_or((0, Some(0)), Iterator::size_hint)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ComponentEntries<'a, K, V> {}
+
+impl<'a, K, V> std::iter::FusedIterator for ComponentEntries<'a, K, V> {}
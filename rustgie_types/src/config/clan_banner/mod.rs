@@ -1,7 +1,34 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+/// The parts clan banners are assembled from, keyed by the hashes a [`crate::groups_v2::ClanBanner`] refers to them by.
+/// Bungie sends the keys as strings; they parse into the same `u32` hashes the banner uses.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
-pub struct ClanBannerSource {}
+pub struct ClanBannerSource {
+    #[serde(rename = "clanBannerDecals", default)]
+    pub clan_banner_decals: Option<HashMap<u32, crate::config::clan_banner::ClanBannerDecal>>,
+
+    #[serde(rename = "clanBannerDecalPrimaryColors", default)]
+    pub clan_banner_decal_primary_colors: Option<HashMap<u32, crate::destiny::misc::DestinyColor>>,
+
+    #[serde(rename = "clanBannerDecalSecondaryColors", default)]
+    pub clan_banner_decal_secondary_colors: Option<HashMap<u32, crate::destiny::misc::DestinyColor>>,
+
+    #[serde(rename = "clanBannerGonfalons", default)]
+    pub clan_banner_gonfalons: Option<HashMap<u32, String>>,
+
+    #[serde(rename = "clanBannerGonfalonColors", default)]
+    pub clan_banner_gonfalon_colors: Option<HashMap<u32, crate::destiny::misc::DestinyColor>>,
+
+    #[serde(rename = "clanBannerGonfalonDetails", default)]
+    pub clan_banner_gonfalon_details: Option<HashMap<u32, crate::config::clan_banner::ClanBannerDecal>>,
+
+    #[serde(rename = "clanBannerGonfalonDetailColors", default)]
+    pub clan_banner_gonfalon_detail_colors: Option<HashMap<u32, crate::destiny::misc::DestinyColor>>,
+
+    #[serde(rename = "clanBannerStandards", default)]
+    pub clan_banner_standards: Option<HashMap<u32, String>>,
+}
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct ClanBannerDecal {
@@ -1,5 +1,8 @@
-﻿use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+﻿use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DateRange {
@@ -11,3 +14,49 @@ pub struct DateRange {
     #[serde(rename = "end")]
     pub end: OffsetDateTime,
 }
+
+/// A calendar day, for query parameters Bungie documents as `YYYY-MM-DD` (e.g. `daystart`/`dayend` on historical stats). Formats as exactly that, never as a full timestamp.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BungieDate(pub Date);
+
+impl BungieDate {
+    pub fn from_calendar_date(year: i32, month: Month, day: u8) -> Result<BungieDate> {
+        Date::from_calendar_date(year, month, day)
+            .map(BungieDate)
+            .map_err(|e| anyhow!("Invalid date {year:04}-{:02}-{day:02}: {e}", month as u8))
+    }
+}
+
+impl From<Date> for BungieDate {
+    fn from(date: Date) -> Self {
+        BungieDate(date)
+    }
+}
+
+/// Takes the UTC calendar day of the timestamp, since Bungie's stats days roll over in UTC.
+impl From<OffsetDateTime> for BungieDate {
+    fn from(datetime: OffsetDateTime) -> Self {
+        BungieDate(datetime.to_offset(UtcOffset::UTC).date())
+    }
+}
+
+impl Display for BungieDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.0.year(), self.0.month() as u8, self.0.day())
+    }
+}
+
+impl FromStr for BungieDate {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()));
+        match (next(), next(), next()) {
+            (Some(year), Some(month), Some(day)) if year.len() == 4 && month.len() == 2 && day.len() == 2 => {
+                let month = Month::try_from(month.parse::<u8>()?)?;
+                BungieDate::from_calendar_date(year.parse()?, month, day.parse()?)
+            }
+            _ => Err(anyhow!("Could not parse {} as a YYYY-MM-DD date", s)),
+        }
+    }
+}
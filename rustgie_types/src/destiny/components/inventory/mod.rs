@@ -21,3 +21,54 @@ pub struct DestinyCurrenciesComponent {
     #[serde(rename = "itemQuantities")]
     pub item_quantities: Option<HashMap<u32, i32>>,
 }
+
+/// Sums item quantities by item hash across the currencies component and any number of inventory components (profile, vault, and character inventories).
+/// Bungie reports some currencies both in `DestinyCurrenciesComponent` and as inventory items, so the currencies component takes precedence: for every hash it contains, its quantity is used as-is and inventory occurrences of that hash are ignored. Sums saturate rather than overflow.
+pub fn material_totals(
+    currencies: Option<&DestinyCurrenciesComponent>,
+    inventories: &[&crate::destiny::entities::inventory::DestinyInventoryComponent],
+) -> HashMap<u32, i64> {
+    let mut totals: HashMap<u32, i64> = currencies
+        .and_then(|c| c.item_quantities.as_ref())
+        .map(|quantities| quantities.iter().map(|(hash, quantity)| (*hash, i64::from(*quantity))).collect())
+        .unwrap_or_default();
+    let from_currencies: Vec<u32> = totals.keys().copied().collect();
+
+    for item in inventories.iter().filter_map(|inventory| inventory.items.as_ref()).flatten() {
+        if from_currencies.contains(&item.item_hash) {
+            continue;
+        }
+        let total = totals.entry(item.item_hash).or_insert(0);
+        *total = total.saturating_add(i64::from(item.quantity));
+    }
+    totals
+}
+
+/// A total from [`material_totals`] with its item's display name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedTotal<'a> {
+    pub item_hash: u32,
+    /// The definition's display name, or `None` when the hash isn't in the definitions map.
+    pub name: Option<&'a str>,
+    pub quantity: i64,
+}
+
+/// Names each total from `definitions`, largest quantity first (ties by item hash).
+pub fn named_totals<'a>(
+    totals: &HashMap<u32, i64>,
+    definitions: &'a HashMap<u32, crate::destiny::definitions::DestinyInventoryItemDefinition>,
+) -> Vec<NamedTotal<'a>> {
+    let mut named: Vec<NamedTotal<'a>> = totals
+        .iter()
+        .map(|(hash, quantity)| NamedTotal {
+            item_hash: *hash,
+            name: definitions
+                .get(hash)
+                .and_then(|d| d.display_properties.as_ref())
+                .and_then(|dp| dp.name.as_deref()),
+            quantity: *quantity,
+        })
+        .collect();
+    named.sort_by(|a, b| b.quantity.cmp(&a.quantity).then(a.item_hash.cmp(&b.item_hash)));
+    named
+}
@@ -1,5 +1,6 @@
 ﻿use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use time::OffsetDateTime;
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemReusablePlugsComponent {
@@ -52,3 +53,56 @@ pub struct DestinyItemPlugComponent {
     #[serde(rename = "enableFailIndexes")]
     pub enable_fail_indexes: Option<Vec<i32>>,
 }
+
+/// What a crafted ("shaped") weapon's crafting frame says about it, as returned by [`crafted_info`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CraftedInfo {
+    pub level: i32,
+    /// Progress toward the next level, from 0.0 to 1.0.
+    pub level_progress: f32,
+    /// `None` if the frame doesn't carry a crafting date.
+    pub date_crafted: Option<OffsetDateTime>,
+    /// How many of the weapon's plugged traits and intrinsics are enhanced.
+    pub enhanced_perk_count: usize,
+}
+
+/// Reads a weapon's crafting data from the objectives on its crafting frame plug (see [`crate::destiny::well_known`]), or `None` if the weapon wasn't crafted.
+/// Plugs missing from `defs` are ignored, so the crafting frame's definition has to be there for this to find anything. Enhanced perks are the Common tier versions of traits and intrinsics, whose base versions are Basic.
+pub fn crafted_info(
+    item_instance_plug_objectives: &DestinyItemPlugObjectivesComponent,
+    sockets: &crate::destiny::entities::items::DestinyItemSocketsComponent,
+    defs: &HashMap<u32, crate::destiny::definitions::DestinyInventoryItemDefinition>,
+) -> Option<CraftedInfo> {
+    use crate::destiny::well_known::*;
+
+    let plugged: Vec<(u32, &crate::destiny::definitions::DestinyInventoryItemDefinition)> = sockets
+        .sockets
+        .iter()
+        .flatten()
+        .filter_map(|socket| socket.plug_hash)
+        .filter_map(|plug_hash| defs.get(&plug_hash).map(|def| (plug_hash, def)))
+        .collect();
+    let plug_category = |def: &crate::destiny::definitions::DestinyInventoryItemDefinition| def.plug.as_ref().map(|p| p.plug_category_hash);
+
+    let (frame_hash, _) = plugged.iter().find(|(_, def)| plug_category(def) == Some(CRAFTED_FRAME_PLUG_CATEGORY_HASH))?;
+    let objectives = item_instance_plug_objectives.objectives_per_plug.as_ref()?.get(frame_hash)?;
+    let objective = |hash: u32| objectives.iter().find(|o| o.objective_hash == hash);
+
+    let level = objective(CRAFTED_WEAPON_LEVEL_OBJECTIVE_HASH)?.progress.unwrap_or_default();
+    let level_progress = objective(CRAFTED_WEAPON_LEVEL_PROGRESS_OBJECTIVE_HASH)
+        .filter(|o| o.completion_value > 0)
+        .map_or(0.0, |o| (o.progress.unwrap_or_default() as f32 / o.completion_value as f32).clamp(0.0, 1.0));
+    let date_crafted = objective(CRAFTED_WEAPON_DATE_OBJECTIVE_HASH)
+        .and_then(|o| o.progress)
+        .filter(|&seconds| seconds > 0)
+        .and_then(|seconds| OffsetDateTime::from_unix_timestamp(i64::from(seconds)).ok());
+    let enhanced_perk_count = plugged
+        .iter()
+        .filter(|(_, def)| {
+            matches!(plug_category(def), Some(WEAPON_TRAIT_PLUG_CATEGORY_HASH | WEAPON_INTRINSIC_PLUG_CATEGORY_HASH))
+                && def.inventory.as_ref().map_or(false, |i| i.tier_type == crate::destiny::TierType::Common)
+        })
+        .count();
+
+    Some(CraftedInfo { level, level_progress, date_crafted, enhanced_perk_count })
+}
@@ -1,5 +1,6 @@
 ﻿use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 
 /// Sockets may refer to a "Plug Set": a set of reusable plugs that may be shared across multiple sockets (or even, in theory, multiple sockets over multiple items).
 /// This is the set of those plugs that we came across in the users' inventory, along with the values for plugs in the set. Any given set in this component may be represented in Character and Profile-level, as some plugs may be Profile-level restricted, and some character-level restricted. (note that the ones that are even more specific will remain on the actual socket component itself, as they cannot be reused)
@@ -9,3 +10,66 @@ pub struct DestinyPlugSetsComponent {
     #[serde(rename = "plugs")]
     pub plugs: Option<HashMap<u32, Vec<crate::destiny::sockets::DestinyItemPlug>>>,
 }
+
+/// The plugs from one plug set a character can use: the profile-scoped ones, plus the character-scoped ones (which are only usable on that character).
+///
+/// Each plug item hash appears once, profile entries first. A plug listed in both scopes is only insertable or enabled if both scopes say so; it's borrowed when the scopes agree and merged into an owned copy (with the fail indexes of both) when they don't.
+pub fn available_plugs<'a>(
+    plug_set_hash: u32,
+    profile: Option<&'a DestinyPlugSetsComponent>,
+    character: Option<&'a DestinyPlugSetsComponent>,
+) -> Vec<Cow<'a, crate::destiny::sockets::DestinyItemPlug>> {
+    let plugs = |scope: Option<&'a DestinyPlugSetsComponent>| {
+        scope.and_then(|s| s.plugs.as_ref()).and_then(|p| p.get(&plug_set_hash)).map(Vec::as_slice).unwrap_or_default()
+    };
+    let character_plugs = plugs(character);
+
+    let mut seen = HashSet::new();
+    let mut available = Vec::new();
+    for plug in plugs(profile) {
+        if !seen.insert(plug.plug_item_hash) {
+            continue;
+        }
+        let merged = match character_plugs.iter().find(|c| c.plug_item_hash == plug.plug_item_hash) {
+            Some(other) if (other.can_insert, other.enabled) != (plug.can_insert, plug.enabled) => Cow::Owned(merge(plug, other)),
+            _ => Cow::Borrowed(plug),
+        };
+        available.push(merged);
+    }
+    available.extend(character_plugs.iter().filter(|plug| seen.insert(plug.plug_item_hash)).map(Cow::Borrowed));
+    available
+}
+
+fn merge(
+    profile: &crate::destiny::sockets::DestinyItemPlug,
+    character: &crate::destiny::sockets::DestinyItemPlug,
+) -> crate::destiny::sockets::DestinyItemPlug {
+    let union = |a: &Option<Vec<i32>>, b: &Option<Vec<i32>>| {
+        let mut indexes: Vec<i32> = a.iter().chain(b).flatten().copied().collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+        Some(indexes)
+    };
+    crate::destiny::sockets::DestinyItemPlug {
+        can_insert: profile.can_insert && character.can_insert,
+        enabled: profile.enabled && character.enabled,
+        insert_fail_indexes: union(&profile.insert_fail_indexes, &character.insert_fail_indexes),
+        enable_fail_indexes: union(&profile.enable_fail_indexes, &character.enable_fail_indexes),
+        ..profile.clone()
+    }
+}
+
+/// Every plug, across all plug sets, a character can currently insert, merging the scopes as [`available_plugs`] does.
+pub fn all_unlocked_plug_hashes(
+    profile: Option<&DestinyPlugSetsComponent>,
+    character: Option<&DestinyPlugSetsComponent>,
+) -> HashSet<u32> {
+    let plug_set_hashes: HashSet<u32> =
+        [profile, character].into_iter().flatten().filter_map(|s| s.plugs.as_ref()).flat_map(|p| p.keys().copied()).collect();
+    plug_set_hashes
+        .into_iter()
+        .flat_map(|plug_set_hash| available_plugs(plug_set_hash, profile, character))
+        .filter(|plug| plug.can_insert && plug.enabled)
+        .map(|plug| plug.plug_item_hash)
+        .collect()
+}
@@ -1,4 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Defines a
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -76,3 +77,59 @@ pub struct DestinyCollectibleStateBlock {
     #[serde(rename = "requirements")]
     pub requirements: Option<crate::destiny::definitions::presentation::DestinyPresentationNodeRequirementsBlock>,
 }
+
+/// Where an item can be acquired from, from [`acquisition_source`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceInfo {
+    /// A line to show for the item, such as "Source: Complete Exotic quests or open Exotic engrams." `None` when nothing says.
+    pub text: Option<String>,
+    /// The names of the reward sources the item's `sourceData` points at, such as "Nightfall", in order and without repeats.
+    pub categories: Vec<String>,
+}
+
+/// Where the item `item_def` can be acquired from, for a collection screen.
+///
+/// The text is the first of these that isn't empty: the collectible's `sourceString`, the item's `displaySource`, and the
+/// names of the reward sources in `categories` joined with commas. The categories come from the `sourceHashes` on the item's
+/// `sourceData` and its per-level `sources`, looked up in `reward_source_defs`; hashes that aren't there, redacted sources and
+/// sources without a name are left out. The collectible's own `sourceHash` only groups collectibles and isn't a definition.
+pub fn acquisition_source(
+    collectible_def: Option<&DestinyCollectibleDefinition>,
+    item_def: &crate::destiny::definitions::DestinyInventoryItemDefinition,
+    reward_source_defs: &HashMap<u32, crate::destiny::definitions::DestinyRewardSourceDefinition>,
+) -> SourceInfo {
+    let source_data = item_def.source_data.as_ref();
+    let source_hashes = source_data
+        .and_then(|data| data.source_hashes.as_deref())
+        .unwrap_or_default()
+        .iter()
+        .chain(
+            source_data
+                .and_then(|data| data.sources.as_deref())
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|source| source.source_hashes.as_deref().unwrap_or_default()),
+        );
+
+    let mut categories: Vec<String> = Vec::new();
+    for hash in source_hashes {
+        let name = reward_source_defs
+            .get(hash)
+            .filter(|source| !source.redacted)
+            .and_then(|source| source.display_properties.as_ref()?.name.as_deref())
+            .filter(|name| !name.is_empty());
+        if let Some(name) = name {
+            if !categories.iter().any(|category| category == name) {
+                categories.push(name.to_string());
+            }
+        }
+    }
+
+    let text = collectible_def
+        .and_then(|collectible| collectible.source_string.as_deref())
+        .filter(|text| !text.is_empty())
+        .or_else(|| item_def.display_source.as_deref().filter(|text| !text.is_empty()))
+        .map(str::to_string)
+        .or_else(|| (!categories.is_empty()).then(|| categories.join(", ")));
+    SourceInfo { text, categories }
+}
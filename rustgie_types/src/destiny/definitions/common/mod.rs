@@ -26,6 +26,179 @@ pub struct DestinyDisplayPropertiesDefinition {
     pub has_icon: bool,
 }
 
+/// Which variant of an icon to prefer when picking one with [`DisplayIcons::best_icon`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IconSize {
+    /// The regular 96px x 96px icon.
+    Standard,
+    /// The largest icon available, usually the high-res icon.
+    Largest,
+}
+
+/// Icon selection shared by the display properties variants that carry `icon`, `iconSequences` and `highResIcon`.
+/// Empty paths, which Bungie sends for some missing icons, are treated as absent.
+pub trait DisplayIcons {
+    fn icon_path(&self) -> Option<&str>;
+    fn high_res_icon_path(&self) -> Option<&str>;
+    fn icon_sequences(&self) -> &[DestinyIconSequenceDefinition];
+
+    /// The relative path of the icon closest to `size`, falling back to whatever icon exists.
+    /// The first frame of the first icon sequence is only used when there is no base icon, since frames are the same size as it.
+    fn best_icon(&self, size: IconSize) -> Option<&str> {
+        let icon = non_empty(self.icon_path());
+        let high_res = non_empty(self.high_res_icon_path());
+        let frame = self
+            .icon_sequences()
+            .iter()
+            .flat_map(|sequence| sequence.frames.iter().flatten())
+            .find_map(|frame| non_empty(Some(frame)));
+        match size {
+            IconSize::Standard => icon.or(frame).or(high_res),
+            IconSize::Largest => high_res.or(icon).or(frame),
+        }
+    }
+
+    /// [`best_icon`](DisplayIcons::best_icon) as an absolute bungie.net URL.
+    fn best_icon_url(&self, size: IconSize) -> Option<url::Url> {
+        let path = self.best_icon(size)?;
+        url::Url::parse("https://www.bungie.net").ok()?.join(path).ok()
+    }
+}
+
+fn non_empty(path: Option<&str>) -> Option<&str> {
+    path.filter(|path| !path.is_empty())
+}
+
+macro_rules! impl_display_icons {
+    ($($display_properties:ty),+ $(,)?) => {
+        $(
+            impl DisplayIcons for $display_properties {
+                fn icon_path(&self) -> Option<&str> {
+                    self.icon.as_deref()
+                }
+
+                fn high_res_icon_path(&self) -> Option<&str> {
+                    self.high_res_icon.as_deref()
+                }
+
+                fn icon_sequences(&self) -> &[DestinyIconSequenceDefinition] {
+                    self.icon_sequences.as_deref().unwrap_or_default()
+                }
+            }
+        )+
+    };
+}
+
+impl_display_icons!(
+    DestinyDisplayPropertiesDefinition,
+    crate::destiny::definitions::DestinyProgressionDisplayPropertiesDefinition,
+    crate::destiny::definitions::DestinyVendorDisplayPropertiesDefinition,
+);
+
+/// The `hash`, `index` and `redacted` fields every manifest definition repeats, for code that works over any definition type.
+/// The definitions keep their own fields, so they parse exactly as Bungie sends them; [`HasDefinitionCommon::common`] collects the three
+/// into a [`DestinyDefinition`](crate::destiny::definitions::DestinyDefinition), Bungie's own contract for them.
+pub trait HasDefinitionCommon {
+    fn hash(&self) -> u32;
+    fn index(&self) -> i32;
+    fn redacted(&self) -> bool;
+
+    fn common(&self) -> crate::destiny::definitions::DestinyDefinition {
+        crate::destiny::definitions::DestinyDefinition {
+            hash: self.hash(),
+            index: self.index(),
+            redacted: self.redacted(),
+        }
+    }
+}
+
+macro_rules! impl_definition_common {
+    ($($definition:ty),+ $(,)?) => {
+        $(
+            impl HasDefinitionCommon for $definition {
+                fn hash(&self) -> u32 {
+                    self.hash
+                }
+
+                fn index(&self) -> i32 {
+                    self.index
+                }
+
+                fn redacted(&self) -> bool {
+                    self.redacted
+                }
+            }
+        )+
+    };
+}
+
+impl_definition_common!(
+    crate::destiny::definitions::DestinyDefinition,
+    crate::destiny::definitions::DestinyActivityDefinition,
+    crate::destiny::definitions::DestinyActivityModeDefinition,
+    crate::destiny::definitions::DestinyActivityTypeDefinition,
+    crate::destiny::definitions::DestinyClassDefinition,
+    crate::destiny::definitions::DestinyDamageTypeDefinition,
+    crate::destiny::definitions::DestinyDestinationDefinition,
+    crate::destiny::definitions::DestinyEquipmentSlotDefinition,
+    crate::destiny::definitions::DestinyFactionDefinition,
+    crate::destiny::definitions::DestinyGenderDefinition,
+    crate::destiny::definitions::DestinyInventoryBucketDefinition,
+    crate::destiny::definitions::DestinyInventoryItemDefinition,
+    crate::destiny::definitions::DestinyItemCategoryDefinition,
+    crate::destiny::definitions::DestinyLocationDefinition,
+    crate::destiny::definitions::DestinyMaterialRequirementSetDefinition,
+    crate::destiny::definitions::DestinyMedalTierDefinition,
+    crate::destiny::definitions::DestinyObjectiveDefinition,
+    crate::destiny::definitions::DestinyPlaceDefinition,
+    crate::destiny::definitions::DestinyProgressionDefinition,
+    crate::destiny::definitions::DestinyProgressionMappingDefinition,
+    crate::destiny::definitions::DestinyRaceDefinition,
+    crate::destiny::definitions::DestinyRewardSourceDefinition,
+    crate::destiny::definitions::DestinySandboxPatternDefinition,
+    crate::destiny::definitions::DestinySandboxPerkDefinition,
+    crate::destiny::definitions::DestinyStatDefinition,
+    crate::destiny::definitions::DestinyStatGroupDefinition,
+    crate::destiny::definitions::DestinyTalentGridDefinition,
+    crate::destiny::definitions::DestinyUnlockDefinition,
+    crate::destiny::definitions::DestinyUnlockValueDefinition,
+    crate::destiny::definitions::DestinyVendorDefinition,
+    crate::destiny::definitions::DestinyVendorGroupDefinition,
+    crate::destiny::definitions::activity_modifiers::DestinyActivityModifierDefinition,
+    crate::destiny::definitions::artifacts::DestinyArtifactDefinition,
+    crate::destiny::definitions::breaker_types::DestinyBreakerTypeDefinition,
+    crate::destiny::definitions::checklists::DestinyChecklistDefinition,
+    crate::destiny::definitions::collectibles::DestinyCollectibleDefinition,
+    crate::destiny::definitions::director::DestinyActivityGraphDefinition,
+    crate::destiny::definitions::energy_types::DestinyEnergyTypeDefinition,
+    crate::destiny::definitions::guardian_ranks::DestinyGuardianRankConstantsDefinition,
+    crate::destiny::definitions::guardian_ranks::DestinyGuardianRankDefinition,
+    crate::destiny::definitions::items::DestinyItemTierTypeDefinition,
+    crate::destiny::definitions::loadouts::DestinyLoadoutColorDefinition,
+    crate::destiny::definitions::loadouts::DestinyLoadoutConstantsDefinition,
+    crate::destiny::definitions::loadouts::DestinyLoadoutIconDefinition,
+    crate::destiny::definitions::loadouts::DestinyLoadoutNameDefinition,
+    crate::destiny::definitions::lore::DestinyLoreDefinition,
+    crate::destiny::definitions::metrics::DestinyMetricDefinition,
+    crate::destiny::definitions::milestones::DestinyMilestoneDefinition,
+    crate::destiny::definitions::power_caps::DestinyPowerCapDefinition,
+    crate::destiny::definitions::presentation::DestinyPresentationNodeBaseDefinition,
+    crate::destiny::definitions::presentation::DestinyPresentationNodeDefinition,
+    crate::destiny::definitions::presentation::DestinyScoredPresentationNodeBaseDefinition,
+    crate::destiny::definitions::progression::DestinyProgressionLevelRequirementDefinition,
+    crate::destiny::definitions::records::DestinyRecordDefinition,
+    crate::destiny::definitions::reporting::DestinyReportReasonCategoryDefinition,
+    crate::destiny::definitions::seasons::DestinyEventCardDefinition,
+    crate::destiny::definitions::seasons::DestinySeasonDefinition,
+    crate::destiny::definitions::seasons::DestinySeasonPassDefinition,
+    crate::destiny::definitions::social::DestinySocialCommendationDefinition,
+    crate::destiny::definitions::social::DestinySocialCommendationNodeDefinition,
+    crate::destiny::definitions::sockets::DestinyPlugSetDefinition,
+    crate::destiny::definitions::sockets::DestinySocketCategoryDefinition,
+    crate::destiny::definitions::sockets::DestinySocketTypeDefinition,
+    crate::destiny::definitions::traits::DestinyTraitDefinition,
+);
+
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyIconSequenceDefinition {
     #[serde(rename = "frames")]
@@ -167,3 +167,68 @@ pub struct DestinyLinkedGraphEntryDefinition {
     #[serde(rename = "activityGraphHash")]
     pub activity_graph_hash: u32,
 }
+
+/// Whether a [`ResolvedGraphNode`] can be launched right now, judged from the character's available activities.
+/// Bungie doesn't expose the game's own node states, so this is the closest live signal there is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GraphNodeAvailability {
+    /// No live activities were given.
+    Unknown,
+    /// None of the node's activities are currently available.
+    Locked,
+    Available,
+    /// The active activity is available and the character has completed it.
+    Completed,
+}
+
+/// One node of an activity graph, combined with live activity availability by [`resolve_graph`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedGraphNode<'a> {
+    pub node_id: u32,
+    /// The node's override display name. When it is `None`, the map shows the active activity's name, which needs its `DestinyActivityDefinition`.
+    pub name: Option<&'a str>,
+    pub position: Option<&'a crate::destiny::definitions::common::DestinyPositionDefinition>,
+    /// The possible highlight styles, in the order the game checks them.
+    pub featuring_states: Vec<crate::destiny::ActivityGraphNodeHighlightType>,
+    /// The hashes of every activity that can be behind the node, in definition order.
+    pub activity_hashes: Vec<u32>,
+    /// The first of the node's activities found in the live activities, which is the one the game shows.
+    pub active: Option<&'a crate::destiny::DestinyActivity>,
+    pub availability: GraphNodeAvailability,
+}
+
+/// Resolves each node of `graph_def` against `live_activities`, normally `DestinyCharacterActivitiesComponent.availableActivities`.
+/// Nodes are returned in definition order.
+pub fn resolve_graph<'a>(
+    graph_def: &'a DestinyActivityGraphDefinition,
+    live_activities: Option<&'a [crate::destiny::DestinyActivity]>,
+) -> Vec<ResolvedGraphNode<'a>> {
+    graph_def
+        .nodes
+        .iter()
+        .flatten()
+        .map(|node| {
+            let activity_hashes: Vec<u32> = node.activities.iter().flatten().map(|a| a.activity_hash).collect();
+            let active = live_activities.and_then(|live| {
+                activity_hashes
+                    .iter()
+                    .find_map(|hash| live.iter().find(|a| a.activity_hash == *hash))
+            });
+            let availability = match (live_activities, active) {
+                (None, _) => GraphNodeAvailability::Unknown,
+                (Some(_), None) => GraphNodeAvailability::Locked,
+                (Some(_), Some(activity)) if activity.is_completed => GraphNodeAvailability::Completed,
+                (Some(_), Some(_)) => GraphNodeAvailability::Available,
+            };
+            ResolvedGraphNode {
+                node_id: node.node_id,
+                name: node.override_display.as_ref().and_then(|d| d.name.as_deref()),
+                position: node.position.as_ref(),
+                featuring_states: node.featuring_states.iter().flatten().map(|s| s.highlight_type).collect(),
+                activity_hashes,
+                active,
+                availability,
+            }
+        })
+        .collect()
+}
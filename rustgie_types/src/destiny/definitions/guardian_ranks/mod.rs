@@ -1,4 +1,6 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use crate::destiny::definitions::common::{DisplayIcons, IconSize};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyGuardianRankDefinition {
@@ -103,3 +105,58 @@ pub struct DestinyGuardianRankIconBackgroundsDefinition {
     #[serde(rename = "backgroundPlateBlackAlphaImagePath")]
     pub background_plate_black_alpha_image_path: Option<String>,
 }
+
+/// The first Guardian Rank that resets when a new season starts. Ranks from here up to the constants' `rankCount` are earned again
+/// each season, and a profile above it drops back to the rank below.
+pub const FIRST_SEASONAL_GUARDIAN_RANK: i32 = 7;
+
+/// A profile's Guardian Ranks ready to show, from [`guardian_rank_display`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuardianRankDisplay<'a> {
+    /// The current rank's name, such as "Vanguard", or `None` if its definition isn't in the rank definitions.
+    pub current_name: Option<&'a str>,
+    /// The current rank, from 1 to the constants' `rankCount`.
+    pub current_number: i32,
+    /// The highest rank the profile has ever reached, from 1 to the constants' `rankCount`. Unlike the current rank, it never resets.
+    pub lifetime_number: i32,
+    /// The current rank's icon.
+    pub icon_url: Option<url::Url>,
+    /// Whether the current rank is one that resets when the season ends.
+    pub seasonal: bool,
+    /// The rank the profile will have once the season ends: the current rank, or the rank below [`FIRST_SEASONAL_GUARDIAN_RANK`] if it's seasonal.
+    pub resets_to: i32,
+}
+
+/// The current and lifetime highest Guardian Ranks on `profile`, with the current rank's name and icon from `rank_defs`.
+/// `None` if the response has no profile component (needs the Profiles component).
+///
+/// Bungie reports ranks as plain numbers; they're matched to definitions by `rankNumber`. Ranks are clamped to the range `constants`
+/// defines, and ranks from [`FIRST_SEASONAL_GUARDIAN_RANK`] up are seasonal: a profile can be below its lifetime highest rank because
+/// the season reset it, not because it lost progress.
+pub fn guardian_rank_display<'a>(
+    profile: &crate::destiny::responses::DestinyProfileResponse,
+    rank_defs: &'a HashMap<u32, DestinyGuardianRankDefinition>,
+    constants: &DestinyGuardianRankConstantsDefinition,
+) -> Option<GuardianRankDisplay<'a>> {
+    let component = profile.profile.as_ref()?.data.as_ref()?;
+    let rank_count = constants.rank_count.max(1);
+    let current_number = component.current_guardian_rank.clamp(1, rank_count);
+    let lifetime_number = component.lifetime_highest_guardian_rank.clamp(current_number, rank_count);
+    let seasonal = current_number >= FIRST_SEASONAL_GUARDIAN_RANK;
+
+    let current = rank_defs.values().find(|rank| rank.rank_number == current_number);
+    let display_properties = current.and_then(|rank| rank.display_properties.as_ref());
+    let icon_url = display_properties.and_then(|display| display.best_icon_url(IconSize::Standard)).or_else(|| {
+        let path = current?.foreground_image_path.as_deref().filter(|path| !path.is_empty())?;
+        url::Url::parse("https://www.bungie.net").ok()?.join(path).ok()
+    });
+
+    Some(GuardianRankDisplay {
+        current_name: display_properties.and_then(|display| display.name.as_deref()),
+        current_number,
+        lifetime_number,
+        icon_url,
+        seasonal,
+        resets_to: if seasonal { FIRST_SEASONAL_GUARDIAN_RANK - 1 } else { current_number },
+    })
+}
@@ -1,4 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyMetricDefinition {
@@ -37,3 +38,66 @@ pub struct DestinyMetricDefinition {
     #[serde(rename = "redacted")]
     pub redacted: bool,
 }
+
+/// A metric ready to show: its name, its value rendered in the tracking objective's style, and the raw value behind it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormattedMetric<'a> {
+    pub name: Option<&'a str>,
+    pub value_text: String,
+    pub raw_value: i32,
+    /// The game doesn't show this metric yet: it's invisible, redacted, or still at zero. Metrics stay hidden until they have a value.
+    pub hidden: bool,
+}
+
+/// Formats `live` for display. The style comes from `objective`, the metric's tracking objective
+/// ([`DestinyMetricDefinition::tracking_objective_hash`]); without it the value is shown as a plain count.
+pub fn format_metric<'a>(
+    live: &crate::destiny::components::metrics::DestinyMetricComponent,
+    def: &'a DestinyMetricDefinition,
+    objective: Option<&crate::destiny::definitions::DestinyObjectiveDefinition>,
+) -> FormattedMetric<'a> {
+    let progress = live.objective_progress.as_ref();
+    let raw_value = progress.and_then(|p| p.progress).unwrap_or(0);
+    let complete = progress.map_or(false, |p| p.complete);
+    let value_text = match objective {
+        Some(objective) => objective.format_progress(raw_value, complete),
+        None => crate::destiny::DestinyUnlockValueUIStyle::Automatic.format_value(raw_value, 0),
+    };
+    FormattedMetric {
+        name: def.display_properties.as_ref().and_then(|d| d.name.as_deref()),
+        value_text,
+        raw_value,
+        hidden: is_hidden(live, def),
+    }
+}
+
+/// Up to `limit` visible metrics from `component`, for leaderboard-card style displays. With `parent_node`, only metrics
+/// under that presentation node are included. Metrics come out in the order the game lists them, by definition index.
+pub fn top_metrics<'a>(
+    component: &'a crate::destiny::components::metrics::DestinyMetricsComponent,
+    defs: &'a HashMap<u32, DestinyMetricDefinition>,
+    parent_node: Option<u32>,
+    limit: usize,
+) -> Vec<(&'a DestinyMetricDefinition, &'a crate::destiny::components::metrics::DestinyMetricComponent)> {
+    let mut metrics: Vec<_> = component
+        .metrics
+        .iter()
+        .flatten()
+        .filter_map(|(hash, live)| Some((defs.get(hash)?, live)))
+        .filter(|(def, live)| !is_hidden(live, def))
+        .filter(|(def, _)| {
+            parent_node.map_or(true, |node| def.parent_node_hashes.as_deref().unwrap_or_default().contains(&node))
+        })
+        .collect();
+    metrics.sort_by_key(|(def, _)| (def.index, def.hash));
+    metrics.truncate(limit);
+    metrics
+}
+
+fn is_hidden(live: &crate::destiny::components::metrics::DestinyMetricComponent, def: &DestinyMetricDefinition) -> bool {
+    let progress = live.objective_progress.as_ref();
+    live.invisible
+        || def.redacted
+        || !progress.map_or(false, |p| p.visible)
+        || progress.and_then(|p| p.progress).unwrap_or(0) == 0
+}
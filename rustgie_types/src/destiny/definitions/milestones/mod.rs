@@ -13,6 +13,7 @@ use std::str::FromStr;
 /// - A "special event" that may have activities related to it, like Taco Tuesday (there's no Taco Tuesday in Destiny 2)
 /// - A checklist you can fulfill, like helping your Clan complete all of its weekly objectives
 /// - A tutorial quest you can play through, like the introduction to the Crucible.
+///
 /// Most of these milestones appear in game as well. Some of them are BNet only, because we're so extra. You're welcome.
 /// There are some important caveats to understand about how we currently render Milestones and their deficiencies. The game currently doesn't have any content that actually tells you oughtright *what* the Milestone is: that is to say, what you'll be doing. The best we get is either a description of the overall Milestone, or of the Quest that the Milestone is having you partake in: which is usually something that assumes you already know what it's talking about, like "Complete 5 Challenges". 5 Challenges for what? What's a challenge? These are not questions that the Milestone data will answer for you unfortunately.
 /// This isn't great, and in the future I'd like to add some custom text to give you more contextual information to pass on to your users. But for now, you can do what we do to render what little display info we do have:
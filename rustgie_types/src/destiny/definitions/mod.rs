@@ -12,6 +12,7 @@ pub mod items;
 pub mod loadouts;
 pub mod lore;
 pub mod metrics;
+pub mod mode_hierarchy;
 pub mod milestones;
 pub mod power_caps;
 pub mod presentation;
@@ -169,6 +170,7 @@ pub struct DestinyProgressionStepDefinition {
 
 /// So much of what you see in Destiny is actually an Item used in a new and creative way. This is the definition for Items in Destiny, which started off as just entities that could exist in your Inventory but ended up being the backing data for so much more: quests, reward previews, slots, and subclasses.
 /// In practice, you will want to associate this data with "live" item data from a Bungie.Net Platform call: these definitions describe the item in generic, non-instanced terms: but an actual instance of an item can vary widely from these generic definitions.
+#[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyInventoryItemDefinition {
     #[serde(rename = "displayProperties")]
@@ -364,11 +366,13 @@ pub struct DestinyInventoryItemDefinition {
 
     /// A value indicating the "base" the of the item. This enum is a useful but dramatic oversimplification of what it means for an item to have a "Type". Still, it's handy in many situations.
     /// itemCategoryHashes are the preferred way of identifying types, we have retained this enum for its convenience.
+    #[serde_as(as = "crate::diagnostics::LenientEnum")]
     #[serde(rename = "itemType")]
     pub item_type: crate::destiny::DestinyItemType,
 
     /// A value indicating the "sub-type" of the item. For instance, where an item might have an itemType value "Weapon", this will be something more specific like "Auto Rifle".
     /// itemCategoryHashes are the preferred way of identifying types, we have retained this enum for its convenience.
+    #[serde_as(as = "crate::diagnostics::LenientEnum")]
     #[serde(rename = "itemSubType")]
     pub item_sub_type: crate::destiny::DestinyItemSubType,
 
@@ -1106,7 +1110,7 @@ pub struct DestinyClassDefinition {
     pub display_properties: Option<crate::destiny::definitions::common::DestinyDisplayPropertiesDefinition>,
 
     /// A localized string referring to the singular form of the Class's name when referred to in gendered form. Keyed by the DestinyGender.
-    #[serde_as(as = "Option<HashMap<DisplayFromStr, _>>")]
+    #[serde_as(as = "Option<crate::diagnostics::LenientKeys>")]
     #[serde(rename = "genderedClassNames")]
     pub gendered_class_names: Option<HashMap<crate::destiny::DestinyGender, String>>,
 
@@ -1161,6 +1165,7 @@ pub struct DestinyGenderDefinition {
 /// - Kiosks/Collections, which are really just Vendors that don't charge currency (or charge some pittance of a currency) and whose gating for purchases revolves more around your character's state.
 /// - Previews for rewards or the contents of sacks. These are implemented as Vendors, where you can't actually purchase from them but the items that they have for sale and the categories of sale items reflect the rewards or contents of the sack. This is so that the game could reuse the existing Vendor display UI for rewards and save a bunch of wheel reinvention.
 /// - Item Transfer capabilities, like the Vault and Postmaster. Vendors can have "acceptedItem" buckets that determine the source and destination buckets for transfers. When you interact with such a vendor, these buckets are what gets shown in the UI instead of any items that the Vendor would have for sale. Yep, the Vault is a vendor.
+///
 /// It is pretty much guaranteed that they'll be used for even more features in the future. They have come to be seen more as generic categorized containers for items than "vendors" in a traditional sense, for better or worse.
 /// Where possible and time allows, we'll attempt to split those out into their own more digestible derived "Definitions": but often time does not allow that, as you can see from the above ways that vendors are used which we never split off from Vendor Definitions externally.
 /// Since Vendors are so many things to so many parts of the game, the definition is understandably complex. You will want to combine this data with live Vendor information from the API when it is available.
@@ -2080,6 +2085,7 @@ pub struct DestinyActivityChallengeDefinition {
 /// - Challenges (which are Objectives defined on an DestinyActivityDefintion)
 /// - Milestones (which refer to Objectives that are defined on both Quest Steps and Activities)
 /// - Anything else that the designers decide to do later.
+///
 /// Objectives have progress, a notion of having been Completed, human readable data describing the task to be accomplished, and a lot of optional tack-on data that can enhance the information provided about the task.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyObjectiveDefinition {
@@ -2189,6 +2195,7 @@ pub struct DestinyObjectivePerkEntryDefinition {
 /// - Perks determine a weapons' damage type.
 /// - Perks put the Mods in Modifiers (they are literally the entity that bestows the Sandbox benefit for whatever fluff text about the modifier in the Socket, Plug or Talent Node)
 /// - Perks are applied for unique alterations of state in Objectives
+///
 /// Anyways, I'm sure you can see why perks are so interesting.
 /// What Perks often don't have is human readable information, so we attempt to reverse engineer that by pulling that data from places that uniquely refer to these perks: namely, Talent Nodes and Plugs. That only gives us a subset of perks that are human readable, but those perks are the ones people generally care about anyways. The others are left as a mystery, their true purpose mostly unknown and undocumented.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -3882,7 +3889,7 @@ pub struct DestinyRaceDefinition {
     pub race_type: crate::destiny::DestinyRace,
 
     /// A localized string referring to the singular form of the Race's name when referred to in gendered form. Keyed by the DestinyGender.
-    #[serde_as(as = "Option<HashMap<DisplayFromStr, _>>")]
+    #[serde_as(as = "Option<crate::diagnostics::LenientKeys>")]
     #[serde(rename = "genderedRaceNames")]
     pub gendered_race_names: Option<HashMap<crate::destiny::DestinyGender, String>>,
 
@@ -3981,3 +3988,204 @@ pub struct DestinyEntitySearchResultItem {
     #[serde(rename = "weight")]
     pub weight: f64,
 }
+
+impl DestinyClassDefinition {
+    /// The class name as it should be shown for a character of the given gender, falling back to the ungendered display name when Bungie didn't provide gendered names.
+    pub fn class_name_for(&self, gender: crate::destiny::DestinyGender) -> Option<&str> {
+        self.gendered_class_names
+            .as_ref()
+            .and_then(|names| names.get(&gender))
+            .or_else(|| self.display_properties.as_ref().and_then(|dp| dp.name.as_ref()))
+            .map(String::as_str)
+    }
+}
+
+impl DestinyRaceDefinition {
+    /// The race name as it should be shown for a character of the given gender, falling back to the ungendered display name when Bungie didn't provide gendered names.
+    pub fn race_name_for(&self, gender: crate::destiny::DestinyGender) -> Option<&str> {
+        self.gendered_race_names
+            .as_ref()
+            .and_then(|names| names.get(&gender))
+            .or_else(|| self.display_properties.as_ref().and_then(|dp| dp.name.as_ref()))
+            .map(String::as_str)
+    }
+}
+
+/// `DestinyItemCategoryDefinition` hash of the "Weapon" category.
+const WEAPON_CATEGORY_HASH: u32 = 1;
+
+/// `DestinyItemCategoryDefinition` hash of the "Armor" category.
+const ARMOR_CATEGORY_HASH: u32 = 20;
+
+impl DestinyInventoryItemDefinition {
+    /// The item's rarity, or `TierType::Unknown` when the definition has no inventory block.
+    pub fn tier(&self) -> crate::destiny::TierType {
+        self.inventory.as_ref().map_or(crate::destiny::TierType::Unknown, |inventory| inventory.tier_type)
+    }
+
+    /// The ammunition the item uses. When the equipping block is missing or doesn't say, weapons are classified by `itemSubType`, and subtypes that come in more than one ammo type are `Unknown`. Non-weapons are `None`.
+    pub fn ammo_type(&self) -> crate::destiny::DestinyAmmunitionType {
+        use crate::destiny::{DestinyAmmunitionType, DestinyItemSubType};
+
+        match self.equipping_block.as_ref().map(|block| block.ammo_type) {
+            Some(ammo_type) if ammo_type != DestinyAmmunitionType::None => ammo_type,
+            _ if !self.is_weapon() => DestinyAmmunitionType::None,
+            _ => match self.item_sub_type {
+                DestinyItemSubType::AutoRifle
+                | DestinyItemSubType::HandCannon
+                | DestinyItemSubType::PulseRifle
+                | DestinyItemSubType::ScoutRifle
+                | DestinyItemSubType::Sidearm
+                | DestinyItemSubType::SubmachineGun
+                | DestinyItemSubType::Bow => DestinyAmmunitionType::Primary,
+                DestinyItemSubType::Shotgun
+                | DestinyItemSubType::SniperRifle
+                | DestinyItemSubType::FusionRifle
+                | DestinyItemSubType::Glaive => DestinyAmmunitionType::Special,
+                DestinyItemSubType::Machinegun
+                | DestinyItemSubType::RocketLauncher
+                | DestinyItemSubType::FusionRifleLine
+                | DestinyItemSubType::Sword => DestinyAmmunitionType::Heavy,
+                _ => DestinyAmmunitionType::Unknown,
+            },
+        }
+    }
+
+    /// The `DestinyEquipmentSlotDefinition` hash of the slot the item equips into, if it can be equipped.
+    pub fn slot_hash(&self) -> Option<u32> {
+        self.equipping_block
+            .as_ref()
+            .map(|block| block.equipment_slot_type_hash)
+            .filter(|hash| *hash != 0)
+    }
+
+    /// Whether the item is a weapon, by item category, falling back to `itemType` when the definition has no categories.
+    pub fn is_weapon(&self) -> bool {
+        self.in_category_or(WEAPON_CATEGORY_HASH, crate::destiny::DestinyItemType::Weapon)
+    }
+
+    /// Whether the item is armor, by item category, falling back to `itemType` when the definition has no categories.
+    pub fn is_armor(&self) -> bool {
+        self.in_category_or(ARMOR_CATEGORY_HASH, crate::destiny::DestinyItemType::Armor)
+    }
+
+    fn in_category_or(&self, category_hash: u32, item_type: crate::destiny::DestinyItemType) -> bool {
+        match self.item_category_hashes.as_ref().filter(|hashes| !hashes.is_empty()) {
+            Some(hashes) => hashes.contains(&category_hash),
+            None => self.item_type == item_type,
+        }
+    }
+}
+
+/// Selects weapons out of a set of item definitions. Criteria left unset match every weapon.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeaponFilter {
+    tier: Option<crate::destiny::TierType>,
+    ammo_type: Option<crate::destiny::DestinyAmmunitionType>,
+    slot_hash: Option<u32>,
+    sub_type: Option<crate::destiny::DestinyItemSubType>,
+}
+
+impl WeaponFilter {
+    pub fn new() -> WeaponFilter {
+        WeaponFilter::default()
+    }
+
+    #[must_use]
+    pub fn tier(mut self, tier: crate::destiny::TierType) -> WeaponFilter {
+        self.tier = Some(tier);
+        self
+    }
+
+    #[must_use]
+    pub fn ammo_type(mut self, ammo_type: crate::destiny::DestinyAmmunitionType) -> WeaponFilter {
+        self.ammo_type = Some(ammo_type);
+        self
+    }
+
+    #[must_use]
+    pub fn slot_hash(mut self, slot_hash: u32) -> WeaponFilter {
+        self.slot_hash = Some(slot_hash);
+        self
+    }
+
+    #[must_use]
+    pub fn sub_type(mut self, sub_type: crate::destiny::DestinyItemSubType) -> WeaponFilter {
+        self.sub_type = Some(sub_type);
+        self
+    }
+
+    pub fn matches(&self, item: &DestinyInventoryItemDefinition) -> bool {
+        item.is_weapon()
+            && self.tier.map_or(true, |tier| item.tier() == tier)
+            && self.ammo_type.map_or(true, |ammo_type| item.ammo_type() == ammo_type)
+            && self.slot_hash.map_or(true, |slot_hash| item.slot_hash() == Some(slot_hash))
+            && self.sub_type.map_or(true, |sub_type| item.item_sub_type == sub_type)
+    }
+
+    pub fn apply<'a>(&self, items: &[&'a DestinyInventoryItemDefinition]) -> Vec<&'a DestinyInventoryItemDefinition> {
+        items.iter().copied().filter(|item| self.matches(item)).collect()
+    }
+}
+
+/// Selects armor out of a set of item definitions. Criteria left unset match every piece of armor.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArmorFilter {
+    tier: Option<crate::destiny::TierType>,
+    class_type: Option<crate::destiny::DestinyClass>,
+    slot_hash: Option<u32>,
+}
+
+impl ArmorFilter {
+    pub fn new() -> ArmorFilter {
+        ArmorFilter::default()
+    }
+
+    #[must_use]
+    pub fn tier(mut self, tier: crate::destiny::TierType) -> ArmorFilter {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Only armor wearable by this class. Armor usable by any class (`DestinyClass::Unknown`) always matches.
+    #[must_use]
+    pub fn class_type(mut self, class_type: crate::destiny::DestinyClass) -> ArmorFilter {
+        self.class_type = Some(class_type);
+        self
+    }
+
+    #[must_use]
+    pub fn slot_hash(mut self, slot_hash: u32) -> ArmorFilter {
+        self.slot_hash = Some(slot_hash);
+        self
+    }
+
+    pub fn matches(&self, item: &DestinyInventoryItemDefinition) -> bool {
+        item.is_armor()
+            && self.tier.map_or(true, |tier| item.tier() == tier)
+            && self.class_type.map_or(true, |class_type| {
+                item.class_type == class_type || item.class_type == crate::destiny::DestinyClass::Unknown
+            })
+            && self.slot_hash.map_or(true, |slot_hash| item.slot_hash() == Some(slot_hash))
+    }
+
+    pub fn apply<'a>(&self, items: &[&'a DestinyInventoryItemDefinition]) -> Vec<&'a DestinyInventoryItemDefinition> {
+        items.iter().copied().filter(|item| self.matches(item)).collect()
+    }
+}
+
+impl DestinyObjectiveDefinition {
+    /// The style progress should be shown in: the completed style once complete, the in-progress style before that.
+    pub fn value_style_for(&self, complete: bool) -> crate::destiny::DestinyUnlockValueUIStyle {
+        if complete {
+            self.completed_value_style
+        } else {
+            self.in_progress_value_style
+        }
+    }
+
+    /// Renders `progress` against this objective's completion value in the style for its completion state.
+    pub fn format_progress(&self, progress: i32, complete: bool) -> String {
+        self.value_style_for(complete).format_value(progress, self.completion_value)
+    }
+}
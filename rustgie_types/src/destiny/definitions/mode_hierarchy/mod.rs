@@ -0,0 +1,75 @@
+﻿use crate::destiny::definitions::DestinyActivityModeDefinition;
+use crate::destiny::historical_stats::definitions::DestinyActivityModeType;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// The activity mode hierarchy (Control → AllPvP → AllModes, and so on), resolved from the parentHashes of each DestinyActivityModeDefinition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModeTree {
+    ancestors: HashMap<DestinyActivityModeType, Vec<DestinyActivityModeType>>,
+}
+
+impl ModeTree {
+    /// Builds the tree from the DestinyActivityModeDefinition table, keyed by hash.
+    /// Parent hashes missing from the table are ignored. Fails if the parents form a cycle.
+    pub fn build(definitions: &HashMap<u32, DestinyActivityModeDefinition>) -> Result<ModeTree> {
+        let mut parents: HashMap<DestinyActivityModeType, Vec<DestinyActivityModeType>> = HashMap::new();
+        for definition in definitions.values() {
+            let mode_parents = parents.entry(definition.mode_type).or_default();
+            for parent in definition.parent_hashes.iter().flatten().filter_map(|hash| definitions.get(hash)) {
+                if !mode_parents.contains(&parent.mode_type) {
+                    mode_parents.push(parent.mode_type);
+                }
+            }
+        }
+
+        let mut tree = ModeTree::default();
+        for &mode in parents.keys() {
+            tree.resolve(mode, &parents, &mut Vec::new())?;
+        }
+        Ok(tree)
+    }
+
+    /// Every mode above `mode`, nearest first. Empty for root and unknown modes.
+    pub fn ancestors(&self, mode: DestinyActivityModeType) -> Vec<DestinyActivityModeType> {
+        self.ancestors.get(&mode).cloned().unwrap_or_default()
+    }
+
+    /// Whether `ancestor` appears anywhere above `mode`. A mode is not its own descendant.
+    pub fn is_descendant(&self, mode: DestinyActivityModeType, ancestor: DestinyActivityModeType) -> bool {
+        self.ancestors.get(&mode).map_or(false, |ancestors| ancestors.contains(&ancestor))
+    }
+
+    fn resolve(
+        &mut self,
+        mode: DestinyActivityModeType,
+        parents: &HashMap<DestinyActivityModeType, Vec<DestinyActivityModeType>>,
+        path: &mut Vec<DestinyActivityModeType>,
+    ) -> Result<()> {
+        if self.ancestors.contains_key(&mode) {
+            return Ok(());
+        }
+        if path.contains(&mode) {
+            return Err(anyhow!("Activity mode {:?} is its own ancestor via {:?}", mode, path));
+        }
+
+        path.push(mode);
+        let mut ancestors = Vec::new();
+        let direct = parents.get(&mode).map(Vec::as_slice).unwrap_or_default();
+        for &parent in direct {
+            self.resolve(parent, parents, path)?;
+            ancestors.push(parent);
+        }
+        for parent in direct {
+            for &ancestor in &self.ancestors[parent] {
+                if !ancestors.contains(&ancestor) {
+                    ancestors.push(ancestor);
+                }
+            }
+        }
+        path.pop();
+
+        self.ancestors.insert(mode, ancestors);
+        Ok(())
+    }
+}
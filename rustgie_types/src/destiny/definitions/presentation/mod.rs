@@ -65,6 +65,7 @@ pub struct DestinyScoredPresentationNodeBaseDefinition {
 /// A PresentationNode is an entity that represents a logical grouping of other entities visually/organizationally.
 /// For now, Presentation Nodes may contain the following... but it may be used for more in the future:
 /// - Collectibles - Records (Or, as the public will call them, "Triumphs." Don't ask me why we're overloading the term "Triumph", it still hurts me to think about it) - Metrics (aka Stat Trackers) - Other Presentation Nodes, allowing a tree of Presentation Nodes to be created
+///
 /// Part of me wants to break these into conceptual definitions per entity being collected, but the possibility of these different types being mixed in the same UI and the possibility that it could actually be more useful to return the "bare metal" presentation node concept has resulted in me deciding against that for the time being.
 /// We'll see if I come to regret this as well.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -27,3 +27,42 @@ pub struct DestinyProgressionLevelRequirementDefinition {
     #[serde(rename = "redacted")]
     pub redacted: bool,
 }
+
+/// The step `live` is working through, which is also the rank it displays.
+/// Past the last step, that's the last step again if the progression repeats it, and `None` otherwise.
+pub fn current_step<'a>(
+    live: &crate::destiny::DestinyProgression,
+    def: &'a crate::destiny::definitions::DestinyProgressionDefinition,
+) -> Option<&'a crate::destiny::definitions::DestinyProgressionStepDefinition> {
+    let steps = def.steps.as_deref().unwrap_or_default();
+    let index = usize::try_from(live.step_index).ok()?;
+    match steps.get(index) {
+        Some(step) => Some(step),
+        None if def.repeat_last_step => steps.last(),
+        None => None,
+    }
+}
+
+/// What `live` earns on reaching its next level: the current step's rewards. Empty once the level cap is reached or the steps run out.
+pub fn next_rewards<'a>(
+    live: &crate::destiny::DestinyProgression,
+    def: &'a crate::destiny::definitions::DestinyProgressionDefinition,
+) -> &'a [crate::destiny::DestinyItemQuantity] {
+    if live.level_cap > 0 && live.level >= live.level_cap {
+        return &[];
+    }
+    current_step(live, def).and_then(|step| step.reward_items.as_deref()).unwrap_or_default()
+}
+
+/// How far `live` is into its current level, from 0 to 100. Capped progressions report a `nextLevelAt` of 0, which counts as 0%.
+pub fn percent_to_next(live: &crate::destiny::DestinyProgression) -> f32 {
+    if live.next_level_at <= 0 {
+        return 0.0;
+    }
+    (live.progress_to_next_level as f32 / live.next_level_at as f32 * 100.0).clamp(0.0, 100.0)
+}
+
+/// How much more progress `live` can earn this week, or `None` if it has no weekly limit.
+pub fn weekly_progress_remaining(live: &crate::destiny::DestinyProgression) -> Option<i32> {
+    (live.weekly_limit > 0).then(|| (live.weekly_limit - live.weekly_progress).max(0))
+}
@@ -163,3 +163,152 @@ pub struct DestinyRecordIntervalRewards {
     #[serde(rename = "intervalRewardItems")]
     pub interval_reward_items: Option<Vec<crate::destiny::DestinyItemQuantity>>,
 }
+
+/// Where a single [`RecordTier`] stands for the player.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordTierState {
+    /// Not complete, or no live data was given.
+    Incomplete,
+    /// Complete but not yet redeemed.
+    Complete,
+    Redeemed,
+}
+
+/// One tier of a record, as returned by [`record_tiers`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordTier<'a> {
+    /// The tier's objectives: exactly one for interval records, the record's whole objective list otherwise.
+    pub objective_hashes: Vec<u32>,
+    /// Live progress for each of `objective_hashes`, in the same order, where the component had it.
+    pub progress: Vec<Option<&'a crate::destiny::quests::DestinyObjectiveProgress>>,
+    pub state: RecordTierState,
+    pub score: i32,
+    pub reward_items: &'a [crate::destiny::DestinyItemQuantity],
+}
+
+impl<'a> RecordTier<'a> {
+    /// The completion value of the tier's first objective, which is its only one for interval records.
+    /// Bungie only sends completion values with live progress, so this is `None` without it.
+    pub fn threshold(&self) -> Option<i32> {
+        self.progress.first().copied().flatten().map(|p| p.completion_value)
+    }
+}
+
+/// The tiers of a record, lowest first.
+/// Interval records (multi-tier triumphs) get one tier per `intervalInfo.intervalObjectives` entry, with the per-tier score and rewards;
+/// any other record is a single tier built from `objectiveHashes`, `completionInfo` and `rewardItems`.
+pub fn record_tiers<'a>(
+    record_def: &'a DestinyRecordDefinition,
+    live: Option<&'a crate::destiny::components::records::DestinyRecordComponent>,
+) -> Vec<RecordTier<'a>> {
+    let intervals = record_def
+        .interval_info
+        .as_ref()
+        .and_then(|info| info.interval_objectives.as_deref())
+        .unwrap_or_default();
+
+    if intervals.is_empty() {
+        let objective_hashes = record_def.objective_hashes.clone().unwrap_or_default();
+        let live_objectives = live.and_then(|l| l.objectives.as_deref()).unwrap_or_default();
+        let state = match live.map(|l| l.state) {
+            Some(state) if state.contains(crate::destiny::DestinyRecordState::RecordRedeemed) => RecordTierState::Redeemed,
+            Some(state) if !state.contains(crate::destiny::DestinyRecordState::ObjectiveNotCompleted) => RecordTierState::Complete,
+            _ => RecordTierState::Incomplete,
+        };
+        return vec![RecordTier {
+            progress: objective_hashes
+                .iter()
+                .map(|hash| live_objectives.iter().find(|p| p.objective_hash == *hash))
+                .collect(),
+            objective_hashes,
+            state,
+            score: record_def.completion_info.as_ref().map_or(0, |c| c.score_value),
+            reward_items: record_def.reward_items.as_deref().unwrap_or_default(),
+        }];
+    }
+
+    let rewards = record_def
+        .interval_info
+        .as_ref()
+        .and_then(|info| info.interval_rewards.as_deref())
+        .unwrap_or_default();
+    let live_intervals = live.and_then(|l| l.interval_objectives.as_deref()).unwrap_or_default();
+    let redeemed = live.map_or(0, |l| usize::try_from(l.intervals_redeemed_count).unwrap_or(0));
+
+    intervals
+        .iter()
+        .enumerate()
+        .map(|(index, interval)| {
+            let progress = live_intervals
+                .get(index)
+                .filter(|p| p.objective_hash == interval.interval_objective_hash)
+                .or_else(|| live_intervals.iter().find(|p| p.objective_hash == interval.interval_objective_hash));
+            let state = if index < redeemed {
+                RecordTierState::Redeemed
+            } else if progress.map_or(false, |p| p.complete) {
+                RecordTierState::Complete
+            } else {
+                RecordTierState::Incomplete
+            };
+            RecordTier {
+                objective_hashes: vec![interval.interval_objective_hash],
+                progress: vec![progress],
+                state,
+                score: interval.interval_score_value,
+                reward_items: rewards
+                    .get(index)
+                    .and_then(|r| r.interval_reward_items.as_deref())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// The title a character has equipped, from [`active_title`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedTitle<'a> {
+    /// The seal record that grants the title.
+    pub record_hash: u32,
+    /// The title as shown for the character's gender.
+    pub name: &'a str,
+    /// Whether the title's gilding record is complete, i.e. it's gilded for the current season.
+    pub gilded: bool,
+    /// How many times the title has been gilded, from the gilding record's completion count. Shown as "×N" in game.
+    pub gild_count: i32,
+}
+
+/// The title `character` has equipped, or `None` if it has none or its seal record isn't in `record_defs`.
+///
+/// The equipped title is `titleRecordHash` on the character component (needs the Characters component); the records
+/// components aren't involved in picking it. Gilding is read from the seal's `titleInfo.gildingTrackingRecordHash`, looked up
+/// in the profile records and then the character's records (needs the Records component).
+pub fn active_title<'a>(
+    character: &crate::destiny::entities::characters::DestinyCharacterComponent,
+    profile_records: &crate::destiny::components::records::DestinyProfileRecordsComponent,
+    character_records: Option<&crate::destiny::components::records::DestinyCharacterRecordsComponent>,
+    record_defs: &'a HashMap<u32, DestinyRecordDefinition>,
+) -> Option<ResolvedTitle<'a>> {
+    let record_hash = character.title_record_hash.filter(|hash| *hash != 0)?;
+    let title_info = record_defs.get(&record_hash)?.title_info.as_ref().filter(|info| info.has_title)?;
+    let name = title_info
+        .titles_by_gender_hash
+        .as_ref()
+        .and_then(|titles| titles.get(&character.gender_hash))
+        .or_else(|| title_info.titles_by_gender.as_ref().and_then(|titles| titles.get(&character.gender_type)))?;
+
+    let gilding = title_info.gilding_tracking_record_hash.and_then(|hash| {
+        profile_records
+            .records
+            .as_ref()
+            .and_then(|records| records.get(&hash))
+            .or_else(|| character_records?.records.as_ref()?.get(&hash))
+    });
+    Some(ResolvedTitle {
+        record_hash,
+        name,
+        gilded: gilding.map_or(false, |record| {
+            !record.state.contains(crate::destiny::DestinyRecordState::ObjectiveNotCompleted)
+        }),
+        gild_count: gilding.and_then(|record| record.completed_count).unwrap_or(0),
+    })
+}
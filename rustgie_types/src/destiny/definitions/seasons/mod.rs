@@ -1,5 +1,7 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use crate::common::models::CoreSettingsConfiguration;
+use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
 use time::OffsetDateTime;
 
 /// Defines a canonical "Season" of Destiny: a range of a few months where the game highlights certain challenges, provides new loot, has new Clan-related rewards and celebrates various seasonal events.
@@ -189,3 +191,32 @@ pub struct DestinyEventCardImages {
     #[serde(rename = "themeBackgroundImagePath")]
     pub theme_background_image_path: Option<String>,
 }
+
+/// The season whose date range contains `now`. Seasons without a start date are skipped, and a season without an end date runs indefinitely.
+/// When ranges overlap, the season that started latest wins, then the higher season number.
+pub fn current_season(
+    definitions: &HashMap<u32, DestinySeasonDefinition>,
+    now: OffsetDateTime,
+) -> Option<&DestinySeasonDefinition> {
+    definitions
+        .values()
+        .filter(|season| {
+            season.start_date.map_or(false, |start| start <= now)
+                && season.end_date.map_or(true, |end| now < end)
+        })
+        .max_by_key(|season| (season.start_date, season.season_number, season.hash))
+}
+
+/// The season the live settings name as current, if the settings include one and it's in `definitions`.
+pub fn season_by_settings<'a>(
+    settings: &CoreSettingsConfiguration,
+    definitions: &'a HashMap<u32, DestinySeasonDefinition>,
+) -> Option<&'a DestinySeasonDefinition> {
+    let hash = settings.destiny2_core_settings.as_ref()?.current_season_hash?;
+    definitions.get(&hash)
+}
+
+/// Whole days left before `season` ends, or `None` if it has no end date. Zero once it has ended.
+pub fn days_remaining(season: &DestinySeasonDefinition, now: OffsetDateTime) -> Option<i64> {
+    season.end_date.map(|end| (end - now).whole_days().max(0))
+}
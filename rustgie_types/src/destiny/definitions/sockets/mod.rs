@@ -1,4 +1,6 @@
 ﻿use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::collections::HashMap;
 
 /// All Sockets have a "Type": a set of common properties that determine when the socket allows Plugs to be inserted, what Categories of Plugs can be inserted, and whether the socket is even visible at all given the current game/character/account state.
 /// See DestinyInventoryItemDefinition for more information about Socketed items and Plugs.
@@ -101,11 +103,109 @@ pub struct DestinySocketTypeScalarMaterialRequirementEntry {
     pub scalar_value: i32,
 }
 
+/// One of an item's socket categories, resolved against its [`DestinySocketCategoryDefinition`], as returned by [`grouped_sockets`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SocketGroup<'a> {
+    pub category_hash: u32,
+    /// The category's display name, if its definition was found and has one.
+    pub name: Option<&'a str>,
+    /// `Unknown` when the category's definition wasn't found.
+    pub style: crate::destiny::DestinySocketCategoryStyle,
+    /// Indexes into the item definition's `sockets.socketEntries`, which line up with the live `DestinyItemSocketsComponent.sockets`, in game-rendered order.
+    pub socket_indexes: Vec<usize>,
+}
+
+impl<'a> SocketGroup<'a> {
+    /// The live state of this group's sockets from an instanced item's sockets component, in the group's order.
+    /// Sockets the component doesn't have an entry for are `None`.
+    pub fn live_sockets<'s>(
+        &self,
+        sockets: &'s crate::destiny::entities::items::DestinyItemSocketsComponent,
+    ) -> Vec<Option<&'s crate::destiny::entities::items::DestinyItemSocketState>> {
+        let states = sockets.sockets.as_deref().unwrap_or_default();
+        self.socket_indexes.iter().map(|&index| states.get(index)).collect()
+    }
+}
+
+/// The item's socket categories (weapon perks, mods, cosmetics and so on) in the order the definition lists them.
+/// Categories missing from `category_defs` are kept, unnamed, so socket indexes aren't silently dropped; negative indexes are skipped.
+pub fn grouped_sockets<'a>(
+    item_def: &crate::destiny::definitions::DestinyInventoryItemDefinition,
+    category_defs: &'a HashMap<u32, DestinySocketCategoryDefinition>,
+) -> Vec<SocketGroup<'a>> {
+    let categories = item_def
+        .sockets
+        .as_ref()
+        .and_then(|sockets| sockets.socket_categories.as_deref())
+        .unwrap_or_default();
+    categories
+        .iter()
+        .map(|category| {
+            let definition = category_defs.get(&category.socket_category_hash);
+            SocketGroup {
+                category_hash: category.socket_category_hash,
+                name: definition
+                    .and_then(|d| d.display_properties.as_ref())
+                    .and_then(|d| d.name.as_deref()),
+                style: definition.map_or(crate::destiny::DestinySocketCategoryStyle::Unknown, |d| d.category_style),
+                socket_indexes: category
+                    .socket_indexes
+                    .iter()
+                    .flatten()
+                    .filter_map(|&index| usize::try_from(index).ok())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Whether `plug_def`'s plug category is on `socket_type_def`'s whitelist, so the game would let it be inserted into a socket of that type.
+/// A missing or empty whitelist accepts nothing, and neither is an item without a plug block ever allowed. Insertion rules and costs aren't checked.
+pub fn plug_allowed(
+    socket_type_def: &DestinySocketTypeDefinition,
+    plug_def: &crate::destiny::definitions::DestinyInventoryItemDefinition,
+) -> bool {
+    let category_hash = match &plug_def.plug {
+        None => return false,
+        Some(plug) => plug.plug_category_hash,
+    };
+    socket_type_def
+        .plug_whitelist
+        .iter()
+        .flatten()
+        .any(|entry| entry.category_hash == category_hash)
+}
+
+/// Indexes into `item_def`'s `sockets.socketEntries` whose socket type allows `plug_def`, in definition order.
+/// Sockets whose type is missing from `socket_type_defs` are skipped.
+pub fn sockets_accepting(
+    plug_def: &crate::destiny::definitions::DestinyInventoryItemDefinition,
+    item_def: &crate::destiny::definitions::DestinyInventoryItemDefinition,
+    socket_type_defs: &HashMap<u32, DestinySocketTypeDefinition>,
+) -> Vec<usize> {
+    let entries = item_def
+        .sockets
+        .as_ref()
+        .and_then(|sockets| sockets.socket_entries.as_deref())
+        .unwrap_or_default();
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| {
+            socket_type_defs
+                .get(&entry.socket_type_hash)
+                .map_or(false, |socket_type_def| plug_allowed(socket_type_def, plug_def))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
 /// Sockets on an item are organized into Categories visually.
 /// You can find references to the socket category defined on an item's DestinyInventoryItemDefinition.sockets.socketCategories property.
 /// This has the display information for rendering the categories' header, and a hint for how the UI should handle showing this category.
 /// The shitty thing about this, however, is that the socket categories' UI style can be overridden by the item's UI style. For instance, the Socket Category used by Emote Sockets says it's "consumable," but that's a lie: they're all reusable, and overridden by the detail UI pages in ways that we can't easily account for in the API.
 /// As a result, I will try to compile these rules into the individual sockets on items, and provide the best hint possible there through the plugSources property. In the future, I may attempt to use this information in conjunction with the item to provide a more usable UI hint on the socket layer, but for now improving the consistency of plugSources is the best I have time to provide. (See https://github.com/Bungie-net/api/issues/522 for more info)
+#[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinySocketCategoryDefinition {
     #[serde(rename = "displayProperties")]
@@ -117,6 +217,7 @@ pub struct DestinySocketCategoryDefinition {
     pub ui_category_style: u32,
 
     /// Same as uiCategoryStyle, but in a more usable enumeration form.
+    #[serde_as(as = "crate::diagnostics::LenientEnum")]
     #[serde(rename = "categoryStyle")]
     pub category_style: crate::destiny::DestinySocketCategoryStyle,
 
@@ -1,4 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// These definitions represent vendors' locations and relevant display information at different times in the game.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -11,3 +12,35 @@ pub struct DestinyVendorLocationDefinition {
     #[serde(rename = "backgroundImagePath")]
     pub background_image_path: Option<String>,
 }
+
+/// Where a vendor currently is, from [`current_location`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedLocation<'a> {
+    pub destination_hash: u32,
+    /// `None` if the destination's definition wasn't provided.
+    pub destination_name: Option<&'a str>,
+    /// `None` if the destination's or place's definition wasn't provided.
+    pub place_name: Option<&'a str>,
+    pub background_image: Option<&'a str>,
+}
+
+/// Resolves the live `vendorLocationIndex` to a destination and place.
+///
+/// `None` if the vendor has no locations, which is normal for vendors that never move, or if the index doesn't point at one.
+pub fn current_location<'a>(
+    vendor_live: &crate::destiny::entities::vendors::DestinyVendorComponent,
+    vendor_def: &'a crate::destiny::definitions::DestinyVendorDefinition,
+    destination_defs: &'a HashMap<u32, crate::destiny::definitions::DestinyDestinationDefinition>,
+    place_defs: &'a HashMap<u32, crate::destiny::definitions::DestinyPlaceDefinition>,
+) -> Option<ResolvedLocation<'a>> {
+    let index = usize::try_from(vendor_live.vendor_location_index).ok()?;
+    let location = vendor_def.locations.as_ref()?.get(index)?;
+    let destination = destination_defs.get(&location.destination_hash);
+    let place = destination.and_then(|destination| place_defs.get(&destination.place_hash));
+    Some(ResolvedLocation {
+        destination_hash: location.destination_hash,
+        destination_name: destination.and_then(|d| d.display_properties.as_ref()).and_then(|d| d.name.as_deref()),
+        place_name: place.and_then(|p| p.display_properties.as_ref()).and_then(|d| d.name.as_deref()),
+        background_image: location.background_image_path.as_deref().filter(|path| !path.is_empty()),
+    })
+}
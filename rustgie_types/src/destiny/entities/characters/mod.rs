@@ -1,4 +1,5 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use crate::ids::id_newtype;
+use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use std::collections::HashMap;
 use time::OffsetDateTime;
@@ -162,6 +163,7 @@ pub struct DestinyCharacterRenderComponent {
     /// A minimal view of:
     /// - Equipped items
     /// - The rendering-related custom options on those equipped items
+    ///
     /// Combined, that should be enough to render all of the items on the equipped character.
     #[serde(rename = "peerView")]
     pub peer_view: Option<crate::destiny::character::DestinyCharacterPeerView>,
@@ -207,3 +209,10 @@ pub struct DestinyCharacterActivitiesComponent {
     #[serde(rename = "lastCompletedStoryHash")]
     pub last_completed_story_hash: u32,
 }
+
+/// The ID of a Destiny character, as accepted by the character endpoints and used to key the character components of a profile.
+/// Bungie sends these as strings; numbers are accepted too. Zero and negative IDs never name a real character and are rejected when parsing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CharacterId(i64);
+
+id_newtype!(CharacterId("a character ID", "Character IDs"));
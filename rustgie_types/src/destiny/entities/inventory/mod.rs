@@ -1,4 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// A list of minimal information for items in an inventory: be it a character's inventory, or a Profile's inventory. (Note that the Vault is a collection of inventory buckets in the Profile's inventory)
 /// Inventory Items returned here are in a flat list, but importantly they have a bucketHash property that indicates the specific inventory bucket that is holding them. These buckets constitute things like the separate sections of the Vault, the user's inventory slots, etc. See DestinyInventoryBucketDefinition for more info.
@@ -8,3 +9,236 @@ pub struct DestinyInventoryComponent {
     #[serde(rename = "items")]
     pub items: Option<Vec<crate::destiny::entities::items::DestinyItemComponent>>,
 }
+
+/// The bucket holding items sent to a character's Postmaster ("Lost Items").
+pub const LOST_ITEMS_BUCKET_HASH: u32 = 215593132;
+
+/// The bucket holding everything in the Vault, regardless of which Vault section it displays in.
+pub const VAULT_BUCKET_HASH: u32 = 138197802;
+
+pub const KINETIC_WEAPONS_BUCKET_HASH: u32 = 1498876634;
+pub const ENERGY_WEAPONS_BUCKET_HASH: u32 = 2465295065;
+pub const POWER_WEAPONS_BUCKET_HASH: u32 = 953998645;
+pub const HELMET_BUCKET_HASH: u32 = 3448274439;
+pub const GAUNTLETS_BUCKET_HASH: u32 = 3551918588;
+pub const CHEST_ARMOR_BUCKET_HASH: u32 = 14239492;
+pub const LEG_ARMOR_BUCKET_HASH: u32 = 20886954;
+pub const CLASS_ARMOR_BUCKET_HASH: u32 = 1585787867;
+
+/// The equipment slots a character's power level is averaged over: three weapons, then five armor pieces.
+pub const POWER_SLOT_BUCKET_HASHES: [u32; 8] = [
+    KINETIC_WEAPONS_BUCKET_HASH,
+    ENERGY_WEAPONS_BUCKET_HASH,
+    POWER_WEAPONS_BUCKET_HASH,
+    HELMET_BUCKET_HASH,
+    GAUNTLETS_BUCKET_HASH,
+    CHEST_ARMOR_BUCKET_HASH,
+    LEG_ARMOR_BUCKET_HASH,
+    CLASS_ARMOR_BUCKET_HASH,
+];
+
+/// Where an item in a [`MergedInventory`] came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ItemSource {
+    /// A character's inventory or equipment, keyed by characterId.
+    Character(i64),
+    Vault,
+    /// A character's Postmaster, keyed by characterId.
+    Postmaster(i64),
+    /// Items in the Profile inventory that aren't in the Vault, such as consumables and mods.
+    ProfileWide,
+}
+
+/// Several inventory components merged into one view, remembering where each item came from.
+/// Items are borrowed from the components rather than cloned, so the components must outlive the merged view.
+#[derive(Clone, Debug, Default)]
+pub struct MergedInventory<'a> {
+    entries: Vec<(ItemSource, &'a crate::destiny::entities::items::DestinyItemComponent)>,
+}
+
+impl<'a> MergedInventory<'a> {
+    pub fn new() -> MergedInventory<'a> {
+        MergedInventory::default()
+    }
+
+    /// Adds every item in `component` under `source`.
+    ///
+    /// Items in a character's Lost Items bucket are recorded as [`ItemSource::Postmaster`], and items in the Vault bucket
+    /// of the Profile inventory are recorded as [`ItemSource::Vault`], so callers can add the raw components as returned.
+    pub fn add(&mut self, source: ItemSource, component: &'a DestinyInventoryComponent) {
+        let items = match &component.items {
+            None => return,
+            Some(items) => items,
+        };
+
+        self.entries.reserve(items.len());
+        for item in items {
+            let item_source = match source {
+                ItemSource::Character(character_id) if item.bucket_hash == LOST_ITEMS_BUCKET_HASH => {
+                    ItemSource::Postmaster(character_id)
+                }
+                ItemSource::ProfileWide if item.bucket_hash == VAULT_BUCKET_HASH => ItemSource::Vault,
+                _ => source,
+            };
+            self.entries.push((item_source, item));
+        }
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = (&ItemSource, &crate::destiny::entities::items::DestinyItemComponent)> {
+        self.entries.iter().map(|(source, item)| (source, *item))
+    }
+
+    pub fn in_bucket(
+        &self,
+        bucket_hash: u32,
+    ) -> impl Iterator<Item = (&ItemSource, &crate::destiny::entities::items::DestinyItemComponent)> {
+        self.all().filter(move |(_, item)| item.bucket_hash == bucket_hash)
+    }
+
+    pub fn with_item_hash(
+        &self,
+        item_hash: u32,
+    ) -> impl Iterator<Item = (&ItemSource, &crate::destiny::entities::items::DestinyItemComponent)> {
+        self.all().filter(move |(_, item)| item.item_hash == item_hash)
+    }
+
+    pub fn from_source(
+        &self,
+        source: ItemSource,
+    ) -> impl Iterator<Item = (&ItemSource, &crate::destiny::entities::items::DestinyItemComponent)> {
+        self.all().filter(move |(item_source, _)| **item_source == source)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'a> Extend<(ItemSource, &'a DestinyInventoryComponent)> for MergedInventory<'a> {
+    fn extend<I: IntoIterator<Item = (ItemSource, &'a DestinyInventoryComponent)>>(&mut self, iter: I) {
+        for (source, component) in iter {
+            self.add(source, component);
+        }
+    }
+}
+
+impl<'a> FromIterator<(ItemSource, &'a DestinyInventoryComponent)> for MergedInventory<'a> {
+    fn from_iter<I: IntoIterator<Item = (ItemSource, &'a DestinyInventoryComponent)>>(iter: I) -> Self {
+        let mut merged = MergedInventory::new();
+        merged.extend(iter);
+        merged
+    }
+}
+
+/// The highest power a character could equip, as computed by [`max_power`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxPowerResult {
+    /// The chosen item for each slot in [`POWER_SLOT_BUCKET_HASHES`], as `(item_hash, power)`. Slots nothing could fill are missing.
+    pub per_slot: HashMap<u32, (u32, i32)>,
+    /// The chosen items' total power divided by all eight slots, so an empty slot counts as 0 power.
+    pub average: f32,
+    /// Whether the one-exotic-weapon and one-exotic-armor rule kept out an item that would otherwise have been chosen.
+    pub limited_by_exotics: bool,
+}
+
+#[derive(Copy, Clone)]
+struct PowerCandidate {
+    item_hash: u32,
+    power: i32,
+}
+
+impl PowerCandidate {
+    /// Higher power wins, then the lower item hash, so the result doesn't depend on the order items were listed in.
+    fn beats(&self, other: &Option<PowerCandidate>) -> bool {
+        other.map_or(true, |other| (self.power, std::cmp::Reverse(self.item_hash)) > (other.power, std::cmp::Reverse(other.item_hash)))
+    }
+}
+
+/// The best items that can be equipped together in each power slot, from items anywhere on the account: equipped,
+/// in inventories, in the Vault, or at the Postmaster.
+///
+/// `items` pairs each item's hash with its instance; the slot is the definition's default bucket and the power is the
+/// instance's primary stat. Items without a definition in `defs`, a power slot, or a primary stat are ignored.
+///
+/// Only one exotic weapon and one exotic armor piece can be equipped at a time. Every placement of each group's exotic
+/// is tried (including none), and the one with the most filled slots and then the highest total power is kept, so a
+/// lower exotic is picked over a higher one when its slot's best legendary is worse. Ties go to no exotic, then to the
+/// exotic in the earliest slot.
+pub fn max_power(
+    items: &[(u32, &crate::destiny::entities::items::DestinyItemInstanceComponent)],
+    defs: &HashMap<u32, crate::destiny::definitions::DestinyInventoryItemDefinition>,
+) -> MaxPowerResult {
+    // The best non-exotic and best exotic item for each slot.
+    let mut best = [(None, None); POWER_SLOT_BUCKET_HASHES.len()];
+    for (item_hash, instance) in items {
+        let inventory = match defs.get(item_hash).and_then(|def| def.inventory.as_ref()) {
+            None => continue,
+            Some(inventory) => inventory,
+        };
+        let (slot, power) = match (
+            POWER_SLOT_BUCKET_HASHES.iter().position(|bucket_hash| *bucket_hash == inventory.bucket_type_hash),
+            &instance.primary_stat,
+        ) {
+            (Some(slot), Some(stat)) => (slot, stat.value),
+            _ => continue,
+        };
+
+        let candidate = PowerCandidate { item_hash: *item_hash, power };
+        let (other, exotic) = &mut best[slot];
+        let current = if inventory.tier_type == crate::destiny::TierType::Exotic { exotic } else { other };
+        if candidate.beats(current) {
+            *current = Some(candidate);
+        }
+    }
+
+    let (weapons, armor) = best.split_at(3);
+    let mut chosen = best_placement(weapons);
+    chosen.extend(best_placement(armor));
+    let unconstrained: Vec<Option<PowerCandidate>> = best
+        .iter()
+        .map(|(other, exotic)| match exotic {
+            Some(exotic) if exotic.beats(other) => Some(*exotic),
+            _ => *other,
+        })
+        .collect();
+
+    let per_slot: HashMap<u32, (u32, i32)> = POWER_SLOT_BUCKET_HASHES
+        .iter()
+        .zip(&chosen)
+        .filter_map(|(bucket_hash, candidate)| candidate.map(|c| (*bucket_hash, (c.item_hash, c.power))))
+        .collect();
+    let (filled, total) = placement_score(&chosen);
+    MaxPowerResult {
+        per_slot,
+        average: total as f32 / POWER_SLOT_BUCKET_HASHES.len() as f32,
+        limited_by_exotics: placement_score(&unconstrained) > (filled, total),
+    }
+}
+
+/// The best items for one group of slots with at most one exotic among them.
+fn best_placement(slots: &[(Option<PowerCandidate>, Option<PowerCandidate>)]) -> Vec<Option<PowerCandidate>> {
+    let mut best: Vec<Option<PowerCandidate>> = slots.iter().map(|(other, _)| *other).collect();
+    for (exotic_slot, (_, exotic)) in slots.iter().enumerate() {
+        if exotic.is_none() {
+            continue;
+        }
+        let placement: Vec<Option<PowerCandidate>> = slots
+            .iter()
+            .enumerate()
+            .map(|(slot, (other, exotic))| if slot == exotic_slot { *exotic } else { *other })
+            .collect();
+        if placement_score(&placement) > placement_score(&best) {
+            best = placement;
+        }
+    }
+    best
+}
+
+/// Filled slots, then total power.
+fn placement_score(placement: &[Option<PowerCandidate>]) -> (usize, i64) {
+    placement.iter().flatten().fold((0, 0), |(filled, total), candidate| (filled + 1, total + i64::from(candidate.power)))
+}
@@ -81,6 +81,48 @@ pub struct DestinyItemComponent {
     pub item_value_visibility: Option<Vec<bool>>,
 }
 
+/// The definition's tooltip notifications that `item.tooltipNotificationIndexes` turns on, in index order.
+/// Indexes outside the definition's list are skipped rather than trusted.
+pub fn active_tooltips<'a>(
+    item: &DestinyItemComponent,
+    def: &'a crate::destiny::definitions::DestinyInventoryItemDefinition,
+) -> Vec<&'a crate::destiny::definitions::DestinyItemTooltipNotification> {
+    let tooltips = def.tooltip_notifications.as_deref().unwrap_or_default();
+    item.tooltip_notification_indexes
+        .iter()
+        .flatten()
+        .filter_map(|&index| usize::try_from(index).ok())
+        .filter_map(|index| tooltips.get(index))
+        .collect()
+}
+
+/// The definition's item values (usually rewards) that `item.itemValueVisibility` doesn't hide.
+/// Without a visibility list every value is shown, as are values past its end; visibility flags past the end of the value list are ignored.
+pub fn visible_values<'a>(
+    item: &DestinyItemComponent,
+    def: &'a crate::destiny::definitions::DestinyInventoryItemDefinition,
+) -> Vec<&'a crate::destiny::DestinyItemQuantity> {
+    let values = def.value.as_ref().and_then(|v| v.item_value.as_deref()).unwrap_or_default();
+    let visibility = item.item_value_visibility.as_deref().unwrap_or_default();
+    values
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| visibility.get(*index).copied().unwrap_or(true))
+        .map(|(_, value)| value)
+        .collect()
+}
+
+/// Sets or clears `item`'s Locked flag, as a successful SetItemLockState call does, so a cached item can be updated without refetching it.
+/// The other state flags are left alone.
+pub fn apply_lock_state(item: &mut DestinyItemComponent, locked: bool) {
+    item.state.set(crate::destiny::ItemState::Locked, locked);
+}
+
+/// Sets or clears `item`'s Tracked flag, as a successful SetQuestTrackedState call does. The other state flags are left alone.
+pub fn apply_tracked_state(item: &mut DestinyItemComponent, tracked: bool) {
+    item.state.set(crate::destiny::ItemState::Tracked, tracked);
+}
+
 /// Instanced items can have perks: benefits that the item bestows.
 /// These are related to DestinySandboxPerkDefinition, and sometimes - but not always - have human readable info. When they do, they are the icons and text that you see in an item's tooltip.
 /// Talent Grids, Sockets, and the item itself can apply Perks, which are then summarized here for your convenience.
@@ -172,11 +214,12 @@ pub struct DestinyItemInstanceComponent {
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemInstanceEnergy {
     /// The type of energy for this item. Plugs that require Energy can only be inserted if they have the "Any" Energy Type or the matching energy type of this item. This is a reference to the DestinyEnergyTypeDefinition for the energy type, where you can find extended info about it.
-    #[serde(rename = "energyTypeHash")]
+    /// Since armor energy stopped having elements, the type is vestigial; 0 if Bungie leaves it out.
+    #[serde(rename = "energyTypeHash", default)]
     pub energy_type_hash: u32,
 
-    /// This is the enum version of the Energy Type value, for convenience.
-    #[serde(rename = "energyType")]
+    /// This is the enum version of the Energy Type value, for convenience. Any if Bungie leaves it out.
+    #[serde(rename = "energyType", default)]
     pub energy_type: crate::destiny::DestinyEnergyType,
 
     /// The total capacity of Energy that the item currently has, regardless of if it is currently being used.
@@ -192,6 +235,30 @@ pub struct DestinyItemInstanceEnergy {
     pub energy_unused: i32,
 }
 
+/// How much of an item's energy is in use, from [`energy_summary`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EnergySummary {
+    pub capacity: i32,
+    pub used: i32,
+    pub unused: i32,
+}
+
+impl EnergySummary {
+    /// Whether a mod costing `mod_energy_cost` fits in the unused energy. Armor energy no longer has an element, so only the cost matters.
+    pub fn can_fit_mod(&self, mod_energy_cost: i32) -> bool {
+        mod_energy_cost <= self.unused
+    }
+}
+
+/// The item's energy, or `None` for items without any, such as weapons.
+pub fn energy_summary(instance: &DestinyItemInstanceComponent) -> Option<EnergySummary> {
+    instance.energy.as_ref().map(|energy| EnergySummary {
+        capacity: energy.energy_capacity,
+        used: energy.energy_used,
+        unused: energy.energy_unused,
+    })
+}
+
 /// Many items can be rendered in 3D. When you request this block, you will obtain the custom data needed to render this specific instance of the item.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemRenderComponent {
@@ -216,6 +283,103 @@ pub struct DestinyItemStatsComponent {
     pub stats: Option<HashMap<u32, crate::destiny::DestinyStat>>,
 }
 
+impl DestinyItemStatsComponent {
+    /// The stats an item's definition gives it, for comparing items that weren't fetched with the ItemStats component or aren't instanced.
+    pub fn from_definition(stats: &crate::destiny::definitions::DestinyItemStatBlockDefinition) -> DestinyItemStatsComponent {
+        DestinyItemStatsComponent {
+            stats: stats.stats.as_ref().map(|stats| {
+                stats
+                    .iter()
+                    .map(|(hash, stat)| (*hash, crate::destiny::DestinyStat { stat_hash: stat.stat_hash, value: stat.value }))
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// One stat of two items being compared, from [`compare_items`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatDelta<'a> {
+    pub stat_hash: u32,
+    pub name: Option<&'a str>,
+    /// The stat on the first item, or 0 if it doesn't have it.
+    pub a_value: i32,
+    /// The stat on the second item, or 0 if it doesn't have it.
+    pub b_value: i32,
+    /// `a_value - b_value`, so positive when the first item has more.
+    pub delta: i32,
+    /// The value a full stat bar stands for, from the stat group. `None` without a stat group, or for stats it doesn't display.
+    pub display_maximum: Option<i32>,
+}
+
+/// Compares two items' stats, aligned by stat hash and ordered by the stats' definition index.
+/// Stats without a name or that are redacted are skipped, and a stat only one item has is compared against 0.
+pub fn compare_items<'a>(
+    a: &DestinyItemStatsComponent,
+    b: &DestinyItemStatsComponent,
+    stat_defs: &'a HashMap<u32, crate::destiny::definitions::DestinyStatDefinition>,
+) -> Vec<StatDelta<'a>> {
+    compare_items_with(a, b, stat_defs, None, false)
+}
+
+/// Like [`compare_items`], but ordered the way `stat_group` lays the stats out, with each stat's bar maximum filled in.
+/// Stats the group doesn't display count as hidden, and come after the displayed ones when `include_hidden` is set.
+pub fn compare_items_with<'a>(
+    a: &DestinyItemStatsComponent,
+    b: &DestinyItemStatsComponent,
+    stat_defs: &'a HashMap<u32, crate::destiny::definitions::DestinyStatDefinition>,
+    stat_group: Option<&crate::destiny::definitions::DestinyStatGroupDefinition>,
+    include_hidden: bool,
+) -> Vec<StatDelta<'a>> {
+    let value = |item: &DestinyItemStatsComponent, hash: u32| {
+        item.stats.as_ref().and_then(|stats| stats.get(&hash)).map_or(0, |stat| stat.value)
+    };
+    let scaled_stats = stat_group.and_then(|group| group.scaled_stats.as_deref()).unwrap_or_default();
+    let group_position = |hash: u32| scaled_stats.iter().position(|stat| stat.stat_hash == hash);
+
+    let mut hashes: Vec<u32> = a
+        .stats
+        .iter()
+        .chain(b.stats.iter())
+        .flat_map(|stats| stats.keys().copied())
+        .collect();
+    hashes.sort_unstable();
+    hashes.dedup();
+
+    let mut deltas: Vec<(Option<usize>, i32, StatDelta<'a>)> = hashes
+        .into_iter()
+        .filter_map(|hash| {
+            let definition = stat_defs.get(&hash);
+            let name = definition
+                .and_then(|d| d.display_properties.as_ref())
+                .and_then(|d| d.name.as_deref())
+                .filter(|name| !name.is_empty());
+            let position = group_position(hash);
+            let hidden = name.is_none()
+                || definition.map_or(false, |d| d.redacted)
+                || (stat_group.is_some() && position.is_none());
+            if hidden && !include_hidden {
+                return None;
+            }
+
+            let (a_value, b_value) = (value(a, hash), value(b, hash));
+            let delta = StatDelta {
+                stat_hash: hash,
+                name,
+                a_value,
+                b_value,
+                delta: a_value - b_value,
+                display_maximum: position.map(|position| scaled_stats[position].maximum_value),
+            };
+            Some((position, definition.map_or(i32::MAX, |d| d.index), delta))
+        })
+        .collect();
+
+    // Displayed stats first, in the group's order, then the rest by definition index.
+    deltas.sort_by_key(|(position, index, delta)| (position.is_none(), *position, *index, delta.stat_hash));
+    deltas.into_iter().map(|(_, _, delta)| delta).collect()
+}
+
 /// Instanced items can have sockets, which are slots on the item where plugs can be inserted.
 /// Sockets are a bit complex: be sure to examine the documentation on the DestinyInventoryItemDefinition's "socket" block and elsewhere on these objects for more details.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -465,3 +465,153 @@ impl FromStr for PeriodType {
         }
     }
 }
+
+macro_rules! historical_stat_ids {
+    ($($variant:ident => $id:literal,)*) => {
+        /// The string IDs `GetHistoricalStatsDefinition` assigns to historical stats, as accepted by the stats and leaderboard endpoints' `statid` parameters.
+        /// IDs not listed here still round-trip through `Other`.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum HistoricalStatId {
+            $($variant,)*
+            Other(String),
+        }
+
+        impl HistoricalStatId {
+            /// Every known stat ID, in definition order.
+            pub const KNOWN: &'static [HistoricalStatId] = &[$(HistoricalStatId::$variant,)*];
+
+            /// The ID as sent to and returned by the Bungie API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(HistoricalStatId::$variant => $id,)*
+                    HistoricalStatId::Other(id) => id,
+                }
+            }
+        }
+
+        impl From<&str> for HistoricalStatId {
+            fn from(s: &str) -> Self {
+                match s {
+                    $($id => HistoricalStatId::$variant,)*
+                    _ => HistoricalStatId::Other(s.to_string()),
+                }
+            }
+        }
+    };
+}
+
+historical_stat_ids! {
+    ActivitiesCleared => "activitiesCleared",
+    ActivitiesEntered => "activitiesEntered",
+    ActivitiesWon => "activitiesWon",
+    Assists => "assists",
+    TotalDeathDistance => "totalDeathDistance",
+    AverageDeathDistance => "averageDeathDistance",
+    TotalKillDistance => "totalKillDistance",
+    Kills => "kills",
+    AverageKillDistance => "averageKillDistance",
+    SecondsPlayed => "secondsPlayed",
+    Deaths => "deaths",
+    AverageLifespan => "averageLifespan",
+    Score => "score",
+    AverageScorePerKill => "averageScorePerKill",
+    AverageScorePerLife => "averageScorePerLife",
+    BestSingleGameKills => "bestSingleGameKills",
+    BestSingleGameScore => "bestSingleGameScore",
+    OpponentsDefeated => "opponentsDefeated",
+    Efficiency => "efficiency",
+    KillsDeathsRatio => "killsDeathsRatio",
+    KillsDeathsAssists => "killsDeathsAssists",
+    ObjectivesCompleted => "objectivesCompleted",
+    PrecisionKills => "precisionKills",
+    ResurrectionsPerformed => "resurrectionsPerformed",
+    ResurrectionsReceived => "resurrectionsReceived",
+    Suicides => "suicides",
+    WeaponKillsAutoRifle => "weaponKillsAutoRifle",
+    WeaponKillsBeamRifle => "weaponKillsBeamRifle",
+    WeaponKillsBow => "weaponKillsBow",
+    WeaponKillsGlaive => "weaponKillsGlaive",
+    WeaponKillsFusionRifle => "weaponKillsFusionRifle",
+    WeaponKillsHandCannon => "weaponKillsHandCannon",
+    WeaponKillsTraceRifle => "weaponKillsTraceRifle",
+    WeaponKillsMachineGun => "weaponKillsMachineGun",
+    WeaponKillsPulseRifle => "weaponKillsPulseRifle",
+    WeaponKillsRocketLauncher => "weaponKillsRocketLauncher",
+    WeaponKillsScoutRifle => "weaponKillsScoutRifle",
+    WeaponKillsShotgun => "weaponKillsShotgun",
+    WeaponKillsSniper => "weaponKillsSniper",
+    WeaponKillsSubmachinegun => "weaponKillsSubmachinegun",
+    WeaponKillsRelic => "weaponKillsRelic",
+    WeaponKillsSideArm => "weaponKillsSideArm",
+    WeaponKillsSword => "weaponKillsSword",
+    WeaponKillsAbility => "weaponKillsAbility",
+    WeaponKillsGrenade => "weaponKillsGrenade",
+    WeaponKillsGrenadeLauncher => "weaponKillsGrenadeLauncher",
+    WeaponKillsSuper => "weaponKillsSuper",
+    WeaponKillsMelee => "weaponKillsMelee",
+    WeaponBestType => "weaponBestType",
+    WinLossRatio => "winLossRatio",
+    AllParticipantsCount => "allParticipantsCount",
+    AllParticipantsScore => "allParticipantsScore",
+    AllParticipantsTimePlayed => "allParticipantsTimePlayed",
+    LongestKillSpree => "longestKillSpree",
+    LongestSingleLife => "longestSingleLife",
+    MostPrecisionKills => "mostPrecisionKills",
+    OrbsDropped => "orbsDropped",
+    OrbsGathered => "orbsGathered",
+    PublicEventsCompleted => "publicEventsCompleted",
+    RemainingTimeAfterQuitSeconds => "remainingTimeAfterQuitSeconds",
+    TeamScore => "teamScore",
+    TotalActivityDurationSeconds => "totalActivityDurationSeconds",
+    FastestCompletionMs => "fastestCompletionMs",
+    LongestKillDistance => "longestKillDistance",
+    HighestCharacterLevel => "highestCharacterLevel",
+    HighestLightLevel => "highestLightLevel",
+    FireteamActivities => "fireteamActivities",
+    ActivityDurationSeconds => "activityDurationSeconds",
+    Completed => "completed",
+    CompletionReason => "completionReason",
+    FireteamId => "fireteamId",
+    StartSeconds => "startSeconds",
+    TimePlayedSeconds => "timePlayedSeconds",
+    PlayerCount => "playerCount",
+    Standing => "standing",
+    Team => "team",
+    CombatRating => "combatRating",
+    ZonesCaptured => "zonesCaptured",
+    ZonesNeutralized => "zonesNeutralized",
+    LbSingleGameKills => "lbSingleGameKills",
+    LbPrecisionKills => "lbPrecisionKills",
+    LbAssists => "lbAssists",
+    LbDeaths => "lbDeaths",
+    LbKills => "lbKills",
+    LbObjectivesCompleted => "lbObjectivesCompleted",
+    LbSingleGameScore => "lbSingleGameScore",
+    LbMostPrecisionKills => "lbMostPrecisionKills",
+    LbLongestKillSpree => "lbLongestKillSpree",
+    LbLongestKillDistance => "lbLongestKillDistance",
+    LbFastestCompletionMs => "lbFastestCompletionMs",
+    LbLongestSingleLife => "lbLongestSingleLife",
+}
+
+impl From<String> for HistoricalStatId {
+    fn from(s: String) -> Self {
+        match HistoricalStatId::from(s.as_str()) {
+            HistoricalStatId::Other(_) => HistoricalStatId::Other(s),
+            known => known,
+        }
+    }
+}
+
+impl Display for HistoricalStatId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for HistoricalStatId {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(HistoricalStatId::from(s))
+    }
+}
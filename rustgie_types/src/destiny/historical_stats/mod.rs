@@ -1,5 +1,6 @@
 ﻿pub mod definitions;
 
+use crate::ids::id_newtype;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use std::collections::HashMap;
@@ -47,9 +48,8 @@ pub struct DestinyHistoricalStatsActivity {
 
     /// The unique identifier for this *specific* match that was played.
     /// This value can be used to get additional data about this activity such as who else was playing via the GetPostGameCarnageReport endpoint.
-    #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "instanceId")]
-    pub instance_id: i64,
+    pub instance_id: crate::destiny::historical_stats::ActivityInstanceId,
 
     /// Indicates the most specific game mode of the activity that we could find.
     #[serde(rename = "mode")]
@@ -386,3 +386,34 @@ pub struct DestinyAggregateActivityStats {
     #[serde(rename = "values")]
     pub values: Option<HashMap<String, crate::destiny::historical_stats::DestinyHistoricalStatsValue>>,
 }
+
+impl DestinyPlayer {
+    /// The name, avatar, and membership to display for this player. `None` only when Bungie returned neither user card.
+    pub fn display_identity(&self) -> Option<crate::user::DisplayIdentity> {
+        crate::user::DisplayIdentity::resolve(
+            self.destiny_user_info.as_ref().map(crate::user::IdentityCard::from),
+            self.bungie_net_user_info.as_ref(),
+        )
+    }
+}
+
+impl DestinyPostGameCarnageReportEntry {
+    /// The name, avatar, and membership to display for this entry's player.
+    pub fn display_identity(&self) -> Option<crate::user::DisplayIdentity> {
+        self.player.as_ref()?.display_identity()
+    }
+}
+
+/// The ID of one specific played activity (a PGCR instance), as returned in `activityDetails.instanceId` and accepted by the PostGameCarnageReport endpoints.
+/// Bungie sends these as strings; numbers are accepted too. Zero and negative IDs never name a real activity and are rejected when parsing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ActivityInstanceId(i64);
+
+id_newtype!(ActivityInstanceId("an activity instance ID", "Activity instance IDs"));
+
+impl ActivityInstanceId {
+    /// The report's page on bungie.net.
+    pub fn pgcr_url(&self) -> url::Url {
+        url::Url::parse(&format!("https://www.bungie.net/en/PGCR/{}", self.0)).expect("a numeric path is always a valid URL")
+    }
+}
@@ -0,0 +1,51 @@
+﻿//! Item helpers that combine several components, for questions like "which perks can this roll have?".
+
+pub mod wishlist;
+
+/// One socket of an instanced item with every plug it can hold, from [`resolve_sockets`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedSocket {
+    /// The socket's index in `DestinyItemSocketsComponent.sockets`, which lines up with the definition's `sockets.socketEntries`.
+    pub socket_index: usize,
+    /// The plug currently inserted, if any.
+    pub plug_hash: Option<u32>,
+    /// The plugs the socket can be switched to, such as a weapon roll's other perk options. Includes the inserted plug when the
+    /// ItemReusablePlugs component lists it.
+    pub reusable_plug_hashes: Vec<u32>,
+}
+
+impl ResolvedSocket {
+    /// The inserted plug and the reusable ones, without duplicates.
+    pub fn plug_hashes(&self) -> Vec<u32> {
+        let mut hashes: Vec<u32> = self.plug_hash.into_iter().collect();
+        for hash in &self.reusable_plug_hashes {
+            if !hashes.contains(hash) {
+                hashes.push(*hash);
+            }
+        }
+        hashes
+    }
+}
+
+/// Every socket of an instanced item (needs the ItemSockets component) with its reusable plugs, if the ItemReusablePlugs
+/// component was fetched too. Without it, each socket only has its inserted plug.
+pub fn resolve_sockets(
+    sockets: &crate::destiny::entities::items::DestinyItemSocketsComponent,
+    reusable_plugs: Option<&crate::destiny::components::items::DestinyItemReusablePlugsComponent>,
+) -> Vec<ResolvedSocket> {
+    sockets
+        .sockets
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(socket_index, state)| ResolvedSocket {
+            socket_index,
+            plug_hash: state.plug_hash.filter(|hash| *hash != 0),
+            reusable_plug_hashes: reusable_plugs
+                .and_then(|component| component.plugs.as_ref())
+                .and_then(|plugs| plugs.get(&(socket_index as i32)))
+                .map(|plugs| plugs.iter().map(|plug| plug.plug_item_hash).collect())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
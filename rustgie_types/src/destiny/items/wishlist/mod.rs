@@ -0,0 +1,129 @@
+﻿//! Wish lists in the community text format DIM popularized, and matching them against an item's rolls.
+//!
+//! Each meaningful line looks like `dimwishlist:item=1234&perks=111,222,333#notes:Some text`. An item hash of `-69420` applies
+//! the line to every item, and any other negative item hash marks an unwanted ("trash") roll of that item. Comments, titles,
+//! descriptions, blank lines and anything else that doesn't parse are skipped.
+
+use std::collections::HashMap;
+
+/// The item hash that applies a wish list line to every item.
+pub const ANY_ITEM: i64 = -69420;
+
+/// Every wish list line for one item, from [`parse_wishlist`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WishlistEntry {
+    /// The item the lines are for, or `None` for lines that apply to every item.
+    pub item_hash: Option<u32>,
+    /// One set per line; a roll matches a line when it can have every perk in the set. An empty set matches any roll.
+    pub desired_perk_sets: Vec<Vec<u32>>,
+    /// True for rolls the list author considers unwanted, written with a negative item hash.
+    pub trash: bool,
+    /// The `#notes:` of the entry's lines, without duplicates.
+    pub notes: Vec<String>,
+}
+
+/// Parses a wish list file. Lines for the same item (and the same trash flag) are merged into one entry, in the order the item
+/// first appears.
+pub fn parse_wishlist(text: &str) -> Vec<WishlistEntry> {
+    let mut entries: Vec<WishlistEntry> = Vec::new();
+    for line in text.lines().filter_map(parse_line) {
+        let entry = match entries.iter().position(|e| e.item_hash == line.item_hash && e.trash == line.trash) {
+            Some(index) => &mut entries[index],
+            None => {
+                entries.push(WishlistEntry { item_hash: line.item_hash, desired_perk_sets: Vec::new(), trash: line.trash, notes: Vec::new() });
+                entries.last_mut().expect("just pushed")
+            }
+        };
+        for perks in line.desired_perk_sets {
+            if !entry.desired_perk_sets.contains(&perks) {
+                entry.desired_perk_sets.push(perks);
+            }
+        }
+        for notes in line.notes {
+            if !entry.notes.contains(&notes) {
+                entry.notes.push(notes);
+            }
+        }
+    }
+    entries
+}
+
+/// One line as an entry of its own, or `None` if it isn't a wish list line.
+fn parse_line(line: &str) -> Option<WishlistEntry> {
+    let line = line.trim().strip_prefix("dimwishlist:")?;
+    let (line, notes) = match line.split_once("#notes:") {
+        Some((line, notes)) => (line, Some(notes.trim().to_string()).filter(|notes| !notes.is_empty())),
+        None => (line, None),
+    };
+
+    let mut item = None;
+    let mut perks = Vec::new();
+    for pair in line.split('&') {
+        match pair.split_once('=') {
+            Some(("item", value)) => item = Some(value.trim().parse::<i64>().ok()?),
+            Some(("perks", value)) => {
+                for perk in value.split(',').map(str::trim).filter(|perk| !perk.is_empty()) {
+                    perks.push(perk.parse::<u32>().ok()?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (item_hash, trash) = match item? {
+        ANY_ITEM => (None, false),
+        hash if hash < 0 => (Some(u32::try_from(hash.checked_neg()?).ok()?), true),
+        hash => (Some(u32::try_from(hash).ok()?), false),
+    };
+    Some(WishlistEntry { item_hash, desired_perk_sets: vec![perks], trash, notes: notes.into_iter().collect() })
+}
+
+/// The entries that apply to `item_hash`, including the ones for every item.
+pub fn entries_for(entries: &[WishlistEntry], item_hash: u32) -> impl Iterator<Item = &WishlistEntry> {
+    entries.iter().filter(move |entry| entry.item_hash.map_or(true, |hash| hash == item_hash))
+}
+
+/// How a roll compares to a wish list entry, from [`matches`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchResult {
+    /// Whether the roll can have every perk of at least one of the entry's perk sets.
+    pub matched: bool,
+    /// The perks of the best matching set that the roll can have, as the wish list writes them.
+    pub matched_perks: Vec<u32>,
+    /// The perks of the best matching set that the roll can't have. Empty when `matched`.
+    pub missing_perks: Vec<u32>,
+}
+
+/// Checks a roll against every perk set of `entry`, reporting the set it matches best: a fully matched one if there is one,
+/// otherwise the one with the most perks present, earliest first.
+///
+/// A perk counts as present if any socket has it inserted or can switch to it. `enhanced_to_base` maps enhanced perk hashes to
+/// their base perks, so an enhanced perk on the roll satisfies a wish list asking for the base perk and the other way around.
+/// It's usually built from the item definitions, since enhanced perks share their base perk's name; pass an empty map to
+/// compare hashes exactly.
+pub fn matches(
+    entry: &WishlistEntry,
+    resolved_sockets: &[crate::destiny::items::ResolvedSocket],
+    enhanced_to_base: &HashMap<u32, u32>,
+) -> MatchResult {
+    let base = |hash: u32| enhanced_to_base.get(&hash).copied().unwrap_or(hash);
+    let available: Vec<u32> = resolved_sockets
+        .iter()
+        .flat_map(|socket| socket.plug_hashes())
+        .map(base)
+        .collect();
+
+    let mut best: Option<MatchResult> = None;
+    for perks in &entry.desired_perk_sets {
+        let (matched_perks, missing_perks): (Vec<u32>, Vec<u32>) =
+            perks.iter().partition(|perk| available.contains(&base(**perk)));
+        let result = MatchResult { matched: missing_perks.is_empty(), matched_perks, missing_perks };
+        if result.matched {
+            return result;
+        }
+        if best.as_ref().map_or(true, |best| result.matched_perks.len() > best.matched_perks.len()) {
+            best = Some(result);
+        }
+    }
+    best.unwrap_or_default()
+}
@@ -9,6 +9,7 @@ pub mod constants;
 pub mod definitions;
 pub mod entities;
 pub mod historical_stats;
+pub mod items;
 pub mod milestones;
 pub mod misc;
 pub mod perks;
@@ -19,6 +20,7 @@ pub mod requests;
 pub mod responses;
 pub mod sockets;
 pub mod vendors;
+pub mod well_known;
 
 use anyhow::{anyhow, Result};
 use enumflags2::bitflags;
@@ -292,6 +294,12 @@ pub enum DestinySocketCategoryStyle {
     Supers = 8,
 }
 
+impl Default for DestinySocketCategoryStyle {
+    fn default() -> Self {
+        DestinySocketCategoryStyle::Unknown
+    }
+}
+
 impl Display for DestinySocketCategoryStyle {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
@@ -572,10 +580,10 @@ impl FromStr for DestinyClass {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
         match s {
-            "Titan" => Ok(DestinyClass::Titan),
-            "Hunter" => Ok(DestinyClass::Hunter),
-            "Warlock" => Ok(DestinyClass::Warlock),
-            "Unknown" => Ok(DestinyClass::Unknown),
+            "Titan" | "0" => Ok(DestinyClass::Titan),
+            "Hunter" | "1" => Ok(DestinyClass::Hunter),
+            "Warlock" | "2" => Ok(DestinyClass::Warlock),
+            "Unknown" | "3" => Ok(DestinyClass::Unknown),
             _ => Err(anyhow!("Could not deserialize string '{}' to DestinyClass", s)),
         }
     }
@@ -599,9 +607,9 @@ impl FromStr for DestinyGender {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
         match s {
-            "Male" => Ok(DestinyGender::Male),
-            "Female" => Ok(DestinyGender::Female),
-            "Unknown" => Ok(DestinyGender::Unknown),
+            "Male" | "0" => Ok(DestinyGender::Male),
+            "Female" | "1" => Ok(DestinyGender::Female),
+            "Unknown" | "2" => Ok(DestinyGender::Unknown),
             _ => Err(anyhow!("Could not deserialize string '{}' to DestinyGender", s)),
         }
     }
@@ -1190,6 +1198,12 @@ pub enum DestinyItemSubType {
     Glaive = 33,
 }
 
+impl Default for DestinyItemSubType {
+    fn default() -> Self {
+        DestinyItemSubType::None
+    }
+}
+
 impl Display for DestinyItemSubType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
@@ -1488,6 +1502,7 @@ impl FromStr for PlugUiStyles {
 /// - Normal means that all existing rules for plug insertion apply.
 /// - UnavailableIfSocketContainsMatchingPlugCategory means that the plug is only available if the socket does NOT match the plug category.
 /// - AvailableIfSocketContainsMatchingPlugCategory means that the plug is only available if the socket DOES match the plug category.
+///
 /// For category matching, use the plug's "plugCategoryIdentifier" property, comparing it to
 #[repr(i32)]
 #[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -1528,6 +1543,12 @@ pub enum DestinyEnergyType {
     Stasis = 6,
 }
 
+impl Default for DestinyEnergyType {
+    fn default() -> Self {
+        DestinyEnergyType::Any
+    }
+}
+
 impl Display for DestinyEnergyType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
@@ -1692,6 +1713,12 @@ pub enum DestinyItemType {
     Pattern = 30,
 }
 
+impl Default for DestinyItemType {
+    fn default() -> Self {
+        DestinyItemType::None
+    }
+}
+
 impl Display for DestinyItemType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
@@ -2307,10 +2334,10 @@ impl FromStr for DestinyRace {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
         match s {
-            "Human" => Ok(DestinyRace::Human),
-            "Awoken" => Ok(DestinyRace::Awoken),
-            "Exo" => Ok(DestinyRace::Exo),
-            "Unknown" => Ok(DestinyRace::Unknown),
+            "Human" | "0" => Ok(DestinyRace::Human),
+            "Awoken" | "1" => Ok(DestinyRace::Awoken),
+            "Exo" | "2" => Ok(DestinyRace::Exo),
+            "Unknown" | "3" => Ok(DestinyRace::Unknown),
             _ => Err(anyhow!("Could not deserialize string '{}' to DestinyRace", s)),
         }
     }
@@ -2766,3 +2793,76 @@ pub struct DestinyEquipItemResult {
     #[serde(rename = "equipStatus")]
     pub equip_status: crate::exceptions::PlatformErrorCodes,
 }
+
+impl DestinyUnlockValueUIStyle {
+    /// Renders `value` the way this style asks for. `completion_value` is the upper bound used by the fractional and percentage styles.
+    ///
+    /// Counts get thousands separators, durations are `H:MM:SS` (or `M:SS` under an hour), dates are UTC, and `Hidden` renders as an empty string.
+    pub fn format_value(self, value: i32, completion_value: i32) -> String {
+        match self {
+            DestinyUnlockValueUIStyle::Automatic
+            | DestinyUnlockValueUIStyle::Integer
+            | DestinyUnlockValueUIStyle::GreenPips
+            | DestinyUnlockValueUIStyle::RedPips
+            | DestinyUnlockValueUIStyle::LevelAndReward => group_thousands(i64::from(value)),
+            DestinyUnlockValueUIStyle::Fraction => {
+                format!("{}/{}", group_thousands(i64::from(value)), group_thousands(i64::from(completion_value)))
+            }
+            DestinyUnlockValueUIStyle::Checkbox => {
+                if value != 0 { "Complete" } else { "Incomplete" }.to_string()
+            }
+            DestinyUnlockValueUIStyle::Percentage | DestinyUnlockValueUIStyle::FractionFloat => {
+                if completion_value <= 0 {
+                    return "0%".to_string();
+                }
+                format!("{}%", i64::from(value) * 100 / i64::from(completion_value))
+            }
+            DestinyUnlockValueUIStyle::ExplicitPercentage => format!("{}%", value),
+            DestinyUnlockValueUIStyle::DateTime => match time::OffsetDateTime::from_unix_timestamp(i64::from(value)) {
+                Ok(date) => format!(
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+                    date.year(),
+                    u8::from(date.month()),
+                    date.day(),
+                    date.hour(),
+                    date.minute(),
+                    date.second()
+                ),
+                Err(_) => value.to_string(),
+            },
+            DestinyUnlockValueUIStyle::TimeDuration => {
+                let sign = if value < 0 { "-" } else { "" };
+                let seconds = i64::from(value).abs();
+                let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+                if hours > 0 {
+                    format!("{}{}:{:02}:{:02}", sign, hours, minutes, seconds)
+                } else {
+                    format!("{}{}:{:02}", sign, minutes, seconds)
+                }
+            }
+            DestinyUnlockValueUIStyle::Hidden => String::new(),
+            DestinyUnlockValueUIStyle::Multiplier => format!("{}x", value),
+            DestinyUnlockValueUIStyle::RawFloat => {
+                let sign = if value < 0 { "-" } else { "" };
+                let hundredths = i64::from(value).abs();
+                format!("{}{}.{:02}", sign, hundredths / 100, hundredths % 100)
+            }
+        }
+    }
+}
+
+/// `1234567` as `1,234,567`.
+fn group_thousands(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if value < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
@@ -103,6 +103,41 @@ pub struct DestinyErrorProfile {
     pub info_card: Option<crate::user::UserInfoCard>,
 }
 
+impl DestinyLinkedProfilesResponse {
+    /// The platforms whose profiles Bungie couldn't return, with the reason for each. These are typically defunct platforms such as Stadia.
+    ///
+    /// An error profile without an info card is reported against [`BungieMembershipType::None`](crate::BungieMembershipType::None).
+    pub fn errored_platforms(&self) -> Vec<(crate::BungieMembershipType, crate::exceptions::PlatformErrorCodes)> {
+        self.profiles_with_errors
+            .iter()
+            .flatten()
+            .map(|errored| {
+                let membership_type =
+                    errored.info_card.as_ref().map_or(crate::BungieMembershipType::None, |card| card.membership_type);
+                (membership_type, errored.error_code)
+            })
+            .collect()
+    }
+}
+
+/// The profiles in `linked` that can be played and queried: those not overridden by Cross Save and not also listed in `profilesWithErrors`.
+pub fn usable_profiles(linked: &DestinyLinkedProfilesResponse) -> Vec<&DestinyProfileUserInfoCard> {
+    let errored: Vec<(crate::BungieMembershipType, i64)> = linked
+        .profiles_with_errors
+        .iter()
+        .flatten()
+        .filter_map(|errored| errored.info_card.as_ref())
+        .map(|card| (card.membership_type, card.membership_id))
+        .collect();
+    linked
+        .profiles
+        .iter()
+        .flatten()
+        .filter(|profile| !profile.is_overridden)
+        .filter(|profile| !errored.contains(&(profile.membership_type, profile.membership_id)))
+        .collect()
+}
+
 /// The response for GetDestinyProfile, with components for character and item-level data.
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -574,3 +609,529 @@ pub struct DestinyItemChangeResponse {
     #[serde(rename = "removedInventoryItems")]
     pub removed_inventory_items: Option<Vec<crate::destiny::entities::items::DestinyItemComponent>>,
 }
+
+/// Maps each DestinyComponentType to the DestinyProfileResponse field(s) it populates.
+/// Kept as one table so every helper that reasons about components stays in sync with the generated response.
+macro_rules! profile_response_components {
+    ($($component:ident => $field:ident $(. $inner:ident)?;)*) => {
+        impl DestinyProfileResponse {
+            /// Every component wrapper on this response, paired with the component type that requests it. Components that map to more than one field (e.g. Records has a profile and a character half) appear once per field.
+            pub fn component_states(&self) -> Vec<(crate::destiny::DestinyComponentType, Option<&dyn crate::components::ComponentResponseState>)> {
+                vec![
+                    $((
+                        crate::destiny::DestinyComponentType::$component,
+                        profile_response_components!(@ref self, $field $(. $inner)?),
+                    ),)*
+                ]
+            }
+
+            /// Drops every component not in `keep`, for long-lived caches that only need part of a response.
+            ///
+            /// Item component sets left with no components are dropped entirely, and the uninstanced item components
+            /// go unless ItemObjectives or ItemPerks (the only components Bungie fills them with) are kept.
+            pub fn retain_components(&mut self, keep: &[crate::destiny::DestinyComponentType]) {
+                $(if !keep.contains(&crate::destiny::DestinyComponentType::$component) {
+                    profile_response_components!(@clear self, $field $(. $inner)?);
+                })*
+
+                let mut nested_in_use: Vec<&str> = Vec::new();
+                $(profile_response_components!(@in_use self, nested_in_use, $field $(. $inner)?);)*
+                $(profile_response_components!(@drop_unused self, nested_in_use, $field $(. $inner)?);)*
+
+                if !keep.contains(&crate::destiny::DestinyComponentType::ItemObjectives)
+                    && !keep.contains(&crate::destiny::DestinyComponentType::ItemPerks)
+                {
+                    self.character_uninstanced_item_components = None;
+                }
+            }
+        }
+    };
+    (@ref $response:ident, $field:ident) => {
+        $response.$field.as_ref().map(|c| c as &dyn crate::components::ComponentResponseState)
+    };
+    (@ref $response:ident, $field:ident . $inner:ident) => {
+        $response.$field.as_ref().and_then(|set| set.$inner.as_ref()).map(|c| c as &dyn crate::components::ComponentResponseState)
+    };
+    (@clear $response:ident, $field:ident) => {
+        $response.$field = None;
+    };
+    (@clear $response:ident, $field:ident . $inner:ident) => {
+        if let Some(set) = $response.$field.as_mut() {
+            set.$inner = None;
+        }
+    };
+    (@in_use $response:ident, $in_use:ident, $field:ident) => {};
+    (@in_use $response:ident, $in_use:ident, $field:ident . $inner:ident) => {
+        if $response.$field.as_ref().map_or(false, |set| set.$inner.is_some()) {
+            $in_use.push(stringify!($field));
+        }
+    };
+    (@drop_unused $response:ident, $in_use:ident, $field:ident) => {};
+    (@drop_unused $response:ident, $in_use:ident, $field:ident . $inner:ident) => {
+        if !$in_use.contains(&stringify!($field)) {
+            $response.$field = None;
+        }
+    };
+}
+
+profile_response_components! {
+    Profiles => profile;
+    VendorReceipts => vendor_receipts;
+    ProfileInventories => profile_inventory;
+    ProfileCurrencies => profile_currencies;
+    ProfileProgression => profile_progression;
+    PlatformSilver => platform_silver;
+    Characters => characters;
+    CharacterInventories => character_inventories;
+    CharacterProgressions => character_progressions;
+    CharacterRenderData => character_render_data;
+    CharacterActivities => character_activities;
+    CharacterEquipment => character_equipment;
+    CharacterLoadouts => character_loadouts;
+    ItemInstances => item_components.instances;
+    ItemObjectives => item_components.objectives;
+    ItemPerks => item_components.perks;
+    ItemRenderData => item_components.render_data;
+    ItemStats => item_components.stats;
+    ItemSockets => item_components.sockets;
+    ItemSockets => profile_plug_sets;
+    ItemSockets => character_plug_sets;
+    ItemTalentGrids => item_components.talent_grids;
+    ItemPlugStates => item_components.plug_states;
+    ItemPlugObjectives => item_components.plug_objectives;
+    ItemReusablePlugs => item_components.reusable_plugs;
+    Kiosks => profile_kiosks;
+    Kiosks => character_kiosks;
+    CurrencyLookups => character_currency_lookups;
+    PresentationNodes => profile_presentation_nodes;
+    PresentationNodes => character_presentation_nodes;
+    Collectibles => profile_collectibles;
+    Collectibles => character_collectibles;
+    Records => profile_records;
+    Records => character_records;
+    Transitory => profile_transitory_data;
+    Metrics => metrics;
+    StringVariables => profile_string_variables;
+    StringVariables => character_string_variables;
+    Craftables => character_craftables;
+    SocialCommendations => profile_commendations;
+}
+
+impl DestinyProfileResponse {
+    /// Every requested component that Bungie returned with `disabled: true`.
+    ///
+    /// During rolling maintenance the envelope can still report Success while individual components come back disabled with no data; check this before treating empty components as "the player has nothing".
+    pub fn degraded_components(&self) -> Vec<crate::destiny::DestinyComponentType> {
+        let mut degraded = Vec::new();
+        for (component_type, state) in self.component_states() {
+            if state.map_or(false, |s| s.is_disabled()) && !degraded.contains(&component_type) {
+                degraded.push(component_type);
+            }
+        }
+        degraded
+    }
+
+    /// A rough estimate of the heap memory held by this response, for cache accounting.
+    ///
+    /// Each component contributes its entry count times a typical size per entry, so the result is only good to within
+    /// a small factor; it is meant for comparing responses and budgeting caches, not for exact measurement.
+    pub fn estimated_size(&self) -> usize {
+        use crate::destiny::DestinyComponentType as C;
+
+        let mut size = std::mem::size_of::<Self>();
+        for (component_type, state) in self.component_states() {
+            let entries = state.map_or(0, |s| s.entry_count());
+            let per_entry = match component_type {
+                // Inventories are keyed per character (or are the single Profile inventory) and hold hundreds of items each.
+                C::ProfileInventories => 600 * 160,
+                C::CharacterInventories | C::CharacterEquipment => 120 * 160,
+                C::ProfileCurrencies => 40 * 160,
+                C::Records | C::Collectibles | C::PresentationNodes => 64 * 1024,
+                C::CharacterProgressions | C::ProfileProgression => 16 * 1024,
+                C::Metrics | C::StringVariables | C::Craftables => 8 * 1024,
+                C::Characters | C::CharacterRenderData | C::CharacterActivities | C::CharacterLoadouts => 2 * 1024,
+                C::ItemSockets | C::ItemReusablePlugs | C::ItemPlugObjectives | C::ItemTalentGrids => 1024,
+                C::ItemPerks | C::ItemStats | C::ItemObjectives | C::ItemPlugStates | C::Kiosks => 512,
+                _ => 256,
+            };
+            size += entries * per_entry;
+        }
+
+        size + self
+            .character_uninstanced_item_components
+            .as_ref()
+            .map_or(0, |characters| characters.len() * 8 * 1024)
+    }
+
+    /// The plugs from one plug set available to a character, merging the ProfilePlugSets and CharacterPlugSets halves of the PlugSets component. See [`available_plugs`](crate::destiny::components::plug_sets::available_plugs).
+    pub fn available_plugs(
+        &self,
+        plug_set_hash: u32,
+        character_id: i64,
+    ) -> Vec<std::borrow::Cow<'_, crate::destiny::sockets::DestinyItemPlug>> {
+        let profile = self.profile_plug_sets.as_ref().and_then(|p| p.data.as_ref());
+        let character =
+            self.character_plug_sets.as_ref().and_then(|c| c.data.as_ref()).and_then(|c| c.get(&character_id));
+        crate::destiny::components::plug_sets::available_plugs(plug_set_hash, profile, character)
+    }
+}
+
+/// The objectives a character has on a non-instanced item such as a bounty or pursuit, from `characterUninstancedItemComponents`.
+///
+/// That component is keyed by characterId and then by item *hash*, not instance ID, so these objectives won't be found in `itemComponents`. Needs the ItemObjectives component.
+pub fn uninstanced_objectives_for(
+    profile: &DestinyProfileResponse,
+    character_id: i64,
+    item_hash: u32,
+) -> Option<&crate::destiny::entities::items::DestinyItemObjectivesComponent> {
+    profile
+        .character_uninstanced_item_components
+        .as_ref()?
+        .get(&character_id)?
+        .objectives
+        .as_ref()?
+        .data
+        .as_ref()?
+        .get(&item_hash)
+}
+
+/// Why a profile response might not have the data that was asked for. See [`DestinyProfileResponse::access_state`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProfileAccessState {
+    /// Every returned component is readable.
+    Ok,
+    /// Every returned component came back Private; the player has hidden their data from this caller.
+    Private,
+    /// The account exists but has never created a character.
+    NoCharacters,
+    /// Some returned components are Private and others are not. Each returned component is listed with its privacy setting.
+    Mixed(Vec<(crate::destiny::DestinyComponentType, crate::components::ComponentPrivacySetting)>),
+}
+
+impl DestinyProfileResponse {
+    /// Tells apart "this account has no characters" from "this account is private", which both otherwise look like components with no data.
+    ///
+    /// Privacy is read per component: Bungie commonly returns profile components as Public while character components of the same account are Private.
+    /// An empty character list on the Profiles component is conclusive, so NoCharacters is reported ahead of any character component privacy.
+    pub fn access_state(&self) -> ProfileAccessState {
+        let no_characters = self.profile.as_ref().and_then(|p| p.data.as_ref()).map_or(false, |p| {
+            p.character_ids.as_ref().map_or(true, Vec::is_empty)
+        }) || self.characters.as_ref().and_then(|c| c.data.as_ref()).map_or(false, HashMap::is_empty);
+        if no_characters {
+            return ProfileAccessState::NoCharacters;
+        }
+
+        let mut returned = Vec::new();
+        for (component_type, state) in self.component_states() {
+            if let Some(state) = state {
+                let entry = (component_type, state.privacy_setting());
+                if !returned.contains(&entry) {
+                    returned.push(entry);
+                }
+            }
+        }
+
+        let private = returned
+            .iter()
+            .filter(|(_, privacy)| *privacy == crate::components::ComponentPrivacySetting::Private)
+            .count();
+        if private == 0 {
+            ProfileAccessState::Ok
+        } else if private == returned.len() {
+            ProfileAccessState::Private
+        } else {
+            ProfileAccessState::Mixed(returned)
+        }
+    }
+}
+
+/// A collectible under the requested presentation node, paired with the preview components of the item it unlocks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectiblePreview<'a> {
+    pub collectible_hash: u32,
+    pub collectible: &'a crate::destiny::components::collectibles::DestinyCollectibleComponent,
+    /// The collectible's `DestinyCollectibleDefinition.itemHash`, which keys `collectibleItemComponents`.
+    pub item_hash: Option<u32>,
+    pub instance: Option<&'a crate::destiny::entities::items::DestinyItemInstanceComponent>,
+    pub render_data: Option<&'a crate::destiny::entities::items::DestinyItemRenderComponent>,
+    pub stats: Option<&'a crate::destiny::entities::items::DestinyItemStatsComponent>,
+    pub sockets: Option<&'a crate::destiny::entities::items::DestinyItemSocketsComponent>,
+    pub reusable_plugs: Option<&'a crate::destiny::components::items::DestinyItemReusablePlugsComponent>,
+    pub plug_objectives: Option<&'a crate::destiny::components::items::DestinyItemPlugObjectivesComponent>,
+    pub talent_grid: Option<&'a crate::destiny::entities::items::DestinyItemTalentGridComponent>,
+    pub objectives: Option<&'a crate::destiny::entities::items::DestinyItemObjectivesComponent>,
+    pub perks: Option<&'a crate::destiny::entities::items::DestinyItemPerksComponent>,
+}
+
+impl DestinyCollectibleNodeDetailResponse {
+    /// Pairs every returned collectible with its item's preview components, ordered by collectible hash.
+    /// The response keys item components by item hash, so `item_hash_for` must map a collectible hash to its `DestinyCollectibleDefinition.itemHash` (usually from the manifest). Collectibles it can't map are still returned, without components.
+    pub fn collectible_previews<F>(&self, mut item_hash_for: F) -> Vec<CollectiblePreview<'_>>
+    where
+        F: FnMut(u32) -> Option<u32>,
+    {
+        let collectibles = match self
+            .collectibles
+            .as_ref()
+            .and_then(|c| c.data.as_ref())
+            .and_then(|c| c.collectibles.as_ref())
+        {
+            Some(collectibles) => collectibles,
+            None => return Vec::new(),
+        };
+        let components = self.collectible_item_components.as_ref();
+
+        macro_rules! lookup {
+            ($item_hash:expr, $field:ident) => {
+                $item_hash.and_then(|item_hash| {
+                    components
+                        .and_then(|set| set.$field.as_ref())
+                        .and_then(|c| c.data.as_ref())
+                        .and_then(|data| data.get(&item_hash))
+                })
+            };
+        }
+
+        let mut previews: Vec<CollectiblePreview<'_>> = collectibles
+            .iter()
+            .map(|(collectible_hash, collectible)| {
+                let item_hash = item_hash_for(*collectible_hash);
+                CollectiblePreview {
+                    collectible_hash: *collectible_hash,
+                    collectible,
+                    item_hash,
+                    instance: lookup!(item_hash, instances),
+                    render_data: lookup!(item_hash, render_data),
+                    stats: lookup!(item_hash, stats),
+                    sockets: lookup!(item_hash, sockets),
+                    reusable_plugs: lookup!(item_hash, reusable_plugs),
+                    plug_objectives: lookup!(item_hash, plug_objectives),
+                    talent_grid: lookup!(item_hash, talent_grids),
+                    objectives: lookup!(item_hash, objectives),
+                    perks: lookup!(item_hash, perks),
+                }
+            })
+            .collect();
+        previews.sort_by_key(|preview| preview.collectible_hash);
+        previews
+    }
+}
+
+/// The numbers most landing pages show for an account, built from a profile response with
+/// [`ProfileSummary::try_from`]. Needs the Records, Characters and ProfileProgression components.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileSummary {
+    pub triumph_score: TriumphScore,
+    /// `None` for an account without characters.
+    pub power: Option<AccountPower>,
+    pub playtime: time::Duration,
+}
+
+/// Triumph score from the profile's records. See [`triumph_score`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TriumphScore {
+    /// The score from triumphs that are still obtainable.
+    pub active: i32,
+    /// The score from every triumph ever completed.
+    pub lifetime: i32,
+}
+
+/// An account's power level. See [`account_power`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AccountPower {
+    /// The highest character's power from gear alone.
+    pub base: i32,
+    /// The seasonal artifact's power bonus.
+    pub artifact_bonus: i32,
+    /// The highest character's power, artifact bonus included.
+    pub total: i32,
+}
+
+/// The components a [`ProfileSummary`] needed that the profile response didn't have, so they can be added to the request.
+/// A component that came back without data, for example because it's private, counts as missing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingComponents(pub Vec<crate::destiny::DestinyComponentType>);
+
+impl std::fmt::Display for MissingComponents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self.0.iter().map(|component| format!("{:?}", component)).collect();
+        write!(f, "The profile response is missing the components {}", names.join(", "))
+    }
+}
+
+impl std::error::Error for MissingComponents {}
+
+impl TryFrom<&DestinyProfileResponse> for ProfileSummary {
+    type Error = MissingComponents;
+
+    fn try_from(profile: &DestinyProfileResponse) -> Result<Self, Self::Error> {
+        use crate::destiny::DestinyComponentType as C;
+
+        let records = profile.profile_records.as_ref().and_then(|r| r.data.as_ref());
+        let characters = profile.characters.as_ref().and_then(|c| c.data.as_ref());
+        let progression = profile.profile_progression.as_ref().and_then(|p| p.data.as_ref());
+        match (records, characters, progression) {
+            (Some(records), Some(characters), Some(progression)) => Ok(ProfileSummary {
+                triumph_score: triumph_score(records),
+                power: account_power(characters.values(), progression.seasonal_artifact.as_ref()),
+                playtime: total_playtime(characters.values()),
+            }),
+            _ => {
+                let mut missing = Vec::new();
+                if records.is_none() {
+                    missing.push(C::Records);
+                }
+                if characters.is_none() {
+                    missing.push(C::Characters);
+                }
+                if progression.is_none() {
+                    missing.push(C::ProfileProgression);
+                }
+                Err(MissingComponents(missing))
+            }
+        }
+    }
+}
+
+pub fn triumph_score(records: &crate::destiny::components::records::DestinyProfileRecordsComponent) -> TriumphScore {
+    TriumphScore {
+        active: records.active_score,
+        lifetime: records.lifetime_score,
+    }
+}
+
+/// The power of the account's highest character, or `None` without characters.
+///
+/// A character's `light` already includes the artifact bonus, so the bonus is subtracted to get the base rather than added.
+/// Without a seasonal artifact the bonus is 0.
+pub fn account_power<'a>(
+    characters: impl IntoIterator<Item = &'a crate::destiny::entities::characters::DestinyCharacterComponent>,
+    artifact: Option<&crate::destiny::artifacts::DestinyArtifactProfileScoped>,
+) -> Option<AccountPower> {
+    let total = characters.into_iter().map(|character| character.light).max()?;
+    let artifact_bonus = artifact.map_or(0, |artifact| artifact.power_bonus);
+    Some(AccountPower {
+        base: total - artifact_bonus,
+        artifact_bonus,
+        total,
+    })
+}
+
+/// The time played across `characters`, from each one's `minutesPlayedTotal`. Deleted characters' time isn't included.
+pub fn total_playtime<'a>(
+    characters: impl IntoIterator<Item = &'a crate::destiny::entities::characters::DestinyCharacterComponent>,
+) -> time::Duration {
+    time::Duration::minutes(characters.into_iter().map(|character| character.minutes_played_total).sum())
+}
+
+/// The profile's progress on the active seasonal event's card, from [`event_card_progress`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventCardProgress<'a> {
+    pub event_card_hash: u32,
+    pub name: Option<&'a str>,
+    /// When the event ends, or `None` if the definition doesn't say.
+    pub ends: Option<OffsetDateTime>,
+    /// Whether the profile owns the event card, i.e. has bought the premium upgrade.
+    pub upgraded: bool,
+    /// Event tickets held, from the profile currencies and inventory (needs ProfileCurrencies or ProfileInventories).
+    pub tickets: i32,
+    /// Completed records under the card's triumphs node and its child nodes (needs Records and the presentation node definitions).
+    pub challenges_completed: usize,
+    pub challenges_total: usize,
+    /// Completed records under the card's seal node, counted the same way.
+    pub seal_completed: usize,
+    pub seal_total: usize,
+}
+
+/// Progress on the event card the profile has active, or `None` outside events: when `activeEventCardHash` is absent or zero,
+/// the card isn't in `event_card_defs`, or the Profiles component wasn't requested.
+///
+/// A record counts as completed when its objectives are complete in the profile records or any character's records, redeemed or not.
+pub fn event_card_progress<'a>(
+    profile: &DestinyProfileResponse,
+    event_card_defs: &'a HashMap<u32, crate::destiny::definitions::seasons::DestinyEventCardDefinition>,
+    presentation_node_defs: &HashMap<u32, crate::destiny::definitions::presentation::DestinyPresentationNodeDefinition>,
+) -> Option<EventCardProgress<'a>> {
+    let profile_component = profile.profile.as_ref()?.data.as_ref()?;
+    let event_card_hash = profile_component.active_event_card_hash.filter(|hash| *hash != 0)?;
+    let card = event_card_defs.get(&event_card_hash)?;
+
+    let tickets = [profile.profile_currencies.as_ref(), profile.profile_inventory.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|component| component.data.as_ref()?.items.as_ref())
+        .flatten()
+        .filter(|item| item.item_hash == card.ticket_currency_item_hash)
+        .map(|item| item.quantity)
+        .sum();
+
+    let record_complete = |hash: u32| {
+        let profile_record = profile
+            .profile_records
+            .as_ref()
+            .and_then(|r| r.data.as_ref())
+            .and_then(|r| r.records.as_ref())
+            .and_then(|records| records.get(&hash));
+        let character_records = profile
+            .character_records
+            .as_ref()
+            .and_then(|r| r.data.as_ref())
+            .into_iter()
+            .flat_map(HashMap::values)
+            .filter_map(|records| records.records.as_ref()?.get(&hash));
+        profile_record
+            .into_iter()
+            .chain(character_records)
+            .any(|record| !record.state.contains(crate::destiny::DestinyRecordState::ObjectiveNotCompleted))
+    };
+    let count = |node_hash: u32| {
+        let records = records_under(node_hash, presentation_node_defs);
+        (records.iter().filter(|hash| record_complete(**hash)).count(), records.len())
+    };
+    let (challenges_completed, challenges_total) = count(card.triumphs_presentation_node_hash);
+    let (seal_completed, seal_total) = count(card.seal_presentation_node_hash);
+
+    Some(EventCardProgress {
+        event_card_hash,
+        name: card.display_properties.as_ref().and_then(|d| d.name.as_deref()),
+        ends: Some(card.end_time)
+            .filter(|end| *end > 0)
+            .and_then(|end| OffsetDateTime::from_unix_timestamp(end).ok()),
+        upgraded: profile_component
+            .event_card_hashes_owned
+            .as_ref()
+            .map_or(false, |owned| owned.contains(&event_card_hash)),
+        tickets,
+        challenges_completed,
+        challenges_total,
+        seal_completed,
+        seal_total,
+    })
+}
+
+/// Every record hash under a presentation node, depth first, each node visited once.
+fn records_under(
+    node_hash: u32,
+    presentation_node_defs: &HashMap<u32, crate::destiny::definitions::presentation::DestinyPresentationNodeDefinition>,
+) -> Vec<u32> {
+    let mut records = Vec::new();
+    let mut visited = Vec::new();
+    let mut pending = vec![node_hash];
+    while let Some(hash) = pending.pop() {
+        if visited.contains(&hash) {
+            continue;
+        }
+        visited.push(hash);
+        let children = match presentation_node_defs.get(&hash).and_then(|node| node.children.as_ref()) {
+            Some(children) => children,
+            None => continue,
+        };
+        for record in children.records.iter().flatten() {
+            if !records.contains(&record.record_hash) {
+                records.push(record.record_hash);
+            }
+        }
+        pending.extend(children.presentation_nodes.iter().flatten().rev().map(|child| child.presentation_node_hash));
+    }
+    records
+}
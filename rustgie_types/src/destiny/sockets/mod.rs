@@ -53,3 +53,67 @@ pub struct DestinyItemPlug {
     #[serde(rename = "enableFailIndexes")]
     pub enable_fail_indexes: Option<Vec<i32>>,
 }
+
+/// Shown in place of a plug rule's failure message when the fail index is out of range or the rule has no message.
+pub const UNKNOWN_PLUG_FAILURE: &str = "Unknown plug requirement";
+
+/// The insert/enable state shared by the plug shapes Bungie returns (DestinyItemPlugBase, DestinyItemPlug, and DestinyItemPlugComponent).
+pub trait PlugState {
+    /// Whether the plug has met all of its insertion requirements.
+    fn can_insert(&self) -> bool;
+
+    /// Whether the plug's enabled requirements are met, so it's applying its effects.
+    fn is_enabled(&self) -> bool;
+
+    /// The failure messages from `plug`'s insertionRules that are stopping this plug from being inserted.
+    fn insert_failures<'a>(&self, plug: &'a crate::destiny::definitions::items::DestinyItemPlugDefinition) -> Vec<&'a str>;
+
+    /// The failure messages from `plug`'s enabledRules that are stopping this plug from being enabled.
+    fn enable_failures<'a>(&self, plug: &'a crate::destiny::definitions::items::DestinyItemPlugDefinition) -> Vec<&'a str>;
+}
+
+/// Looks up each fail index in `rules`, substituting [`UNKNOWN_PLUG_FAILURE`] for indexes outside it.
+fn failure_messages<'a>(
+    indexes: Option<&Vec<i32>>,
+    rules: Option<&'a Vec<crate::destiny::definitions::items::DestinyPlugRuleDefinition>>,
+) -> Vec<&'a str> {
+    indexes
+        .into_iter()
+        .flatten()
+        .map(|&index| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| rules?.get(index))
+                .and_then(|rule| rule.failure_message.as_deref())
+                .unwrap_or(UNKNOWN_PLUG_FAILURE)
+        })
+        .collect()
+}
+
+macro_rules! impl_plug_state {
+    ($($plug:ty),* $(,)?) => {$(
+        impl PlugState for $plug {
+            fn can_insert(&self) -> bool {
+                self.can_insert
+            }
+
+            fn is_enabled(&self) -> bool {
+                self.enabled
+            }
+
+            fn insert_failures<'a>(&self, plug: &'a crate::destiny::definitions::items::DestinyItemPlugDefinition) -> Vec<&'a str> {
+                failure_messages(self.insert_fail_indexes.as_ref(), plug.insertion_rules.as_ref())
+            }
+
+            fn enable_failures<'a>(&self, plug: &'a crate::destiny::definitions::items::DestinyItemPlugDefinition) -> Vec<&'a str> {
+                failure_messages(self.enable_fail_indexes.as_ref(), plug.enabled_rules.as_ref())
+            }
+        }
+    )*};
+}
+
+impl_plug_state!(
+    DestinyItemPlugBase,
+    DestinyItemPlug,
+    crate::destiny::components::items::DestinyItemPlugComponent,
+);
@@ -1,5 +1,6 @@
 ﻿use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
 use time::OffsetDateTime;
 
 /// If a character purchased an item that is refundable, a Vendor Receipt will be created on the user's Destiny Profile. These expire after a configurable period of time, but until then can be used to get refunds on items. BNet does not provide the ability to refund a purchase *yet*, but you know.
@@ -41,3 +42,182 @@ pub struct DestinyVendorReceipt {
     #[serde(rename = "expiresOn")]
     pub expires_on: OffsetDateTime,
 }
+
+/// One vendor's categories and sales, read the same way whether they came from the public vendors response (anyone can see)
+/// or a character's vendor response (needs their OAuth token), so one rendering path can serve both.
+///
+/// Fields only the character's response has are `Option`s, `None` in a view built from the public response.
+/// [`VendorView::merge`] fills them in on a public view when both responses are at hand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VendorView<'a> {
+    pub vendor_hash: u32,
+    pub enabled: bool,
+    pub next_refresh_date: OffsetDateTime,
+    /// How the vendor's sales are grouped for display, with indexes into the vendor definition's items.
+    pub categories: &'a [crate::destiny::entities::vendors::DestinyVendorCategory],
+    /// In `vendorItemIndex` order.
+    pub sales: Vec<VendorSale<'a>>,
+    /// Whether the character can buy from this vendor at all.
+    pub can_purchase: Option<bool>,
+    /// The character's reputation with the vendor, if it has one.
+    pub progression: Option<&'a crate::destiny::DestinyProgression>,
+    pub seasonal_rank: Option<i32>,
+    pub vendor_location_index: Option<i32>,
+}
+
+/// One item for sale in a [`VendorView`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VendorSale<'a> {
+    pub vendor_item_index: i32,
+    pub item_hash: u32,
+    pub override_style_item_hash: Option<u32>,
+    pub quantity: i32,
+    /// The costs from whichever response the view was built from: the default price in the public response,
+    /// the character's price in theirs. A merged view keeps the public price; see [`VendorSale::character_costs`].
+    pub costs: &'a [crate::destiny::DestinyItemQuantity],
+    pub override_next_refresh_date: Option<OffsetDateTime>,
+    pub api_purchasable: Option<bool>,
+    /// The sale as the character sees it, or `None` in a public view.
+    pub personal: Option<&'a crate::destiny::entities::vendors::DestinyVendorSaleItemComponent>,
+}
+
+impl<'a> VendorSale<'a> {
+    /// What the character would pay, which can differ from the default price.
+    pub fn character_costs(&self) -> Option<&'a [crate::destiny::DestinyItemQuantity]> {
+        self.personal.map(|sale| sale.costs.as_deref().unwrap_or_default())
+    }
+
+    /// Whether the character can buy it, and if not, roughly why.
+    pub fn sale_status(&self) -> Option<enumflags2::BitFlags<crate::destiny::VendorItemStatus>> {
+        self.personal.map(|sale| sale.sale_status)
+    }
+
+    /// Indexes into the vendor definition's `failureStrings` explaining why the character can't buy it; empty when they can.
+    pub fn failure_indexes(&self) -> Option<&'a [i32]> {
+        self.personal.map(|sale| sale.failure_indexes.as_deref().unwrap_or_default())
+    }
+}
+
+impl<'a> VendorView<'a> {
+    /// `vendor_hash` from the public vendors response, or `None` if the response doesn't have that vendor.
+    pub fn from_public(response: &'a crate::destiny::responses::DestinyPublicVendorsResponse, vendor_hash: u32) -> Option<VendorView<'a>> {
+        let vendor = response.vendors.as_ref()?.data.as_ref()?.get(&vendor_hash)?;
+        let categories = response
+            .categories
+            .as_ref()
+            .and_then(|categories| categories.data.as_ref())
+            .and_then(|categories| categories.get(&vendor_hash))
+            .and_then(|categories| categories.categories.as_deref())
+            .unwrap_or_default();
+        let sales = response
+            .sales
+            .as_ref()
+            .and_then(|sales| sales.data.as_ref())
+            .and_then(|sales| sales.get(&vendor_hash))
+            .and_then(|sales| sales.sale_items.as_ref())
+            .map(|sale_items| {
+                sorted_sales(sale_items, |sale| VendorSale {
+                    vendor_item_index: sale.vendor_item_index,
+                    item_hash: sale.item_hash,
+                    override_style_item_hash: sale.override_style_item_hash,
+                    quantity: sale.quantity,
+                    costs: sale.costs.as_deref().unwrap_or_default(),
+                    override_next_refresh_date: sale.override_next_refresh_date,
+                    api_purchasable: sale.api_purchasable,
+                    personal: None,
+                })
+            })
+            .unwrap_or_default();
+
+        Some(VendorView {
+            vendor_hash: vendor.vendor_hash,
+            enabled: vendor.enabled,
+            next_refresh_date: vendor.next_refresh_date,
+            categories,
+            sales,
+            can_purchase: None,
+            progression: None,
+            seasonal_rank: None,
+            vendor_location_index: None,
+        })
+    }
+
+    /// The vendor from a character's vendor response, or `None` if it was fetched without the Vendors component.
+    pub fn from_authed(response: &'a crate::destiny::responses::DestinyVendorResponse) -> Option<VendorView<'a>> {
+        let vendor = response.vendor.as_ref()?.data.as_ref()?;
+        let categories = response
+            .categories
+            .as_ref()
+            .and_then(|categories| categories.data.as_ref())
+            .and_then(|categories| categories.categories.as_deref())
+            .unwrap_or_default();
+        let sales = response
+            .sales
+            .as_ref()
+            .and_then(|sales| sales.data.as_ref())
+            .map(|sale_items| {
+                sorted_sales(sale_items, |sale| VendorSale {
+                    vendor_item_index: sale.vendor_item_index,
+                    item_hash: sale.item_hash,
+                    override_style_item_hash: sale.override_style_item_hash,
+                    quantity: sale.quantity,
+                    costs: sale.costs.as_deref().unwrap_or_default(),
+                    override_next_refresh_date: sale.override_next_refresh_date,
+                    api_purchasable: sale.api_purchasable,
+                    personal: Some(sale),
+                })
+            })
+            .unwrap_or_default();
+
+        Some(VendorView {
+            vendor_hash: vendor.vendor_hash,
+            enabled: vendor.enabled,
+            next_refresh_date: vendor.next_refresh_date,
+            categories,
+            sales,
+            can_purchase: Some(vendor.can_purchase),
+            progression: vendor.progression.as_ref(),
+            seasonal_rank: vendor.seasonal_rank,
+            vendor_location_index: Some(vendor.vendor_location_index),
+        })
+    }
+
+    /// The public view with the character's detail laid over it: the vendor-level fields, and `personal` on every sale
+    /// the character's response also has.
+    ///
+    /// The categories and the list of sales stay the public ones, so anonymous and signed-in users see the same layout.
+    /// Sales only the character's response has, such as class-specific items, are left out; use the authed view on its own
+    /// to show those. Views of two different vendors aren't merged: `public` is returned unchanged.
+    pub fn merge(public: VendorView<'a>, authed: VendorView<'a>) -> VendorView<'a> {
+        if public.vendor_hash != authed.vendor_hash {
+            return public;
+        }
+
+        let personal: HashMap<i32, &'a crate::destiny::entities::vendors::DestinyVendorSaleItemComponent> = authed
+            .sales
+            .iter()
+            .filter_map(|sale| Some((sale.vendor_item_index, sale.personal?)))
+            .collect();
+        VendorView {
+            sales: public
+                .sales
+                .into_iter()
+                .map(|sale| VendorSale {
+                    personal: personal.get(&sale.vendor_item_index).copied(),
+                    ..sale
+                })
+                .collect(),
+            can_purchase: authed.can_purchase,
+            progression: authed.progression,
+            seasonal_rank: authed.seasonal_rank,
+            vendor_location_index: authed.vendor_location_index,
+            ..public
+        }
+    }
+}
+
+fn sorted_sales<'a, T>(sale_items: &'a HashMap<i32, T>, view: impl Fn(&'a T) -> VendorSale<'a>) -> Vec<VendorSale<'a>> {
+    let mut sales: Vec<VendorSale<'a>> = sale_items.values().map(view).collect();
+    sales.sort_by_key(|sale| sale.vendor_item_index);
+    sales
+}
@@ -0,0 +1,20 @@
+﻿//! Hashes the game relies on by convention rather than by anything the API describes.
+//! These are stable across manifest versions in practice, but Bungie doesn't promise it.
+
+/// The plug category of the frame identifier plug every crafted ("shaped") weapon has in one of its sockets. Its plug objectives carry the crafting data.
+pub const CRAFTED_FRAME_PLUG_CATEGORY_HASH: u32 = 3583996951;
+
+/// The crafting frame plug's objective holding the weapon's level.
+pub const CRAFTED_WEAPON_LEVEL_OBJECTIVE_HASH: u32 = 3077315735;
+
+/// The crafting frame plug's objective holding the progress toward the weapon's next level.
+pub const CRAFTED_WEAPON_LEVEL_PROGRESS_OBJECTIVE_HASH: u32 = 2899837482;
+
+/// The crafting frame plug's objective holding when the weapon was crafted, in seconds since the Unix epoch.
+pub const CRAFTED_WEAPON_DATE_OBJECTIVE_HASH: u32 = 3947811849;
+
+/// The plug category of weapon traits ("frames"). Enhanced traits share it with their base versions.
+pub const WEAPON_TRAIT_PLUG_CATEGORY_HASH: u32 = 7906839;
+
+/// The plug category of weapon intrinsics. Enhanced intrinsics share it with their base versions.
+pub const WEAPON_INTRINSIC_PLUG_CATEGORY_HASH: u32 = 1744546145;
@@ -0,0 +1,136 @@
+﻿//! Reporting for data the lenient deserializers tolerated instead of failing on.
+//!
+//! Nothing is recorded unless a parse runs inside [`with_diagnostics`], so the lenient paths cost a thread-local lookup when nobody is listening.
+
+use enumflags2::{BitFlag, BitFlags};
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// Something a lenient deserializer accepted that didn't match the documented contract.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeserializationEvent {
+    /// An enum value this version of rustgie_types doesn't know. The field was given its fallback value.
+    UnknownEnumValue { type_name: &'static str, value: i64 },
+    /// A map key that couldn't be parsed as the key type. The entry was dropped.
+    SkippedMapKey { type_name: &'static str, key: String },
+    /// Bits set in a flags value that this version of rustgie_types has no flag for. They were cleared and the known flags kept.
+    UnknownFlagBits { type_name: &'static str, bits: u64 },
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Vec<DeserializationEvent>>> = const { RefCell::new(None) };
+}
+
+/// Runs `parse`, returning its result along with every event the lenient deserializers recorded while it ran on this thread.
+/// Calls may nest; the inner call only sees its own events.
+pub fn with_diagnostics<T, F: FnOnce() -> T>(parse: F) -> (T, Vec<DeserializationEvent>) {
+    let outer = COLLECTOR.with(|collector| collector.replace(Some(Vec::new())));
+    let result = parse();
+    let events = COLLECTOR.with(|collector| collector.replace(outer)).unwrap_or_default();
+    (result, events)
+}
+
+/// Records an event if a collector is installed on this thread. `event` is only built when someone is listening.
+pub fn record<F: FnOnce() -> DeserializationEvent>(event: F) {
+    COLLECTOR.with(|collector| {
+        if let Some(events) = collector.borrow_mut().as_mut() {
+            events.push(event());
+        }
+    });
+}
+
+/// `serde_as` adapter for integer-backed enums that falls back to `T::default()` on values it doesn't know, recording [`DeserializationEvent::UnknownEnumValue`].
+pub struct LenientEnum;
+
+impl<'de, T> DeserializeAs<'de, T> for LenientEnum
+where
+    T: Deserialize<'de> + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let value = i64::deserialize(deserializer)?;
+        let known: Result<T, serde::de::value::Error> = T::deserialize(value.into_deserializer());
+        Ok(known.unwrap_or_else(|_| {
+            record(|| DeserializationEvent::UnknownEnumValue {
+                type_name: std::any::type_name::<T>(),
+                value,
+            });
+            T::default()
+        }))
+    }
+}
+
+impl<T: Serialize> SerializeAs<T> for LenientEnum {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        source.serialize(serializer)
+    }
+}
+
+/// `serde_as` adapter for maps whose keys are parsed with `FromStr`, dropping entries whose key doesn't parse and recording [`DeserializationEvent::SkippedMapKey`].
+pub struct LenientKeys;
+
+impl<'de, K, V> DeserializeAs<'de, HashMap<K, V>> for LenientKeys
+where
+    K: FromStr + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<K, V>, D::Error> {
+        let raw = HashMap::<String, V>::deserialize(deserializer)?;
+        Ok(raw
+            .into_iter()
+            .filter_map(|(key, value)| match key.parse::<K>() {
+                Ok(parsed) => Some((parsed, value)),
+                Err(_) => {
+                    record(|| DeserializationEvent::SkippedMapKey {
+                        type_name: std::any::type_name::<K>(),
+                        key,
+                    });
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+impl<K: Display, V: Serialize> SerializeAs<HashMap<K, V>> for LenientKeys {
+    fn serialize_as<S: Serializer>(source: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(source.iter().map(|(key, value)| (key.to_string(), value)))
+    }
+}
+
+/// `serde_as` adapter for flags fields that keeps the known flags of a combined value and clears any bits it doesn't know, recording [`DeserializationEvent::UnknownFlagBits`].
+pub struct LenientFlags;
+
+impl<'de, T> DeserializeAs<'de, BitFlags<T>> for LenientFlags
+where
+    T: BitFlag,
+    T::Numeric: Deserialize<'de> + Into<u64>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<BitFlags<T>, D::Error> {
+        let raw = T::Numeric::deserialize(deserializer)?;
+        let known = BitFlags::<T>::from_bits_truncate(raw);
+        let unknown = raw ^ known.bits();
+        if unknown != T::Numeric::default() {
+            record(|| DeserializationEvent::UnknownFlagBits {
+                type_name: std::any::type_name::<T>(),
+                bits: unknown.into(),
+            });
+        }
+        Ok(known)
+    }
+}
+
+impl<T> SerializeAs<BitFlags<T>> for LenientFlags
+where
+    T: BitFlag,
+    T::Numeric: Serialize,
+{
+    fn serialize_as<S: Serializer>(source: &BitFlags<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        source.bits().serialize(serializer)
+    }
+}
@@ -322,3 +322,17 @@ impl FromStr for FireteamPlatformInviteResult {
         }
     }
 }
+
+impl FireteamMember {
+    /// The name, avatar, and membership to display for this member. `None` only when Bungie returned neither user card.
+    pub fn display_identity(&self) -> Option<crate::user::DisplayIdentity> {
+        let destiny = self.destiny_user_info.as_ref().map(|card| crate::user::IdentityCard {
+            global_name: card.bungie_global_display_name.as_deref(),
+            global_code: card.bungie_global_display_name_code,
+            platform_name: card.fireteam_display_name.as_deref().filter(|n| !n.is_empty()).or(card.display_name.as_deref()),
+            icon_path: card.icon_path.as_deref(),
+            membership: (card.membership_type, card.membership_id),
+        });
+        crate::user::DisplayIdentity::resolve(destiny, self.bungie_net_user_info.as_ref())
+    }
+}
@@ -157,6 +157,67 @@ pub struct PostResponse {
 
     #[serde(rename = "locale")]
     pub locale: Option<String>,
+
+    // The fields below are sent by the forum endpoints but missing from the OpenAPI spec.
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "postId")]
+    pub post_id: Option<i64>,
+
+    /// The post this is a reply to. Topics have no parent, which Bungie sends as null or 0.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "parentPostId")]
+    pub parent_post_id: Option<i64>,
+
+    /// The topic this post belongs to. Polls are keyed by this ID.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "topicId")]
+    pub topic_id: Option<i64>,
+
+    #[serde(rename = "threadDepth")]
+    pub thread_depth: Option<i32>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "authorMembershipId")]
+    pub author_membership_id: Option<i64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "editorMembershipId")]
+    pub editor_membership_id: Option<i64>,
+
+    #[serde(rename = "subject")]
+    pub subject: Option<String>,
+
+    #[serde(rename = "body")]
+    pub body: Option<String>,
+
+    #[serde(rename = "urlLinkOrImage")]
+    pub url_link_or_image: Option<String>,
+
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[serde(default)]
+    #[serde(rename = "creationDate")]
+    pub creation_date: Option<OffsetDateTime>,
+
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[serde(default)]
+    #[serde(rename = "lastModified")]
+    pub last_modified: Option<OffsetDateTime>,
+
+    #[serde(rename = "tags")]
+    pub tags: Option<Vec<String>>,
+
+    #[serde(rename = "replyCount")]
+    pub reply_count: Option<i32>,
+
+    #[serde(rename = "viewCount")]
+    pub view_count: Option<i32>,
+
+    #[serde(rename = "rating")]
+    pub rating: Option<i32>,
+
+    #[serde(rename = "ratingCount")]
+    pub rating_count: Option<i32>,
 }
 
 #[repr(i32)]
@@ -436,3 +497,117 @@ impl FromStr for CommunityContentSortMode {
         }
     }
 }
+
+/// A post with its replies nested under it, as rebuilt by [`build_thread`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadNode<'a> {
+    pub post: &'a crate::forum::PostResponse,
+    /// The post's author, joined from `PostSearchResponse.authors`.
+    pub author: Option<&'a crate::user::GeneralUser>,
+    /// Poll results, when this post is a topic with a poll.
+    pub poll: Option<&'a crate::forum::PollResponse>,
+    /// Direct replies, oldest first.
+    pub replies: Vec<ThreadNode<'a>>,
+}
+
+/// The posts of a forum search response rebuilt into reply trees.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadTree<'a> {
+    /// Posts without a parent (topics), oldest first.
+    pub roots: Vec<ThreadNode<'a>>,
+    /// Replies whose parent wasn't part of the fetched page. These hang off a synthetic root so no fetched post is lost.
+    pub orphans: Vec<ThreadNode<'a>>,
+}
+
+impl<'a> ThreadTree<'a> {
+    /// Every node in the tree, depth first, topics before orphans.
+    pub fn iter(&self) -> impl Iterator<Item = &ThreadNode<'a>> {
+        let mut stack: Vec<&ThreadNode<'a>> = self.orphans.iter().rev().chain(self.roots.iter().rev()).collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.replies.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+/// Rebuilds the reply tree from the flat `results` and `relatedPosts` lists of a forum response, joining each post to its
+/// author and poll.
+///
+/// Replies are ordered by creation date, with post ID breaking ties. A post that appears in both lists is used once.
+pub fn build_thread(response: &crate::forum::PostSearchResponse) -> ThreadTree<'_> {
+    let mut posts: Vec<&crate::forum::PostResponse> = Vec::new();
+    for post in response.results.iter().flatten().chain(response.related_posts.iter().flatten()) {
+        let duplicate = post.post_id.is_some() && posts.iter().any(|p| p.post_id == post.post_id);
+        if !duplicate {
+            posts.push(post);
+        }
+    }
+    posts.sort_by_key(|p| (p.creation_date.is_none(), p.creation_date, p.post_id));
+
+    let fetched: std::collections::HashSet<i64> = posts.iter().filter_map(|p| p.post_id).collect();
+    let mut children: std::collections::HashMap<i64, Vec<&crate::forum::PostResponse>> = std::collections::HashMap::new();
+    let mut tree = ThreadTree::default();
+    let mut root_posts = Vec::new();
+    let mut orphan_posts = Vec::new();
+    for post in posts {
+        match post.parent_post_id.filter(|id| *id != 0) {
+            None => root_posts.push(post),
+            Some(parent) if fetched.contains(&parent) && post.post_id != Some(parent) => {
+                children.entry(parent).or_default().push(post)
+            }
+            Some(_) => orphan_posts.push(post),
+        }
+    }
+
+    tree.roots = root_posts
+        .into_iter()
+        .map(|post| thread_node(response, post, &mut children))
+        .collect();
+    tree.orphans = orphan_posts
+        .into_iter()
+        .map(|post| thread_node(response, post, &mut children))
+        .collect();
+
+    // Anything left is part of a parent cycle that never reaches a topic; keep it reachable rather than dropping it.
+    let mut unreached: Vec<_> = children.drain().flat_map(|(_, replies)| replies).collect();
+    unreached.sort_by_key(|p| (p.creation_date.is_none(), p.creation_date, p.post_id));
+    tree.orphans.extend(unreached.into_iter().map(|post| ThreadNode {
+        post,
+        author: post_author(response, post),
+        poll: None,
+        replies: Vec::new(),
+    }));
+    tree
+}
+
+fn thread_node<'a>(
+    response: &'a crate::forum::PostSearchResponse,
+    post: &'a crate::forum::PostResponse,
+    children: &mut std::collections::HashMap<i64, Vec<&'a crate::forum::PostResponse>>,
+) -> ThreadNode<'a> {
+    let replies = post
+        .post_id
+        .and_then(|id| children.remove(&id))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reply| thread_node(response, reply, children))
+        .collect();
+
+    ThreadNode {
+        post,
+        author: post_author(response, post),
+        poll: post.post_id.and_then(|id| {
+            response.polls.iter().flatten().find(|poll| poll.topic_id == id)
+        }),
+        replies,
+    }
+}
+
+fn post_author<'a>(
+    response: &'a crate::forum::PostSearchResponse,
+    post: &crate::forum::PostResponse,
+) -> Option<&'a crate::user::GeneralUser> {
+    let author = post.author_membership_id?;
+    response.authors.iter().flatten().find(|a| a.membership_id == author)
+}
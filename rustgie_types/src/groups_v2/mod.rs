@@ -1,4 +1,5 @@
-﻿use anyhow::{anyhow, Result};
+﻿use crate::ids::id_newtype;
+use anyhow::{anyhow, Result};
 use enumflags2::bitflags;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -735,6 +736,7 @@ pub struct GroupSearchResponse {
 /// If you are querying for a group, you can pass any of the properties below.
 /// If you are querying for a Clan, you MUST NOT pass any of the following properties (they must be null or undefined in your request, not just empty string/default values):
 /// - groupMemberCountFilter - localeFilter - tagText
+///
 /// If you pass these, you will get a useless InvalidParameters error.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct GroupQuery {
@@ -1228,3 +1230,24 @@ pub struct GroupApplicationResponse {
     #[serde(rename = "resolution")]
     pub resolution: crate::groups_v2::GroupApplicationResolveState,
 }
+
+impl GroupMember {
+    /// The name, avatar, and membership to display for this member. `None` only when Bungie returned neither user card.
+    pub fn display_identity(&self) -> Option<crate::user::DisplayIdentity> {
+        let destiny = self.destiny_user_info.as_ref().map(|card| crate::user::IdentityCard {
+            global_name: card.bungie_global_display_name.as_deref(),
+            global_code: card.bungie_global_display_name_code,
+            platform_name: card.last_seen_display_name.as_deref().filter(|n| !n.is_empty()).or(card.display_name.as_deref()),
+            icon_path: card.icon_path.as_deref(),
+            membership: (card.membership_type, card.membership_id),
+        });
+        crate::user::DisplayIdentity::resolve(destiny, self.bungie_net_user_info.as_ref())
+    }
+}
+
+/// The ID of a group, such as a clan, as accepted by the GroupV2 endpoints and the clan endpoints of Destiny2 and Fireteam.
+/// Bungie sends these as strings; numbers are accepted too. Zero and negative IDs never name a real group and are rejected when parsing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GroupId(i64);
+
+id_newtype!(GroupId("a group ID", "Group IDs"));
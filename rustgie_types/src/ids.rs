@@ -0,0 +1,96 @@
+﻿//! The shared implementation of the validated 64-bit ID newtypes, such as [`CharacterId`](crate::destiny::entities::characters::CharacterId).
+
+/// Implements `new`, `get`, the `i64` conversions, `Display`, `FromStr`, `TryFrom<&str>` and serde for a `struct $name(i64)` declared
+/// next to the invocation, as in `id_newtype!(CharacterId("a character ID", "Character IDs"))`. The strings name the ID in error messages.
+///
+/// Bungie sends these IDs as strings; numbers are accepted too. Zero and negative IDs are rejected by `new`, parsing and deserializing,
+/// but `From<i64>` stays unvalidated so existing `i64` IDs keep working with the endpoints, which is also why there is no `TryFrom<i64>`.
+macro_rules! id_newtype {
+    ($name:ident($a_noun:literal, $nouns:literal)) => {
+        impl $name {
+            /// `None` for zero and negative IDs.
+            pub fn new(id: i64) -> Option<$name> {
+                if id > 0 {
+                    Some($name(id))
+                } else {
+                    None
+                }
+            }
+
+            pub fn get(self) -> i64 {
+                self.0
+            }
+        }
+
+        #[doc = concat!("Unvalidated; prefer [`", stringify!($name), "::new`] or parsing for untrusted input.")]
+        impl From<i64> for $name {
+            fn from(id: i64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = anyhow::Error;
+            fn from_str(s: &str) -> anyhow::Result<Self> {
+                let id: i64 = s.parse().map_err(|_| anyhow::anyhow!(concat!("Could not parse '{}' as ", $a_noun), s))?;
+                $name::new(id).ok_or_else(|| anyhow::anyhow!(concat!($nouns, " must be positive, got {}"), id))
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = anyhow::Error;
+            fn try_from(s: &str) -> anyhow::Result<Self> {
+                s.parse()
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        /// Also works as a map key, so dictionaries keyed by these IDs can be read into a `HashMap` keyed by the newtype.
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct IdVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for IdVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        f.write_str(concat!($a_noun, " as a positive string or integer"))
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                        v.parse().map_err(E::custom)
+                    }
+
+                    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                        $name::new(v).ok_or_else(|| E::custom(format!(concat!($nouns, " must be positive, got {}"), v)))
+                    }
+
+                    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                        i64::try_from(v).map_err(E::custom).and_then(|v| self.visit_i64(v))
+                    }
+                }
+
+                deserializer.deserialize_any(IdVisitor)
+            }
+        }
+    };
+}
+
+pub(crate) use id_newtype;
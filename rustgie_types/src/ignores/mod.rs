@@ -90,3 +90,169 @@ impl FromStr for IgnoreLength {
         }
     }
 }
+
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IgnoredItemType {
+    All = 0,
+    Post = 1,
+    Group = 2,
+    User = 3,
+    Tag = 4,
+    GroupProfile = 5,
+    UserProfile = 6,
+    UserPrivateMessage = 7,
+    GroupWallPost = 8,
+    PrivateMessage = 9,
+}
+
+impl Display for IgnoredItemType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as i32)
+    }
+}
+
+impl FromStr for IgnoredItemType {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "All" => Ok(IgnoredItemType::All),
+            "Post" => Ok(IgnoredItemType::Post),
+            "Group" => Ok(IgnoredItemType::Group),
+            "User" => Ok(IgnoredItemType::User),
+            "Tag" => Ok(IgnoredItemType::Tag),
+            "GroupProfile" => Ok(IgnoredItemType::GroupProfile),
+            "UserProfile" => Ok(IgnoredItemType::UserProfile),
+            "UserPrivateMessage" => Ok(IgnoredItemType::UserPrivateMessage),
+            "GroupWallPost" => Ok(IgnoredItemType::GroupWallPost),
+            "PrivateMessage" => Ok(IgnoredItemType::PrivateMessage),
+            _ => Err(anyhow!("Could not deserialize string '{}' to IgnoredItemType", s)),
+        }
+    }
+}
+
+/// The body of the Ignore and Unignore endpoints.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct IgnoreItemRequest {
+    /// The ID of the ignored item: a membership ID, post ID, or group ID, or the tag itself when ignoring a tag.
+    #[serde(rename = "ignoredItemId")]
+    pub ignored_item_id: String,
+
+    #[serde(rename = "ignoredItemType")]
+    pub ignored_item_type: crate::ignores::IgnoredItemType,
+
+    #[serde(rename = "comment")]
+    pub comment: Option<String>,
+
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+
+    /// The group a post or wall post was made in, when the ignore should only apply within that group.
+    #[serde(rename = "itemContextId")]
+    pub item_context_id: Option<String>,
+
+    #[serde(rename = "itemContextType")]
+    pub item_context_type: crate::ignores::IgnoredItemType,
+
+    /// Only group moderators acting on behalf of their group may set this.
+    #[serde(rename = "moderatorRequest")]
+    pub moderator_request: bool,
+
+    /// Only honored for moderator requests; personal ignores last until they are removed.
+    #[serde(rename = "banLength")]
+    pub ban_length: crate::ignores::IgnoreLength,
+}
+
+impl IgnoreItemRequest {
+    pub fn new(ignored_item_type: IgnoredItemType, ignored_item_id: &str) -> IgnoreItemRequest {
+        IgnoreItemRequest {
+            ignored_item_id: ignored_item_id.to_string(),
+            ignored_item_type,
+            comment: None,
+            reason: None,
+            item_context_id: None,
+            item_context_type: IgnoredItemType::All,
+            moderator_request: false,
+            ban_length: IgnoreLength::None,
+        }
+    }
+
+    pub fn user(membership_id: i64) -> IgnoreItemRequest {
+        IgnoreItemRequest::new(IgnoredItemType::User, &membership_id.to_string())
+    }
+
+    pub fn post(post_id: i64) -> IgnoreItemRequest {
+        IgnoreItemRequest::new(IgnoredItemType::Post, &post_id.to_string())
+    }
+
+    pub fn group(group_id: i64) -> IgnoreItemRequest {
+        IgnoreItemRequest::new(IgnoredItemType::Group, &group_id.to_string())
+    }
+
+    pub fn tag(tag: &str) -> IgnoreItemRequest {
+        IgnoreItemRequest::new(IgnoredItemType::Tag, tag)
+    }
+
+    /// Scopes the ignore to content posted in one group.
+    #[must_use]
+    pub fn in_group(mut self, group_id: i64) -> IgnoreItemRequest {
+        self.item_context_id = Some(group_id.to_string());
+        self.item_context_type = IgnoredItemType::Group;
+        self
+    }
+
+    /// The ignore flag Bungie reports on content once this request has been applied.
+    pub fn resulting_status(&self) -> Option<crate::ignores::IgnoreStatus> {
+        match self.ignored_item_type {
+            IgnoredItemType::User | IgnoredItemType::UserProfile | IgnoredItemType::UserPrivateMessage => {
+                Some(IgnoreStatus::IgnoredUser)
+            }
+            IgnoredItemType::Group | IgnoredItemType::GroupProfile => Some(IgnoreStatus::IgnoredGroup),
+            IgnoredItemType::Post | IgnoredItemType::GroupWallPost | IgnoredItemType::PrivateMessage => {
+                Some(IgnoreStatus::IgnoredPost)
+            }
+            IgnoredItemType::Tag => Some(IgnoreStatus::IgnoredTag),
+            IgnoredItemType::All => None,
+        }
+    }
+
+    /// Rejects combinations Bungie would refuse (or silently misapply) before the request is sent.
+    pub fn validate(&self) -> Result<()> {
+        if self.ignored_item_type == IgnoredItemType::All {
+            return Err(anyhow!("An ignore request must name a specific item type"));
+        }
+
+        if self.ignored_item_type != IgnoredItemType::Tag && self.ignored_item_id.parse::<i64>().is_err() {
+            return Err(anyhow!(
+                "'{}' is not a valid ID for an ignored {:?}",
+                self.ignored_item_id,
+                self.ignored_item_type
+            ));
+        }
+
+        match (self.item_context_type, self.item_context_id.as_deref()) {
+            (IgnoredItemType::All, None) => {}
+            (IgnoredItemType::Group, Some(context_id)) if context_id.parse::<i64>().is_ok() => {
+                if !matches!(self.ignored_item_type, IgnoredItemType::Post | IgnoredItemType::GroupWallPost) {
+                    return Err(anyhow!(
+                        "A group context only applies to posts, not to an ignored {:?}",
+                        self.ignored_item_type
+                    ));
+                }
+            }
+            (context_type, context_id) => {
+                return Err(anyhow!(
+                    "Invalid ignore context: type {:?} with ID {:?}",
+                    context_type,
+                    context_id
+                ));
+            }
+        }
+
+        if self.ban_length != IgnoreLength::None && !self.moderator_request {
+            return Err(anyhow!("A ban length can only be set on a moderator request"));
+        }
+
+        Ok(())
+    }
+}
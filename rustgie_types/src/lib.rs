@@ -1,40 +1,88 @@
 ﻿#![forbid(unsafe_code)]
 
+#[cfg(feature = "full")]
 pub mod api_response_;
+#[cfg(feature = "full")]
 pub mod applications;
+#[cfg(feature = "changelog")]
+pub mod changelog;
+#[cfg(feature = "full")]
 pub mod common;
+#[cfg(feature = "full")]
+pub mod compat;
+#[cfg(feature = "full")]
 pub mod components;
+#[cfg(feature = "full")]
 pub mod config;
+#[cfg(feature = "full")]
 pub mod content;
+#[cfg(feature = "full")]
 pub mod dates;
+#[cfg(feature = "full")]
 pub mod destiny;
+#[cfg(feature = "full")]
+pub mod diagnostics;
+#[cfg(feature = "full")]
 pub mod entities;
+#[cfg(feature = "full")]
 pub mod exceptions;
+#[cfg(feature = "full")]
 pub mod fireteam;
+#[cfg(feature = "full")]
 pub mod forum;
+#[cfg(feature = "full")]
 pub mod forums;
+#[cfg(feature = "full")]
 pub mod groups_v2;
+#[cfg(feature = "full")]
+mod ids;
+#[cfg(feature = "full")]
 pub mod ignores;
+#[cfg(feature = "full")]
 pub mod interpolation;
+#[cfg(feature = "full")]
 pub mod links;
+#[cfg(feature = "minimal")]
+pub mod minimal;
+#[cfg(feature = "full")]
 pub mod queries;
+#[cfg(feature = "full")]
+pub mod semantic_eq;
+#[cfg(feature = "full")]
 pub mod social;
+#[cfg(feature = "full")]
 pub mod streaming;
+#[cfg(feature = "full")]
 pub mod tags;
+#[cfg(feature = "full")]
 pub mod tokens;
+#[cfg(feature = "full")]
 pub mod trending;
+#[cfg(feature = "full")]
 pub mod user;
 
+#[cfg(feature = "changelog")]
+pub use changelog::{changes, ChangeKind, ChangeTarget, TypeChange, CHANGES};
+
+#[cfg(feature = "full")]
 use anyhow::{anyhow, Result};
+#[cfg(feature = "full")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
+#[cfg(feature = "full")]
 use serde_with::{serde_as, DisplayFromStr};
+#[cfg(feature = "full")]
 use std::collections::HashMap;
+#[cfg(feature = "full")]
 use std::fmt::{Display, Formatter};
+#[cfg(feature = "full")]
 use std::str::FromStr;
+#[cfg(feature = "full")]
 use time::OffsetDateTime;
 
 /// The types of membership the Accounts system supports. This is the external facing enum used in place of the internal-only Bungie.SharedDefinitions.MembershipType.
+#[cfg(feature = "full")]
 #[repr(i32)]
 #[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BungieMembershipType {
@@ -51,12 +99,21 @@ pub enum BungieMembershipType {
     All = -1,
 }
 
+#[cfg(feature = "full")]
+impl Default for BungieMembershipType {
+    fn default() -> Self {
+        BungieMembershipType::None
+    }
+}
+
+#[cfg(feature = "full")]
 impl Display for BungieMembershipType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
     }
 }
 
+#[cfg(feature = "full")]
 impl FromStr for BungieMembershipType {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -77,6 +134,7 @@ impl FromStr for BungieMembershipType {
 }
 
 /// The types of credentials the Accounts system supports. This is the external facing enum used in place of the internal-only Bungie.SharedDefinitions.CredentialType.
+#[cfg(feature = "full")]
 #[repr(u8)]
 #[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BungieCredentialType {
@@ -96,12 +154,14 @@ pub enum BungieCredentialType {
     EgsId = 20,
 }
 
+#[cfg(feature = "full")]
 impl Display for BungieCredentialType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as u8)
     }
 }
 
+#[cfg(feature = "full")]
 impl FromStr for BungieCredentialType {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -125,6 +185,7 @@ impl FromStr for BungieCredentialType {
     }
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfContentItemPublicContract {
     #[serde(rename = "results")]
@@ -150,6 +211,7 @@ pub struct SearchResultOfContentItemPublicContract {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfPostResponse {
     #[serde(rename = "results")]
@@ -175,6 +237,7 @@ pub struct SearchResultOfPostResponse {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupV2Card {
     #[serde(rename = "results")]
@@ -200,6 +263,7 @@ pub struct SearchResultOfGroupV2Card {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupMember {
     #[serde(rename = "results")]
@@ -225,6 +289,7 @@ pub struct SearchResultOfGroupMember {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupBan {
     #[serde(rename = "results")]
@@ -250,6 +315,7 @@ pub struct SearchResultOfGroupBan {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupMemberApplication {
     #[serde(rename = "results")]
@@ -275,6 +341,7 @@ pub struct SearchResultOfGroupMemberApplication {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupMembership {
     #[serde(rename = "results")]
@@ -300,6 +367,7 @@ pub struct SearchResultOfGroupMembership {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfGroupPotentialMembership {
     #[serde(rename = "results")]
@@ -325,6 +393,7 @@ pub struct SearchResultOfGroupPotentialMembership {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyVendorReceiptsComponent {
     #[serde(rename = "data")]
@@ -338,6 +407,7 @@ pub struct SingleComponentResponseOfDestinyVendorReceiptsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyInventoryComponent {
     #[serde(rename = "data")]
@@ -351,6 +421,7 @@ pub struct SingleComponentResponseOfDestinyInventoryComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyProfileComponent {
     #[serde(rename = "data")]
@@ -364,6 +435,7 @@ pub struct SingleComponentResponseOfDestinyProfileComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyPlatformSilverComponent {
     #[serde(rename = "data")]
@@ -377,6 +449,7 @@ pub struct SingleComponentResponseOfDestinyPlatformSilverComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyKiosksComponent {
     #[serde(rename = "data")]
@@ -390,6 +463,7 @@ pub struct SingleComponentResponseOfDestinyKiosksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyPlugSetsComponent {
     #[serde(rename = "data")]
@@ -403,6 +477,7 @@ pub struct SingleComponentResponseOfDestinyPlugSetsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyProfileProgressionComponent {
     #[serde(rename = "data")]
@@ -416,6 +491,7 @@ pub struct SingleComponentResponseOfDestinyProfileProgressionComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyPresentationNodesComponent {
     #[serde(rename = "data")]
@@ -429,6 +505,7 @@ pub struct SingleComponentResponseOfDestinyPresentationNodesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyProfileRecordsComponent {
     #[serde(rename = "data")]
@@ -442,6 +519,7 @@ pub struct SingleComponentResponseOfDestinyProfileRecordsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyProfileCollectiblesComponent {
     #[serde(rename = "data")]
@@ -455,6 +533,7 @@ pub struct SingleComponentResponseOfDestinyProfileCollectiblesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyProfileTransitoryComponent {
     #[serde(rename = "data")]
@@ -468,6 +547,7 @@ pub struct SingleComponentResponseOfDestinyProfileTransitoryComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyMetricsComponent {
     #[serde(rename = "data")]
@@ -481,6 +561,7 @@ pub struct SingleComponentResponseOfDestinyMetricsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyStringVariablesComponent {
     #[serde(rename = "data")]
@@ -494,6 +575,7 @@ pub struct SingleComponentResponseOfDestinyStringVariablesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinySocialCommendationsComponent {
     #[serde(rename = "data")]
@@ -507,6 +589,7 @@ pub struct SingleComponentResponseOfDestinySocialCommendationsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCharacterComponent {
@@ -522,6 +605,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCharacterComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyInventoryComponent {
@@ -537,6 +621,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyInventoryComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyLoadoutsComponent {
@@ -552,6 +637,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyLoadoutsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCharacterProgressionComponent {
@@ -567,6 +653,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCharacterProgressionCompo
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCharacterRenderComponent {
@@ -582,6 +669,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCharacterRenderComponent
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCharacterActivitiesComponent {
@@ -597,6 +685,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCharacterActivitiesCompon
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyKiosksComponent {
@@ -612,6 +701,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyKiosksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyPlugSetsComponent {
@@ -627,6 +717,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyPlugSetsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyBaseItemComponentSetOfuint32 {
     #[serde(rename = "objectives")]
@@ -636,6 +727,7 @@ pub struct DestinyBaseItemComponentSetOfuint32 {
     pub perks: Option<crate::DictionaryComponentResponseOfuint32AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemObjectivesComponent {
     #[serde(rename = "data")]
@@ -649,6 +741,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemObjectivesComponent
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemPerksComponent {
     #[serde(rename = "data")]
@@ -662,6 +755,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemPerksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyPresentationNodesComponent {
@@ -677,6 +771,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyPresentationNodesComponen
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCharacterRecordsComponent {
@@ -692,6 +787,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCharacterRecordsComponent
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCollectiblesComponent {
@@ -707,6 +803,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCollectiblesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyStringVariablesComponent {
@@ -722,6 +819,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyStringVariablesComponent
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCraftablesComponent {
@@ -737,6 +835,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCraftablesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyBaseItemComponentSetOfint64 {
     #[serde(rename = "objectives")]
@@ -746,6 +845,7 @@ pub struct DestinyBaseItemComponentSetOfint64 {
     pub perks: Option<crate::DictionaryComponentResponseOfint64AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemObjectivesComponent {
@@ -761,6 +861,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemObjectivesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemPerksComponent {
@@ -776,6 +877,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemPerksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemComponentSetOfint64 {
     #[serde(rename = "instances")]
@@ -809,6 +911,7 @@ pub struct DestinyItemComponentSetOfint64 {
     pub perks: Option<crate::DictionaryComponentResponseOfint64AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent {
@@ -824,6 +927,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemRenderComponent {
@@ -839,6 +943,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemRenderComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemStatsComponent {
@@ -854,6 +959,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemStatsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemSocketsComponent {
@@ -869,6 +975,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemSocketsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemReusablePlugsComponent {
@@ -884,6 +991,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemReusablePlugsComponen
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemPlugObjectivesComponent {
@@ -899,6 +1007,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemPlugObjectivesCompone
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyItemTalentGridComponent {
@@ -914,6 +1023,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyItemTalentGridComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemPlugComponent {
     #[serde(rename = "data")]
@@ -927,6 +1037,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemPlugComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint64AndDestinyCurrenciesComponent {
@@ -942,6 +1053,7 @@ pub struct DictionaryComponentResponseOfint64AndDestinyCurrenciesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCharacterComponent {
     #[serde(rename = "data")]
@@ -955,6 +1067,7 @@ pub struct SingleComponentResponseOfDestinyCharacterComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCharacterProgressionComponent {
     #[serde(rename = "data")]
@@ -968,6 +1081,7 @@ pub struct SingleComponentResponseOfDestinyCharacterProgressionComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCharacterRenderComponent {
     #[serde(rename = "data")]
@@ -981,6 +1095,7 @@ pub struct SingleComponentResponseOfDestinyCharacterRenderComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCharacterActivitiesComponent {
     #[serde(rename = "data")]
@@ -994,6 +1109,7 @@ pub struct SingleComponentResponseOfDestinyCharacterActivitiesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyLoadoutsComponent {
     #[serde(rename = "data")]
@@ -1007,6 +1123,7 @@ pub struct SingleComponentResponseOfDestinyLoadoutsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCharacterRecordsComponent {
     #[serde(rename = "data")]
@@ -1020,6 +1137,7 @@ pub struct SingleComponentResponseOfDestinyCharacterRecordsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCollectiblesComponent {
     #[serde(rename = "data")]
@@ -1033,6 +1151,7 @@ pub struct SingleComponentResponseOfDestinyCollectiblesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyCurrenciesComponent {
     #[serde(rename = "data")]
@@ -1046,6 +1165,7 @@ pub struct SingleComponentResponseOfDestinyCurrenciesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemComponent {
     #[serde(rename = "data")]
@@ -1059,6 +1179,7 @@ pub struct SingleComponentResponseOfDestinyItemComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemInstanceComponent {
     #[serde(rename = "data")]
@@ -1072,6 +1193,7 @@ pub struct SingleComponentResponseOfDestinyItemInstanceComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemObjectivesComponent {
     #[serde(rename = "data")]
@@ -1085,6 +1207,7 @@ pub struct SingleComponentResponseOfDestinyItemObjectivesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemPerksComponent {
     #[serde(rename = "data")]
@@ -1098,6 +1221,7 @@ pub struct SingleComponentResponseOfDestinyItemPerksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemRenderComponent {
     #[serde(rename = "data")]
@@ -1111,6 +1235,7 @@ pub struct SingleComponentResponseOfDestinyItemRenderComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemStatsComponent {
     #[serde(rename = "data")]
@@ -1124,6 +1249,7 @@ pub struct SingleComponentResponseOfDestinyItemStatsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemTalentGridComponent {
     #[serde(rename = "data")]
@@ -1137,6 +1263,7 @@ pub struct SingleComponentResponseOfDestinyItemTalentGridComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemSocketsComponent {
     #[serde(rename = "data")]
@@ -1150,6 +1277,7 @@ pub struct SingleComponentResponseOfDestinyItemSocketsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemReusablePlugsComponent {
     #[serde(rename = "data")]
@@ -1163,6 +1291,7 @@ pub struct SingleComponentResponseOfDestinyItemReusablePlugsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyItemPlugObjectivesComponent {
     #[serde(rename = "data")]
@@ -1176,6 +1305,7 @@ pub struct SingleComponentResponseOfDestinyItemPlugObjectivesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyVendorGroupComponent {
     #[serde(rename = "data")]
@@ -1189,6 +1319,7 @@ pub struct SingleComponentResponseOfDestinyVendorGroupComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyVendorComponent {
     #[serde(rename = "data")]
@@ -1202,6 +1333,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyVendorComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyVendorCategoriesComponent {
     #[serde(rename = "data")]
@@ -1215,12 +1347,14 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyVendorCategoriesComponen
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyVendorSaleItemSetComponentOfDestinyVendorSaleItemComponent {
     #[serde(rename = "saleItems")]
     pub sale_items: Option<HashMap<i32, crate::destiny::entities::vendors::DestinyVendorSaleItemComponent>>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndPersonalDestinyVendorSaleItemSetComponent {
     #[serde(rename = "data")]
@@ -1234,6 +1368,7 @@ pub struct DictionaryComponentResponseOfuint32AndPersonalDestinyVendorSaleItemSe
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyBaseItemComponentSetOfint32 {
     #[serde(rename = "objectives")]
@@ -1243,6 +1378,7 @@ pub struct DestinyBaseItemComponentSetOfint32 {
     pub perks: Option<crate::DictionaryComponentResponseOfint32AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemObjectivesComponent {
     #[serde(rename = "data")]
@@ -1256,6 +1392,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemObjectivesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemPerksComponent {
     #[serde(rename = "data")]
@@ -1269,6 +1406,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemPerksComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemComponentSetOfint32 {
     #[serde(rename = "instances")]
@@ -1302,6 +1440,7 @@ pub struct DestinyItemComponentSetOfint32 {
     pub perks: Option<crate::DictionaryComponentResponseOfint32AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemInstanceComponent {
     #[serde(rename = "data")]
@@ -1315,6 +1454,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemInstanceComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemRenderComponent {
     #[serde(rename = "data")]
@@ -1328,6 +1468,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemRenderComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemStatsComponent {
     #[serde(rename = "data")]
@@ -1341,6 +1482,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemStatsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemSocketsComponent {
     #[serde(rename = "data")]
@@ -1354,6 +1496,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemSocketsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemReusablePlugsComponent {
     #[serde(rename = "data")]
@@ -1367,6 +1510,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemReusablePlugsComponen
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemPlugObjectivesComponent {
     #[serde(rename = "data")]
@@ -1380,6 +1524,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemPlugObjectivesCompone
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyItemTalentGridComponent {
     #[serde(rename = "data")]
@@ -1393,6 +1538,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyItemTalentGridComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyVendorComponent {
     #[serde(rename = "data")]
@@ -1406,6 +1552,7 @@ pub struct SingleComponentResponseOfDestinyVendorComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SingleComponentResponseOfDestinyVendorCategoriesComponent {
     #[serde(rename = "data")]
@@ -1419,6 +1566,7 @@ pub struct SingleComponentResponseOfDestinyVendorCategoriesComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfint32AndDestinyVendorSaleItemComponent {
     #[serde(rename = "data")]
@@ -1432,6 +1580,7 @@ pub struct DictionaryComponentResponseOfint32AndDestinyVendorSaleItemComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyPublicVendorComponent {
     #[serde(rename = "data")]
@@ -1445,12 +1594,14 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyPublicVendorComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyVendorSaleItemSetComponentOfDestinyPublicVendorSaleItemComponent {
     #[serde(rename = "saleItems")]
     pub sale_items: Option<HashMap<i32, crate::destiny::components::vendors::DestinyPublicVendorSaleItemComponent>>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndPublicDestinyVendorSaleItemSetComponent {
     #[serde(rename = "data")]
@@ -1464,6 +1615,7 @@ pub struct DictionaryComponentResponseOfuint32AndPublicDestinyVendorSaleItemSetC
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DestinyItemComponentSetOfuint32 {
     #[serde(rename = "instances")]
@@ -1497,6 +1649,7 @@ pub struct DestinyItemComponentSetOfuint32 {
     pub perks: Option<crate::DictionaryComponentResponseOfuint32AndDestinyItemPerksComponent>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemInstanceComponent {
     #[serde(rename = "data")]
@@ -1510,6 +1663,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemInstanceComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemRenderComponent {
     #[serde(rename = "data")]
@@ -1523,6 +1677,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemRenderComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemStatsComponent {
     #[serde(rename = "data")]
@@ -1536,6 +1691,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemStatsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemSocketsComponent {
     #[serde(rename = "data")]
@@ -1549,6 +1705,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemSocketsComponent {
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemReusablePlugsComponent {
     #[serde(rename = "data")]
@@ -1562,6 +1719,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemReusablePlugsCompone
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemPlugObjectivesComponent {
     #[serde(rename = "data")]
@@ -1575,6 +1733,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemPlugObjectivesCompon
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct DictionaryComponentResponseOfuint32AndDestinyItemTalentGridComponent {
     #[serde(rename = "data")]
@@ -1588,6 +1747,7 @@ pub struct DictionaryComponentResponseOfuint32AndDestinyItemTalentGridComponent
     pub disabled: Option<bool>,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfDestinyEntitySearchResultItem {
     #[serde(rename = "results")]
@@ -1613,6 +1773,7 @@ pub struct SearchResultOfDestinyEntitySearchResultItem {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfTrendingEntry {
     #[serde(rename = "results")]
@@ -1638,6 +1799,7 @@ pub struct SearchResultOfTrendingEntry {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfFireteamSummary {
     #[serde(rename = "results")]
@@ -1663,6 +1825,7 @@ pub struct SearchResultOfFireteamSummary {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct SearchResultOfFireteamResponse {
     #[serde(rename = "results")]
@@ -1688,6 +1851,7 @@ pub struct SearchResultOfFireteamResponse {
     pub use_total_results: bool,
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct GlobalAlert {
     #[serde(rename = "AlertKey")]
@@ -1713,6 +1877,7 @@ pub struct GlobalAlert {
     pub stream_info: Option<crate::StreamInfo>,
 }
 
+#[cfg(feature = "full")]
 #[repr(i32)]
 #[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum GlobalAlertLevel {
@@ -1722,12 +1887,14 @@ pub enum GlobalAlertLevel {
     Red = 3,
 }
 
+#[cfg(feature = "full")]
 impl Display for GlobalAlertLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
     }
 }
 
+#[cfg(feature = "full")]
 impl FromStr for GlobalAlertLevel {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -1741,6 +1908,7 @@ impl FromStr for GlobalAlertLevel {
     }
 }
 
+#[cfg(feature = "full")]
 #[repr(i32)]
 #[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum GlobalAlertType {
@@ -1748,12 +1916,14 @@ pub enum GlobalAlertType {
     StreamingAlert = 1,
 }
 
+#[cfg(feature = "full")]
 impl Display for GlobalAlertType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", *self as i32)
     }
 }
 
+#[cfg(feature = "full")]
 impl FromStr for GlobalAlertType {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -1765,8 +1935,156 @@ impl FromStr for GlobalAlertType {
     }
 }
 
+#[cfg(feature = "full")]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct StreamInfo {
     #[serde(rename = "ChannelName")]
     pub channel_name: Option<String>,
 }
+
+/// The stream Bungie is currently featuring, from global alerts fetched with `includestreaming`.
+/// Only a [`GlobalAlertType::StreamingAlert`] naming a channel counts; if there are several, the first is returned.
+#[cfg(feature = "full")]
+pub fn active_stream(alerts: &[GlobalAlert]) -> Option<StreamInfo> {
+    alerts
+        .iter()
+        .filter(|alert| alert.alert_type == GlobalAlertType::StreamingAlert)
+        .filter_map(|alert| alert.stream_info.as_ref())
+        .find(|stream| stream.channel_name.as_deref().map_or(false, |name| !name.is_empty()))
+        .cloned()
+}
+
+#[cfg(feature = "full")]
+macro_rules! impl_component_response_state {
+    (single: $($single:ident),* ; dictionary: $($dictionary:ident<$key:ty, $value:ty>),* $(,)?) => {
+        $(impl_component_response_state!(@impl $single, single);)*
+        $(
+            impl_component_response_state!(@impl $dictionary, dictionary);
+
+            impl $dictionary {
+                /// Whatever entries came back, or none when the component has no data or is disabled; see [`crate::components::ComponentEntries`].
+                pub fn entries(&self) -> crate::components::ComponentEntries<'_, $key, $value> {
+                    crate::components::ComponentEntries::new(self.data.as_ref(), self.disabled)
+                }
+            }
+        )*
+    };
+    (@impl $wrapper:ident, $kind:ident) => {
+        impl crate::components::ComponentResponseState for $wrapper {
+            fn privacy_setting(&self) -> crate::components::ComponentPrivacySetting {
+                self.privacy
+            }
+
+            fn is_disabled(&self) -> bool {
+                self.disabled.unwrap_or(false)
+            }
+
+            fn has_data(&self) -> bool {
+                self.data.is_some()
+            }
+
+            fn entry_count(&self) -> usize {
+                impl_component_response_state!(@entries self, $kind)
+            }
+
+            fn access(&self) -> crate::components::ComponentAccess {
+                crate::components::classify(&self.data, self.privacy, self.disabled)
+            }
+        }
+    };
+    (@entries $wrapper:ident, single) => {
+        usize::from($wrapper.data.is_some())
+    };
+    (@entries $wrapper:ident, dictionary) => {
+        $wrapper.data.as_ref().map_or(0, HashMap::len)
+    };
+}
+
+#[cfg(feature = "full")]
+impl_component_response_state!(
+    single:
+    SingleComponentResponseOfDestinyVendorReceiptsComponent,
+    SingleComponentResponseOfDestinyInventoryComponent,
+    SingleComponentResponseOfDestinyProfileComponent,
+    SingleComponentResponseOfDestinyPlatformSilverComponent,
+    SingleComponentResponseOfDestinyKiosksComponent,
+    SingleComponentResponseOfDestinyPlugSetsComponent,
+    SingleComponentResponseOfDestinyProfileProgressionComponent,
+    SingleComponentResponseOfDestinyPresentationNodesComponent,
+    SingleComponentResponseOfDestinyProfileRecordsComponent,
+    SingleComponentResponseOfDestinyProfileCollectiblesComponent,
+    SingleComponentResponseOfDestinyProfileTransitoryComponent,
+    SingleComponentResponseOfDestinyMetricsComponent,
+    SingleComponentResponseOfDestinyStringVariablesComponent,
+    SingleComponentResponseOfDestinySocialCommendationsComponent,
+    SingleComponentResponseOfDestinyCharacterComponent,
+    SingleComponentResponseOfDestinyCharacterProgressionComponent,
+    SingleComponentResponseOfDestinyCharacterRenderComponent,
+    SingleComponentResponseOfDestinyCharacterActivitiesComponent,
+    SingleComponentResponseOfDestinyLoadoutsComponent,
+    SingleComponentResponseOfDestinyCharacterRecordsComponent,
+    SingleComponentResponseOfDestinyCollectiblesComponent,
+    SingleComponentResponseOfDestinyCurrenciesComponent,
+    SingleComponentResponseOfDestinyItemComponent,
+    SingleComponentResponseOfDestinyItemInstanceComponent,
+    SingleComponentResponseOfDestinyItemObjectivesComponent,
+    SingleComponentResponseOfDestinyItemPerksComponent,
+    SingleComponentResponseOfDestinyItemRenderComponent,
+    SingleComponentResponseOfDestinyItemStatsComponent,
+    SingleComponentResponseOfDestinyItemTalentGridComponent,
+    SingleComponentResponseOfDestinyItemSocketsComponent,
+    SingleComponentResponseOfDestinyItemReusablePlugsComponent,
+    SingleComponentResponseOfDestinyItemPlugObjectivesComponent,
+    SingleComponentResponseOfDestinyVendorGroupComponent,
+    SingleComponentResponseOfDestinyVendorComponent,
+    SingleComponentResponseOfDestinyVendorCategoriesComponent;
+    dictionary:
+    DictionaryComponentResponseOfint64AndDestinyCharacterComponent<i64, crate::destiny::entities::characters::DestinyCharacterComponent>,
+    DictionaryComponentResponseOfint64AndDestinyInventoryComponent<i64, crate::destiny::entities::inventory::DestinyInventoryComponent>,
+    DictionaryComponentResponseOfint64AndDestinyLoadoutsComponent<i64, crate::destiny::components::loadouts::DestinyLoadoutsComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCharacterProgressionComponent<i64, crate::destiny::entities::characters::DestinyCharacterProgressionComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCharacterRenderComponent<i64, crate::destiny::entities::characters::DestinyCharacterRenderComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCharacterActivitiesComponent<i64, crate::destiny::entities::characters::DestinyCharacterActivitiesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyKiosksComponent<i64, crate::destiny::components::kiosks::DestinyKiosksComponent>,
+    DictionaryComponentResponseOfint64AndDestinyPlugSetsComponent<i64, crate::destiny::components::plug_sets::DestinyPlugSetsComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemObjectivesComponent<u32, crate::destiny::entities::items::DestinyItemObjectivesComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemPerksComponent<u32, crate::destiny::entities::items::DestinyItemPerksComponent>,
+    DictionaryComponentResponseOfint64AndDestinyPresentationNodesComponent<i64, crate::destiny::components::presentation::DestinyPresentationNodesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCharacterRecordsComponent<i64, crate::destiny::components::records::DestinyCharacterRecordsComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCollectiblesComponent<i64, crate::destiny::components::collectibles::DestinyCollectiblesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyStringVariablesComponent<i64, crate::destiny::components::string_variables::DestinyStringVariablesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCraftablesComponent<i64, crate::destiny::components::craftables::DestinyCraftablesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemObjectivesComponent<i64, crate::destiny::entities::items::DestinyItemObjectivesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemPerksComponent<i64, crate::destiny::entities::items::DestinyItemPerksComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemInstanceComponent<i64, crate::destiny::entities::items::DestinyItemInstanceComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemRenderComponent<i64, crate::destiny::entities::items::DestinyItemRenderComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemStatsComponent<i64, crate::destiny::entities::items::DestinyItemStatsComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemSocketsComponent<i64, crate::destiny::entities::items::DestinyItemSocketsComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemReusablePlugsComponent<i64, crate::destiny::components::items::DestinyItemReusablePlugsComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemPlugObjectivesComponent<i64, crate::destiny::components::items::DestinyItemPlugObjectivesComponent>,
+    DictionaryComponentResponseOfint64AndDestinyItemTalentGridComponent<i64, crate::destiny::entities::items::DestinyItemTalentGridComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemPlugComponent<u32, crate::destiny::components::items::DestinyItemPlugComponent>,
+    DictionaryComponentResponseOfint64AndDestinyCurrenciesComponent<i64, crate::destiny::components::inventory::DestinyCurrenciesComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyVendorComponent<u32, crate::destiny::entities::vendors::DestinyVendorComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyVendorCategoriesComponent<u32, crate::destiny::entities::vendors::DestinyVendorCategoriesComponent>,
+    DictionaryComponentResponseOfuint32AndPersonalDestinyVendorSaleItemSetComponent<u32, crate::destiny::responses::PersonalDestinyVendorSaleItemSetComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemObjectivesComponent<i32, crate::destiny::entities::items::DestinyItemObjectivesComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemPerksComponent<i32, crate::destiny::entities::items::DestinyItemPerksComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemInstanceComponent<i32, crate::destiny::entities::items::DestinyItemInstanceComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemRenderComponent<i32, crate::destiny::entities::items::DestinyItemRenderComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemStatsComponent<i32, crate::destiny::entities::items::DestinyItemStatsComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemSocketsComponent<i32, crate::destiny::entities::items::DestinyItemSocketsComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemReusablePlugsComponent<i32, crate::destiny::components::items::DestinyItemReusablePlugsComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemPlugObjectivesComponent<i32, crate::destiny::components::items::DestinyItemPlugObjectivesComponent>,
+    DictionaryComponentResponseOfint32AndDestinyItemTalentGridComponent<i32, crate::destiny::entities::items::DestinyItemTalentGridComponent>,
+    DictionaryComponentResponseOfint32AndDestinyVendorSaleItemComponent<i32, crate::destiny::entities::vendors::DestinyVendorSaleItemComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyPublicVendorComponent<u32, crate::destiny::components::vendors::DestinyPublicVendorComponent>,
+    DictionaryComponentResponseOfuint32AndPublicDestinyVendorSaleItemSetComponent<u32, crate::destiny::responses::PublicDestinyVendorSaleItemSetComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemInstanceComponent<u32, crate::destiny::entities::items::DestinyItemInstanceComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemRenderComponent<u32, crate::destiny::entities::items::DestinyItemRenderComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemStatsComponent<u32, crate::destiny::entities::items::DestinyItemStatsComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemSocketsComponent<u32, crate::destiny::entities::items::DestinyItemSocketsComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemReusablePlugsComponent<u32, crate::destiny::components::items::DestinyItemReusablePlugsComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemPlugObjectivesComponent<u32, crate::destiny::components::items::DestinyItemPlugObjectivesComponent>,
+    DictionaryComponentResponseOfuint32AndDestinyItemTalentGridComponent<u32, crate::destiny::entities::items::DestinyItemTalentGridComponent>,
+);
@@ -0,0 +1,726 @@
+﻿//! A small copy of the definition types build scripts and procedural macros most often read, for builds that can't afford the whole crate.
+//!
+//! Depend on `rustgie_types` with `default-features = false, features = ["minimal"]` and this is all that gets compiled: no `enumflags2`,
+//! `time`, `serde_with`, `serde_json` or `anyhow`. These types are separate from the ones the default `full` feature provides and keep only the
+//! fields listed on them; unknown fields are ignored, so they read the same definition JSON. Flags fields hold their raw bits and dates the
+//! strings Bungie sent, and unknown enum values fall back to the enum's default instead of failing.
+//!
+//! The subset:
+//! - [`DestinyManifest`], for the JSON content paths
+//! - [`DestinyDisplayPropertiesDefinition`] and [`DestinyIconSequenceDefinition`]
+//! - [`DestinyItemQuantity`]
+//! - [`DestinyInventoryItemDefinition`], with its [`DestinyItemTooltipNotification`], [`DestinyItemInventoryBlockDefinition`], [`DestinyItemStatBlockDefinition`],
+//!   [`DestinyInventoryItemStatDefinition`], [`DestinyEquippingBlockDefinition`], [`DestinyItemValueBlockDefinition`], [`DestinyItemSocketBlockDefinition`],
+//!   [`DestinyItemSocketEntryDefinition`], [`DestinyItemSocketEntryPlugItemDefinition`], [`DestinyItemIntrinsicSocketEntryDefinition`] and [`DestinyItemSocketCategoryDefinition`]
+//! - [`DestinySeasonDefinition`]
+//! - the enums [`DestinyItemType`], [`DestinyItemSubType`], [`TierType`], [`DestinyClass`], [`DamageType`] and [`DestinyAmmunitionType`]
+
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+
+/// Bungie sends 64-bit IDs as strings.
+mod string_i64 {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            None => serializer.serialize_none(),
+            Some(value) => serializer.serialize_some(&value.to_string()),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+/// Reads an integer-backed enum, falling back to its default on values it doesn't know, like the full crate's `LenientEnum`.
+fn lenient<'de, D: Deserializer<'de>, T: Deserialize<'de> + Default>(deserializer: D) -> Result<T, D::Error> {
+    let value = i64::deserialize(deserializer)?;
+    let known: Result<T, serde::de::value::Error> = T::deserialize(value.into_deserializer());
+    Ok(known.unwrap_or_default())
+}
+
+/// DestinyManifest is the external-facing contract for just the properties needed by those calling the Destiny Platform.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyManifest {
+    #[serde(rename = "version")]
+    pub version: Option<String>,
+
+    #[serde(rename = "mobileWorldContentPaths")]
+    pub mobile_world_content_paths: Option<HashMap<String, String>>,
+
+    /// This points to the generated JSON that contains all the Definitions. Each key is a locale. The value is a path to the aggregated world definitions (warning: large file!)
+    #[serde(rename = "jsonWorldContentPaths")]
+    pub json_world_content_paths: Option<HashMap<String, String>>,
+
+    /// This points to the generated JSON that contains all the Definitions. Each key is a locale. The value is a dictionary, where the key is a definition type by name, and the value is the path to the file for that definition. WARNING: This is unsafe and subject to change - do not depend on data in these files staying around long-term.
+    #[serde(rename = "jsonWorldComponentContentPaths")]
+    pub json_world_component_content_paths: Option<HashMap<String, HashMap<String, String>>>,
+}
+
+/// Many Destiny*Definition contracts - the "first order" entities of Destiny that have their own tables in the Manifest Database - also have displayable information. This is the base class for that display information.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyDisplayPropertiesDefinition {
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+
+    /// Note that "icon" is sometimes misleading, and should be interpreted in the context of the entity. For instance, in Destiny 1 the DestinyRecordBookDefinition's icon was a big picture of a book.
+    /// But usually, it will be a small square image that you can use as... well, an icon.
+    /// They are currently represented as 96px x 96px images.
+    #[serde(rename = "icon")]
+    pub icon: Option<String>,
+
+    #[serde(rename = "iconSequences")]
+    pub icon_sequences: Option<Vec<DestinyIconSequenceDefinition>>,
+
+    /// If this item has a high-res icon (at least for now, many things won't), then the path to that icon will be here.
+    #[serde(rename = "highResIcon")]
+    pub high_res_icon: Option<String>,
+
+    #[serde(rename = "hasIcon")]
+    pub has_icon: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyIconSequenceDefinition {
+    #[serde(rename = "frames")]
+    pub frames: Option<Vec<String>>,
+}
+
+/// Used in a number of Destiny contracts to return data about an item stack and its quantity. Can optionally return an itemInstanceId if the item is instanced - in which case, the quantity returned will be 1. If it's not... uh, let me know okay? Thanks.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemQuantity {
+    /// The hash identifier for the item in question. Use it to look up the item's DestinyInventoryItemDefinition.
+    #[serde(rename = "itemHash")]
+    pub item_hash: u32,
+
+    /// If this quantity is referring to a specific instance of an item, this will have the item's instance ID. Normally, this will be null.
+    #[serde(with = "string_i64")]
+    #[serde(default)]
+    #[serde(rename = "itemInstanceId")]
+    pub item_instance_id: Option<i64>,
+
+    /// The amount of the item needed/available depending on the context of where DestinyItemQuantity is being used.
+    #[serde(rename = "quantity")]
+    pub quantity: i32,
+
+    /// Indicates that this item quantity may be conditionally shown or hidden, based on various sources of state. For example: server flags, account state, or character progress.
+    #[serde(rename = "hasConditionalVisibility")]
+    pub has_conditional_visibility: bool,
+}
+
+/// So much of what you see in Destiny is actually an Item used in a new and creative way. This is the definition for Items in Destiny, which started off as just entities that could exist in your Inventory but ended up being the backing data for so much more: quests, reward previews, slots, and subclasses.
+/// In practice, you will want to associate this data with "live" item data from a Bungie.Net Platform call: these definitions describe the item in generic, non-instanced terms: but an actual instance of an item can vary widely from these generic definitions.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyInventoryItemDefinition {
+    #[serde(rename = "displayProperties")]
+    pub display_properties: Option<DestinyDisplayPropertiesDefinition>,
+
+    /// Tooltips that only come up conditionally for the item. Check the live data DestinyItemComponent.tooltipNotificationIndexes property for which of these should be shown at runtime.
+    #[serde(rename = "tooltipNotifications")]
+    pub tooltip_notifications: Option<Vec<DestinyItemTooltipNotification>>,
+
+    /// If this item has a collectible related to it, this is the hash identifier of that collectible entry.
+    #[serde(rename = "collectibleHash")]
+    pub collectible_hash: Option<u32>,
+
+    /// If available, this is the original 'active' release watermark overlay for the icon. If the item has different versions, this can be overridden by the 'display version watermark icon' from the 'quality' block. Alternatively, if there is no watermark for the version, and the item version has a power cap below the current season power cap, this can be overridden by the iconWatermarkShelved property.
+    #[serde(rename = "iconWatermark")]
+    pub icon_watermark: Option<String>,
+
+    /// If available, this is the 'shelved' release watermark overlay for the icon. If the item version has a power cap below the current season power cap, it can be treated as 'shelved', and should be shown with this 'shelved' watermark overlay.
+    #[serde(rename = "iconWatermarkShelved")]
+    pub icon_watermark_shelved: Option<String>,
+
+    /// A secondary icon associated with the item. Currently this is used in very context specific applications, such as Emblem Nameplates.
+    #[serde(rename = "secondaryIcon")]
+    pub secondary_icon: Option<String>,
+
+    /// If we were able to acquire an in-game screenshot for the item, the path to that screenshot will be returned here. Note that not all items have screenshots: particularly not any non-equippable items.
+    #[serde(rename = "screenshot")]
+    pub screenshot: Option<String>,
+
+    /// The localized title/name of the item's type. This can be whatever the designers want, and has no guarantee of consistency between items.
+    #[serde(rename = "itemTypeDisplayName")]
+    pub item_type_display_name: Option<String>,
+
+    #[serde(rename = "flavorText")]
+    pub flavor_text: Option<String>,
+
+    /// A string identifier that the game's UI uses to determine how the item should be rendered in inventory screens and the like. This could really be anything - at the moment, we don't have the time to really breakdown and maintain all the possible strings this could be, partly because new ones could be added ad hoc. But if you want to use it to dictate your own UI, or look for items with a certain display style, go for it!
+    #[serde(rename = "uiItemDisplayStyle")]
+    pub ui_item_display_style: Option<String>,
+
+    /// It became a common enough pattern in our UI to show Item Type and Tier combined into a single localized string that I'm just going to go ahead and start pre-creating these for items.
+    #[serde(rename = "itemTypeAndTierDisplayName")]
+    pub item_type_and_tier_display_name: Option<String>,
+
+    /// In theory, it is a localized string telling you about how you can find the item. I really wish this was more consistent. Many times, it has nothing. Sometimes, it's instead a more narrative-forward description of the item. Which is cool, and I wish all properties had that data, but it should really be its own property.
+    #[serde(rename = "displaySource")]
+    pub display_source: Option<String>,
+
+    /// If this item can exist in an inventory, this block will be non-null. In practice, every item that currently exists has one of these blocks. But note that it is not necessarily guaranteed.
+    #[serde(rename = "inventory")]
+    pub inventory: Option<DestinyItemInventoryBlockDefinition>,
+
+    /// If this item can have stats (such as a weapon, armor, or vehicle), this block will be non-null and populated with the stats found on the item.
+    #[serde(rename = "stats")]
+    pub stats: Option<DestinyItemStatBlockDefinition>,
+
+    /// If this item can be equipped, this block will be non-null and will be populated with the conditions under which it can be equipped.
+    #[serde(rename = "equippingBlock")]
+    pub equipping_block: Option<DestinyEquippingBlockDefinition>,
+
+    /// The conceptual "Value" of an item, if any was defined. See the DestinyItemValueBlockDefinition for more details.
+    #[serde(rename = "value")]
+    pub value: Option<DestinyItemValueBlockDefinition>,
+
+    /// If this item has any Sockets, this will be non-null and the individual sockets on the item will be defined herein.
+    #[serde(rename = "sockets")]
+    pub sockets: Option<DestinyItemSocketBlockDefinition>,
+
+    /// If the item has any related Lore (DestinyLoreDefinition), this will be the hash identifier you can use to look up the lore definition.
+    #[serde(rename = "loreHash")]
+    pub lore_hash: Option<u32>,
+
+    /// The intrinsic transferability of an item.
+    /// I hate that this boolean is negative - but there's a reason.
+    /// Just because an item is intrinsically transferrable doesn't mean that it can be transferred, and we don't want to imply that this is the only source of that transferability.
+    #[serde(rename = "nonTransferrable")]
+    pub non_transferrable: bool,
+
+    /// BNet attempts to make a more formal definition of item "Categories", as defined by DestinyItemCategoryDefinition. This is a list of all Categories that we were able to algorithmically determine that this item is a member of. (for instance, that it's a "Weapon", that it's an "Auto Rifle", etc...)
+    /// The algorithm for these is, unfortunately, volatile. If you believe you see a miscategorized item, please let us know on the Bungie API forums.
+    #[serde(rename = "itemCategoryHashes")]
+    pub item_category_hashes: Option<Vec<u32>>,
+
+    /// A value indicating the "base" the of the item. This enum is a useful but dramatic oversimplification of what it means for an item to have a "Type". Still, it's handy in many situations.
+    /// itemCategoryHashes are the preferred way of identifying types, we have retained this enum for its convenience.
+    #[serde(deserialize_with = "lenient")]
+    #[serde(rename = "itemType")]
+    pub item_type: DestinyItemType,
+
+    /// A value indicating the "sub-type" of the item. For instance, where an item might have an itemType value "Weapon", this will be something more specific like "Auto Rifle".
+    /// itemCategoryHashes are the preferred way of identifying types, we have retained this enum for its convenience.
+    #[serde(deserialize_with = "lenient")]
+    #[serde(rename = "itemSubType")]
+    pub item_sub_type: DestinyItemSubType,
+
+    /// We run a similarly weak-sauce algorithm to try and determine whether an item is restricted to a specific class. If we find it to be restricted in such a way, we set this classType property to match the class' enumeration value so that users can easily identify class restricted items.
+    /// If you see a mis-classed item, please inform the developers in the Bungie API forum.
+    #[serde(rename = "classType")]
+    pub class_type: DestinyClass,
+
+    /// If true, then you will be allowed to equip the item if you pass its other requirements.
+    /// This being false means that you cannot equip the item under any circumstances.
+    #[serde(rename = "equippable")]
+    pub equippable: bool,
+
+    /// Theoretically, an item can have many possible damage types. In *practice*, this is not true, but just in case weapons start being made that have multiple (for instance, an item where a socket has reusable plugs for every possible damage type that you can choose from freely), this field will return all of the possible damage types that are available to the weapon by default.
+    #[serde(rename = "damageTypeHashes")]
+    pub damage_type_hashes: Option<Vec<u32>>,
+
+    /// If the item has a damage type that could be considered to be default, it will be populated here.
+    /// For various upsetting reasons, it's surprisingly cumbersome to figure this out. I hope you're happy.
+    #[serde(rename = "defaultDamageType")]
+    pub default_damage_type: DamageType,
+
+    /// Similar to defaultDamageType, but represented as the hash identifier for a DestinyDamageTypeDefinition.
+    /// I will likely regret leaving in the enumeration versions of these properties, but for now they're very convenient.
+    #[serde(rename = "defaultDamageTypeHash")]
+    pub default_damage_type_hash: Option<u32>,
+
+    /// If this item is related directly to a Season of Destiny, this is the hash identifier for that season.
+    #[serde(rename = "seasonHash")]
+    pub season_hash: Option<u32>,
+
+    /// If true, this is a dummy vendor-wrapped item template. Items purchased from Eververse will be "wrapped" by one of these items so that we can safely provide refund capabilities before the item is "unwrapped".
+    #[serde(rename = "isWrapper")]
+    pub is_wrapper: bool,
+
+    /// Traits are metadata tags applied to this item. For example: armor slot, weapon type, foundry, faction, etc. These IDs come from the game and don't map to any content, but should still be useful.
+    #[serde(rename = "traitIds")]
+    pub trait_ids: Option<Vec<String>>,
+
+    /// These are the corresponding trait definition hashes for the entries in traitIds.
+    #[serde(rename = "traitHashes")]
+    pub trait_hashes: Option<Vec<u32>>,
+
+    /// The unique identifier for this entity. Guaranteed to be unique for the type of entity, but not globally.
+    /// When entities refer to each other in Destiny content, it is this hash that they are referring to.
+    #[serde(rename = "hash")]
+    pub hash: u32,
+
+    /// The index of the entity as it was found in the investment tables.
+    #[serde(rename = "index")]
+    pub index: i32,
+
+    /// If this is true, then there is an entity with this identifier/type combination, but BNet is not yet allowed to show it. Sorry!
+    #[serde(rename = "redacted")]
+    pub redacted: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemTooltipNotification {
+    #[serde(rename = "displayString")]
+    pub display_string: Option<String>,
+
+    #[serde(rename = "displayStyle")]
+    pub display_style: Option<String>,
+}
+
+/// If the item can exist in an inventory - the overwhelming majority of them can and do - then this is the basic properties regarding the item's relationship with the inventory.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemInventoryBlockDefinition {
+    /// If this string is populated, you can't have more than one stack with this label in a given inventory. Note that this is different from the equipping block's unique label, which is used for equipping uniqueness.
+    #[serde(rename = "stackUniqueLabel")]
+    pub stack_unique_label: Option<String>,
+
+    /// The maximum quantity of this item that can exist in a stack.
+    #[serde(rename = "maxStackSize")]
+    pub max_stack_size: i32,
+
+    /// The hash identifier for the DestinyInventoryBucketDefinition to which this item belongs. I should have named this "bucketHash", but too many things refer to it now. Sigh.
+    #[serde(rename = "bucketTypeHash")]
+    pub bucket_type_hash: u32,
+
+    /// If the item is picked up by the lost loot queue, this is the hash identifier for the DestinyInventoryBucketDefinition into which it will be placed. Again, I should have named this recoveryBucketHash instead.
+    #[serde(rename = "recoveryBucketTypeHash")]
+    pub recovery_bucket_type_hash: u32,
+
+    /// The hash identifier for the Tier Type of the item, use to look up its DestinyItemTierTypeDefinition if you need to show localized data for the item's tier.
+    #[serde(rename = "tierTypeHash")]
+    pub tier_type_hash: u32,
+
+    /// If TRUE, this item is instanced. Otherwise, it is a generic item that merely has a quantity in a stack (like Glimmer).
+    #[serde(rename = "isInstanceItem")]
+    pub is_instance_item: bool,
+
+    /// The localized name of the tier type, which is a useful shortcut so you don't have to look up the definition every time. However, it's mostly a holdover from days before we had a DestinyItemTierTypeDefinition to refer to.
+    #[serde(rename = "tierTypeName")]
+    pub tier_type_name: Option<String>,
+
+    /// The enumeration matching the tier type of the item to known values, again for convenience sake.
+    #[serde(rename = "tierType")]
+    pub tier_type: TierType,
+
+    /// The tooltip message to show, if any, when the item expires.
+    #[serde(rename = "expirationTooltip")]
+    pub expiration_tooltip: Option<String>,
+
+    /// If the item expires while playing in an activity, we show a different message.
+    #[serde(rename = "expiredInActivityMessage")]
+    pub expired_in_activity_message: Option<String>,
+
+    /// If the item expires in orbit, we show a... more different message. ("Consummate V's, consummate!")
+    #[serde(rename = "expiredInOrbitMessage")]
+    pub expired_in_orbit_message: Option<String>,
+
+    #[serde(rename = "suppressExpirationWhenObjectivesComplete")]
+    pub suppress_expiration_when_objectives_complete: bool,
+
+    /// A reference to the associated crafting 'recipe' item definition, if this item can be crafted.
+    #[serde(rename = "recipeItemHash")]
+    pub recipe_item_hash: Option<u32>,
+}
+
+/// Information about the item's calculated stats, with as much data as we can find for the stats without having an actual instance of the item.
+/// Note that this means the entire concept of providing these stats is fundamentally insufficient: we cannot predict with 100% accuracy the conditions under which an item can spawn, so we use various heuristics to attempt to simulate the conditions as accurately as possible. Actual stats for items in-game can and will vary, but these should at least be useful base points for comparison and display.
+/// It is also worth noting that some stats, like Magazine size, have further calculations performed on them by scripts in-game and on the game servers that BNet does not have access to. We cannot know how those stats are further transformed, and thus some stats will be inaccurate even on instances of items in BNet vs. how they appear in-game. This is a known limitation of our item statistics, without any planned fix.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemStatBlockDefinition {
+    /// If true, the game won't show the "primary" stat on this item when you inspect it.
+    /// NOTE: This is being manually mapped, because I happen to want it in a block that isn't going to directly create this derivative block.
+    #[serde(rename = "disablePrimaryStatDisplay")]
+    pub disable_primary_stat_display: bool,
+
+    /// If the item's stats are meant to be modified by a DestinyStatGroupDefinition, this will be the identifier for that definition.
+    /// If you are using live data or precomputed stats data on the DestinyInventoryItemDefinition.stats.stats property, you don't have to worry about statGroupHash and how it alters stats: the already altered stats are provided to you. But if you want to see how the sausage gets made, or perform computations yourself, this is valuable information.
+    #[serde(rename = "statGroupHash")]
+    pub stat_group_hash: Option<u32>,
+
+    /// If you are looking for precomputed values for the stats on a weapon, this is where they are stored. Technically these are the "Display" stat values. Please see DestinyStatsDefinition for what Display Stat Values means, it's a very long story... but essentially these are the closest values BNet can get to the item stats that you see in-game.
+    /// These stats are keyed by the DestinyStatDefinition's hash identifier for the stat that's found on the item.
+    #[serde(rename = "stats")]
+    pub stats: Option<HashMap<u32, DestinyInventoryItemStatDefinition>>,
+
+    /// A quick and lazy way to determine whether any stat other than the "primary" stat is actually visible on the item. Items often have stats that we return in case people find them useful, but they're not part of the "Stat Group" and thus we wouldn't display them in our UI. If this is False, then we're not going to display any of these stats other than the primary one.
+    #[serde(rename = "hasDisplayableStats")]
+    pub has_displayable_stats: bool,
+
+    /// This stat is determined to be the "primary" stat, and can be looked up in the stats or any other stat collection related to the item.
+    /// Use this hash to look up the stat's value using DestinyInventoryItemDefinition.stats.stats, and the renderable data for the primary stat in the related DestinyStatDefinition.
+    #[serde(rename = "primaryBaseStatHash")]
+    pub primary_base_stat_hash: u32,
+}
+
+/// Defines a specific stat value on an item, and the minimum/maximum range that we could compute for the item based on our heuristics for how the item might be generated.
+/// Not guaranteed to match real-world instances of the item, but should hopefully at least be close. If it's not close, let us know on the Bungie API forums.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyInventoryItemStatDefinition {
+    /// The hash for the DestinyStatDefinition representing this stat.
+    #[serde(rename = "statHash")]
+    pub stat_hash: u32,
+
+    /// This value represents the stat value assuming the minimum possible roll but accounting for any mandatory bonuses that should be applied to the stat on item creation.
+    /// In Destiny 1, this was different from the "minimum" value because there were certain conditions where an item could be theoretically lower level/value than the initial roll.
+    /// In Destiny 2, this is not possible unless Talent Grids begin to be used again for these purposes or some other system change occurs... thus in practice, value and minimum should be the same in Destiny 2. Good riddance.
+    #[serde(rename = "value")]
+    pub value: i32,
+
+    /// The minimum possible value for this stat that we think the item can roll.
+    #[serde(rename = "minimum")]
+    pub minimum: i32,
+
+    /// The maximum possible value for this stat that we think the item can roll.
+    /// WARNING: In Destiny 1, this field was calculated using the potential stat rolls on the item's talent grid. In Destiny 2, items no longer have meaningful talent grids and instead have sockets: but the calculation of this field was never altered to adapt to this change. As such, this field should be considered deprecated until we can address this oversight.
+    #[serde(rename = "maximum")]
+    pub maximum: i32,
+
+    /// The maximum possible value for the stat as shown in the UI, if it is being shown somewhere that reveals maximum in the UI (such as a bar chart-style view).
+    /// This is pulled directly from the item's DestinyStatGroupDefinition, and placed here for convenience.
+    /// If not returned, there is no maximum to use (and thus the stat should not be shown in a way that assumes there is a limit to the stat)
+    #[serde(rename = "displayMaximum")]
+    pub display_maximum: Option<i32>,
+}
+
+/// Items that can be equipped define this block. It contains information we need to understand how and when the item can be equipped.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyEquippingBlockDefinition {
+    /// If the item is part of a gearset, this is a reference to that gearset item.
+    #[serde(rename = "gearsetItemHash")]
+    pub gearset_item_hash: Option<u32>,
+
+    /// If defined, this is the label used to check if the item has other items of matching types already equipped.
+    /// For instance, when you aren't allowed to equip more than one Exotic Weapon, that's because all exotic weapons have identical uniqueLabels and the game checks the to-be-equipped item's uniqueLabel vs. all other already equipped items (other than the item in the slot that's about to be occupied).
+    #[serde(rename = "uniqueLabel")]
+    pub unique_label: Option<String>,
+
+    /// The hash of that unique label. Does not point to a specific definition.
+    #[serde(rename = "uniqueLabelHash")]
+    pub unique_label_hash: u32,
+
+    /// An equipped item *must* be equipped in an Equipment Slot. This is the hash identifier of the DestinyEquipmentSlotDefinition into which it must be equipped.
+    #[serde(rename = "equipmentSlotTypeHash")]
+    pub equipment_slot_type_hash: u32,
+
+    /// These are custom attributes on the equippability of the item.
+    /// For now, this can only be "equip on acquire", which would mean that the item will be automatically equipped as soon as you pick it up.
+    /// The raw bits of the `EquippingItemBlockAttributes` flags.
+    #[serde(rename = "attributes")]
+    pub attributes: u32,
+
+    /// Ammo type used by a weapon is no longer determined by the bucket in which it is contained. If the item has an ammo type - i.e. if it is a weapon - this will be the type of ammunition expected.
+    #[serde(rename = "ammoType")]
+    pub ammo_type: DestinyAmmunitionType,
+
+    /// These are strings that represent the possible Game/Account/Character state failure conditions that can occur when trying to equip the item. They match up one-to-one with requiredUnlockExpressions.
+    #[serde(rename = "displayStrings")]
+    pub display_strings: Option<Vec<String>>,
+}
+
+/// This defines an item's "Value". Unfortunately, this appears to be used in different ways depending on the way that the item itself is used.
+/// For items being sold at a Vendor, this is the default "sale price" of the item. These days, the vendor itself almost always sets the price, but it still possible for the price to fall back to this value. For quests, it is a preview of rewards you can gain by completing the quest. For dummy items, if the itemValue refers to an Emblem, it is the emblem that should be shown as the reward. (jeez louise)
+/// It will likely be used in a number of other ways in the future, it appears to be a bucket where they put arbitrary items and quantities into the item.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemValueBlockDefinition {
+    /// References to the items that make up this item's "value", and the quantity.
+    #[serde(rename = "itemValue")]
+    pub item_value: Option<Vec<DestinyItemQuantity>>,
+
+    /// If there's a localized text description of the value provided, this will be said description.
+    #[serde(rename = "valueDescription")]
+    pub value_description: Option<String>,
+}
+
+/// If defined, the item has at least one socket.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemSocketBlockDefinition {
+    /// This was supposed to be a string that would give per-item details about sockets. In practice, it turns out that all this ever has is the localized word "details". ... that's lame, but perhaps it will become something cool in the future.
+    #[serde(rename = "detail")]
+    pub detail: Option<String>,
+
+    /// Each non-intrinsic (or mutable) socket on an item is defined here. Check inside for more info.
+    #[serde(rename = "socketEntries")]
+    pub socket_entries: Option<Vec<DestinyItemSocketEntryDefinition>>,
+
+    /// Each intrinsic (or immutable/permanent) socket on an item is defined here, along with the plug that is permanently affixed to the socket.
+    #[serde(rename = "intrinsicSockets")]
+    pub intrinsic_sockets: Option<Vec<DestinyItemIntrinsicSocketEntryDefinition>>,
+
+    /// A convenience property, that refers to the sockets in the "sockets" property, pre-grouped by category and ordered in the manner that they should be grouped in the UI. You could form this yourself with the existing data, but why would you want to? Enjoy life man.
+    #[serde(rename = "socketCategories")]
+    pub socket_categories: Option<Vec<DestinyItemSocketCategoryDefinition>>,
+}
+
+/// The definition information for a specific socket on an item. This will determine how the socket behaves in-game.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemSocketEntryDefinition {
+    /// All sockets have a type, and this is the hash identifier for this particular type. Use it to look up the DestinySocketTypeDefinition: read there for more information on how socket types affect the behavior of the socket.
+    #[serde(rename = "socketTypeHash")]
+    pub socket_type_hash: u32,
+
+    /// If a valid hash, this is the hash identifier for the DestinyInventoryItemDefinition representing the Plug that will be initially inserted into the item on item creation. Otherwise, this Socket will either start without a plug inserted, or will have one randomly inserted.
+    #[serde(rename = "singleInitialItemHash")]
+    pub single_initial_item_hash: u32,
+
+    /// This is a list of pre-determined plugs that can *always* be plugged into this socket, without the character having the plug in their inventory.
+    /// If this list is populated, you will not be allowed to plug an arbitrary item in the socket: you will only be able to choose from one of these reusable plugs.
+    #[serde(rename = "reusablePlugItems")]
+    pub reusable_plug_items: Option<Vec<DestinyItemSocketEntryPlugItemDefinition>>,
+
+    /// If this is true, then the socket will not be initialized with a plug if the item is purchased from a Vendor.
+    /// Remember that Vendors are much more than conceptual vendors: they include "Collection Kiosks" and other entities. See DestinyVendorDefinition for more information.
+    #[serde(rename = "preventInitializationOnVendorPurchase")]
+    pub prevent_initialization_on_vendor_purchase: bool,
+
+    /// If this is true, the perks provided by this socket shouldn't be shown in the item's tooltip. This might be useful if it's providing a hidden bonus, or if the bonus is less important than other benefits on the item.
+    #[serde(rename = "hidePerksInItemTooltip")]
+    pub hide_perks_in_item_tooltip: bool,
+
+    /// Indicates where you should go to get plugs for this socket. This will affect how you populate your UI, as well as what plugs are valid for this socket. It's an alternative to having to check for the existence of certain properties (reusablePlugItems for example) to infer where plugs should come from.
+    /// The raw bits of the `SocketPlugSources` flags.
+    #[serde(rename = "plugSources")]
+    pub plug_sources: u32,
+
+    /// If this socket's plugs come from a reusable DestinyPlugSetDefinition, this is the identifier for that set. We added this concept to reduce some major duplication that's going to come from sockets as replacements for what was once implemented as large sets of items and kiosks (like Emotes).
+    /// As of Shadowkeep, these will come up much more frequently and be driven by game content rather than custom curation.
+    #[serde(rename = "reusablePlugSetHash")]
+    pub reusable_plug_set_hash: Option<u32>,
+
+    /// This field replaces "randomizedPlugItems" as of Shadowkeep launch. If a socket has randomized plugs, this is a pointer to the set of plugs that could be used, as defined in DestinyPlugSetDefinition.
+    /// If null, the item has no randomized plugs.
+    #[serde(rename = "randomizedPlugSetHash")]
+    pub randomized_plug_set_hash: Option<u32>,
+
+    /// If true, then this socket is visible in the item's "default" state. If you have an instance, you should always check the runtime state, as that can override this visibility setting: but if you're looking at the item on a conceptual level, this property can be useful for hiding data such as legacy sockets - which remain defined on items for infrastructure purposes, but can be confusing for users to see.
+    #[serde(rename = "defaultVisible")]
+    pub default_visible: bool,
+}
+
+/// The definition of a known, reusable plug that can be applied to a socket.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemSocketEntryPlugItemDefinition {
+    /// The hash identifier of a DestinyInventoryItemDefinition representing the plug that can be inserted.
+    #[serde(rename = "plugItemHash")]
+    pub plug_item_hash: u32,
+}
+
+/// Represents a socket that has a plug associated with it intrinsically. This is useful for situations where the weapon needs to have a visual plug/Mod on it, but that plug/Mod should never change.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemIntrinsicSocketEntryDefinition {
+    /// Indicates the plug that is intrinsically inserted into this socket.
+    #[serde(rename = "plugItemHash")]
+    pub plug_item_hash: u32,
+
+    /// Indicates the type of this intrinsic socket.
+    #[serde(rename = "socketTypeHash")]
+    pub socket_type_hash: u32,
+
+    /// If true, then this socket is visible in the item's "default" state. If you have an instance, you should always check the runtime state, as that can override this visibility setting: but if you're looking at the item on a conceptual level, this property can be useful for hiding data such as legacy sockets - which remain defined on items for infrastructure purposes, but can be confusing for users to see.
+    #[serde(rename = "defaultVisible")]
+    pub default_visible: bool,
+}
+
+/// Sockets are grouped into categories in the UI. These define which category and which sockets are under that category.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinyItemSocketCategoryDefinition {
+    /// The hash for the Socket Category: a quick way to go get the header display information for the category. Use it to look up DestinySocketCategoryDefinition info.
+    #[serde(rename = "socketCategoryHash")]
+    pub socket_category_hash: u32,
+
+    /// Use these indexes to look up the sockets in the "sockets.socketEntries" property on the item definition. These are the indexes under the category, in game-rendered order.
+    #[serde(rename = "socketIndexes")]
+    pub socket_indexes: Option<Vec<i32>>,
+}
+
+/// Defines a canonical "Season" of Destiny: a range of a few months where the game highlights certain challenges, provides new loot, has new Clan-related rewards and celebrates various seasonal events.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct DestinySeasonDefinition {
+    #[serde(rename = "displayProperties")]
+    pub display_properties: Option<DestinyDisplayPropertiesDefinition>,
+
+    #[serde(rename = "backgroundImagePath")]
+    pub background_image_path: Option<String>,
+
+    #[serde(rename = "seasonNumber")]
+    pub season_number: i32,
+
+    /// An RFC 3339 timestamp, as Bungie sent it.
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+
+    /// An RFC 3339 timestamp, as Bungie sent it.
+    #[serde(rename = "endDate")]
+    pub end_date: Option<String>,
+
+    #[serde(rename = "seasonPassHash")]
+    pub season_pass_hash: Option<u32>,
+
+    #[serde(rename = "artifactItemHash")]
+    pub artifact_item_hash: Option<u32>,
+
+    #[serde(rename = "sealPresentationNodeHash")]
+    pub seal_presentation_node_hash: Option<u32>,
+
+    #[serde(rename = "seasonalChallengesPresentationNodeHash")]
+    pub seasonal_challenges_presentation_node_hash: Option<u32>,
+
+    /// The unique identifier for this entity. Guaranteed to be unique for the type of entity, but not globally.
+    /// When entities refer to each other in Destiny content, it is this hash that they are referring to.
+    #[serde(rename = "hash")]
+    pub hash: u32,
+
+    /// The index of the entity as it was found in the investment tables.
+    #[serde(rename = "index")]
+    pub index: i32,
+
+    /// If this is true, then there is an entity with this identifier/type combination, but BNet is not yet allowed to show it. Sorry!
+    #[serde(rename = "redacted")]
+    pub redacted: bool,
+}
+
+/// An enumeration that indicates the high-level "type" of the item, attempting to iron out the context specific differences for specific instances of an entity. For instance, though a weapon may be of various weapon "Types", in DestinyItemType they are all classified as "Weapon". This allows for better filtering on a higher level of abstraction for the concept of types.
+/// This enum is provided for historical compatibility with Destiny 1, but an ideal alternative is to use DestinyItemCategoryDefinitions and the DestinyItemDefinition.itemCategories property instead. Item Categories allow for arbitrary hierarchies of specificity, and for items to belong to multiple categories across multiple hierarchies simultaneously. For this enum, we pick a single type as a "best guess" fit.
+/// NOTE: This is not all of the item types available, and some of these are holdovers from Destiny 1 that may or may not still exist.
+/// I keep updating these because they're so damn convenient. I guess I shouldn't fight it.
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DestinyItemType {
+    None = 0,
+    Currency = 1,
+    Armor = 2,
+    Weapon = 3,
+    Message = 7,
+    Engram = 8,
+    Consumable = 9,
+    ExchangeMaterial = 10,
+    MissionReward = 11,
+    QuestStep = 12,
+    QuestStepComplete = 13,
+    Emblem = 14,
+    Quest = 15,
+    Subclass = 16,
+    ClanBanner = 17,
+    Aura = 18,
+    Mod = 19,
+    Dummy = 20,
+    Ship = 21,
+    Vehicle = 22,
+    Emote = 23,
+    Ghost = 24,
+    Package = 25,
+    Bounty = 26,
+    Wrapper = 27,
+    SeasonalArtifact = 28,
+    Finisher = 29,
+    Pattern = 30,
+}
+
+impl Default for DestinyItemType {
+    fn default() -> Self {
+        DestinyItemType::None
+    }
+}
+
+/// This Enumeration further classifies items by more specific categorizations than DestinyItemType. The "Sub-Type" is where we classify and categorize items one step further in specificity: "Auto Rifle" instead of just "Weapon" for example, or "Vanguard Bounty" instead of merely "Bounty".
+/// These sub-types are provided for historical compatibility with Destiny 1, but an ideal alternative is to use DestinyItemCategoryDefinitions and the DestinyItemDefinition.itemCategories property instead. Item Categories allow for arbitrary hierarchies of specificity, and for items to belong to multiple categories across multiple hierarchies simultaneously. For this enum, we pick a single type as a "best guess" fit.
+/// NOTE: This is not all of the item types available, and some of these are holdovers from Destiny 1 that may or may not still exist.
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DestinyItemSubType {
+    None = 0,
+    /// DEPRECATED. Items can be both "Crucible" and something else interesting.
+    Crucible = 1,
+    /// DEPRECATED. An item can both be "Vanguard" and something else.
+    Vanguard = 2,
+    /// DEPRECATED. An item can both be Exotic and something else.
+    Exotic = 5,
+    AutoRifle = 6,
+    Shotgun = 7,
+    Machinegun = 8,
+    HandCannon = 9,
+    RocketLauncher = 10,
+    FusionRifle = 11,
+    SniperRifle = 12,
+    PulseRifle = 13,
+    ScoutRifle = 14,
+    /// DEPRECATED. An item can both be CRM and something else.
+    Crm = 16,
+    Sidearm = 17,
+    Sword = 18,
+    Mask = 19,
+    Shader = 20,
+    Ornament = 21,
+    FusionRifleLine = 22,
+    GrenadeLauncher = 23,
+    SubmachineGun = 24,
+    TraceRifle = 25,
+    HelmetArmor = 26,
+    GauntletsArmor = 27,
+    ChestArmor = 28,
+    LegArmor = 29,
+    ClassArmor = 30,
+    Bow = 31,
+    DummyRepeatableBounty = 32,
+    Glaive = 33,
+}
+
+impl Default for DestinyItemSubType {
+    fn default() -> Self {
+        DestinyItemSubType::None
+    }
+}
+
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TierType {
+    Unknown = 0,
+    Currency = 1,
+    Basic = 2,
+    Common = 3,
+    Rare = 4,
+    Superior = 5,
+    Exotic = 6,
+}
+
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DestinyClass {
+    Titan = 0,
+    Hunter = 1,
+    Warlock = 2,
+    Unknown = 3,
+}
+
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    None = 0,
+    Kinetic = 1,
+    Arc = 2,
+    Thermal = 3,
+    Void = 4,
+    Raid = 5,
+    Stasis = 6,
+    Strand = 7,
+}
+
+#[repr(i32)]
+#[derive(Deserialize_repr, Serialize_repr, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DestinyAmmunitionType {
+    None = 0,
+    Primary = 1,
+    Special = 2,
+    Heavy = 3,
+    Unknown = 4,
+}
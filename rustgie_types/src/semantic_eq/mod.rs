@@ -0,0 +1,56 @@
+﻿//! Equality for responses that ignores fields Bungie changes on every request.
+//!
+//! Two fetches of an unchanged profile still differ in their minted timestamps, and paged results echo the query and carry a fresh continuation token, so `PartialEq` can't tell a cache whether anything meaningful changed.
+//! The volatile fields for each response type are listed in one table at the bottom of this file; add to it when a new response grows one.
+
+/// Equality that skips a curated list of per-request fields.
+pub trait SemanticEq {
+    /// Whether `self` and `other` are equal once their volatile fields are disregarded.
+    /// This clones `self` once, which is still far cheaper than the request that produced it.
+    fn eq_ignoring_volatile(&self, other: &Self) -> bool;
+}
+
+impl<T: SemanticEq> SemanticEq for Option<T> {
+    fn eq_ignoring_volatile(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eq_ignoring_volatile(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+macro_rules! volatile_fields {
+    ($($ty:ty { $($field:ident),+ $(,)? })*) => {$(
+        impl SemanticEq for $ty {
+            fn eq_ignoring_volatile(&self, other: &Self) -> bool {
+                let mut masked = self.clone();
+                $(masked.$field = other.$field.clone();)+
+                masked == *other
+            }
+        }
+    )*};
+}
+
+volatile_fields! {
+    crate::destiny::responses::DestinyProfileResponse { response_minted_timestamp, secondary_components_minted_timestamp }
+
+    crate::forum::PostSearchResponse { query, replacement_continuation_token }
+    crate::groups_v2::GetGroupsForMemberResponse { query, replacement_continuation_token }
+    crate::groups_v2::GroupMembershipSearchResponse { query, replacement_continuation_token }
+    crate::groups_v2::GroupPotentialMembershipSearchResponse { query, replacement_continuation_token }
+    crate::groups_v2::GroupSearchResponse { query, replacement_continuation_token }
+    crate::queries::SearchResult { query, replacement_continuation_token }
+    crate::SearchResultOfContentItemPublicContract { query, replacement_continuation_token }
+    crate::SearchResultOfDestinyEntitySearchResultItem { query, replacement_continuation_token }
+    crate::SearchResultOfFireteamResponse { query, replacement_continuation_token }
+    crate::SearchResultOfFireteamSummary { query, replacement_continuation_token }
+    crate::SearchResultOfGroupBan { query, replacement_continuation_token }
+    crate::SearchResultOfGroupMember { query, replacement_continuation_token }
+    crate::SearchResultOfGroupMemberApplication { query, replacement_continuation_token }
+    crate::SearchResultOfGroupMembership { query, replacement_continuation_token }
+    crate::SearchResultOfGroupPotentialMembership { query, replacement_continuation_token }
+    crate::SearchResultOfGroupV2Card { query, replacement_continuation_token }
+    crate::SearchResultOfPostResponse { query, replacement_continuation_token }
+    crate::SearchResultOfTrendingEntry { query, replacement_continuation_token }
+}
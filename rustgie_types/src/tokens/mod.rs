@@ -95,7 +95,7 @@ pub struct TwitchDropHistoryResponse {
     pub created_at: Option<OffsetDateTime>,
 
     #[serde(rename = "ClaimState")]
-    pub claim_state: Option<u8>,
+    pub claim_state: Option<crate::streaming::DropStateEnum>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -88,7 +88,8 @@ pub struct UserInfoCard {
     pub icon_path: Option<String>,
 
     /// If there is a cross save override in effect, this value will tell you the type that is overridding this one.
-    #[serde(rename = "crossSaveOverride")]
+    /// Missing from the partial cards in [`DestinyErrorProfile`](crate::destiny::responses::DestinyErrorProfile), where it reads as None.
+    #[serde(rename = "crossSaveOverride", default)]
     pub cross_save_override: crate::BungieMembershipType,
 
     /// The list of Membership Types indicating the platforms on which this Membership can be used.
@@ -97,7 +98,8 @@ pub struct UserInfoCard {
     pub applicable_membership_types: Option<Vec<crate::BungieMembershipType>>,
 
     /// If True, this is a public user membership.
-    #[serde(rename = "isPublic")]
+    /// Missing from the partial cards in [`DestinyErrorProfile`](crate::destiny::responses::DestinyErrorProfile), where it reads as false.
+    #[serde(rename = "isPublic", default)]
     pub is_public: bool,
 
     /// Type of the membership. Not necessarily the native type.
@@ -245,6 +247,25 @@ pub struct GeneralUser {
     pub egs_display_name: Option<String>,
 }
 
+impl GeneralUser {
+    /// The partner display name Bungie caches on the profile for `cred_type`, if that partner has one and it is set.
+    /// These are only present for credentials the user has made public.
+    pub fn partner_display_name(&self, cred_type: crate::BungieCredentialType) -> Option<&str> {
+        match cred_type {
+            crate::BungieCredentialType::Xuid => self.xbox_display_name.as_deref(),
+            crate::BungieCredentialType::Psnid => self.psn_display_name.as_deref(),
+            crate::BungieCredentialType::Facebook => self.fb_display_name.as_deref(),
+            crate::BungieCredentialType::SteamId => self.steam_display_name.as_deref(),
+            crate::BungieCredentialType::BattleNetId => self.blizzard_display_name.as_deref(),
+            crate::BungieCredentialType::StadiaId => self.stadia_display_name.as_deref(),
+            crate::BungieCredentialType::TwitchId => self.twitch_display_name.as_deref(),
+            crate::BungieCredentialType::EgsId => self.egs_display_name.as_deref(),
+            _ => None,
+        }
+        .filter(|name| !name.is_empty())
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct UserToUserContext {
     #[serde(rename = "isFollowing")]
@@ -354,7 +375,37 @@ pub struct EmailSettings {
     pub views: Option<HashMap<String, crate::user::EmailViewDefinition>>,
 }
 
+impl EmailSettings {
+    /// The identifiers of the opt-in categories whose flags are all set in `flags`, sorted.
+    /// Bungie doesn't expose a user's own flags through the public API; pass them from wherever your application stores them.
+    pub fn opted_in(&self, flags: enumflags2::BitFlags<OptInFlags>) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .opt_in_definitions
+            .iter()
+            .flatten()
+            .filter(|(_, definition)| !definition.value.is_empty() && flags.contains(definition.value))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// The identifiers of the subscriptions whose bit is set in `subscriptions`, sorted.
+    pub fn subscribed(&self, subscriptions: i64) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .subscription_definitions
+            .iter()
+            .flatten()
+            .filter(|(_, definition)| definition.value != 0 && subscriptions & definition.value == definition.value)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort_unstable();
+        names
+    }
+}
+
 /// Defines a single opt-in category: a wide-scoped permission to send emails for the subject related to the opt-in.
+#[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct EmailOptInDefinition {
     /// The unique identifier for this opt-in category.
@@ -362,6 +413,7 @@ pub struct EmailOptInDefinition {
     pub name: Option<String>,
 
     /// The flag value for this opt-in category. For historical reasons, this is defined as a flags enum.
+    #[serde_as(as = "crate::diagnostics::LenientFlags")]
     #[serde(rename = "value")]
     pub value: enumflags2::BitFlags<crate::user::OptInFlags>,
 
@@ -478,6 +530,7 @@ pub struct EmailViewDefinition {
     pub view_settings: Option<Vec<crate::user::EmailViewDefinitionSetting>>,
 }
 
+#[serde_as]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct EmailViewDefinitionSetting {
     /// The identifier for this UI Setting, which can be used to relate it to custom strings or other data as desired.
@@ -493,6 +546,7 @@ pub struct EmailViewDefinitionSetting {
     pub set_by_default: bool,
 
     /// The OptInFlags value to set or clear if this setting is set or cleared in the UI. It is the aggregate of all underlying opt-in flags related to this setting.
+    #[serde_as(as = "crate::diagnostics::LenientFlags")]
     #[serde(rename = "optInAggregateValue")]
     pub opt_in_aggregate_value: enumflags2::BitFlags<crate::user::OptInFlags>,
 
@@ -500,3 +554,89 @@ pub struct EmailViewDefinitionSetting {
     #[serde(rename = "subscriptions")]
     pub subscriptions: Option<Vec<crate::user::EmailSubscriptionDefinition>>,
 }
+
+/// The name, avatar, and membership to show for a player, resolved from the user cards attached to a group member, fireteam member, or post game carnage report entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayIdentity {
+    /// The Bungie global display name when set, otherwise the best platform display name available.
+    pub name: String,
+
+    /// The Bungie global display name code. Only present when `name` is a Bungie global display name.
+    pub code: Option<u16>,
+
+    /// The bungie.net avatar, or the Destiny membership's icon when no bungie.net account is linked, as an absolute URL.
+    pub avatar_url: Option<url::Url>,
+
+    /// The Destiny membership to use in further API calls, or the bungie.net membership when no Destiny membership was returned.
+    pub membership: (crate::BungieMembershipType, i64),
+}
+
+impl DisplayIdentity {
+    /// The name as shown in game, with the code padded to four digits (e.g. `Guardian#0042`).
+    pub fn full_name(&self) -> String {
+        match self.code {
+            Some(code) => format!("{}#{:04}", self.name, code),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Resolves an identity in the documented order: the Bungie global display name (from the Destiny card, then the bungie.net card), then the Destiny card's platform name, then the bungie.net display name.
+    pub(crate) fn resolve(destiny: Option<IdentityCard>, bungie_net: Option<&UserInfoCard>) -> Option<Self> {
+        let bungie_net = bungie_net.map(IdentityCard::from);
+        let cards: Vec<&IdentityCard> = destiny.iter().chain(bungie_net.iter()).collect();
+        let membership = cards.first()?.membership;
+
+        let global = cards.iter().find_map(|card| {
+            non_empty(card.global_name).map(|name| (name, card.global_code.and_then(|code| u16::try_from(code).ok())))
+        });
+        let (name, code) = match global {
+            Some((name, code)) => (name, code),
+            None => (cards.iter().find_map(|card| non_empty(card.platform_name)).unwrap_or_default(), None),
+        };
+
+        let avatar_url = bungie_net
+            .as_ref()
+            .and_then(|card| non_empty(card.icon_path))
+            .or_else(|| destiny.as_ref().and_then(|card| non_empty(card.icon_path)))
+            .and_then(|path| url::Url::parse("https://www.bungie.net").ok()?.join(path).ok());
+
+        Some(DisplayIdentity {
+            name: name.to_string(),
+            code,
+            avatar_url,
+            membership,
+        })
+    }
+}
+
+impl Display for DisplayIdentity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_name())
+    }
+}
+
+/// The identity-related fields shared by `UserInfoCard`, `GroupUserInfoCard`, and `FireteamUserInfoCard`.
+pub(crate) struct IdentityCard<'a> {
+    pub(crate) global_name: Option<&'a str>,
+    pub(crate) global_code: Option<i16>,
+    /// The platform name to prefer for this card, e.g. the name the clan server last saw.
+    pub(crate) platform_name: Option<&'a str>,
+    pub(crate) icon_path: Option<&'a str>,
+    pub(crate) membership: (crate::BungieMembershipType, i64),
+}
+
+impl<'a> From<&'a UserInfoCard> for IdentityCard<'a> {
+    fn from(card: &'a UserInfoCard) -> Self {
+        IdentityCard {
+            global_name: card.bungie_global_display_name.as_deref(),
+            global_code: card.bungie_global_display_name_code,
+            platform_name: card.display_name.as_deref(),
+            icon_path: card.icon_path.as_deref(),
+            membership: (card.membership_type, card.membership_id),
+        }
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.filter(|v| !v.trim().is_empty())
+}
@@ -14,3 +14,19 @@ pub struct GetCredentialTypesForAccountResponse {
     #[serde(rename = "credentialAsString")]
     pub credential_as_string: Option<String>,
 }
+
+/// Whether `credentials` includes a credential of `cred_type`, public or not.
+/// Without OAuth (or for another user's account) Bungie only lists public credentials, so a `false` here can also mean "linked but private".
+pub fn has_linked(credentials: &[GetCredentialTypesForAccountResponse], cred_type: crate::BungieCredentialType) -> bool {
+    credentials.iter().any(|c| c.credential_type == cred_type)
+}
+
+/// The display names of the account's public credentials, in the order Bungie returned them.
+/// Credentials without a display name are skipped.
+pub fn public_display_names(credentials: &[GetCredentialTypesForAccountResponse]) -> Vec<(crate::BungieCredentialType, &str)> {
+    credentials
+        .iter()
+        .filter(|c| c.is_public)
+        .filter_map(|c| c.credential_display_name.as_deref().map(|name| (c.credential_type, name)))
+        .collect()
+}